@@ -622,6 +622,17 @@ where
     /// This is the main entrypoint for verifying proofs.
     /// You can find a specification of the verifier algorithm in:
     /// https://github.com/AleoNet/protocol-docs
+    #[cfg_attr(
+        feature = "spans",
+        tracing::instrument(
+            name = "Varuna::verify_batch",
+            skip_all,
+            fields(
+                num_circuits = keys_to_inputs.len(),
+                num_instances = keys_to_inputs.values().map(|inputs| inputs.len()).sum::<usize>()
+            )
+        )
+    )]
     fn verify_batch<B: Borrow<Self::VerifierInput>>(
         universal_verifier: &Self::UniversalVerifier,
         fs_parameters: &Self::FSParameters,
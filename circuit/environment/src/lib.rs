@@ -26,6 +26,9 @@ pub use canary_circuit::*;
 pub mod circuit;
 pub use circuit::*;
 
+pub mod devnet_circuit;
+pub use devnet_circuit::*;
+
 pub mod environment;
 pub use environment::*;
 
@@ -17,6 +17,9 @@ mod metrics;
 #[allow(unused_imports)]
 pub use metrics::*;
 
+mod network_circuit;
+pub(crate) use network_circuit::network_circuit;
+
 mod scope;
 #[allow(unused_imports)]
 pub use scope::*;
@@ -16,9 +16,14 @@
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
 
+mod macros;
+
 pub mod canary_v0;
 pub use canary_v0::*;
 
+pub mod devnet_v0;
+pub use devnet_v0::*;
+
 pub mod testnet_v0;
 pub use testnet_v0::*;
 
@@ -32,6 +32,7 @@ pub mod modules {
         Assignment,
         CanaryCircuit,
         Circuit,
+        DevnetCircuit,
         Eject,
         Environment,
         Inject,
@@ -0,0 +1,305 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::TRANSACTION_PREFIX;
+use snarkvm_console_algorithms::{
+    BHP256,
+    BHP512,
+    BHP768,
+    BHP1024,
+    Blake2Xs,
+    Keccak256,
+    Keccak384,
+    Keccak512,
+    Pedersen64,
+    Pedersen128,
+    Poseidon2,
+    Poseidon4,
+    Poseidon8,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+};
+use snarkvm_parameters::devnet::DevnetProtocol;
+
+crate::macros::network_primitives!(DevnetV0, DEVNET);
+
+/// The name of the inclusion circuit key in the devnet keys directory.
+const DEVNET_INCLUSION_KEY_NAME: &str = "inclusion";
+
+/// The proving key for the inclusion circuit, once loaded.
+static INCLUSION_PROVING_KEY: OnceCell<Arc<VarunaProvingKey<Console>>> = OnceCell::new();
+/// The verifying key for the inclusion circuit, once loaded.
+static INCLUSION_VERIFYING_KEY: OnceCell<Arc<VarunaVerifyingKey<Console>>> = OnceCell::new();
+
+/// Returns the devnet parameters.
+///
+/// # Panics
+///
+/// Panics if the devnet parameters can not be loaded, which is unreachable after a successful
+/// [`DevnetV0::configure`] or [`DevnetV0::initialize`].
+fn devnet_parameters() -> &'static snarkvm_parameters::devnet::DevnetParameters {
+    snarkvm_parameters::devnet::parameters()
+        .unwrap_or_else(|error| panic!("Failed to load the devnet parameters: {error}"))
+}
+
+/// Returns the devnet protocol parameters.
+fn devnet_protocol() -> &'static DevnetProtocol {
+    devnet_parameters().protocol()
+}
+
+/// Returns the `credits.aleo` circuit keys of the given type (`prover` or `verifier`) in the devnet keys directory.
+fn load_devnet_credits_keys<T: FromBytes>(key_type: &str) -> Result<IndexMap<String, Arc<T>>> {
+    let parameters = snarkvm_parameters::devnet::parameters()?;
+    let mut keys = IndexMap::new();
+    for name in parameters.key_names()? {
+        if name != DEVNET_INCLUSION_KEY_NAME {
+            let key = parameters.load_key(&name, key_type)?;
+            keys.insert(name, Arc::new(key));
+        }
+    }
+    Ok(keys)
+}
+
+/// Returns the inclusion circuit key of the given type (`prover` or `verifier`) in the devnet keys directory.
+fn load_devnet_inclusion_key<T: FromBytes>(key_type: &str) -> Result<Arc<T>> {
+    let parameters = snarkvm_parameters::devnet::parameters()?;
+    Ok(Arc::new(parameters.load_key(DEVNET_INCLUSION_KEY_NAME, key_type)?))
+}
+
+/// Returns the inclusion circuit key in the given cell, loading it of the given type (`prover` or `verifier`).
+///
+/// # Panics
+///
+/// Panics if the key can not be loaded, which is unreachable after a successful
+/// [`DevnetV0::configure`] or [`DevnetV0::initialize`], as they load both inclusion circuit keys.
+fn devnet_inclusion_key<T: FromBytes>(cell: &'static OnceCell<Arc<T>>, key_type: &str) -> &'static Arc<T> {
+    cell.get_or_init(|| {
+        load_devnet_inclusion_key(key_type)
+            .unwrap_or_else(|error| panic!("Failed to load the devnet inclusion {key_type}: {error}"))
+    })
+}
+
+/// A network for private devnets and integration environments.
+///
+/// The genesis block (and with it, the starting committee), the restrictions list, the circuit keys, and
+/// the protocol parameters are loaded at startup from the configuration file given to [`DevnetV0::configure`],
+/// or from the file in the `SNARKVM_DEVNET_CONFIG` environment variable with [`DevnetV0::initialize`].
+/// One of them must succeed before the network is used, as the `Network` functions can not return an error.
+///
+/// The protocol parameters (the batch size, the block times, the epoch length, and the coinbase and fee schedules)
+/// are returned by the protocol parameter functions of the `Network` trait. The corresponding constants are
+/// deprecated and hold the default protocol parameters, as constants can not be loaded at startup, while
+/// `MAX_CERTIFICATES` holds the upper bound on the configured maximum number of certificates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DevnetV0;
+
+impl DevnetV0 {
+    /// Configures the devnet with the parameters in the given configuration file, and loads its inclusion circuit keys.
+    pub fn configure<P: AsRef<std::path::Path>>(path: P) -> Result<()> {
+        snarkvm_parameters::devnet::configure(path)?;
+        Self::initialize()
+    }
+
+    /// Loads the devnet parameters, from the file in the `SNARKVM_DEVNET_CONFIG` environment variable
+    /// if the devnet was not configured, and loads its inclusion circuit keys.
+    pub fn initialize() -> Result<()> {
+        snarkvm_parameters::devnet::parameters()?;
+        INCLUSION_PROVING_KEY.get_or_try_init(|| load_devnet_inclusion_key("prover"))?;
+        INCLUSION_VERIFYING_KEY.get_or_try_init(|| load_devnet_inclusion_key("verifier"))?;
+        Ok(())
+    }
+}
+
+impl Network for DevnetV0 {
+    /// The block hash type.
+    type BlockHash = AleoID<Field<Self>, { hrp2!("ab") }>;
+    /// The ratification ID type.
+    type RatificationID = AleoID<Field<Self>, { hrp2!("ar") }>;
+    /// The state root type.
+    type StateRoot = AleoID<Field<Self>, { hrp2!("sr") }>;
+    /// The transaction ID type.
+    type TransactionID = AleoID<Field<Self>, { hrp2!(TRANSACTION_PREFIX) }>;
+    /// The transition ID type.
+    type TransitionID = AleoID<Field<Self>, { hrp2!("au") }>;
+    /// The transmission checksum type.
+    type TransmissionChecksum = u128;
+
+    /// The default anchor time in seconds.
+    const ANCHOR_TIME: u16 = DevnetProtocol::DEFAULT.anchor_time;
+    /// The default expected time per block in seconds.
+    const BLOCK_TIME: u16 = DevnetProtocol::DEFAULT.block_time;
+    /// The block height from which consensus V2 rules apply.
+    const CONSENSUS_V2_HEIGHT: u32 = 0;
+    /// The block height from which consensus V3 rules apply.
+    const CONSENSUS_V3_HEIGHT: u32 = 0;
+    /// The default cost in microcredits per byte for the deployment transaction.
+    const DEPLOYMENT_FEE_MULTIPLIER: u64 = DevnetProtocol::DEFAULT.deployment_fee_multiplier;
+    /// The network edition.
+    const EDITION: u16 = 0;
+    /// The default constant that divides the storage polynomial.
+    const EXECUTION_STORAGE_FEE_SCALING_FACTOR: u64 = DevnetProtocol::DEFAULT.execution_storage_fee_scaling_factor;
+    /// The default maximum size execution transactions can be before a quadratic storage penalty applies.
+    const EXECUTION_STORAGE_PENALTY_THRESHOLD: u64 = DevnetProtocol::DEFAULT.execution_storage_penalty_threshold;
+    /// The default genesis block coinbase target.
+    const GENESIS_COINBASE_TARGET: u64 = DevnetProtocol::DEFAULT.genesis_coinbase_target;
+    /// The default genesis block proof target.
+    const GENESIS_PROOF_TARGET: u64 = DevnetProtocol::DEFAULT.genesis_proof_target;
+    /// The fixed timestamp of the genesis block.
+    const GENESIS_TIMESTAMP: i64 = 1727740800 /* 2024-10-01 00:00:00 UTC */;
+    /// The network ID.
+    const ID: u16 = 3;
+    /// The function name for the inclusion circuit.
+    const INCLUSION_FUNCTION_NAME: &'static str = MainnetV0::INCLUSION_FUNCTION_NAME;
+    /// The upper bound on the number of certificates in a batch.
+    const MAX_CERTIFICATES: u16 = DevnetProtocol::MAX_CERTIFICATES_LIMIT;
+    /// The default maximum number of solutions that can be included per block as a power of 2.
+    const MAX_SOLUTIONS_AS_POWER_OF_TWO: u8 = DevnetProtocol::DEFAULT.max_solutions_as_power_of_two;
+    /// The network name.
+    const NAME: &'static str = "Aleo Devnet (v0)";
+    /// The default number of blocks per epoch.
+    const NUM_BLOCKS_PER_EPOCH: u32 = DevnetProtocol::DEFAULT.num_blocks_per_epoch;
+    /// The default starting supply of Aleo credits.
+    const STARTING_SUPPLY: u64 = DevnetProtocol::DEFAULT.starting_supply;
+    /// The default cost in microcredits per constraint for the deployment transaction.
+    const SYNTHESIS_FEE_MULTIPLIER: u64 = DevnetProtocol::DEFAULT.synthesis_fee_multiplier;
+
+    /// Returns the maximum number of certificates in a batch.
+    fn max_certificates() -> u16 {
+        devnet_protocol().max_certificates
+    }
+
+    /// Returns the genesis block coinbase target.
+    fn genesis_coinbase_target() -> u64 {
+        devnet_protocol().genesis_coinbase_target
+    }
+
+    /// Returns the genesis block proof target.
+    fn genesis_proof_target() -> u64 {
+        devnet_protocol().genesis_proof_target
+    }
+
+    /// Returns the maximum number of solutions that can be included per block as a power of 2.
+    fn max_solutions_as_power_of_two() -> u8 {
+        devnet_protocol().max_solutions_as_power_of_two
+    }
+
+    /// Returns the starting supply of Aleo credits.
+    fn starting_supply() -> u64 {
+        devnet_protocol().starting_supply
+    }
+
+    /// Returns the cost in microcredits per byte for the deployment transaction.
+    fn deployment_fee_multiplier() -> u64 {
+        devnet_protocol().deployment_fee_multiplier
+    }
+
+    /// Returns the constant that divides the storage polynomial.
+    fn execution_storage_fee_scaling_factor() -> u64 {
+        devnet_protocol().execution_storage_fee_scaling_factor
+    }
+
+    /// Returns the maximum size execution transactions can be before a quadratic storage penalty applies.
+    fn execution_storage_penalty_threshold() -> u64 {
+        devnet_protocol().execution_storage_penalty_threshold
+    }
+
+    /// Returns the cost in microcredits per constraint for the deployment transaction.
+    fn synthesis_fee_multiplier() -> u64 {
+        devnet_protocol().synthesis_fee_multiplier
+    }
+
+    /// Returns the anchor time in seconds.
+    fn anchor_time() -> u16 {
+        devnet_protocol().anchor_time
+    }
+
+    /// Returns the expected time per block in seconds.
+    fn block_time() -> u16 {
+        devnet_protocol().block_time
+    }
+
+    /// Returns the number of blocks per epoch.
+    fn num_blocks_per_epoch() -> u32 {
+        devnet_protocol().num_blocks_per_epoch
+    }
+
+    /// Returns the genesis block bytes.
+    fn genesis_bytes() -> &'static [u8] {
+        devnet_parameters().genesis_bytes()
+    }
+
+    /// Returns the restrictions list as a JSON-compatible string.
+    fn restrictions_list_as_str() -> &'static str {
+        devnet_parameters().restrictions_list()
+    }
+
+    /// Returns the address of the governor that signs restrictions updates, if updates are enabled.
+    fn restrictions_governor_as_str() -> Option<&'static str> {
+        devnet_parameters().restrictions_governor()
+    }
+
+    /// Returns the proving key for the given function name in `credits.aleo`.
+    fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>> {
+        static INSTANCE: OnceCell<IndexMap<String, Arc<VarunaProvingKey<Console>>>> = OnceCell::new();
+        INSTANCE
+            .get_or_try_init(|| load_devnet_credits_keys("prover"))?
+            .get(&function_name)
+            .ok_or_else(|| anyhow!("Proving key for credits.aleo/{function_name}' not found"))
+    }
+
+    /// Returns the verifying key for the given function name in `credits.aleo`.
+    fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<VarunaVerifyingKey<Self>>> {
+        static INSTANCE: OnceCell<IndexMap<String, Arc<VarunaVerifyingKey<Console>>>> = OnceCell::new();
+        INSTANCE
+            .get_or_try_init(|| load_devnet_credits_keys("verifier"))?
+            .get(&function_name)
+            .ok_or_else(|| anyhow!("Verifying key for credits.aleo/{function_name}' not found"))
+    }
+
+    /// Returns the `proving key` for the inclusion circuit.
+    fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>> {
+        devnet_inclusion_key(&INCLUSION_PROVING_KEY, "prover")
+    }
+
+    /// Returns the `verifying key` for the inclusion circuit.
+    fn inclusion_verifying_key() -> &'static Arc<VarunaVerifyingKey<Self>> {
+        devnet_inclusion_key(&INCLUSION_VERIFYING_KEY, "verifier")
+    }
+
+    crate::macros::network_primitive_functions!(DEVNET);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = DevnetV0;
+
+    #[test]
+    fn test_g_scalar_multiply() {
+        // Compute G^r.
+        let scalar = Scalar::rand(&mut TestRng::default());
+        let group = CurrentNetwork::g_scalar_multiply(&scalar);
+        assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
+    }
+
+    #[test]
+    fn test_configure_missing() {
+        // Ensure a missing configuration is reported as an error, instead of panicking on first use.
+        assert!(CurrentNetwork::configure("missing_devnet_configuration.json").is_err());
+    }
+}
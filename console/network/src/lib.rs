@@ -26,9 +26,14 @@ pub use snarkvm_console_network_environment::*;
 mod helpers;
 pub use helpers::*;
 
+mod macros;
+
 mod canary_v0;
 pub use canary_v0::*;
 
+mod devnet_v0;
+pub use devnet_v0::*;
+
 mod mainnet_v0;
 pub use mainnet_v0::*;
 
@@ -93,6 +98,8 @@ pub trait Network:
 
     /// The block height from which consensus V2 rules apply.
     const CONSENSUS_V2_HEIGHT: u32;
+    /// The block height from which consensus V3 rules apply, committing the mapping root in the block header.
+    const CONSENSUS_V3_HEIGHT: u32;
 
     /// The function name for the inclusion circuit.
     const INCLUSION_FUNCTION_NAME: &'static str;
@@ -100,23 +107,33 @@ pub trait Network:
     /// The fixed timestamp of the genesis block.
     const GENESIS_TIMESTAMP: i64;
     /// The genesis block coinbase target.
+    #[deprecated(note = "use `Network::genesis_coinbase_target`, as networks may configure it at startup")]
     const GENESIS_COINBASE_TARGET: u64;
     /// The genesis block proof target.
+    #[deprecated(note = "use `Network::genesis_proof_target`, as networks may configure it at startup")]
     const GENESIS_PROOF_TARGET: u64;
     /// The maximum number of solutions that can be included per block as a power of 2.
+    #[deprecated(note = "use `Network::max_solutions_as_power_of_two`, as networks may configure it at startup")]
     const MAX_SOLUTIONS_AS_POWER_OF_TWO: u8 = 2; // 4 solutions
     /// The maximum number of solutions that can be included per block.
+    #[deprecated(note = "use `Network::max_solutions`, as networks may configure it at startup")]
+    #[allow(deprecated)]
     const MAX_SOLUTIONS: usize = 1 << Self::MAX_SOLUTIONS_AS_POWER_OF_TWO; // 4 solutions
 
     /// The starting supply of Aleo credits.
+    #[deprecated(note = "use `Network::starting_supply`, as networks may configure it at startup")]
     const STARTING_SUPPLY: u64 = 1_500_000_000_000_000; // 1.5B credits
     /// The cost in microcredits per byte for the deployment transaction.
+    #[deprecated(note = "use `Network::deployment_fee_multiplier`, as networks may configure it at startup")]
     const DEPLOYMENT_FEE_MULTIPLIER: u64 = 1_000; // 1 millicredit per byte
     /// The constant that divides the storage polynomial.
+    #[deprecated(note = "use `Network::execution_storage_fee_scaling_factor`, as networks may configure it at startup")]
     const EXECUTION_STORAGE_FEE_SCALING_FACTOR: u64 = 5000;
     /// The maximum size execution transactions can be before a quadratic storage penalty applies.
+    #[deprecated(note = "use `Network::execution_storage_penalty_threshold`, as networks may configure it at startup")]
     const EXECUTION_STORAGE_PENALTY_THRESHOLD: u64 = 5000;
     /// The cost in microcredits per constraint for the deployment transaction.
+    #[deprecated(note = "use `Network::synthesis_fee_multiplier`, as networks may configure it at startup")]
     const SYNTHESIS_FEE_MULTIPLIER: u64 = 25; // 25 microcredits per constraint
     /// The maximum number of variables in a deployment.
     const MAX_DEPLOYMENT_VARIABLES: u64 = 1 << 20; // 1,048,576 variables
@@ -128,12 +145,18 @@ pub trait Network:
     const TRANSACTION_SPEND_LIMIT: u64 = 100_000_000;
 
     /// The anchor height, defined as the expected number of blocks to reach the coinbase target.
+    #[deprecated(note = "use `Network::anchor_height`, as networks may configure it at startup")]
+    #[allow(deprecated)]
     const ANCHOR_HEIGHT: u32 = Self::ANCHOR_TIME as u32 / Self::BLOCK_TIME as u32;
     /// The anchor time in seconds.
+    #[deprecated(note = "use `Network::anchor_time`, as networks may configure it at startup")]
     const ANCHOR_TIME: u16 = 25;
     /// The expected time per block in seconds.
+    #[deprecated(note = "use `Network::block_time`, as networks may configure it at startup")]
     const BLOCK_TIME: u16 = 10;
     /// The number of blocks per epoch.
+    #[deprecated(note = "use `Network::num_blocks_per_epoch`, as networks may configure it at startup")]
+    #[allow(deprecated)]
     const NUM_BLOCKS_PER_EPOCH: u32 = 3600 / Self::BLOCK_TIME as u32; // 360 blocks == ~1 hour
 
     /// The maximum number of entries in data.
@@ -192,7 +215,7 @@ pub trait Network:
     /// The maximum number of imports.
     const MAX_IMPORTS: usize = 64;
 
-    /// The maximum number of certificates in a batch.
+    /// The upper bound on the number of certificates in a batch, which bounds the batch and committee encodings.
     const MAX_CERTIFICATES: u16;
 
     /// The maximum number of bytes in a transaction.
@@ -212,12 +235,99 @@ pub trait Network:
     /// The transmission checksum type.
     type TransmissionChecksum: IntegerType;
 
+    /// Returns the maximum number of certificates in a batch, which is at most `MAX_CERTIFICATES`.
+    fn max_certificates() -> u16 {
+        Self::MAX_CERTIFICATES
+    }
+
+    /// Returns the genesis block coinbase target.
+    ///
+    /// Note: The protocol parameter functions default to the corresponding (deprecated) constants, and are
+    /// overridden by networks whose protocol parameters are loaded at startup, so they must be used instead.
+    #[allow(deprecated)]
+    fn genesis_coinbase_target() -> u64 {
+        Self::GENESIS_COINBASE_TARGET
+    }
+
+    /// Returns the genesis block proof target.
+    #[allow(deprecated)]
+    fn genesis_proof_target() -> u64 {
+        Self::GENESIS_PROOF_TARGET
+    }
+
+    /// Returns the maximum number of solutions that can be included per block as a power of 2.
+    #[allow(deprecated)]
+    fn max_solutions_as_power_of_two() -> u8 {
+        Self::MAX_SOLUTIONS_AS_POWER_OF_TWO
+    }
+
+    /// Returns the maximum number of solutions that can be included per block.
+    fn max_solutions() -> usize {
+        1 << Self::max_solutions_as_power_of_two()
+    }
+
+    /// Returns the starting supply of Aleo credits.
+    #[allow(deprecated)]
+    fn starting_supply() -> u64 {
+        Self::STARTING_SUPPLY
+    }
+
+    /// Returns the cost in microcredits per byte for the deployment transaction.
+    #[allow(deprecated)]
+    fn deployment_fee_multiplier() -> u64 {
+        Self::DEPLOYMENT_FEE_MULTIPLIER
+    }
+
+    /// Returns the constant that divides the storage polynomial.
+    #[allow(deprecated)]
+    fn execution_storage_fee_scaling_factor() -> u64 {
+        Self::EXECUTION_STORAGE_FEE_SCALING_FACTOR
+    }
+
+    /// Returns the maximum size execution transactions can be before a quadratic storage penalty applies.
+    #[allow(deprecated)]
+    fn execution_storage_penalty_threshold() -> u64 {
+        Self::EXECUTION_STORAGE_PENALTY_THRESHOLD
+    }
+
+    /// Returns the cost in microcredits per constraint for the deployment transaction.
+    #[allow(deprecated)]
+    fn synthesis_fee_multiplier() -> u64 {
+        Self::SYNTHESIS_FEE_MULTIPLIER
+    }
+
+    /// Returns the anchor height, defined as the expected number of blocks to reach the coinbase target.
+    fn anchor_height() -> u32 {
+        Self::anchor_time() as u32 / Self::block_time() as u32
+    }
+
+    /// Returns the anchor time in seconds.
+    #[allow(deprecated)]
+    fn anchor_time() -> u16 {
+        Self::ANCHOR_TIME
+    }
+
+    /// Returns the expected time per block in seconds.
+    #[allow(deprecated)]
+    fn block_time() -> u16 {
+        Self::BLOCK_TIME
+    }
+
+    /// Returns the number of blocks per epoch.
+    #[allow(deprecated)]
+    fn num_blocks_per_epoch() -> u32 {
+        Self::NUM_BLOCKS_PER_EPOCH
+    }
+
     /// Returns the genesis block bytes.
     fn genesis_bytes() -> &'static [u8];
 
     /// Returns the restrictions list as a JSON-compatible string.
     fn restrictions_list_as_str() -> &'static str;
 
+    /// Returns the address of the governor that signs restrictions updates, if updates are enabled.
+    fn restrictions_governor_as_str() -> Option<&'static str>;
+
     /// Returns the proving key for the given function name in `credits.aleo`.
     fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>>;
 
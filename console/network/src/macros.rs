@@ -0,0 +1,388 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Implements the cryptographic primitives of the given network, as statics with the given prefix,
+/// along with its group bases and its `Environment`, which is shared with `Console`.
+macro_rules! network_primitives {
+    ($network:ident, $prefix:ident) => {
+        paste::paste! {
+            lazy_static! {
+                /// The group bases for the Aleo signature and encryption schemes.
+                static ref GENERATOR_G: Vec<Group<$network >> = $network::new_bases("AleoAccountEncryptionAndSignatureScheme0");
+
+                /// The Varuna sponge parameters.
+                static ref VARUNA_FS_PARAMETERS: FiatShamirParameters<$network> = FiatShamir::<$network>::sample_parameters();
+
+                /// The encryption domain as a constant field element.
+                static ref ENCRYPTION_DOMAIN: Field<$network> = Field::<$network>::new_domain_separator("AleoSymmetricEncryption0");
+                /// The graph key domain as a constant field element.
+                static ref GRAPH_KEY_DOMAIN: Field<$network> = Field::<$network>::new_domain_separator("AleoGraphKey0");
+                /// The serial number domain as a constant field element.
+                static ref SERIAL_NUMBER_DOMAIN: Field<$network> = Field::<$network>::new_domain_separator("AleoSerialNumber0");
+
+                /// The BHP hash function, which can take an input of up to 256 bits.
+                pub static ref [<$prefix _BHP_256>]: BHP256<$network> = BHP256::<$network>::setup("AleoBHP256").expect("Failed to setup BHP256");
+                /// The BHP hash function, which can take an input of up to 512 bits.
+                pub static ref [<$prefix _BHP_512>]: BHP512<$network> = BHP512::<$network>::setup("AleoBHP512").expect("Failed to setup BHP512");
+                /// The BHP hash function, which can take an input of up to 768 bits.
+                pub static ref [<$prefix _BHP_768>]: BHP768<$network> = BHP768::<$network>::setup("AleoBHP768").expect("Failed to setup BHP768");
+                /// The BHP hash function, which can take an input of up to 1024 bits.
+                pub static ref [<$prefix _BHP_1024>]: BHP1024<$network> = BHP1024::<$network>::setup("AleoBHP1024").expect("Failed to setup BHP1024");
+
+                /// The Pedersen hash function, which can take an input of up to 64 bits.
+                pub static ref [<$prefix _PEDERSEN_64>]: Pedersen64<$network> = Pedersen64::<$network>::setup("AleoPedersen64");
+                /// The Pedersen hash function, which can take an input of up to 128 bits.
+                pub static ref [<$prefix _PEDERSEN_128>]: Pedersen128<$network> = Pedersen128::<$network>::setup("AleoPedersen128");
+
+                /// The Poseidon hash function, using a rate of 2.
+                pub static ref [<$prefix _POSEIDON_2>]: Poseidon2<$network> = Poseidon2::<$network>::setup("AleoPoseidon2").expect("Failed to setup Poseidon2");
+                /// The Poseidon hash function, using a rate of 4.
+                pub static ref [<$prefix _POSEIDON_4>]: Poseidon4<$network> = Poseidon4::<$network>::setup("AleoPoseidon4").expect("Failed to setup Poseidon4");
+                /// The Poseidon hash function, using a rate of 8.
+                pub static ref [<$prefix _POSEIDON_8>]: Poseidon8<$network> = Poseidon8::<$network>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8");
+            }
+
+            impl $network {
+                /// Initializes a new instance of group bases from a given input domain message.
+                fn new_bases(message: &str) -> Vec<Group<Self>> {
+                    // Hash the given message to a point on the curve, to initialize the starting base.
+                    let (base, _, _) = Blake2Xs::hash_to_curve::<<Self as Environment>::Affine>(message);
+
+                    // Compute the bases up to the size of the scalar field (in bits).
+                    let mut g = Group::<Self>::new(base);
+                    let mut g_bases = Vec::with_capacity(Scalar::<Self>::size_in_bits());
+                    for _ in 0..Scalar::<Self>::size_in_bits() {
+                        g_bases.push(g);
+                        g = g.double();
+                    }
+                    g_bases
+                }
+            }
+
+            impl Environment for $network {
+                type Affine = <Console as Environment>::Affine;
+                type BigInteger = <Console as Environment>::BigInteger;
+                type Field = <Console as Environment>::Field;
+                type PairingCurve = <Console as Environment>::PairingCurve;
+                type Projective = <Console as Environment>::Projective;
+                type Scalar = <Console as Environment>::Scalar;
+
+                /// The coefficient `A` of the twisted Edwards curve.
+                const EDWARDS_A: Self::Field = Console::EDWARDS_A;
+                /// The coefficient `D` of the twisted Edwards curve.
+                const EDWARDS_D: Self::Field = Console::EDWARDS_D;
+                /// The coefficient `A` of the Montgomery curve.
+                const MONTGOMERY_A: Self::Field = Console::MONTGOMERY_A;
+                /// The coefficient `B` of the Montgomery curve.
+                const MONTGOMERY_B: Self::Field = Console::MONTGOMERY_B;
+            }
+        }
+    };
+}
+pub(crate) use network_primitives;
+
+/// Implements the `Network` functions over the cryptographic primitives with the given prefix,
+/// as defined by `network_primitives`.
+macro_rules! network_primitive_functions {
+    ($prefix:ident) => {
+        paste::paste! {
+            /// Returns the powers of `G`.
+            fn g_powers() -> &'static Vec<Group<Self>> {
+                &GENERATOR_G
+            }
+
+            /// Returns the scalar multiplication on the generator `G`.
+            fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
+                GENERATOR_G
+                    .iter()
+                    .zip_eq(&scalar.to_bits_le())
+                    .filter_map(|(base, bit)| match bit {
+                        true => Some(base),
+                        false => None,
+                    })
+                    .sum()
+            }
+
+            /// Returns the Varuna universal prover.
+            fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve> {
+                MainnetV0::varuna_universal_prover()
+            }
+
+            /// Returns the Varuna universal verifier.
+            fn varuna_universal_verifier() -> &'static UniversalVerifier<Self::PairingCurve> {
+                MainnetV0::varuna_universal_verifier()
+            }
+
+            /// Returns the sponge parameters used for the sponge in the Varuna SNARK.
+            fn varuna_fs_parameters() -> &'static FiatShamirParameters<Self> {
+                &VARUNA_FS_PARAMETERS
+            }
+
+            /// Returns the encryption domain as a constant field element.
+            fn encryption_domain() -> Field<Self> {
+                *ENCRYPTION_DOMAIN
+            }
+
+            /// Returns the graph key domain as a constant field element.
+            fn graph_key_domain() -> Field<Self> {
+                *GRAPH_KEY_DOMAIN
+            }
+
+            /// Returns the serial number domain as a constant field element.
+            fn serial_number_domain() -> Field<Self> {
+                *SERIAL_NUMBER_DOMAIN
+            }
+
+            /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
+            fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                [<$prefix _BHP_256>].commit(input, randomizer)
+            }
+
+            /// Returns a BHP commitment with an input hasher of 512-bits and randomizer.
+            fn commit_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                [<$prefix _BHP_512>].commit(input, randomizer)
+            }
+
+            /// Returns a BHP commitment with an input hasher of 768-bits and randomizer.
+            fn commit_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                [<$prefix _BHP_768>].commit(input, randomizer)
+            }
+
+            /// Returns a BHP commitment with an input hasher of 1024-bits and randomizer.
+            fn commit_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                [<$prefix _BHP_1024>].commit(input, randomizer)
+            }
+
+            /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
+            fn commit_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                [<$prefix _PEDERSEN_64>].commit(input, randomizer)
+            }
+
+            /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
+            fn commit_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                [<$prefix _PEDERSEN_128>].commit(input, randomizer)
+            }
+
+            /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
+            fn commit_to_group_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                [<$prefix _BHP_256>].commit_uncompressed(input, randomizer)
+            }
+
+            /// Returns a BHP commitment with an input hasher of 512-bits and randomizer.
+            fn commit_to_group_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                [<$prefix _BHP_512>].commit_uncompressed(input, randomizer)
+            }
+
+            /// Returns a BHP commitment with an input hasher of 768-bits and randomizer.
+            fn commit_to_group_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                [<$prefix _BHP_768>].commit_uncompressed(input, randomizer)
+            }
+
+            /// Returns a BHP commitment with an input hasher of 1024-bits and randomizer.
+            fn commit_to_group_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                [<$prefix _BHP_1024>].commit_uncompressed(input, randomizer)
+            }
+
+            /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
+            fn commit_to_group_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                [<$prefix _PEDERSEN_64>].commit_uncompressed(input, randomizer)
+            }
+
+            /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
+            fn commit_to_group_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                [<$prefix _PEDERSEN_128>].commit_uncompressed(input, randomizer)
+            }
+
+            /// Returns the BHP hash with an input hasher of 256-bits.
+            fn hash_bhp256(input: &[bool]) -> Result<Field<Self>> {
+                [<$prefix _BHP_256>].hash(input)
+            }
+
+            /// Returns the BHP hash with an input hasher of 512-bits.
+            fn hash_bhp512(input: &[bool]) -> Result<Field<Self>> {
+                [<$prefix _BHP_512>].hash(input)
+            }
+
+            /// Returns the BHP hash with an input hasher of 768-bits.
+            fn hash_bhp768(input: &[bool]) -> Result<Field<Self>> {
+                [<$prefix _BHP_768>].hash(input)
+            }
+
+            /// Returns the BHP hash with an input hasher of 1024-bits.
+            fn hash_bhp1024(input: &[bool]) -> Result<Field<Self>> {
+                [<$prefix _BHP_1024>].hash(input)
+            }
+
+            /// Returns the Keccak hash with a 256-bit output.
+            fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>> {
+                Keccak256::default().hash(input)
+            }
+
+            /// Returns the Keccak hash with a 384-bit output.
+            fn hash_keccak384(input: &[bool]) -> Result<Vec<bool>> {
+                Keccak384::default().hash(input)
+            }
+
+            /// Returns the Keccak hash with a 512-bit output.
+            fn hash_keccak512(input: &[bool]) -> Result<Vec<bool>> {
+                Keccak512::default().hash(input)
+            }
+
+            /// Returns the Pedersen hash for a given (up to) 64-bit input.
+            fn hash_ped64(input: &[bool]) -> Result<Field<Self>> {
+                [<$prefix _PEDERSEN_64>].hash(input)
+            }
+
+            /// Returns the Pedersen hash for a given (up to) 128-bit input.
+            fn hash_ped128(input: &[bool]) -> Result<Field<Self>> {
+                [<$prefix _PEDERSEN_128>].hash(input)
+            }
+
+            /// Returns the Poseidon hash with an input rate of 2.
+            fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>> {
+                [<$prefix _POSEIDON_2>].hash(input)
+            }
+
+            /// Returns the Poseidon hash with an input rate of 4.
+            fn hash_psd4(input: &[Field<Self>]) -> Result<Field<Self>> {
+                [<$prefix _POSEIDON_4>].hash(input)
+            }
+
+            /// Returns the Poseidon hash with an input rate of 8.
+            fn hash_psd8(input: &[Field<Self>]) -> Result<Field<Self>> {
+                [<$prefix _POSEIDON_8>].hash(input)
+            }
+
+            /// Returns the SHA-3 hash with a 256-bit output.
+            fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>> {
+                Sha3_256::default().hash(input)
+            }
+
+            /// Returns the SHA-3 hash with a 384-bit output.
+            fn hash_sha3_384(input: &[bool]) -> Result<Vec<bool>> {
+                Sha3_384::default().hash(input)
+            }
+
+            /// Returns the SHA-3 hash with a 512-bit output.
+            fn hash_sha3_512(input: &[bool]) -> Result<Vec<bool>> {
+                Sha3_512::default().hash(input)
+            }
+
+            /// Returns the extended Poseidon hash with an input rate of 2.
+            fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+                [<$prefix _POSEIDON_2>].hash_many(input, num_outputs)
+            }
+
+            /// Returns the extended Poseidon hash with an input rate of 4.
+            fn hash_many_psd4(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+                [<$prefix _POSEIDON_4>].hash_many(input, num_outputs)
+            }
+
+            /// Returns the extended Poseidon hash with an input rate of 8.
+            fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+                [<$prefix _POSEIDON_8>].hash_many(input, num_outputs)
+            }
+
+            /// Returns the BHP hash with an input hasher of 256-bits.
+            fn hash_to_group_bhp256(input: &[bool]) -> Result<Group<Self>> {
+                [<$prefix _BHP_256>].hash_uncompressed(input)
+            }
+
+            /// Returns the BHP hash with an input hasher of 512-bits.
+            fn hash_to_group_bhp512(input: &[bool]) -> Result<Group<Self>> {
+                [<$prefix _BHP_512>].hash_uncompressed(input)
+            }
+
+            /// Returns the BHP hash with an input hasher of 768-bits.
+            fn hash_to_group_bhp768(input: &[bool]) -> Result<Group<Self>> {
+                [<$prefix _BHP_768>].hash_uncompressed(input)
+            }
+
+            /// Returns the BHP hash with an input hasher of 1024-bits.
+            fn hash_to_group_bhp1024(input: &[bool]) -> Result<Group<Self>> {
+                [<$prefix _BHP_1024>].hash_uncompressed(input)
+            }
+
+            /// Returns the Pedersen hash for a given (up to) 64-bit input.
+            fn hash_to_group_ped64(input: &[bool]) -> Result<Group<Self>> {
+                [<$prefix _PEDERSEN_64>].hash_uncompressed(input)
+            }
+
+            /// Returns the Pedersen hash for a given (up to) 128-bit input.
+            fn hash_to_group_ped128(input: &[bool]) -> Result<Group<Self>> {
+                [<$prefix _PEDERSEN_128>].hash_uncompressed(input)
+            }
+
+            /// Returns the Poseidon hash with an input rate of 2 on the affine curve.
+            fn hash_to_group_psd2(input: &[Field<Self>]) -> Result<Group<Self>> {
+                [<$prefix _POSEIDON_2>].hash_to_group(input)
+            }
+
+            /// Returns the Poseidon hash with an input rate of 4 on the affine curve.
+            fn hash_to_group_psd4(input: &[Field<Self>]) -> Result<Group<Self>> {
+                [<$prefix _POSEIDON_4>].hash_to_group(input)
+            }
+
+            /// Returns the Poseidon hash with an input rate of 8 on the affine curve.
+            fn hash_to_group_psd8(input: &[Field<Self>]) -> Result<Group<Self>> {
+                [<$prefix _POSEIDON_8>].hash_to_group(input)
+            }
+
+            /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
+            fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+                [<$prefix _POSEIDON_2>].hash_to_scalar(input)
+            }
+
+            /// Returns the Poseidon hash with an input rate of 4 on the scalar field.
+            fn hash_to_scalar_psd4(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+                [<$prefix _POSEIDON_4>].hash_to_scalar(input)
+            }
+
+            /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
+            fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+                [<$prefix _POSEIDON_8>].hash_to_scalar(input)
+            }
+
+            /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
+            fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>> {
+                MerkleTree::new(&*[<$prefix _BHP_1024>], &*[<$prefix _BHP_512>], leaves)
+            }
+
+            /// Returns a Merkle tree with a Poseidon leaf hasher with input rate of 4 and a Poseidon path hasher with input rate of 2.
+            fn merkle_tree_psd<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<PoseidonMerkleTree<Self, DEPTH>> {
+                MerkleTree::new(&*[<$prefix _POSEIDON_4>], &*[<$prefix _POSEIDON_2>], leaves)
+            }
+
+            /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+            fn verify_merkle_path_bhp<const DEPTH: u8>(
+                path: &MerklePath<Self, DEPTH>,
+                root: &Field<Self>,
+                leaf: &Vec<bool>,
+            ) -> bool {
+                path.verify(&*[<$prefix _BHP_1024>], &*[<$prefix _BHP_512>], root, leaf)
+            }
+
+            /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+            fn verify_merkle_path_psd<const DEPTH: u8>(
+                path: &MerklePath<Self, DEPTH>,
+                root: &Field<Self>,
+                leaf: &Vec<Field<Self>>,
+            ) -> bool {
+                path.verify(&*[<$prefix _POSEIDON_4>], &*[<$prefix _POSEIDON_2>], root, leaf)
+            }
+        }
+    };
+}
+pub(crate) use network_primitive_functions;
@@ -141,6 +141,13 @@ impl Network for MainnetV0 {
     /// The block height from which consensus V2 rules apply.
     #[cfg(any(test, feature = "test"))]
     const CONSENSUS_V2_HEIGHT: u32 = 10;
+    /// The block height from which consensus V3 rules apply.
+    /// Note: Consensus V3 is not yet scheduled, and will be activated in a coordinated network upgrade.
+    #[cfg(not(any(test, feature = "test")))]
+    const CONSENSUS_V3_HEIGHT: u32 = u32::MAX;
+    /// The block height from which consensus V3 rules apply.
+    #[cfg(any(test, feature = "test"))]
+    const CONSENSUS_V3_HEIGHT: u32 = 10;
     /// The network edition.
     const EDITION: u16 = 0;
     /// The genesis block coinbase target.
@@ -178,6 +185,11 @@ impl Network for MainnetV0 {
         snarkvm_parameters::mainnet::RESTRICTIONS_LIST
     }
 
+    /// Returns the address of the governor that signs restrictions updates, if updates are enabled.
+    fn restrictions_governor_as_str() -> Option<&'static str> {
+        None
+    }
+
     /// Returns the proving key for the given function name in `credits.aleo`.
     fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>> {
         CREDITS_PROVING_KEYS
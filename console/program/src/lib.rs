@@ -40,6 +40,9 @@ pub use id::*;
 mod locator;
 pub use locator::*;
 
+mod mapping_tree;
+pub use mapping_tree::*;
+
 mod owner;
 pub use owner::*;
 
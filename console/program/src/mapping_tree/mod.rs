@@ -0,0 +1,481 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod proof;
+pub use proof::*;
+
+use crate::{Identifier, Plaintext, ProgramID, Value};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+use std::{collections::HashMap, sync::Arc};
+
+/// The position of a node in the mapping tree, as `(depth, key prefix)`,
+/// where the key prefix is the field element of the first `depth` little-endian bits of the key IDs in the subtree.
+pub type MappingNodePosition<N> = (u8, Field<N>);
+
+/// A node in the mapping tree, as `(node hash, leaf)`, where the leaf is `(key ID, value hash)` if the node is a leaf.
+/// Note: An empty subtree has no node.
+pub type MappingNodeEntry<N> = (Field<N>, Option<(Field<N>, Field<N>)>);
+
+/// A node in the mapping tree.
+#[derive(Clone)]
+enum MappingNode<N: Network> {
+    /// An empty subtree.
+    Empty,
+    /// A subtree containing a single entry, as (key ID, value hash, value ID).
+    Leaf(Field<N>, Field<N>, Field<N>),
+    /// A subtree containing two or more entries, as (node hash, left child, right child).
+    Internal(Field<N>, Arc<MappingNode<N>>, Arc<MappingNode<N>>),
+}
+
+impl<N: Network> MappingNode<N> {
+    /// Returns the hash of the node.
+    fn hash(&self) -> Field<N> {
+        match self {
+            Self::Empty => Field::zero(),
+            Self::Leaf(_, _, value_id) => *value_id,
+            Self::Internal(hash, _, _) => *hash,
+        }
+    }
+
+    /// Returns the entry of the node, or `None` if the node is empty.
+    fn to_entry(&self) -> Option<MappingNodeEntry<N>> {
+        match self {
+            Self::Empty => None,
+            Self::Leaf(key_id, value_hash, value_id) => Some((*value_id, Some((*key_id, *value_hash)))),
+            Self::Internal(hash, _, _) => Some((*hash, None)),
+        }
+    }
+
+    /// Initializes a leaf for the given key ID and value hash.
+    fn leaf(key_id: Field<N>, value_hash: Field<N>) -> Result<Self> {
+        Ok(Self::Leaf(key_id, value_hash, MappingTree::to_value_id(key_id, value_hash)?))
+    }
+
+    /// Initializes an internal node for the given children.
+    /// Note: A subtree with fewer than two entries is collapsed into an empty node or a leaf.
+    fn internal(left: Arc<Self>, right: Arc<Self>) -> Result<Arc<Self>> {
+        match (left.as_ref(), right.as_ref()) {
+            (Self::Empty, Self::Empty | Self::Leaf(..)) => Ok(right),
+            (Self::Leaf(..), Self::Empty) => Ok(left),
+            _ => Ok(Arc::new(Self::Internal(MappingTree::hash_children(&left.hash(), &right.hash())?, left, right))),
+        }
+    }
+}
+
+/// The mapping tree is a sparse Merkle tree over the entries of every program mapping.
+///
+/// Each entry is placed at the shortest prefix of the little-endian bits of its key ID that is not shared
+/// with any other entry, and is committed to by its value ID. The tree is canonical, meaning its root
+/// depends only on the set of entries, and not on the order in which they were inserted or removed.
+///
+/// We define the `key ID := Hash ( program ID || mapping name || key )`
+/// and the `value ID := Hash ( key ID || Hash(value) )`.
+#[derive(Clone)]
+pub struct MappingTree<N: Network> {
+    /// The root node of the tree.
+    root: Arc<MappingNode<N>>,
+    /// The number of entries in the tree.
+    number_of_leaves: usize,
+}
+
+impl<N: Network> MappingTree<N> {
+    /// Initializes a new mapping tree from the given `(key ID, value hash)` entries.
+    pub fn new(entries: impl IntoIterator<Item = (Field<N>, Field<N>)>) -> Result<Self> {
+        // Prepare the entries, sorted by the little-endian bits of the key ID.
+        let mut entries = entries
+            .into_iter()
+            .map(|(key_id, value_hash)| (key_id.to_bits_le(), key_id, value_hash))
+            .collect::<Vec<_>>();
+        entries.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+        // Ensure there are no duplicate key IDs.
+        ensure!(entries.windows(2).all(|pair| pair[0].1 != pair[1].1), "Found duplicate key IDs in the mapping tree");
+
+        // Construct the tree.
+        let root = Self::build(&entries, 0)?;
+        Ok(Self { root, number_of_leaves: entries.len() })
+    }
+
+    /// Initializes the mapping tree from the given nodes, as returned by `to_node_updates`.
+    /// Note: The node hashes are not recomputed, so the nodes must come from a trusted source.
+    pub fn from_nodes(nodes: impl IntoIterator<Item = (MappingNodePosition<N>, MappingNodeEntry<N>)>) -> Result<Self> {
+        let mut nodes = nodes.into_iter().collect::<HashMap<_, _>>();
+        // Construct the tree, starting from the root.
+        let mut number_of_leaves = 0;
+        let root = Self::build_from_nodes(&mut nodes, &mut Vec::new(), &mut number_of_leaves)?;
+        // Ensure every node belongs to the tree.
+        ensure!(nodes.is_empty(), "Found {} nodes outside of the mapping tree", nodes.len());
+        Ok(Self { root, number_of_leaves })
+    }
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> Field<N> {
+        self.root.hash()
+    }
+
+    /// Returns the number of entries in the tree.
+    pub const fn number_of_leaves(&self) -> usize {
+        self.number_of_leaves
+    }
+
+    /// Inserts the given entry into the tree, replacing the existing entry for the key ID (if any).
+    pub fn insert(&mut self, key_id: Field<N>, value_hash: Field<N>) -> Result<()> {
+        let (root, is_new) =
+            Self::insert_inner(&self.root, 0, &key_id.to_bits_le(), MappingNode::leaf(key_id, value_hash)?)?;
+        self.root = root;
+        self.number_of_leaves += is_new as usize;
+        Ok(())
+    }
+
+    /// Removes the entry for the given key ID from the tree, if it exists.
+    pub fn remove(&mut self, key_id: &Field<N>) -> Result<()> {
+        if let Some(root) = Self::remove_inner(&self.root, 0, key_id, &key_id.to_bits_le())? {
+            self.root = root;
+            self.number_of_leaves -= 1;
+        }
+        Ok(())
+    }
+
+    /// Returns the nodes that changed from the given `previous` tree to this tree,
+    /// where a node that no longer exists is returned as `None`.
+    #[allow(clippy::type_complexity)]
+    pub fn to_node_updates(
+        &self,
+        previous: &Self,
+    ) -> Result<Vec<(MappingNodePosition<N>, Option<MappingNodeEntry<N>>)>> {
+        let mut updates = Vec::new();
+        Self::diff_nodes(&previous.root, &self.root, &mut Vec::new(), &mut updates)?;
+        Ok(updates)
+    }
+
+    /// Returns the inclusion proof for the given key ID if it exists in the tree, or the exclusion proof otherwise.
+    pub fn prove(&self, key_id: &Field<N>) -> MappingProof<N> {
+        let key_bits = key_id.to_bits_le();
+        // Descend along the key bits, collecting the sibling hashes.
+        let mut siblings = Vec::new();
+        let mut node = &self.root;
+        loop {
+            match node.as_ref() {
+                MappingNode::Empty => return MappingProof::new(siblings, None),
+                MappingNode::Leaf(leaf_key_id, value_hash, _) => {
+                    return MappingProof::new(siblings, Some((*leaf_key_id, *value_hash)));
+                }
+                MappingNode::Internal(_, left, right) => match key_bits[siblings.len()] {
+                    true => {
+                        siblings.push(left.hash());
+                        node = right;
+                    }
+                    false => {
+                        siblings.push(right.hash());
+                        node = left;
+                    }
+                },
+            }
+        }
+    }
+}
+
+impl<N: Network> MappingTree<N> {
+    /// Returns the key ID for the given `program ID`, `mapping name`, and `key`.
+    pub fn to_key_id(program_id: &ProgramID<N>, mapping_name: &Identifier<N>, key: &Plaintext<N>) -> Result<Field<N>> {
+        // Construct the preimage.
+        let mut preimage = Vec::new();
+        program_id.write_bits_le(&mut preimage);
+        false.write_bits_le(&mut preimage); // Separator
+        mapping_name.write_bits_le(&mut preimage);
+        false.write_bits_le(&mut preimage); // Separator
+        key.write_bits_le(&mut preimage);
+        // Compute the key ID.
+        N::hash_bhp1024(&preimage)
+    }
+
+    /// Returns the value hash for the given `value`.
+    pub fn to_value_hash(value: &Value<N>) -> Result<Field<N>> {
+        N::hash_bhp1024(&value.to_bits_le())
+    }
+
+    /// Returns the value ID for the given `key ID` and `value hash`.
+    pub fn to_value_id(key_id: Field<N>, value_hash: Field<N>) -> Result<Field<N>> {
+        N::hash_bhp1024(&(key_id, value_hash).to_bits_le())
+    }
+
+    /// Returns the hash of an internal node with the given children.
+    fn hash_children(left: &Field<N>, right: &Field<N>) -> Result<Field<N>> {
+        N::hash_psd2(&[*left, *right])
+    }
+
+    /// Returns the position of the node with the given key prefix.
+    fn to_position(prefix: &[bool]) -> Result<MappingNodePosition<N>> {
+        Ok((u8::try_from(prefix.len())?, Field::from_bits_le(prefix)?))
+    }
+
+    /// Returns the subtree at the given key prefix, removing its nodes from the given nodes.
+    fn build_from_nodes(
+        nodes: &mut HashMap<MappingNodePosition<N>, MappingNodeEntry<N>>,
+        prefix: &mut Vec<bool>,
+        number_of_leaves: &mut usize,
+    ) -> Result<Arc<MappingNode<N>>> {
+        match nodes.remove(&Self::to_position(prefix)?) {
+            None => Ok(Arc::new(MappingNode::Empty)),
+            Some((value_id, Some((key_id, value_hash)))) => {
+                *number_of_leaves += 1;
+                Ok(Arc::new(MappingNode::Leaf(key_id, value_hash, value_id)))
+            }
+            Some((hash, None)) => {
+                ensure!(prefix.len() < Field::<N>::size_in_bits(), "The mapping tree exceeds the maximum depth");
+                prefix.push(false);
+                let left = Self::build_from_nodes(nodes, prefix, number_of_leaves)?;
+                prefix.pop();
+                prefix.push(true);
+                let right = Self::build_from_nodes(nodes, prefix, number_of_leaves)?;
+                prefix.pop();
+                Ok(Arc::new(MappingNode::Internal(hash, left, right)))
+            }
+        }
+    }
+
+    /// Collects the nodes that changed from the `previous` subtree to the `next` subtree, at the given key prefix.
+    #[allow(clippy::type_complexity)]
+    fn diff_nodes(
+        previous: &Arc<MappingNode<N>>,
+        next: &Arc<MappingNode<N>>,
+        prefix: &mut Vec<bool>,
+        updates: &mut Vec<(MappingNodePosition<N>, Option<MappingNodeEntry<N>>)>,
+    ) -> Result<()> {
+        // Skip the subtrees that are shared between both trees.
+        if Arc::ptr_eq(previous, next) {
+            return Ok(());
+        }
+        // Update the node at this position.
+        match (previous.as_ref(), next.as_ref()) {
+            (MappingNode::Empty, MappingNode::Empty) => return Ok(()),
+            _ => updates.push((Self::to_position(prefix)?, next.to_entry())),
+        }
+        // Retrieve the children of each subtree, where a leaf or empty subtree has empty children.
+        let children = |node: &Arc<MappingNode<N>>| match node.as_ref() {
+            MappingNode::Internal(_, left, right) => Some((left.clone(), right.clone())),
+            _ => None,
+        };
+        let (previous_children, next_children) = (children(previous), children(next));
+        // Update the nodes of the children.
+        if previous_children.is_some() || next_children.is_some() {
+            let empty = Arc::new(MappingNode::Empty);
+            let (previous_left, previous_right) = previous_children.unwrap_or_else(|| (empty.clone(), empty.clone()));
+            let (next_left, next_right) = next_children.unwrap_or_else(|| (empty.clone(), empty.clone()));
+            prefix.push(false);
+            Self::diff_nodes(&previous_left, &next_left, prefix, updates)?;
+            prefix.pop();
+            prefix.push(true);
+            Self::diff_nodes(&previous_right, &next_right, prefix, updates)?;
+            prefix.pop();
+        }
+        Ok(())
+    }
+
+    /// Returns the subtree for the given sorted entries, at the given depth.
+    fn build(entries: &[(Vec<bool>, Field<N>, Field<N>)], depth: usize) -> Result<Arc<MappingNode<N>>> {
+        match entries {
+            [] => Ok(Arc::new(MappingNode::Empty)),
+            [(_, key_id, value_hash)] => Ok(Arc::new(MappingNode::leaf(*key_id, *value_hash)?)),
+            _ => {
+                ensure!(depth < Field::<N>::size_in_bits(), "The mapping tree exceeds the maximum depth");
+                // Split the entries on the key bit at the current depth.
+                let split = entries.partition_point(|(bits, _, _)| !bits[depth]);
+                let left = Self::build(&entries[..split], depth + 1)?;
+                let right = Self::build(&entries[split..], depth + 1)?;
+                MappingNode::internal(left, right)
+            }
+        }
+    }
+
+    /// Returns the subtree with the given leaf inserted, and `true` if the key ID did not previously exist.
+    fn insert_inner(
+        node: &Arc<MappingNode<N>>,
+        depth: usize,
+        key_bits: &[bool],
+        leaf: MappingNode<N>,
+    ) -> Result<(Arc<MappingNode<N>>, bool)> {
+        match node.as_ref() {
+            MappingNode::Empty => Ok((Arc::new(leaf), true)),
+            MappingNode::Leaf(existing_key_id, ..) => match &leaf {
+                MappingNode::Leaf(key_id, ..) if key_id == existing_key_id => Ok((Arc::new(leaf), false)),
+                _ => Ok((Self::split(node, &existing_key_id.to_bits_le(), Arc::new(leaf), key_bits, depth)?, true)),
+            },
+            MappingNode::Internal(_, left, right) => match key_bits[depth] {
+                true => {
+                    let (right, is_new) = Self::insert_inner(right, depth + 1, key_bits, leaf)?;
+                    Ok((MappingNode::internal(left.clone(), right)?, is_new))
+                }
+                false => {
+                    let (left, is_new) = Self::insert_inner(left, depth + 1, key_bits, leaf)?;
+                    Ok((MappingNode::internal(left, right.clone())?, is_new))
+                }
+            },
+        }
+    }
+
+    /// Returns the subtree containing the two given leaves, at the given depth.
+    fn split(
+        a: &Arc<MappingNode<N>>,
+        a_bits: &[bool],
+        b: Arc<MappingNode<N>>,
+        b_bits: &[bool],
+        depth: usize,
+    ) -> Result<Arc<MappingNode<N>>> {
+        ensure!(depth < Field::<N>::size_in_bits(), "The mapping tree exceeds the maximum depth");
+        let empty = Arc::new(MappingNode::Empty);
+        match (a_bits[depth], b_bits[depth]) {
+            (false, false) => MappingNode::internal(Self::split(a, a_bits, b, b_bits, depth + 1)?, empty),
+            (true, true) => MappingNode::internal(empty, Self::split(a, a_bits, b, b_bits, depth + 1)?),
+            (false, true) => MappingNode::internal(a.clone(), b),
+            (true, false) => MappingNode::internal(b, a.clone()),
+        }
+    }
+
+    /// Returns the subtree with the given key ID removed, or `None` if the key ID does not exist.
+    fn remove_inner(
+        node: &Arc<MappingNode<N>>,
+        depth: usize,
+        key_id: &Field<N>,
+        key_bits: &[bool],
+    ) -> Result<Option<Arc<MappingNode<N>>>> {
+        match node.as_ref() {
+            MappingNode::Empty => Ok(None),
+            MappingNode::Leaf(leaf_key_id, ..) => match leaf_key_id == key_id {
+                true => Ok(Some(Arc::new(MappingNode::Empty))),
+                false => Ok(None),
+            },
+            MappingNode::Internal(_, left, right) => match key_bits[depth] {
+                true => match Self::remove_inner(right, depth + 1, key_id, key_bits)? {
+                    Some(right) => Ok(Some(MappingNode::internal(left.clone(), right)?)),
+                    None => Ok(None),
+                },
+                false => match Self::remove_inner(left, depth + 1, key_id, key_bits)? {
+                    Some(left) => Ok(Some(MappingNode::internal(left, right.clone())?)),
+                    None => Ok(None),
+                },
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: usize = 10;
+
+    /// Samples the given number of random `(key ID, value hash)` entries.
+    fn sample_entries(num_entries: usize, rng: &mut TestRng) -> Vec<(Field<CurrentNetwork>, Field<CurrentNetwork>)> {
+        (0..num_entries).map(|_| (Uniform::rand(rng), Uniform::rand(rng))).collect()
+    }
+
+    #[test]
+    fn test_empty_tree() -> Result<()> {
+        let tree = MappingTree::<CurrentNetwork>::new(vec![])?;
+        assert_eq!(tree.root(), Field::zero());
+        assert_eq!(tree.number_of_leaves(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_and_remove_are_canonical() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for num_entries in 0..ITERATIONS {
+            let entries = sample_entries(num_entries * 3, rng);
+
+            // Construct the tree from all of the entries at once.
+            let expected = MappingTree::<CurrentNetwork>::new(entries.clone())?;
+            assert_eq!(expected.number_of_leaves(), entries.len());
+
+            // Construct the tree by inserting the entries in reverse order.
+            let mut candidate = MappingTree::<CurrentNetwork>::new(vec![])?;
+            for (key_id, value_hash) in entries.iter().rev() {
+                candidate.insert(*key_id, *value_hash)?;
+            }
+            assert_eq!(expected.root(), candidate.root());
+            assert_eq!(expected.number_of_leaves(), candidate.number_of_leaves());
+
+            // Update an entry, and ensure the root changes.
+            if let Some((key_id, _)) = entries.first() {
+                candidate.insert(*key_id, Uniform::rand(rng))?;
+                assert_ne!(expected.root(), candidate.root());
+                assert_eq!(expected.number_of_leaves(), candidate.number_of_leaves());
+            }
+
+            // Remove half of the entries, and ensure the root matches a tree of the remaining entries.
+            let (removed, remaining) = entries.split_at(entries.len() / 2);
+            let mut candidate = expected.clone();
+            for (key_id, _) in removed {
+                candidate.remove(key_id)?;
+            }
+            // Removing a key ID that does not exist is a no-op.
+            candidate.remove(&Uniform::rand(rng))?;
+            let expected = MappingTree::<CurrentNetwork>::new(remaining.to_vec())?;
+            assert_eq!(expected.root(), candidate.root());
+            assert_eq!(expected.number_of_leaves(), candidate.number_of_leaves());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_nodes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let mut nodes = HashMap::new();
+        let mut previous = MappingTree::<CurrentNetwork>::new(vec![])?;
+        for num_entries in 0..ITERATIONS {
+            // Update the tree, by inserting new entries and removing some of the existing ones.
+            let mut tree = previous.clone();
+            let entries = sample_entries(num_entries * 3, rng);
+            for (key_id, value_hash) in &entries {
+                tree.insert(*key_id, *value_hash)?;
+            }
+            for (key_id, _) in entries.iter().step_by(2) {
+                tree.remove(key_id)?;
+            }
+
+            // Apply the node updates, and ensure the nodes reconstruct the tree.
+            for (position, node) in tree.to_node_updates(&previous)? {
+                match node {
+                    Some(node) => nodes.insert(position, node),
+                    None => nodes.remove(&position),
+                };
+            }
+            let candidate = MappingTree::from_nodes(nodes.clone())?;
+            assert_eq!(tree.root(), candidate.root());
+            assert_eq!(tree.number_of_leaves(), candidate.number_of_leaves());
+            assert_eq!(candidate.to_node_updates(&MappingTree::new(vec![])?)?.len(), nodes.len());
+            previous = tree;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_key_ids_fail() {
+        let rng = &mut TestRng::default();
+
+        let key_id = Uniform::rand(rng);
+        assert!(
+            MappingTree::<CurrentNetwork>::new(vec![(key_id, Uniform::rand(rng)), (key_id, Uniform::rand(rng))])
+                .is_err()
+        );
+    }
+}
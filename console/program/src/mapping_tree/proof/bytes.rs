@@ -0,0 +1,82 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for MappingProof<N> {
+    /// Reads the mapping proof from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid mapping proof version"));
+        }
+        // Read the number of siblings.
+        let num_siblings = u8::read_le(&mut reader)?;
+        // Read the siblings.
+        let siblings = (0..num_siblings).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the leaf.
+        let leaf = match bool::read_le(&mut reader)? {
+            true => Some((Field::read_le(&mut reader)?, Field::read_le(&mut reader)?)),
+            false => None,
+        };
+        // Return the mapping proof.
+        Ok(Self::new(siblings, leaf))
+    }
+}
+
+impl<N: Network> ToBytes for MappingProof<N> {
+    /// Writes the mapping proof to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the number of siblings.
+        u8::try_from(self.siblings.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the siblings.
+        self.siblings.write_le(&mut writer)?;
+        // Write the leaf.
+        match self.leaf {
+            Some((key_id, value_hash)) => {
+                true.write_le(&mut writer)?;
+                key_id.write_le(&mut writer)?;
+                value_hash.write_le(&mut writer)
+            }
+            None => false.write_le(&mut writer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the proof.
+            let expected = test_helpers::sample_proof(&mut rng);
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, MappingProof::read_le(&expected_bytes[..])?);
+        }
+        Ok(())
+    }
+}
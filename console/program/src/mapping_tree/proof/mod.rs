@@ -0,0 +1,219 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::{Identifier, MappingTree, Plaintext, ProgramID, Value};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+/// An inclusion or exclusion proof for an entry in the mapping tree.
+#[derive(Clone, PartialEq, Eq)]
+pub struct MappingProof<N: Network> {
+    /// The sibling hashes, from the root of the tree to the terminal node.
+    siblings: Vec<Field<N>>,
+    /// The terminal node, as (key ID, value hash), or `None` if the terminal node is empty.
+    leaf: Option<(Field<N>, Field<N>)>,
+}
+
+impl<N: Network> MappingProof<N> {
+    /// Initializes a new instance of `MappingProof`.
+    pub const fn new(siblings: Vec<Field<N>>, leaf: Option<(Field<N>, Field<N>)>) -> Self {
+        Self { siblings, leaf }
+    }
+
+    /// Returns the sibling hashes, from the root of the tree to the terminal node.
+    pub fn siblings(&self) -> &[Field<N>] {
+        &self.siblings
+    }
+
+    /// Returns the terminal node, as (key ID, value hash), or `None` if the terminal node is empty.
+    pub const fn leaf(&self) -> Option<(Field<N>, Field<N>)> {
+        self.leaf
+    }
+
+    /// Returns `true` if the proof shows that the given `key` in the given mapping has the given `value` under the `root`.
+    /// If `value` is `None`, returns `true` if the proof shows that the given `key` does not exist under the `root`.
+    pub fn verify(
+        &self,
+        root: &Field<N>,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+        value: Option<&Value<N>>,
+    ) -> bool {
+        // Compute the key ID.
+        let Ok(key_id) = MappingTree::to_key_id(program_id, mapping_name, key) else {
+            return false;
+        };
+        // Compute the value hash.
+        let value_hash = match value.map(MappingTree::to_value_hash).transpose() {
+            Ok(value_hash) => value_hash,
+            Err(_) => return false,
+        };
+        self.verify_entry(root, &key_id, value_hash.as_ref())
+    }
+
+    /// Returns `true` if the proof shows that the given `key ID` has the given `value hash` under the `root`.
+    /// If `value hash` is `None`, returns `true` if the proof shows that the given `key ID` does not exist under the `root`.
+    pub fn verify_entry(&self, root: &Field<N>, key_id: &Field<N>, value_hash: Option<&Field<N>>) -> bool {
+        let depth = self.siblings.len();
+        let key_bits = key_id.to_bits_le();
+        // Ensure the proof does not exceed the maximum depth.
+        if depth >= key_bits.len() {
+            return false;
+        }
+
+        // Compute the hash of the terminal node.
+        let mut node = match (self.leaf, value_hash) {
+            // For an inclusion proof, the terminal node must be the leaf for the key ID and value hash.
+            (Some((leaf_key_id, leaf_value_hash)), Some(value_hash)) => {
+                if leaf_key_id != *key_id || leaf_value_hash != *value_hash {
+                    return false;
+                }
+                match MappingTree::to_value_id(leaf_key_id, leaf_value_hash) {
+                    Ok(value_id) => value_id,
+                    Err(_) => return false,
+                }
+            }
+            // For an exclusion proof, the terminal node may be the leaf for a different key ID
+            // that shares the same path, as the key ID would otherwise be placed in its subtree.
+            (Some((leaf_key_id, leaf_value_hash)), None) => {
+                if leaf_key_id == *key_id || leaf_key_id.to_bits_le()[..depth] != key_bits[..depth] {
+                    return false;
+                }
+                match MappingTree::to_value_id(leaf_key_id, leaf_value_hash) {
+                    Ok(value_id) => value_id,
+                    Err(_) => return false,
+                }
+            }
+            // For an exclusion proof, the terminal node may be an empty subtree.
+            (None, None) => Field::zero(),
+            (None, Some(_)) => return false,
+        };
+
+        // Compute the root, from the terminal node up to the root.
+        for (sibling, bit) in self.siblings.iter().zip(&key_bits[..depth]).rev() {
+            let hash = match bit {
+                true => N::hash_psd2(&[*sibling, node]),
+                false => N::hash_psd2(&[node, *sibling]),
+            };
+            match hash {
+                Ok(hash) => node = hash,
+                Err(_) => return false,
+            }
+        }
+        // Ensure the computed root matches.
+        node == *root
+    }
+}
+
+#[cfg(test)]
+mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    pub(super) fn sample_proof(rng: &mut TestRng) -> MappingProof<CurrentNetwork> {
+        // Sample a tree.
+        let entries = (0..32).map(|_| (Uniform::rand(rng), Uniform::rand(rng))).collect::<Vec<_>>();
+        let tree = MappingTree::<CurrentNetwork>::new(entries.clone()).unwrap();
+        // Prove an entry in the tree.
+        tree.prove(&entries[rng.gen_range(0..entries.len())].0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: usize = 25;
+
+    #[test]
+    fn test_verify_entry() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for num_entries in 0..ITERATIONS {
+            let entries = (0..num_entries).map(|_| (Uniform::rand(rng), Uniform::rand(rng))).collect::<Vec<_>>();
+            let tree = MappingTree::<CurrentNetwork>::new(entries.clone())?;
+            let root = tree.root();
+
+            // Check the inclusion proofs.
+            for (key_id, value_hash) in &entries {
+                let proof = tree.prove(key_id);
+                assert!(proof.verify_entry(&root, key_id, Some(value_hash)));
+                // Ensure the proof does not verify for a different value, or as an exclusion proof.
+                assert!(!proof.verify_entry(&root, key_id, Some(&Uniform::rand(rng))));
+                assert!(!proof.verify_entry(&root, key_id, None));
+                // Ensure the proof does not verify for a different root.
+                assert!(!proof.verify_entry(&Uniform::rand(rng), key_id, Some(value_hash)));
+            }
+
+            // Check the exclusion proofs.
+            for _ in 0..ITERATIONS {
+                let key_id = Uniform::rand(rng);
+                let proof = tree.prove(&key_id);
+                assert!(proof.verify_entry(&root, &key_id, None));
+                assert!(!proof.verify_entry(&root, &key_id, Some(&Uniform::rand(rng))));
+            }
+
+            // Ensure an exclusion proof can not be forged for an existing entry.
+            if let Some((key_id, value_hash)) = entries.first() {
+                let proof = tree.prove(key_id);
+                let forged = MappingProof::new(proof.siblings().to_vec(), None);
+                assert!(!forged.verify_entry(&root, key_id, None));
+                // Ensure an inclusion proof can not be forged at a different depth.
+                if let Some((_, siblings)) = proof.siblings().split_last() {
+                    let forged = MappingProof::new(siblings.to_vec(), proof.leaf());
+                    assert!(!forged.verify_entry(&root, key_id, Some(value_hash)));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify() -> Result<()> {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("credits.aleo")?;
+        let mapping_name = Identifier::from_str("account")?;
+        let key = Plaintext::from_str("aleo1rhgdu77hgyqd3xjj8ucu3jj9r2krwz6mnzyd80gncr5fxcwlh5rsvzp9px")?;
+        let value = Value::from_str("100u64")?;
+
+        // Construct a tree with the entry.
+        let key_id = MappingTree::to_key_id(&program_id, &mapping_name, &key)?;
+        let value_hash = MappingTree::to_value_hash(&value)?;
+        let tree = MappingTree::<CurrentNetwork>::new(vec![(key_id, value_hash)])?;
+
+        // Check the inclusion proof.
+        let proof = tree.prove(&key_id);
+        assert!(proof.verify(&tree.root(), &program_id, &mapping_name, &key, Some(&value)));
+        assert!(!proof.verify(&tree.root(), &program_id, &mapping_name, &key, Some(&Value::from_str("101u64")?)));
+        assert!(!proof.verify(&tree.root(), &program_id, &Identifier::from_str("bonded")?, &key, Some(&value)));
+
+        // Check the exclusion proof.
+        let other_key = Plaintext::from_str("aleo1s3ws5tra87fjycnjrwsjcrnw2qxr8jfqqdugnf0xzqqw29q9m5pqem2u4t")?;
+        let other_key_id = MappingTree::to_key_id(&program_id, &mapping_name, &other_key)?;
+        let proof = tree.prove(&other_key_id);
+        assert!(proof.verify(&tree.root(), &program_id, &mapping_name, &other_key, None));
+        assert!(!proof.verify(&tree.root(), &program_id, &mapping_name, &key, None));
+        Ok(())
+    }
+}
@@ -22,7 +22,7 @@ impl<N: Network> FromBytes for Header<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid header version"));
         }
 
@@ -30,16 +30,27 @@ impl<N: Network> FromBytes for Header<N> {
         let previous_state_root = N::StateRoot::read_le(&mut reader)?;
         let transactions_root = Field::<N>::read_le(&mut reader)?;
         let finalize_root = Field::<N>::read_le(&mut reader)?;
+        // Note: The mapping root is only written in version 2 (and is otherwise zero).
+        let mapping_root = match version {
+            1 => Field::zero(),
+            _ => Field::<N>::read_le(&mut reader)?,
+        };
         let ratifications_root = Field::<N>::read_le(&mut reader)?;
         let solutions_root = Field::<N>::read_le(&mut reader)?;
         let subdag_root = Field::<N>::read_le(&mut reader)?;
         let metadata = Metadata::read_le(&mut reader)?;
 
+        // Ensure the version matches the block height.
+        if version != Self::version_at_height(metadata.height()) {
+            return Err(error(format!("Invalid header version {version} for block {}", metadata.height())));
+        }
+
         // Construct the block header.
         Self::from(
             previous_state_root,
             transactions_root,
             finalize_root,
+            mapping_root,
             ratifications_root,
             solutions_root,
             subdag_root,
@@ -53,13 +64,19 @@ impl<N: Network> ToBytes for Header<N> {
     /// Writes the block header to the buffer.
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Determine the version.
+        // Note: Version 2 is only used from `N::CONSENSUS_V3_HEIGHT` onwards, so that prior headers are unchanged.
+        let version = self.version();
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        version.write_le(&mut writer)?;
 
         // Write to the buffer.
         self.previous_state_root.write_le(&mut writer)?;
         self.transactions_root.write_le(&mut writer)?;
         self.finalize_root.write_le(&mut writer)?;
+        if version == 2 {
+            self.mapping_root.write_le(&mut writer)?;
+        }
         self.ratifications_root.write_le(&mut writer)?;
         self.solutions_root.write_le(&mut writer)?;
         self.subdag_root.write_le(&mut writer)?;
@@ -86,4 +103,64 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_with_mapping_root() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Samples a header at the given height, with the given mapping root.
+        let mut sample_header = |height: u32, mapping_root: Field<CurrentNetwork>| {
+            let coinbase_target = u64::rand(rng);
+            Header::<CurrentNetwork>::from(
+                Into::<<CurrentNetwork as Network>::StateRoot>::into(Field::rand(rng)),
+                Field::rand(rng),
+                Field::rand(rng),
+                mapping_root,
+                Field::rand(rng),
+                Field::rand(rng),
+                Field::rand(rng),
+                Metadata::new(
+                    CurrentNetwork::ID,
+                    u64::rand(rng),
+                    height,
+                    u128::rand(rng),
+                    u128::rand(rng),
+                    coinbase_target,
+                    rng.gen_range(0..coinbase_target),
+                    u64::rand(rng),
+                    rng.gen_range(0..i64::MAX),
+                    rng.gen_range(0..i64::MAX),
+                )?,
+            )
+        };
+
+        // Ensure a header from the consensus V3 height onwards is written with version 2.
+        let header = sample_header(CurrentNetwork::CONSENSUS_V3_HEIGHT, Field::rand(rng))?;
+        let expected_bytes = header.to_bytes_le()?;
+        assert_eq!(expected_bytes[0], 2);
+        assert_eq!(header, Header::read_le(&expected_bytes[..])?);
+
+        // Ensure a header prior to the consensus V3 height is written with version 1.
+        let previous_header = sample_header(CurrentNetwork::CONSENSUS_V3_HEIGHT - 1, Field::zero())?;
+        let previous_bytes = previous_header.to_bytes_le()?;
+        assert_eq!(previous_bytes[0], 1);
+        assert_eq!(previous_header, Header::read_le(&previous_bytes[..])?);
+
+        // Ensure a header prior to the consensus V3 height cannot commit to a mapping root.
+        assert!(sample_header(CurrentNetwork::CONSENSUS_V3_HEIGHT - 1, Field::rand(rng)).is_err());
+
+        // Ensure a version 1 header is rejected from the consensus V3 height onwards.
+        let mut candidate_bytes = expected_bytes[..1 + 3 * 32].to_vec();
+        candidate_bytes[0] = 1;
+        candidate_bytes.extend(&expected_bytes[1 + 4 * 32..]);
+        assert!(Header::<CurrentNetwork>::read_le(&candidate_bytes[..]).is_err());
+
+        // Ensure a version 2 header is rejected prior to the consensus V3 height.
+        let mut candidate_bytes = previous_bytes[..1 + 3 * 32].to_vec();
+        candidate_bytes[0] = 2;
+        candidate_bytes.extend(Field::<CurrentNetwork>::zero().to_bytes_le()?);
+        candidate_bytes.extend(&previous_bytes[1 + 3 * 32..]);
+        assert!(Header::<CurrentNetwork>::read_le(&candidate_bytes[..]).is_err());
+        Ok(())
+    }
 }
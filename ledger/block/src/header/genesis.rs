@@ -31,6 +31,7 @@ impl<N: Network> Header<N> {
         let previous_state_root = Into::<N::StateRoot>::into(Field::zero());
         let transactions_root = transactions.to_transactions_root()?;
         let finalize_root = transactions.to_finalize_root(ratified_finalize_operations)?;
+        let mapping_root = Field::zero();
         let ratifications_root = ratifications.to_ratifications_root()?;
         let solutions_root = Field::zero();
         let subdag_root = Field::zero();
@@ -41,6 +42,7 @@ impl<N: Network> Header<N> {
             previous_state_root,
             transactions_root,
             finalize_root,
+            mapping_root,
             ratifications_root,
             solutions_root,
             subdag_root,
@@ -56,6 +58,8 @@ impl<N: Network> Header<N> {
             && self.transactions_root != Field::zero()
             // Ensure the finalize root is nonzero.
             && self.finalize_root != Field::zero()
+            // Ensure the mapping root is zero.
+            && self.mapping_root == Field::zero()
             // Ensure the ratifications root is nonzero.
             && self.ratifications_root != Field::zero()
             // Ensure the solutions root is zero.
@@ -114,9 +118,9 @@ mod tests {
         assert_eq!(header.round(), 0);
         assert_eq!(header.height(), 0);
         assert_eq!(header.cumulative_weight(), 0);
-        assert_eq!(header.coinbase_target(), CurrentNetwork::GENESIS_COINBASE_TARGET);
-        assert_eq!(header.proof_target(), CurrentNetwork::GENESIS_PROOF_TARGET);
-        assert_eq!(header.last_coinbase_target(), CurrentNetwork::GENESIS_COINBASE_TARGET);
+        assert_eq!(header.coinbase_target(), CurrentNetwork::genesis_coinbase_target());
+        assert_eq!(header.proof_target(), CurrentNetwork::genesis_proof_target());
+        assert_eq!(header.last_coinbase_target(), CurrentNetwork::genesis_coinbase_target());
         assert_eq!(header.last_coinbase_timestamp(), CurrentNetwork::GENESIS_TIMESTAMP);
         assert_eq!(header.timestamp(), CurrentNetwork::GENESIS_TIMESTAMP);
 
@@ -53,6 +53,10 @@ impl<N: Network> Header<N> {
         else if id == &self.subdag_root {
             Ok(HeaderLeaf::<N>::new(5, self.subdag_root))
         }
+        // If the ID is the mapping root, return the 6th leaf.
+        else if id == &self.mapping_root {
+            Ok(HeaderLeaf::<N>::new(6, self.mapping_root))
+        }
         // If the ID is the metadata hash, then return the 7th leaf.
         else if id == &self.metadata.to_hash()? {
             Ok(HeaderLeaf::<N>::new(7, *id))
@@ -76,7 +80,12 @@ impl<N: Network> Header<N> {
         leaves.push(HeaderLeaf::<N>::new(3, self.ratifications_root).to_bits_le());
         leaves.push(HeaderLeaf::<N>::new(4, self.solutions_root).to_bits_le());
         leaves.push(HeaderLeaf::<N>::new(5, self.subdag_root).to_bits_le());
-        leaves.push(HeaderLeaf::<N>::new(6, Field::zero()).to_bits_le());
+        // Note: The 6th leaf is only set to the mapping root from `N::CONSENSUS_V3_HEIGHT` onwards.
+        let mapping_root = match Self::commits_mapping_root(self.height()) {
+            true => self.mapping_root,
+            false => Field::zero(),
+        };
+        leaves.push(HeaderLeaf::<N>::new(6, mapping_root).to_bits_le());
         leaves.push(HeaderLeaf::<N>::new(7, self.metadata.to_hash()?).to_bits_le());
 
         // Ensure the correct number of leaves are allocated.
@@ -122,10 +131,11 @@ mod tests {
                 Field::rand(rng),
                 Field::rand(rng),
                 Field::rand(rng),
+                Field::rand(rng),
                 Metadata::new(
                     CurrentNetwork::ID,
                     u64::rand(rng),
-                    u32::rand(rng),
+                    rng.gen_range(CurrentNetwork::CONSENSUS_V3_HEIGHT..u32::MAX),
                     u128::rand(rng),
                     u128::rand(rng),
                     coinbase_target,
@@ -169,6 +179,11 @@ mod tests {
             assert_eq!(leaf.index(), 5);
             check_path(header.to_path(&leaf)?, root, &leaf)?;
 
+            // Check the 6th leaf.
+            let leaf = header.to_leaf(&header.mapping_root())?;
+            assert_eq!(leaf.index(), 6);
+            check_path(header.to_path(&leaf)?, root, &leaf)?;
+
             // Check the 7th leaf.
             let leaf = header.to_leaf(&CurrentNetwork::hash_bhp1024(&header.metadata().to_bits_le())?)?;
             assert_eq!(leaf.index(), 7);
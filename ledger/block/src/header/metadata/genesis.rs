@@ -24,9 +24,9 @@ impl<N: Network> Metadata<N> {
         let height = 0;
         let cumulative_weight = 0;
         let cumulative_proof_target = 0;
-        let coinbase_target = N::GENESIS_COINBASE_TARGET;
-        let proof_target = N::GENESIS_PROOF_TARGET;
-        let last_coinbase_target = N::GENESIS_COINBASE_TARGET;
+        let coinbase_target = N::genesis_coinbase_target();
+        let proof_target = N::genesis_proof_target();
+        let last_coinbase_target = N::genesis_coinbase_target();
         let last_coinbase_timestamp = N::GENESIS_TIMESTAMP;
         let timestamp = N::GENESIS_TIMESTAMP;
 
@@ -58,11 +58,11 @@ impl<N: Network> Metadata<N> {
             // Ensure the cumulative proof target in the genesis block is 0.
             && self.cumulative_proof_target == 0u128
             // Ensure the coinbase target in the genesis block is `GENESIS_COINBASE_TARGET`.
-            && self.coinbase_target == N::GENESIS_COINBASE_TARGET
+            && self.coinbase_target == N::genesis_coinbase_target()
             // Ensure the proof target in the genesis block is `GENESIS_PROOF_TARGET`.
-            && self.proof_target == N::GENESIS_PROOF_TARGET
+            && self.proof_target == N::genesis_proof_target()
             // Ensure the last coinbase target in the genesis block is `GENESIS_COINBASE_TARGET`.
-            && self.last_coinbase_target == N::GENESIS_COINBASE_TARGET
+            && self.last_coinbase_target == N::genesis_coinbase_target()
             // Ensure the last coinbase timestamp in the genesis block is `GENESIS_TIMESTAMP`.
             && self.last_coinbase_timestamp == N::GENESIS_TIMESTAMP
             // Ensure the timestamp in the genesis block is `GENESIS_TIMESTAMP`.
@@ -113,9 +113,9 @@ mod tests {
         assert_eq!(metadata.height(), 0);
         assert_eq!(metadata.cumulative_weight(), 0);
         assert_eq!(metadata.cumulative_proof_target(), 0);
-        assert_eq!(metadata.coinbase_target(), CurrentNetwork::GENESIS_COINBASE_TARGET);
-        assert_eq!(metadata.proof_target(), CurrentNetwork::GENESIS_PROOF_TARGET);
-        assert_eq!(metadata.last_coinbase_target(), CurrentNetwork::GENESIS_COINBASE_TARGET);
+        assert_eq!(metadata.coinbase_target(), CurrentNetwork::genesis_coinbase_target());
+        assert_eq!(metadata.proof_target(), CurrentNetwork::genesis_proof_target());
+        assert_eq!(metadata.last_coinbase_target(), CurrentNetwork::genesis_coinbase_target());
         assert_eq!(metadata.last_coinbase_timestamp(), CurrentNetwork::GENESIS_TIMESTAMP);
         assert_eq!(metadata.timestamp(), CurrentNetwork::GENESIS_TIMESTAMP);
     }
@@ -101,13 +101,13 @@ impl<N: Network> Metadata<N> {
                     // Ensure the round is at least as large as the height.
                     && self.round >= self.height as u64
                     // Ensure the coinbase target is at or above the minimum.
-                    && self.coinbase_target >= N::GENESIS_COINBASE_TARGET
+                    && self.coinbase_target >= N::genesis_coinbase_target()
                     // Ensure the proof target is at or above the minimum.
-                    && self.proof_target >= N::GENESIS_PROOF_TARGET
+                    && self.proof_target >= N::genesis_proof_target()
                     // Ensure the coinbase target is larger than the proof target.
                     && self.coinbase_target > self.proof_target
                     // Ensure the last coinbase target is at or above the minimum.
-                    && self.last_coinbase_target >= N::GENESIS_COINBASE_TARGET
+                    && self.last_coinbase_target >= N::genesis_coinbase_target()
                     // Ensure the last coinbase timestamp is after the genesis timestamp.
                     && self.last_coinbase_timestamp >= N::GENESIS_TIMESTAMP
                     // Ensure the timestamp in the block is after the genesis timestamp.
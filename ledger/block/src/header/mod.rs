@@ -40,6 +40,9 @@ pub struct Header<N: Network> {
     transactions_root: Field<N>,
     /// The Merkle root representing the on-chain finalize including the current block.
     finalize_root: Field<N>,
+    /// The Merkle root representing the on-chain mappings up to the previous block.
+    /// Note: This is zero for blocks prior to `N::CONSENSUS_V3_HEIGHT`.
+    mapping_root: Field<N>,
     /// The Merkle root representing the ratifications in the block.
     ratifications_root: Field<N>,
     /// The solutions root of the puzzle.
@@ -56,6 +59,7 @@ impl<N: Network> Header<N> {
         previous_state_root: N::StateRoot,
         transactions_root: Field<N>,
         finalize_root: Field<N>,
+        mapping_root: Field<N>,
         ratifications_root: Field<N>,
         solutions_root: Field<N>,
         subdag_root: Field<N>,
@@ -66,6 +70,7 @@ impl<N: Network> Header<N> {
             previous_state_root,
             transactions_root,
             finalize_root,
+            mapping_root,
             ratifications_root,
             solutions_root,
             subdag_root,
@@ -89,6 +94,8 @@ impl<N: Network> Header<N> {
                     && self.transactions_root != Field::zero()
                     // Ensure the finalize root is nonzero.
                     && self.finalize_root != Field::zero()
+                    // Ensure the mapping root is zero, if the block header does not commit to it.
+                    && (Self::commits_mapping_root(self.height()) || self.mapping_root == Field::zero())
                     // Ensure the ratifications root is nonzero.
                     && self.ratifications_root != Field::zero()
                     // Ensure the metadata is valid.
@@ -97,6 +104,26 @@ impl<N: Network> Header<N> {
         }
     }
 
+    /// Returns `true` if the block header at the given height commits to the mapping root.
+    /// Note: The mapping root is only committed from `N::CONSENSUS_V3_HEIGHT` onwards, and never in the genesis block.
+    pub fn commits_mapping_root(height: u32) -> bool {
+        height > 0 && height >= N::CONSENSUS_V3_HEIGHT
+    }
+
+    /// Returns the version of the block header at the given height.
+    /// Note: Version 2 adds the mapping root, and is used if and only if the block header commits to it.
+    pub fn version_at_height(height: u32) -> u8 {
+        match Self::commits_mapping_root(height) {
+            true => 2,
+            false => 1,
+        }
+    }
+
+    /// Returns the version of the block header.
+    pub fn version(&self) -> u8 {
+        Self::version_at_height(self.height())
+    }
+
     /// Returns the previous state root from the block header.
     pub const fn previous_state_root(&self) -> N::StateRoot {
         self.previous_state_root
@@ -112,6 +139,11 @@ impl<N: Network> Header<N> {
         self.finalize_root
     }
 
+    /// Returns the mapping root in the block header.
+    pub const fn mapping_root(&self) -> Field<N> {
+        self.mapping_root
+    }
+
     /// Returns the ratifications root in the block header.
     pub const fn ratifications_root(&self) -> Field<N> {
         self.ratifications_root
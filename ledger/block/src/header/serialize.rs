@@ -20,10 +20,15 @@ impl<N: Network> Serialize for Header<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut header = serializer.serialize_struct("Header", 7)?;
+                // Note: The mapping root is only serialized in version 2, so that prior headers are unchanged.
+                let num_fields = 7 + (self.version() == 2) as usize;
+                let mut header = serializer.serialize_struct("Header", num_fields)?;
                 header.serialize_field("previous_state_root", &self.previous_state_root)?;
                 header.serialize_field("transactions_root", &self.transactions_root)?;
                 header.serialize_field("finalize_root", &self.finalize_root)?;
+                if self.version() == 2 {
+                    header.serialize_field("mapping_root", &self.mapping_root)?;
+                }
                 header.serialize_field("ratifications_root", &self.ratifications_root)?;
                 header.serialize_field("solutions_root", &self.solutions_root)?;
                 header.serialize_field("subdag_root", &self.subdag_root)?;
@@ -45,6 +50,10 @@ impl<'de, N: Network> Deserialize<'de> for Header<N> {
                     DeserializeExt::take_from_value::<D>(&mut header, "previous_state_root")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "transactions_root")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "finalize_root")?,
+                    match header.get("mapping_root") {
+                        Some(_) => DeserializeExt::take_from_value::<D>(&mut header, "mapping_root")?,
+                        None => Field::zero(),
+                    },
                     DeserializeExt::take_from_value::<D>(&mut header, "ratifications_root")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "solutions_root")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "subdag_root")?,
@@ -24,6 +24,7 @@ impl<N: Network> Header<N> {
         expected_previous_state_root: N::StateRoot,
         expected_transactions_root: Field<N>,
         expected_finalize_root: Field<N>,
+        expected_mapping_root: Field<N>,
         expected_ratifications_root: Field<N>,
         expected_solutions_root: Field<N>,
         expected_subdag_root: Field<N>,
@@ -61,6 +62,13 @@ impl<N: Network> Header<N> {
             self.finalize_root,
             expected_finalize_root
         );
+        // Ensure the mapping root is correct.
+        ensure!(
+            self.mapping_root == expected_mapping_root,
+            "Mapping root is incorrect in block {expected_height} (found '{}', expected '{}')",
+            self.mapping_root,
+            expected_mapping_root
+        );
         // Ensure the ratifications root is correct.
         ensure!(
             self.ratifications_root == expected_ratifications_root,
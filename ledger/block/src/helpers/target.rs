@@ -455,13 +455,13 @@ pub fn to_next_targets<N: Network>(
         last_coinbase_target,
         last_coinbase_timestamp,
         next_timestamp,
-        N::ANCHOR_TIME,
-        N::NUM_BLOCKS_PER_EPOCH,
-        N::GENESIS_COINBASE_TARGET,
+        N::anchor_time(),
+        N::num_blocks_per_epoch(),
+        N::genesis_coinbase_target(),
     )?;
     // Construct the next proof target.
     let next_proof_target =
-        proof_target(next_coinbase_target, N::GENESIS_PROOF_TARGET, N::MAX_SOLUTIONS_AS_POWER_OF_TWO);
+        proof_target(next_coinbase_target, N::genesis_proof_target(), N::max_solutions_as_power_of_two());
 
     // Update the next cumulative proof target, if necessary.
     let next_cumulative_proof_target = match is_coinbase_threshold_reached {
@@ -507,19 +507,19 @@ mod tests {
         // Check the anchor block reward at block 1.
         let reward_at_block_1 = anchor_block_reward_at_height(
             1,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_height(),
+            CurrentNetwork::block_time(),
         );
         assert_eq!(reward_at_block_1, EXPECTED_ANCHOR_BLOCK_REWARD_AT_BLOCK_1);
 
         // A helper function to check the the reward at the first expected block of a given year.
         fn check_reward_at_year(year: u32, expected_reward: u128) {
             let reward_at_year = anchor_block_reward_at_height(
-                block_height_at_year(CurrentNetwork::BLOCK_TIME, year),
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_HEIGHT,
-                CurrentNetwork::BLOCK_TIME,
+                block_height_at_year(CurrentNetwork::block_time(), year),
+                CurrentNetwork::starting_supply(),
+                CurrentNetwork::anchor_height(),
+                CurrentNetwork::block_time(),
             );
             assert_eq!(reward_at_year, expected_reward);
         }
@@ -542,17 +542,17 @@ mod tests {
         check_reward_at_year(15, 19_025_874);
 
         // Calculate the block height at year 9.
-        let block_height_at_year_9 = block_height_at_year(CurrentNetwork::BLOCK_TIME, 9);
+        let block_height_at_year_9 = block_height_at_year(CurrentNetwork::block_time(), 9);
 
         // Ensure that the reward is decreasing for blocks before year 9.
         let mut previous_reward = reward_at_block_1;
-        let anchor_height = CurrentNetwork::ANCHOR_HEIGHT as usize;
+        let anchor_height = CurrentNetwork::anchor_height() as usize;
         for height in (2..block_height_at_year_9).step_by(anchor_height).skip(1) {
             let reward = anchor_block_reward_at_height(
                 height,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_HEIGHT,
-                CurrentNetwork::BLOCK_TIME,
+                CurrentNetwork::starting_supply(),
+                CurrentNetwork::anchor_height(),
+                CurrentNetwork::block_time(),
             );
             assert!(reward < previous_reward, "Failed on block height {height}");
             previous_reward = reward;
@@ -562,9 +562,9 @@ mod tests {
         for height in block_height_at_year_9..(block_height_at_year_9 + ITERATIONS) {
             let reward = anchor_block_reward_at_height(
                 height,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_HEIGHT,
-                CurrentNetwork::BLOCK_TIME,
+                CurrentNetwork::starting_supply(),
+                CurrentNetwork::anchor_height(),
+                CurrentNetwork::block_time(),
             );
             assert_eq!(reward, 19_025_874);
         }
@@ -574,10 +574,10 @@ mod tests {
     fn test_anchor_block_reward_v2() {
         // Check the anchor block reward at block 1.
         let reward_at_block_1 = anchor_block_reward_at_timestamp(
-            CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::BLOCK_TIME as i64,
+            CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::block_time() as i64,
             CurrentNetwork::GENESIS_TIMESTAMP,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_time(),
         );
         assert_eq!(reward_at_block_1, EXPECTED_ANCHOR_BLOCK_REWARD_AT_BLOCK_1);
 
@@ -586,8 +586,8 @@ mod tests {
             let reward_at_year = anchor_block_reward_at_timestamp(
                 timestamp_at_year(CurrentNetwork::GENESIS_TIMESTAMP, year),
                 CurrentNetwork::GENESIS_TIMESTAMP,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_TIME,
+                CurrentNetwork::starting_supply(),
+                CurrentNetwork::anchor_time(),
             );
             assert_eq!(reward_at_year, expected_reward);
         }
@@ -614,13 +614,13 @@ mod tests {
 
         // Ensure that the reward is decreasing for blocks before year 9.
         let mut previous_reward = reward_at_block_1;
-        let anchor_time = CurrentNetwork::ANCHOR_TIME as usize;
+        let anchor_time = CurrentNetwork::anchor_time() as usize;
         for timestamp in (CurrentNetwork::GENESIS_TIMESTAMP..timestamp_at_year_9).step_by(anchor_time).skip(1) {
             let reward = anchor_block_reward_at_timestamp(
                 timestamp,
                 CurrentNetwork::GENESIS_TIMESTAMP,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_TIME,
+                CurrentNetwork::starting_supply(),
+                CurrentNetwork::anchor_time(),
             );
             assert!(reward < previous_reward, "Failed on timestamp {timestamp}");
             previous_reward = reward;
@@ -631,8 +631,8 @@ mod tests {
             let reward = anchor_block_reward_at_timestamp(
                 timestamp,
                 CurrentNetwork::GENESIS_TIMESTAMP,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_TIME,
+                CurrentNetwork::starting_supply(),
+                CurrentNetwork::anchor_time(),
             );
             assert_eq!(reward, 19_025_874);
         }
@@ -645,9 +645,9 @@ mod tests {
             for height in start_height..end_height {
                 *total_reward += anchor_block_reward_at_height(
                     height,
-                    CurrentNetwork::STARTING_SUPPLY,
-                    CurrentNetwork::ANCHOR_HEIGHT,
-                    CurrentNetwork::BLOCK_TIME,
+                    CurrentNetwork::starting_supply(),
+                    CurrentNetwork::anchor_height(),
+                    CurrentNetwork::block_time(),
                 );
             }
         }
@@ -658,8 +658,8 @@ mod tests {
         // A helper function to check the sum of all possible anchor rewards over a given year.
         let mut check_sum_of_anchor_rewards = |year: u32, expected_reward: u128| {
             assert!(year > 0, "Year must be greater than 0");
-            let end_height = block_height_at_year(CurrentNetwork::BLOCK_TIME, year);
-            let start_height = std::cmp::max(1, block_height_at_year(CurrentNetwork::BLOCK_TIME, year - 1));
+            let end_height = block_height_at_year(CurrentNetwork::block_time(), year);
+            let start_height = std::cmp::max(1, block_height_at_year(CurrentNetwork::block_time(), year - 1));
             add_anchor_block_reward(&mut total_reward, start_height, end_height);
             assert_eq!(total_reward, expected_reward);
         };
@@ -700,12 +700,12 @@ mod tests {
     fn test_total_anchor_block_reward_v2() {
         // A helper function used to add the anchor block reward for a given range of block timestamps.
         fn add_anchor_block_reward(total_reward: &mut u128, start_timestamp: i64, end_timestamp: i64) {
-            for timestamp in (start_timestamp..end_timestamp).step_by(CurrentNetwork::BLOCK_TIME as usize) {
+            for timestamp in (start_timestamp..end_timestamp).step_by(CurrentNetwork::block_time() as usize) {
                 *total_reward += anchor_block_reward_at_timestamp(
                     timestamp,
                     CurrentNetwork::GENESIS_TIMESTAMP,
-                    CurrentNetwork::STARTING_SUPPLY,
-                    CurrentNetwork::ANCHOR_TIME,
+                    CurrentNetwork::starting_supply(),
+                    CurrentNetwork::anchor_time(),
                 );
             }
         }
@@ -766,13 +766,13 @@ mod tests {
         let time_since_last_block = rng.gen_range(1..=V2_MAX_BLOCK_INTERVAL);
         let reward = block_reward::<TestnetV0>(
             TestnetV0::CONSENSUS_V2_HEIGHT,
-            TestnetV0::STARTING_SUPPLY,
-            TestnetV0::BLOCK_TIME,
+            TestnetV0::starting_supply(),
+            TestnetV0::block_time(),
             time_since_last_block,
             0,
             0,
         );
-        let expected_reward = block_reward_v2(TestnetV0::STARTING_SUPPLY, time_since_last_block, 0, 0);
+        let expected_reward = block_reward_v2(TestnetV0::starting_supply(), time_since_last_block, 0, 0);
         assert_eq!(reward, expected_reward);
 
         for _ in 0..100 {
@@ -780,13 +780,13 @@ mod tests {
             let consensus_v1_height = rng.gen_range(0..TestnetV0::CONSENSUS_V2_HEIGHT);
             let consensus_v1_reward = block_reward::<TestnetV0>(
                 consensus_v1_height,
-                TestnetV0::STARTING_SUPPLY,
-                TestnetV0::BLOCK_TIME,
+                TestnetV0::starting_supply(),
+                TestnetV0::block_time(),
                 0,
                 0,
                 0,
             );
-            let expected_reward = block_reward_v1(TestnetV0::STARTING_SUPPLY, TestnetV0::BLOCK_TIME, 0, 0);
+            let expected_reward = block_reward_v1(TestnetV0::starting_supply(), TestnetV0::block_time(), 0, 0);
             assert_eq!(consensus_v1_reward, expected_reward);
 
             // Check that the block reward is correct for the second consensus version.
@@ -794,55 +794,55 @@ mod tests {
             let time_since_last_block = rng.gen_range(1..=V2_MAX_BLOCK_INTERVAL);
             let consensus_v2_reward = block_reward::<TestnetV0>(
                 consensus_v2_height,
-                TestnetV0::STARTING_SUPPLY,
-                TestnetV0::BLOCK_TIME,
+                TestnetV0::starting_supply(),
+                TestnetV0::block_time(),
                 time_since_last_block,
                 0,
                 0,
             );
-            let expected_reward = block_reward_v2(TestnetV0::STARTING_SUPPLY, time_since_last_block, 0, 0);
+            let expected_reward = block_reward_v2(TestnetV0::starting_supply(), time_since_last_block, 0, 0);
             assert_eq!(consensus_v2_reward, expected_reward);
         }
     }
 
     #[test]
     fn test_block_reward_v1() {
-        let reward = block_reward_v1(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME, 0, 0);
+        let reward = block_reward_v1(CurrentNetwork::starting_supply(), CurrentNetwork::block_time(), 0, 0);
         assert_eq!(reward, EXPECTED_STAKING_REWARD);
 
         // Increasing the anchor time will increase the reward.
-        let larger_reward = block_reward_v1(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME + 1, 0, 0);
+        let larger_reward = block_reward_v1(CurrentNetwork::starting_supply(), CurrentNetwork::block_time() + 1, 0, 0);
         assert!(reward < larger_reward);
 
         // Decreasing the anchor time will decrease the reward.
-        let smaller_reward = block_reward_v1(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME - 1, 0, 0);
+        let smaller_reward = block_reward_v1(CurrentNetwork::starting_supply(), CurrentNetwork::block_time() - 1, 0, 0);
         assert!(reward > smaller_reward);
     }
 
     #[test]
     fn test_block_reward_v2() {
-        let reward = block_reward_v2(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME as i64, 0, 0);
+        let reward = block_reward_v2(CurrentNetwork::starting_supply(), CurrentNetwork::block_time() as i64, 0, 0);
         assert_eq!(reward, EXPECTED_STAKING_REWARD);
 
         // Increasing the anchor time will increase the reward.
         let larger_reward =
-            block_reward_v2(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME as i64 + 1, 0, 0);
+            block_reward_v2(CurrentNetwork::starting_supply(), CurrentNetwork::block_time() as i64 + 1, 0, 0);
         assert!(reward < larger_reward);
 
         // Decreasing the anchor time will decrease the reward.
         let smaller_reward =
-            block_reward_v2(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME as i64 - 1, 0, 0);
+            block_reward_v2(CurrentNetwork::starting_supply(), CurrentNetwork::block_time() as i64 - 1, 0, 0);
         assert!(reward > smaller_reward);
 
         // Increasing the block interval past `V2_MAX_BLOCK_INTERVAL` does not increase the reward.
-        let max_reward = block_reward_v2(CurrentNetwork::STARTING_SUPPLY, V2_MAX_BLOCK_INTERVAL, 0, 0);
+        let max_reward = block_reward_v2(CurrentNetwork::starting_supply(), V2_MAX_BLOCK_INTERVAL, 0, 0);
         assert_eq!(max_reward, EXPECTED_MAX_STAKING_REWARD);
-        let equivalent_reward = block_reward_v2(CurrentNetwork::STARTING_SUPPLY, V2_MAX_BLOCK_INTERVAL + 1, 0, 0);
+        let equivalent_reward = block_reward_v2(CurrentNetwork::starting_supply(), V2_MAX_BLOCK_INTERVAL + 1, 0, 0);
         assert_eq!(max_reward, equivalent_reward);
 
         // Test that there is a minimum block reward when the time since last block is 1 second.
-        let min_reward = block_reward_v2(CurrentNetwork::STARTING_SUPPLY, 1, 0, 0);
-        let equivalent_reward = block_reward_v2(CurrentNetwork::STARTING_SUPPLY, 0, 0, 0);
+        let min_reward = block_reward_v2(CurrentNetwork::starting_supply(), 1, 0, 0);
+        let equivalent_reward = block_reward_v2(CurrentNetwork::starting_supply(), 0, 0, 0);
         assert_eq!(min_reward, equivalent_reward);
     }
 
@@ -853,21 +853,21 @@ mod tests {
         // Declare a tolerance for reward divergence between v1 and v2 due to truncation.
         const TOLERANCE: f64 = 0.001; // 0.1% tolerance
 
-        // Expect that the v2 block reward is equivalent to the v1 block reward if the `CurrentNetwork::BLOCK_TIME` is fixed.
-        let reward_v1 = block_reward_v1(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME, 0, 0);
+        // Expect that the v2 block reward is equivalent to the v1 block reward if the `CurrentNetwork::block_time()` is fixed.
+        let reward_v1 = block_reward_v1(CurrentNetwork::starting_supply(), CurrentNetwork::block_time(), 0, 0);
         assert_eq!(reward_v1, EXPECTED_STAKING_REWARD);
-        let reward_v2 = block_reward_v2(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME as i64, 0, 0);
+        let reward_v2 = block_reward_v2(CurrentNetwork::starting_supply(), CurrentNetwork::block_time() as i64, 0, 0);
         assert_eq!(reward_v1, reward_v2);
 
-        // Decreasing the time since last block based on `CurrentNetwork::BLOCK_TIME` will proportionally reduce the v2 rewards.
-        let shorter_time = CurrentNetwork::BLOCK_TIME / 2;
-        let smaller_reward = block_reward_v2(CurrentNetwork::STARTING_SUPPLY, shorter_time as i64, 0, 0);
+        // Decreasing the time since last block based on `CurrentNetwork::block_time()` will proportionally reduce the v2 rewards.
+        let shorter_time = CurrentNetwork::block_time() / 2;
+        let smaller_reward = block_reward_v2(CurrentNetwork::starting_supply(), shorter_time as i64, 0, 0);
         let expected_reward = EXPECTED_STAKING_REWARD / 2;
         assert!((smaller_reward as f64 - expected_reward as f64).abs() / expected_reward as f64 <= TOLERANCE);
 
-        // Increasing the time since last block based on `CurrentNetwork::BLOCK_TIME` will proportionally increase the v2 rewards (up to a certain cap).
-        let longer_time = CurrentNetwork::BLOCK_TIME * 2;
-        let larger_reward = block_reward_v2(CurrentNetwork::STARTING_SUPPLY, longer_time as i64, 0, 0);
+        // Increasing the time since last block based on `CurrentNetwork::block_time()` will proportionally increase the v2 rewards (up to a certain cap).
+        let longer_time = CurrentNetwork::block_time() * 2;
+        let larger_reward = block_reward_v2(CurrentNetwork::starting_supply(), longer_time as i64, 0, 0);
         let expected_reward = EXPECTED_STAKING_REWARD * 2;
         assert!((larger_reward as f64 - expected_reward as f64).abs() / expected_reward as f64 <= TOLERANCE);
 
@@ -876,16 +876,16 @@ mod tests {
             let factor = rng.gen_range(1..10);
 
             // Ensure that scaling the time elapsed down scales the reward down proportionally.
-            let shorter_time = CurrentNetwork::BLOCK_TIME / factor;
-            let time_factor: f64 = CurrentNetwork::BLOCK_TIME as f64 / shorter_time as f64;
-            let smaller_reward = block_reward_v2(CurrentNetwork::STARTING_SUPPLY, shorter_time as i64, 0, 0);
+            let shorter_time = CurrentNetwork::block_time() / factor;
+            let time_factor: f64 = CurrentNetwork::block_time() as f64 / shorter_time as f64;
+            let smaller_reward = block_reward_v2(CurrentNetwork::starting_supply(), shorter_time as i64, 0, 0);
             let expected_reward = (EXPECTED_STAKING_REWARD as f64 / time_factor) as u64;
             assert!((smaller_reward as f64 - expected_reward as f64).abs() / expected_reward as f64 <= TOLERANCE);
 
             // Ensure that scaling the time elapsed up scales the reward up proportionally (up to a certain cap).
-            let longer_time = CurrentNetwork::BLOCK_TIME * factor;
-            let time_factor: f64 = longer_time as f64 / CurrentNetwork::BLOCK_TIME as f64;
-            let larger_reward = block_reward_v2(CurrentNetwork::STARTING_SUPPLY, longer_time as i64, 0, 0);
+            let longer_time = CurrentNetwork::block_time() * factor;
+            let time_factor: f64 = longer_time as f64 / CurrentNetwork::block_time() as f64;
+            let larger_reward = block_reward_v2(CurrentNetwork::starting_supply(), longer_time as i64, 0, 0);
             let expected_reward = (EXPECTED_STAKING_REWARD as f64 * time_factor) as u64;
             match longer_time as i64 > V2_MAX_BLOCK_INTERVAL {
                 true => assert_eq!(larger_reward, EXPECTED_MAX_STAKING_REWARD),
@@ -902,15 +902,15 @@ mod tests {
 
         // Ensure that a block height of `TestnetV0::CONSENSUS_V2_HEIGHT` uses coinbase reward V2.
         let block_timestamp = TestnetV0::GENESIS_TIMESTAMP
-            .saturating_add(TestnetV0::CONSENSUS_V2_HEIGHT.saturating_mul(TestnetV0::BLOCK_TIME as u32) as i64);
+            .saturating_add(TestnetV0::CONSENSUS_V2_HEIGHT.saturating_mul(TestnetV0::block_time() as u32) as i64);
         let reward = coinbase_reward::<TestnetV0>(
             TestnetV0::CONSENSUS_V2_HEIGHT,
             block_timestamp,
             TestnetV0::GENESIS_TIMESTAMP,
-            TestnetV0::STARTING_SUPPLY,
-            TestnetV0::ANCHOR_TIME,
-            TestnetV0::ANCHOR_HEIGHT,
-            TestnetV0::BLOCK_TIME,
+            TestnetV0::starting_supply(),
+            TestnetV0::anchor_time(),
+            TestnetV0::anchor_height(),
+            TestnetV0::block_time(),
             1,
             0,
             1,
@@ -919,8 +919,8 @@ mod tests {
         let expected_reward = coinbase_reward_v2(
             block_timestamp,
             TestnetV0::GENESIS_TIMESTAMP,
-            TestnetV0::STARTING_SUPPLY,
-            TestnetV0::ANCHOR_TIME,
+            TestnetV0::starting_supply(),
+            TestnetV0::anchor_time(),
             1,
             0,
             1,
@@ -932,15 +932,15 @@ mod tests {
             // Check that the block reward is correct for the first consensus version.
             let consensus_v1_height = rng.gen_range(0..TestnetV0::CONSENSUS_V2_HEIGHT);
             let block_timestamp = TestnetV0::GENESIS_TIMESTAMP
-                .saturating_add(consensus_v1_height.saturating_mul(TestnetV0::BLOCK_TIME as u32) as i64);
+                .saturating_add(consensus_v1_height.saturating_mul(TestnetV0::block_time() as u32) as i64);
             let consensus_v1_reward = coinbase_reward::<TestnetV0>(
                 consensus_v1_height,
                 block_timestamp,
                 TestnetV0::GENESIS_TIMESTAMP,
-                TestnetV0::STARTING_SUPPLY,
-                TestnetV0::ANCHOR_TIME,
-                TestnetV0::ANCHOR_HEIGHT,
-                TestnetV0::BLOCK_TIME,
+                TestnetV0::starting_supply(),
+                TestnetV0::anchor_time(),
+                TestnetV0::anchor_height(),
+                TestnetV0::block_time(),
                 1,
                 0,
                 1,
@@ -948,9 +948,9 @@ mod tests {
             .unwrap();
             let expected_reward = coinbase_reward_v1(
                 consensus_v1_height,
-                TestnetV0::STARTING_SUPPLY,
-                TestnetV0::ANCHOR_HEIGHT,
-                TestnetV0::BLOCK_TIME,
+                TestnetV0::starting_supply(),
+                TestnetV0::anchor_height(),
+                TestnetV0::block_time(),
                 1,
                 0,
                 1,
@@ -961,15 +961,15 @@ mod tests {
             // Check that the block reward is correct for the second consensus version.
             let consensus_v2_height = rng.gen_range(TestnetV0::CONSENSUS_V2_HEIGHT..u32::MAX);
             let block_timestamp = TestnetV0::GENESIS_TIMESTAMP
-                .saturating_add(consensus_v2_height.saturating_mul(TestnetV0::BLOCK_TIME as u32) as i64);
+                .saturating_add(consensus_v2_height.saturating_mul(TestnetV0::block_time() as u32) as i64);
             let consensus_v2_reward = coinbase_reward::<TestnetV0>(
                 consensus_v2_height,
                 block_timestamp,
                 TestnetV0::GENESIS_TIMESTAMP,
-                TestnetV0::STARTING_SUPPLY,
-                TestnetV0::ANCHOR_TIME,
-                TestnetV0::ANCHOR_HEIGHT,
-                TestnetV0::BLOCK_TIME,
+                TestnetV0::starting_supply(),
+                TestnetV0::anchor_time(),
+                TestnetV0::anchor_height(),
+                TestnetV0::block_time(),
                 1,
                 0,
                 1,
@@ -978,8 +978,8 @@ mod tests {
             let expected_reward = coinbase_reward_v2(
                 block_timestamp,
                 TestnetV0::GENESIS_TIMESTAMP,
-                TestnetV0::STARTING_SUPPLY,
-                TestnetV0::ANCHOR_TIME,
+                TestnetV0::starting_supply(),
+                TestnetV0::anchor_time(),
                 1,
                 0,
                 1,
@@ -996,9 +996,9 @@ mod tests {
 
         let reward = coinbase_reward_v1(
             1,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_height(),
+            CurrentNetwork::block_time(),
             combined_proof_target,
             0,
             coinbase_target,
@@ -1009,9 +1009,9 @@ mod tests {
         // Halving the combined proof target halves the reward.
         let smaller_reward = coinbase_reward_v1(
             1,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_height(),
+            CurrentNetwork::block_time(),
             combined_proof_target / 2,
             0,
             coinbase_target,
@@ -1022,9 +1022,9 @@ mod tests {
         // Halving the remaining coinbase target halves the reward.
         let smaller_reward = coinbase_reward_v1(
             1,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_height(),
+            CurrentNetwork::block_time(),
             combined_proof_target,
             coinbase_target / 2,
             coinbase_target,
@@ -1035,9 +1035,9 @@ mod tests {
         // Dramatically increasing the combined proof target greater than the remaining coinbase target will not increase the reward.
         let equivalent_reward = coinbase_reward_v1(
             1,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_height(),
+            CurrentNetwork::block_time(),
             u128::MAX,
             0,
             coinbase_target,
@@ -1048,9 +1048,9 @@ mod tests {
         // Decreasing the combined proof target to 0 will result in a reward of 0.
         let zero_reward = coinbase_reward_v1(
             1,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_height(),
+            CurrentNetwork::block_time(),
             0,
             0,
             coinbase_target,
@@ -1061,9 +1061,9 @@ mod tests {
         // Increasing the cumulative proof target beyond the coinbase target will result in a reward of 0.
         let zero_reward = coinbase_reward_v1(
             1,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_height(),
+            CurrentNetwork::block_time(),
             1,
             coinbase_target + 1,
             coinbase_target,
@@ -1078,10 +1078,10 @@ mod tests {
         let combined_proof_target: u128 = coinbase_target as u128;
 
         let reward = coinbase_reward_v2(
-            CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::BLOCK_TIME as i64,
+            CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::block_time() as i64,
             CurrentNetwork::GENESIS_TIMESTAMP,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_time(),
             combined_proof_target,
             0,
             coinbase_target,
@@ -1091,10 +1091,10 @@ mod tests {
 
         // Halving the combined proof target halves the reward.
         let smaller_reward = coinbase_reward_v2(
-            CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::BLOCK_TIME as i64,
+            CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::block_time() as i64,
             CurrentNetwork::GENESIS_TIMESTAMP,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_time(),
             combined_proof_target / 2,
             0,
             coinbase_target,
@@ -1104,10 +1104,10 @@ mod tests {
 
         // Halving the remaining coinbase target halves the reward.
         let smaller_reward = coinbase_reward_v2(
-            CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::BLOCK_TIME as i64,
+            CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::block_time() as i64,
             CurrentNetwork::GENESIS_TIMESTAMP,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_time(),
             combined_proof_target,
             coinbase_target / 2,
             coinbase_target,
@@ -1117,10 +1117,10 @@ mod tests {
 
         // Dramatically increasing the combined proof target greater than the remaining coinbase target will not increase the reward.
         let equivalent_reward = coinbase_reward_v2(
-            CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::BLOCK_TIME as i64,
+            CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::block_time() as i64,
             CurrentNetwork::GENESIS_TIMESTAMP,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_time(),
             u128::MAX,
             0,
             coinbase_target,
@@ -1130,10 +1130,10 @@ mod tests {
 
         // Decreasing the combined proof target to 0 will result in a reward of 0.
         let zero_reward = coinbase_reward_v2(
-            CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::BLOCK_TIME as i64,
+            CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::block_time() as i64,
             CurrentNetwork::GENESIS_TIMESTAMP,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_time(),
             0,
             0,
             coinbase_target,
@@ -1143,10 +1143,10 @@ mod tests {
 
         // Increasing the cumulative proof target beyond the coinbase target will result in a reward of 0.
         let zero_reward = coinbase_reward_v2(
-            CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::BLOCK_TIME as i64,
+            CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::block_time() as i64,
             CurrentNetwork::GENESIS_TIMESTAMP,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_time(),
             1,
             coinbase_target + 1,
             coinbase_target,
@@ -1166,9 +1166,9 @@ mod tests {
         ) -> u64 {
             coinbase_reward_v1(
                 1,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_HEIGHT,
-                CurrentNetwork::BLOCK_TIME,
+                CurrentNetwork::starting_supply(),
+                CurrentNetwork::anchor_height(),
+                CurrentNetwork::block_time(),
                 combined_proof_target as u128,
                 cumulative_proof_target,
                 coinbase_target,
@@ -1223,10 +1223,10 @@ mod tests {
             coinbase_target: u64,
         ) -> u64 {
             coinbase_reward_v2(
-                CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::BLOCK_TIME as i64,
+                CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::block_time() as i64,
                 CurrentNetwork::GENESIS_TIMESTAMP,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_TIME,
+                CurrentNetwork::starting_supply(),
+                CurrentNetwork::anchor_time(),
                 combined_proof_target as u128,
                 cumulative_proof_target,
                 coinbase_target,
@@ -1273,15 +1273,15 @@ mod tests {
 
     #[test]
     fn test_coinbase_reward_v1_up_to_year_10() {
-        let block_height_at_year_10 = block_height_at_year(CurrentNetwork::BLOCK_TIME, 10);
+        let block_height_at_year_10 = block_height_at_year(CurrentNetwork::block_time(), 10);
 
         let mut block_height = 1;
 
         let mut previous_reward = coinbase_reward_v1(
             block_height,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_height(),
+            CurrentNetwork::block_time(),
             1,
             0,
             1,
@@ -1292,7 +1292,7 @@ mod tests {
 
         let mut total_reward = previous_reward;
 
-        let coinbase_target = CurrentNetwork::ANCHOR_HEIGHT as u64;
+        let coinbase_target = CurrentNetwork::anchor_height() as u64;
         let mut cumulative_proof_target = 0;
 
         let mut hit_500m = false;
@@ -1301,9 +1301,9 @@ mod tests {
         while block_height < block_height_at_year_10 {
             let reward = coinbase_reward_v1(
                 block_height,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_HEIGHT,
-                CurrentNetwork::BLOCK_TIME,
+                CurrentNetwork::starting_supply(),
+                CurrentNetwork::anchor_height(),
+                CurrentNetwork::block_time(),
                 1,
                 cumulative_proof_target,
                 coinbase_target,
@@ -1342,21 +1342,21 @@ mod tests {
         let mut timestamp = CurrentNetwork::GENESIS_TIMESTAMP;
 
         let mut previous_reward = coinbase_reward_v2(
-            CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::BLOCK_TIME as i64,
+            CurrentNetwork::GENESIS_TIMESTAMP + CurrentNetwork::block_time() as i64,
             CurrentNetwork::GENESIS_TIMESTAMP,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_time(),
             1,
             0,
             1,
         )
         .unwrap();
 
-        timestamp += CurrentNetwork::BLOCK_TIME as i64;
+        timestamp += CurrentNetwork::block_time() as i64;
 
         let mut total_reward = previous_reward;
 
-        let coinbase_target = CurrentNetwork::ANCHOR_HEIGHT as u64;
+        let coinbase_target = CurrentNetwork::anchor_height() as u64;
         let mut cumulative_proof_target = 0;
 
         let mut hit_500m = false;
@@ -1366,8 +1366,8 @@ mod tests {
             let reward = coinbase_reward_v2(
                 timestamp,
                 CurrentNetwork::GENESIS_TIMESTAMP,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_TIME,
+                CurrentNetwork::starting_supply(),
+                CurrentNetwork::anchor_time(),
                 1,
                 cumulative_proof_target,
                 coinbase_target,
@@ -1377,7 +1377,7 @@ mod tests {
 
             total_reward += reward;
             previous_reward = reward;
-            timestamp += CurrentNetwork::BLOCK_TIME as i64;
+            timestamp += CurrentNetwork::block_time() as i64;
 
             // Update the cumulative proof target.
             cumulative_proof_target = match cumulative_proof_target + 1 {
@@ -1403,14 +1403,14 @@ mod tests {
     fn test_coinbase_reward_v1_after_year_10() {
         let mut rng = TestRng::default();
 
-        let block_height_at_year_10 = block_height_at_year(CurrentNetwork::BLOCK_TIME, 10);
+        let block_height_at_year_10 = block_height_at_year(CurrentNetwork::block_time(), 10);
 
         // Check that the block at year 10 has a reward of 19.
         let reward = coinbase_reward_v1(
             block_height_at_year_10,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_HEIGHT,
-            CurrentNetwork::BLOCK_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_height(),
+            CurrentNetwork::block_time(),
             1,
             0,
             1,
@@ -1427,17 +1427,17 @@ mod tests {
 
             let anchor_reward = anchor_block_reward_at_height(
                 block_height,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_HEIGHT,
-                CurrentNetwork::BLOCK_TIME,
+                CurrentNetwork::starting_supply(),
+                CurrentNetwork::anchor_height(),
+                CurrentNetwork::block_time(),
             );
             assert_eq!(anchor_reward, 19_025_874);
 
             let reward = coinbase_reward_v1(
                 block_height,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_HEIGHT,
-                CurrentNetwork::BLOCK_TIME,
+                CurrentNetwork::starting_supply(),
+                CurrentNetwork::anchor_height(),
+                CurrentNetwork::block_time(),
                 combined_proof_target,
                 cumulative_proof_target,
                 coinbase_target,
@@ -1457,8 +1457,8 @@ mod tests {
         let reward = coinbase_reward_v2(
             timestamp_at_year_10,
             CurrentNetwork::GENESIS_TIMESTAMP,
-            CurrentNetwork::STARTING_SUPPLY,
-            CurrentNetwork::ANCHOR_TIME,
+            CurrentNetwork::starting_supply(),
+            CurrentNetwork::anchor_time(),
             1,
             0,
             1,
@@ -1476,16 +1476,16 @@ mod tests {
             let anchor_reward = anchor_block_reward_at_timestamp(
                 timestamp,
                 CurrentNetwork::GENESIS_TIMESTAMP,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_TIME,
+                CurrentNetwork::starting_supply(),
+                CurrentNetwork::anchor_time(),
             );
             assert_eq!(anchor_reward, 19_025_874);
 
             let reward = coinbase_reward_v2(
                 timestamp,
                 CurrentNetwork::GENESIS_TIMESTAMP,
-                CurrentNetwork::STARTING_SUPPLY,
-                CurrentNetwork::ANCHOR_TIME,
+                CurrentNetwork::starting_supply(),
+                CurrentNetwork::anchor_time(),
                 combined_proof_target,
                 cumulative_proof_target,
                 coinbase_target,
@@ -1505,65 +1505,65 @@ mod tests {
             let previous_coinbase_target: u64 = rng.gen_range(minimum_coinbase_target..u64::MAX);
             let previous_prover_target = proof_target(
                 previous_coinbase_target,
-                CurrentNetwork::GENESIS_PROOF_TARGET,
-                CurrentNetwork::MAX_SOLUTIONS_AS_POWER_OF_TWO,
+                CurrentNetwork::genesis_proof_target(),
+                CurrentNetwork::max_solutions_as_power_of_two(),
             );
 
             let previous_timestamp = rng.gen();
 
             // Targets stay the same when the drift is as expected.
-            let next_timestamp = previous_timestamp + CurrentNetwork::ANCHOR_TIME as i64;
+            let next_timestamp = previous_timestamp + CurrentNetwork::anchor_time() as i64;
             let new_coinbase_target = coinbase_target(
                 previous_coinbase_target,
                 previous_timestamp,
                 next_timestamp,
-                CurrentNetwork::ANCHOR_TIME,
-                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
-                CurrentNetwork::GENESIS_COINBASE_TARGET,
+                CurrentNetwork::anchor_time(),
+                CurrentNetwork::num_blocks_per_epoch(),
+                CurrentNetwork::genesis_coinbase_target(),
             )
             .unwrap();
             let new_prover_target = proof_target(
                 new_coinbase_target,
-                CurrentNetwork::GENESIS_PROOF_TARGET,
-                CurrentNetwork::MAX_SOLUTIONS_AS_POWER_OF_TWO,
+                CurrentNetwork::genesis_proof_target(),
+                CurrentNetwork::max_solutions_as_power_of_two(),
             );
             assert_eq!(new_coinbase_target, previous_coinbase_target);
             assert_eq!(new_prover_target, previous_prover_target);
 
             // Targets decrease (easier) when the drift is greater than expected.
-            let new_timestamp = previous_timestamp + 2 * CurrentNetwork::ANCHOR_TIME as i64;
+            let new_timestamp = previous_timestamp + 2 * CurrentNetwork::anchor_time() as i64;
             let new_coinbase_target = coinbase_target(
                 previous_coinbase_target,
                 previous_timestamp,
                 new_timestamp,
-                CurrentNetwork::ANCHOR_TIME,
-                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
-                CurrentNetwork::GENESIS_COINBASE_TARGET,
+                CurrentNetwork::anchor_time(),
+                CurrentNetwork::num_blocks_per_epoch(),
+                CurrentNetwork::genesis_coinbase_target(),
             )
             .unwrap();
             let new_prover_target = proof_target(
                 new_coinbase_target,
-                CurrentNetwork::GENESIS_PROOF_TARGET,
-                CurrentNetwork::MAX_SOLUTIONS_AS_POWER_OF_TWO,
+                CurrentNetwork::genesis_proof_target(),
+                CurrentNetwork::max_solutions_as_power_of_two(),
             );
             assert!(new_coinbase_target < previous_coinbase_target);
             assert!(new_prover_target < previous_prover_target);
 
             // Targets increase (harder) when the drift is less than expected.
-            let next_timestamp = previous_timestamp + (CurrentNetwork::ANCHOR_TIME / 2) as i64;
+            let next_timestamp = previous_timestamp + (CurrentNetwork::anchor_time() / 2) as i64;
             let new_coinbase_target = coinbase_target(
                 previous_coinbase_target,
                 previous_timestamp,
                 next_timestamp,
-                CurrentNetwork::ANCHOR_TIME,
-                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
-                CurrentNetwork::GENESIS_COINBASE_TARGET,
+                CurrentNetwork::anchor_time(),
+                CurrentNetwork::num_blocks_per_epoch(),
+                CurrentNetwork::genesis_coinbase_target(),
             )
             .unwrap();
             let new_prover_target = proof_target(
                 new_coinbase_target,
-                CurrentNetwork::GENESIS_PROOF_TARGET,
-                CurrentNetwork::MAX_SOLUTIONS_AS_POWER_OF_TWO,
+                CurrentNetwork::genesis_proof_target(),
+                CurrentNetwork::max_solutions_as_power_of_two(),
             );
 
             assert!(new_coinbase_target > previous_coinbase_target);
@@ -1585,9 +1585,9 @@ mod tests {
             let previous_coinbase_target: u64 = rng.gen_range(minimum_coinbase_target..u64::MAX);
             let previous_timestamp = rng.gen();
 
-            let half_life = CurrentNetwork::NUM_BLOCKS_PER_EPOCH
+            let half_life = CurrentNetwork::num_blocks_per_epoch()
                 .saturating_div(2)
-                .saturating_mul(CurrentNetwork::ANCHOR_TIME as u32) as i64;
+                .saturating_mul(CurrentNetwork::anchor_time() as u32) as i64;
 
             // New coinbase target is greater than half if the drift equals the half life.
             let next_timestamp = previous_timestamp + half_life;
@@ -1595,37 +1595,37 @@ mod tests {
                 previous_coinbase_target,
                 previous_timestamp,
                 next_timestamp,
-                CurrentNetwork::ANCHOR_TIME,
-                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
-                CurrentNetwork::GENESIS_COINBASE_TARGET,
+                CurrentNetwork::anchor_time(),
+                CurrentNetwork::num_blocks_per_epoch(),
+                CurrentNetwork::genesis_coinbase_target(),
             )
             .unwrap();
 
             assert!(next_coinbase_target > previous_coinbase_target / 2);
 
             // New coinbase target is halved if the drift is 1 anchor height past the half life.
-            let next_timestamp = previous_timestamp + half_life + CurrentNetwork::ANCHOR_TIME as i64;
+            let next_timestamp = previous_timestamp + half_life + CurrentNetwork::anchor_time() as i64;
             let next_coinbase_target = coinbase_target(
                 previous_coinbase_target,
                 previous_timestamp,
                 next_timestamp,
-                CurrentNetwork::ANCHOR_TIME,
-                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
-                CurrentNetwork::GENESIS_COINBASE_TARGET,
+                CurrentNetwork::anchor_time(),
+                CurrentNetwork::num_blocks_per_epoch(),
+                CurrentNetwork::genesis_coinbase_target(),
             )
             .unwrap();
 
             assert_eq!(next_coinbase_target, previous_coinbase_target / 2);
 
             // New coinbase target is less than half if the drift is more than 1 anchor height past the half life.
-            let next_timestamp = previous_timestamp + half_life + 2 * CurrentNetwork::ANCHOR_TIME as i64;
+            let next_timestamp = previous_timestamp + half_life + 2 * CurrentNetwork::anchor_time() as i64;
             let next_coinbase_target = coinbase_target(
                 previous_coinbase_target,
                 previous_timestamp,
                 next_timestamp,
-                CurrentNetwork::ANCHOR_TIME,
-                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
-                CurrentNetwork::GENESIS_COINBASE_TARGET,
+                CurrentNetwork::anchor_time(),
+                CurrentNetwork::num_blocks_per_epoch(),
+                CurrentNetwork::genesis_coinbase_target(),
             )
             .unwrap();
 
@@ -1658,9 +1658,9 @@ mod tests {
                 previous_coinbase_target,
                 previous_timestamp,
                 next_timestamp,
-                CurrentNetwork::ANCHOR_TIME,
-                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
-                CurrentNetwork::GENESIS_COINBASE_TARGET,
+                CurrentNetwork::anchor_time(),
+                CurrentNetwork::num_blocks_per_epoch(),
+                CurrentNetwork::genesis_coinbase_target(),
             )
             .unwrap();
 
@@ -1674,7 +1674,7 @@ mod tests {
         let seconds = previous_timestamp - initial_timestamp;
         println!(
             "For drifts of {ANCHOR_TIME_DELTA} seconds and epochs of {} blocks, doubling the coinbase target took {num_blocks} blocks. ({seconds} seconds)",
-            CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+            CurrentNetwork::num_blocks_per_epoch(),
         );
 
         assert_eq!(EXPECTED_NUM_BLOCKS_TO_DOUBLE, num_blocks);
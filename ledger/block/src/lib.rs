@@ -351,7 +351,7 @@ impl<N: Network> Block<N> {
 
     /// Returns the epoch number of this block.
     pub const fn epoch_number(&self) -> u32 {
-        self.height() / N::NUM_BLOCKS_PER_EPOCH
+        self.height() / N::num_blocks_per_epoch()
     }
 
     /// Returns the cumulative weight for this block.
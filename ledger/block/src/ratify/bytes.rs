@@ -73,7 +73,25 @@ impl<N: Network> FromBytes for Ratify<N> {
                 // Return the ratify object.
                 Self::PuzzleReward(amount)
             }
-            3.. => return Err(error(format!("Failed to decode ratify object variant {variant}"))),
+            3 => {
+                // Read the number of bytes in the restrictions list.
+                let num_bytes = u32::read_le(&mut reader)? as usize;
+                // Ensure the restrictions list does not exceed the maximum size.
+                if num_bytes > Self::MAX_RESTRICTIONS_SIZE {
+                    return Err(error(format!("Restrictions list exceeds the maximum size ({num_bytes} bytes)")));
+                }
+                // Read the restrictions list.
+                let mut bytes = vec![0u8; num_bytes];
+                reader.read_exact(&mut bytes)?;
+                let restrictions = String::from_utf8(bytes).map_err(|e| error(e.to_string()))?;
+                // Read the activation height.
+                let activation_height: u32 = FromBytes::read_le(&mut reader)?;
+                // Read the signature.
+                let signature: Signature<N> = FromBytes::read_le(&mut reader)?;
+                // Return the ratify object.
+                Self::Restrictions(Box::new(restrictions), activation_height, Box::new(signature))
+            }
+            4.. => return Err(error(format!("Failed to decode ratify object variant {variant}"))),
         };
         Ok(ratify)
     }
@@ -111,6 +129,20 @@ impl<N: Network> ToBytes for Ratify<N> {
                 (2 as Variant).write_le(&mut writer)?;
                 amount.write_le(&mut writer)
             }
+            Self::Restrictions(restrictions, activation_height, signature) => {
+                // Ensure the restrictions list does not exceed the maximum size.
+                if restrictions.len() > Self::MAX_RESTRICTIONS_SIZE {
+                    return Err(error(format!(
+                        "Restrictions list exceeds the maximum size ({} bytes)",
+                        restrictions.len()
+                    )));
+                }
+                (3 as Variant).write_le(&mut writer)?;
+                u32::try_from(restrictions.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+                writer.write_all(restrictions.as_bytes())?;
+                activation_height.write_le(&mut writer)?;
+                signature.write_le(&mut writer)
+            }
         }
     }
 }
@@ -17,7 +17,7 @@ mod bytes;
 mod serialize;
 mod string;
 
-use console::{network::prelude::*, types::Address};
+use console::{account::Signature, network::prelude::*, types::Address};
 use ledger_committee::Committee;
 
 use indexmap::IndexMap;
@@ -38,9 +38,15 @@ pub enum Ratify<N: Network> {
     BlockReward(u64),
     /// The puzzle reward.
     PuzzleReward(u64),
+    /// The restrictions update, as the restrictions list (as a JSON-compatible string),
+    /// the activation height, and the signature of the restrictions governor.
+    Restrictions(Box<String>, u32, Box<Signature<N>>),
 }
 
 impl<N: Network> Ratify<N> {
+    /// The maximum number of bytes in the restrictions list of a restrictions update (1 MiB).
+    pub const MAX_RESTRICTIONS_SIZE: usize = 1 << 20;
+
     /// Returns the ratification ID.
     pub fn to_id(&self) -> Result<N::RatificationID> {
         Ok(N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())?.into())
@@ -50,7 +56,7 @@ impl<N: Network> Ratify<N> {
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use super::*;
-    use console::network::MainnetV0;
+    use console::{account::PrivateKey, network::MainnetV0};
 
     type CurrentNetwork = MainnetV0;
 
@@ -70,8 +76,17 @@ pub(crate) mod test_helpers {
             Ratify::Genesis(Box::new(committee), Box::new(public_balances), Box::new(bonded_balances)),
             Ratify::BlockReward(rng.gen()),
             Ratify::PuzzleReward(rng.gen()),
+            sample_restrictions_ratify(rng),
         ]
     }
+
+    /// Samples a restrictions update, with a blank restrictions list.
+    pub(crate) fn sample_restrictions_ratify(rng: &mut TestRng) -> Ratify<CurrentNetwork> {
+        let restrictions = r#"{"restrictions_id":"0field","programs":{},"functions":{},"arguments":{}}"#.to_string();
+        let private_key = PrivateKey::new(rng).unwrap();
+        let signature = Signature::sign(&private_key, &[rng.gen()], rng).unwrap();
+        Ratify::Restrictions(Box::new(restrictions), rng.gen(), Box::new(signature))
+    }
 }
 
 #[cfg(test)]
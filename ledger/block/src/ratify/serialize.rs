@@ -40,6 +40,14 @@ impl<N: Network> Serialize for Ratify<N> {
                     input.serialize_field("amount", &amount)?;
                     input.end()
                 }
+                Self::Restrictions(restrictions, activation_height, signature) => {
+                    let mut input = serializer.serialize_struct("Ratify", 4)?;
+                    input.serialize_field("type", "restrictions")?;
+                    input.serialize_field("restrictions", &restrictions)?;
+                    input.serialize_field("activation_height", &activation_height)?;
+                    input.serialize_field("signature", &signature)?;
+                    input.end()
+                }
             },
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
         }
@@ -80,6 +88,17 @@ impl<'de, N: Network> Deserialize<'de> for Ratify<N> {
                         // Construct the ratify object.
                         Ratify::PuzzleReward(amount)
                     }
+                    Some("restrictions") => {
+                        // Retrieve the restrictions list.
+                        let restrictions: String = DeserializeExt::take_from_value::<D>(&mut object, "restrictions")?;
+                        // Retrieve the activation height.
+                        let activation_height: u32 =
+                            DeserializeExt::take_from_value::<D>(&mut object, "activation_height")?;
+                        // Retrieve the signature.
+                        let signature: Signature<N> = DeserializeExt::take_from_value::<D>(&mut object, "signature")?;
+                        // Construct the ratify object.
+                        Ratify::Restrictions(Box::new(restrictions), activation_height, Box::new(signature))
+                    }
                     _ => return Err(de::Error::custom("Invalid ratify object type")),
                 };
                 // Return the ratify object.
@@ -71,7 +71,7 @@ pub(super) mod tests {
     pub(crate) fn sample_solutions(rng: &mut TestRng) -> Solutions<CurrentNetwork> {
         // Sample a new solutions.
         let mut solutions = vec![];
-        for _ in 0..rng.gen_range(1..=CurrentNetwork::MAX_SOLUTIONS) {
+        for _ in 0..rng.gen_range(1..=CurrentNetwork::max_solutions()) {
             let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
             let address = Address::try_from(private_key).unwrap();
             let partial_solution = PartialSolution::new(rng.gen(), address, u64::rand(rng)).unwrap();
@@ -362,6 +362,24 @@ impl<N: Network> Transition<N> {
             && self.program_id.to_string() == "credits.aleo"
             && self.function_name.to_string() == "split"
     }
+
+    /// Returns `true` if this is a `transfer_private_to_public` transition.
+    #[inline]
+    pub fn is_transfer_private_to_public(&self) -> bool {
+        self.inputs.len() == 3
+            && self.outputs.len() == 2
+            && self.program_id.to_string() == "credits.aleo"
+            && self.function_name.to_string() == "transfer_private_to_public"
+    }
+
+    /// Returns `true` if this is a `transfer_public_to_private` transition.
+    #[inline]
+    pub fn is_transfer_public_to_private(&self) -> bool {
+        self.inputs.len() == 2
+            && self.outputs.len() == 2
+            && self.program_id.to_string() == "credits.aleo"
+            && self.function_name.to_string() == "transfer_public_to_private"
+    }
 }
 
 impl<N: Network> Transition<N> {
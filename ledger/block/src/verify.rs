@@ -31,6 +31,7 @@ impl<N: Network> Block<N> {
         &self,
         previous_block: &Block<N>,
         current_state_root: N::StateRoot,
+        current_mapping_root: Field<N>,
         previous_committee_lookback: &Committee<N>,
         current_committee_lookback: &Committee<N>,
         current_puzzle: &Puzzle<N>,
@@ -79,6 +80,12 @@ impl<N: Network> Block<N> {
         let expected_transactions_root = self.compute_transactions_root()?;
         // Compute the expected finalize root.
         let expected_finalize_root = self.compute_finalize_root(ratified_finalize_operations)?;
+        // Set the expected mapping root.
+        // Note: The mapping root is only committed from `N::CONSENSUS_V3_HEIGHT` onwards.
+        let expected_mapping_root = match Header::<N>::commits_mapping_root(self.height()) {
+            true => current_mapping_root,
+            false => Field::zero(),
+        };
         // Compute the expected ratifications root.
         let expected_ratifications_root = self.compute_ratifications_root()?;
         // Compute the expected solutions root.
@@ -91,6 +98,7 @@ impl<N: Network> Block<N> {
             expected_previous_state_root,
             expected_transactions_root,
             expected_finalize_root,
+            expected_mapping_root,
             expected_ratifications_root,
             expected_solutions_root,
             expected_subdag_root,
@@ -296,6 +304,21 @@ impl<N: Network> Block<N> {
             Some(Ratify::PuzzleReward(puzzle_reward)) => *puzzle_reward,
             _ => bail!("Block {height} is invalid - the second ratification must be a puzzle reward"),
         };
+        // Ensure the remaining ratifications contain at most one restrictions update.
+        let mut num_restrictions_updates = 0usize;
+        for ratify in ratifications_iter {
+            match ratify {
+                Ratify::Restrictions(..) => num_restrictions_updates += 1,
+                _ => bail!("Block {height} is invalid - the remaining ratifications must be restrictions updates"),
+            }
+        }
+        ensure!(num_restrictions_updates <= 1, "Block {height} is invalid - found multiple restrictions updates");
+        // Ensure restrictions updates are only ratified from `N::CONSENSUS_V3_HEIGHT` onwards.
+        ensure!(
+            num_restrictions_updates == 0 || height >= N::CONSENSUS_V3_HEIGHT,
+            "Block {height} is invalid - restrictions updates are only supported from block {}",
+            N::CONSENSUS_V3_HEIGHT
+        );
 
         // Ensure the block reward is correct.
         ensure!(
@@ -322,10 +345,10 @@ impl<N: Network> Block<N> {
 
         // Ensure the number of solutions is within the allowed range.
         ensure!(
-            self.solutions.len() <= N::MAX_SOLUTIONS,
+            self.solutions.len() <= N::max_solutions(),
             "Block {height} contains too many prover solutions (found '{}', expected '{}')",
             self.solutions.len(),
-            N::MAX_SOLUTIONS
+            N::max_solutions()
         );
         // Ensure the number of aborted solution IDs is within the allowed range.
         ensure!(
@@ -392,10 +415,10 @@ impl<N: Network> Block<N> {
             height,
             timestamp,
             N::GENESIS_TIMESTAMP,
-            N::STARTING_SUPPLY,
-            N::ANCHOR_TIME,
-            N::ANCHOR_HEIGHT,
-            N::BLOCK_TIME,
+            N::starting_supply(),
+            N::anchor_time(),
+            N::anchor_height(),
+            N::block_time(),
             combined_proof_target,
             u64::try_from(previous_block.cumulative_proof_target())?,
             previous_block.coinbase_target(),
@@ -410,8 +433,8 @@ impl<N: Network> Block<N> {
         // Compute the expected block reward.
         let expected_block_reward = block_reward::<N>(
             height,
-            N::STARTING_SUPPLY,
-            N::BLOCK_TIME,
+            N::starting_supply(),
+            N::block_time(),
             time_since_last_block,
             expected_coinbase_reward,
             expected_transaction_fees,
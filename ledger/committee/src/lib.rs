@@ -21,6 +21,9 @@ mod serialize;
 mod string;
 mod to_id;
 
+mod proof;
+pub use proof::*;
+
 #[cfg(any(test, feature = "prop-tests"))]
 pub mod prop_tests;
 
@@ -64,6 +67,12 @@ impl<N: Network> Committee<N> {
     /// The maximum number of members that may be in a committee.
     pub const MAX_COMMITTEE_SIZE: u16 = BatchHeader::<N>::MAX_CERTIFICATES;
 
+    /// Returns the maximum number of members that may be in a committee, as configured by the network.
+    /// This is at most `MAX_COMMITTEE_SIZE`, which bounds the encodings.
+    pub fn max_committee_size() -> u16 {
+        BatchHeader::<N>::max_certificates()
+    }
+
     /// Initializes a new `Committee` instance.
     pub fn new_genesis(members: IndexMap<Address<N>, (u64, bool, u8)>) -> Result<Self> {
         // Return the new committee.
@@ -76,9 +85,9 @@ impl<N: Network> Committee<N> {
         ensure!(members.len() >= 3, "Committee must have at least 3 members");
         // Ensure there are no more than the maximum number of members.
         ensure!(
-            members.len() <= Self::MAX_COMMITTEE_SIZE as usize,
+            members.len() <= Self::max_committee_size() as usize,
             "Committee must have no more than {} members",
-            Self::MAX_COMMITTEE_SIZE
+            Self::max_committee_size()
         );
         // Ensure all members have the minimum required stake.
         ensure!(
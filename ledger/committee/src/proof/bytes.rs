@@ -0,0 +1,70 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for CommitteeProof<N> {
+    /// Reads the committee proof from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid committee proof version"));
+        }
+        // Read the number of proofs.
+        let num_proofs = u16::read_le(&mut reader)?;
+        // Ensure the number of proofs does not exceed the entries of the maximum committee size.
+        if num_proofs > Committee::<N>::MAX_COMMITTEE_SIZE.saturating_mul(2).saturating_add(1) {
+            return Err(error(format!("Committee proof contains too many entries ({num_proofs})")));
+        }
+        // Read the proofs.
+        let proofs = (0..num_proofs).map(|_| MappingProof::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the committee proof.
+        Ok(Self::new(proofs))
+    }
+}
+
+impl<N: Network> ToBytes for CommitteeProof<N> {
+    /// Writes the committee proof to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the number of proofs.
+        u16::try_from(self.proofs.len()).map_err(error)?.write_le(&mut writer)?;
+        // Write the proofs.
+        self.proofs.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proof::tests::{sample_mapping_tree, sample_proof};
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the committee proof.
+        let committee = crate::test_helpers::sample_committee(rng);
+        let expected = sample_proof(&committee, &sample_mapping_tree(&committee));
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert!(expected == CommitteeProof::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
+}
@@ -0,0 +1,162 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+
+use crate::Committee;
+use console::{
+    prelude::*,
+    program::{Identifier, Literal, MappingProof, Plaintext, ProgramID, Value},
+    types::Field,
+};
+
+/// A proof that a committee is the committee in the `credits.aleo` mappings under a mapping root.
+///
+/// The proof consists of the inclusion proofs of the number of committee members in the `metadata` mapping,
+/// followed by the `committee` and `delegated` entries of each committee member, in committee order.
+/// As the number of committee members is proven, the committee can not omit any member.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CommitteeProof<N: Network> {
+    /// The inclusion proofs of the committee entries.
+    proofs: Vec<MappingProof<N>>,
+}
+
+impl<N: Network> CommitteeProof<N> {
+    /// Initializes a new committee proof from the given inclusion proofs.
+    pub const fn new(proofs: Vec<MappingProof<N>>) -> Self {
+        Self { proofs }
+    }
+
+    /// Returns a committee proof for the given committee, using the given function to prove each mapping entry.
+    /// Note: The given function must prove against the mapping root that committed to the given committee.
+    pub fn prove(
+        committee: &Committee<N>,
+        get_mapping_proof: impl Fn(&ProgramID<N>, &Identifier<N>, &Plaintext<N>) -> Result<MappingProof<N>>,
+    ) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        // Prove each committee entry.
+        let proofs = Self::to_entries(committee)?
+            .iter()
+            .map(|(mapping_name, key, _)| get_mapping_proof(&program_id, mapping_name, key))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::new(proofs))
+    }
+
+    /// Returns the inclusion proofs of the committee entries.
+    pub fn proofs(&self) -> &[MappingProof<N>] {
+        &self.proofs
+    }
+
+    /// Ensures the proof shows the given committee is the committee under the given mapping root.
+    pub fn verify(&self, mapping_root: &Field<N>, committee: &Committee<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        // Construct the committee entries.
+        let entries = Self::to_entries(committee)?;
+        // Ensure there is a proof for each committee entry.
+        ensure!(
+            self.proofs.len() == entries.len(),
+            "Committee proof has an incorrect number of entries (found '{}', expected '{}')",
+            self.proofs.len(),
+            entries.len()
+        );
+        // Ensure each committee entry is included under the mapping root.
+        for (proof, (mapping_name, key, value)) in self.proofs.iter().zip(&entries) {
+            ensure!(
+                proof.verify(mapping_root, &program_id, mapping_name, key, Some(value)),
+                "Committee proof is invalid for '{program_id}/{mapping_name}[{key}]' under mapping root '{mapping_root}'"
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the `credits.aleo` mapping entries that define the given committee, as (mapping name, key, value).
+    fn to_entries(committee: &Committee<N>) -> Result<Vec<(Identifier<N>, Plaintext<N>, Value<N>)>> {
+        // Prepare the mapping names.
+        let metadata = Identifier::from_str("metadata")?;
+        let committee_mapping = Identifier::from_str("committee")?;
+        let delegated = Identifier::from_str("delegated")?;
+
+        // Construct the entry for the number of committee members.
+        let mut entries = vec![(
+            metadata,
+            Plaintext::from_str("aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc")?,
+            Value::from_str(&format!("{}u32", committee.num_members()))?,
+        )];
+        // Construct the entries for each committee member.
+        for (address, (microcredits, is_open, commission)) in committee.members() {
+            let key = Plaintext::from(Literal::Address(*address));
+            let state = Value::from_str(&format!("{{ is_open: {is_open}, commission: {commission}u8 }}"))?;
+            entries.push((committee_mapping, key.clone(), state));
+            entries.push((delegated, key, Value::from_str(&format!("{microcredits}u64"))?));
+        }
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::program::MappingTree;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    /// Returns the mapping tree with the `credits.aleo` entries of the given committee.
+    pub(super) fn sample_mapping_tree(committee: &Committee<CurrentNetwork>) -> MappingTree<CurrentNetwork> {
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let entries = CommitteeProof::to_entries(committee).unwrap();
+        MappingTree::new(entries.iter().map(|(mapping_name, key, value)| {
+            (
+                MappingTree::to_key_id(&program_id, mapping_name, key).unwrap(),
+                MappingTree::to_value_hash(value).unwrap(),
+            )
+        }))
+        .unwrap()
+    }
+
+    /// Returns the committee proof of the given committee against the given mapping tree.
+    pub(super) fn sample_proof(
+        committee: &Committee<CurrentNetwork>,
+        mapping_tree: &MappingTree<CurrentNetwork>,
+    ) -> CommitteeProof<CurrentNetwork> {
+        CommitteeProof::prove(committee, |program_id, mapping_name, key| {
+            Ok(mapping_tree.prove(&MappingTree::to_key_id(program_id, mapping_name, key)?))
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_verify() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee, and prove it against its mapping tree.
+        let committee = crate::test_helpers::sample_committee(rng);
+        let mapping_tree = sample_mapping_tree(&committee);
+        let proof = sample_proof(&committee, &mapping_tree);
+        proof.verify(&mapping_tree.root(), &committee).unwrap();
+
+        // Ensure the proof is rejected for a different mapping root.
+        assert!(proof.verify(&Field::rand(rng), &committee).is_err());
+        // Ensure the proof is rejected for a different committee.
+        let mut members = committee.members().clone();
+        members[0].0 += 1;
+        let other_committee = Committee::new(committee.starting_round(), members).unwrap();
+        assert!(proof.verify(&mapping_tree.root(), &other_committee).is_err());
+        // Ensure the proof is rejected if an entry is missing.
+        let invalid_proof = CommitteeProof::new(proof.proofs()[1..].to_vec());
+        assert!(invalid_proof.verify(&mapping_tree.root(), &committee).is_err());
+    }
+}
@@ -70,6 +70,20 @@ impl<N: Network> BatchHeader<N> {
     /// This limit can be increased in the future as performance improves. Alternatively,
     /// the rate of block production can be sped up to compensate for the limit set here.
     pub const MAX_TRANSMISSIONS_PER_BATCH: usize = 50;
+
+    /// Returns the maximum number of certificates in a batch, as configured by the network.
+    /// This is at most `MAX_CERTIFICATES`, which bounds the encodings.
+    #[cfg(not(any(test, feature = "test-helpers")))]
+    pub fn max_certificates() -> u16 {
+        N::max_certificates().min(Self::MAX_CERTIFICATES)
+    }
+
+    /// Returns the maximum number of certificates in a batch.
+    /// This is deliberately set to `MAX_CERTIFICATES` for testing purposes only.
+    #[cfg(any(test, feature = "test-helpers"))]
+    pub fn max_certificates() -> u16 {
+        Self::MAX_CERTIFICATES
+    }
 }
 
 impl<N: Network> BatchHeader<N> {
@@ -100,7 +114,7 @@ impl<N: Network> BatchHeader<N> {
         );
         // Ensure that the number of previous certificate IDs is within bounds.
         ensure!(
-            previous_certificate_ids.len() <= Self::MAX_CERTIFICATES as usize,
+            previous_certificate_ids.len() <= Self::max_certificates() as usize,
             "Invalid number of previous certificate IDs ({})",
             previous_certificate_ids.len()
         );
@@ -158,7 +172,7 @@ impl<N: Network> BatchHeader<N> {
         );
         // Ensure that the number of previous certificate IDs is within bounds.
         ensure!(
-            previous_certificate_ids.len() <= Self::MAX_CERTIFICATES as usize,
+            previous_certificate_ids.len() <= Self::max_certificates() as usize,
             "Invalid number of previous certificate IDs ({})",
             previous_certificate_ids.len()
         );
@@ -59,7 +59,7 @@ fn puzzle_verify(c: &mut Criterion) {
     // Initialize an epoch hash.
     let epoch_hash = rng.gen();
 
-    for batch_size in [1, 2, <MainnetV0 as Network>::MAX_SOLUTIONS] {
+    for batch_size in [1, 2, <MainnetV0 as Network>::max_solutions()] {
         let solutions = (0..batch_size)
             .map(|_| {
                 let (address, counter) = sample_address_and_counter(rng);
@@ -296,6 +296,10 @@ impl<N: Network> Puzzle<N> {
     }
 
     /// Returns `Ok(())` if the solutions are valid.
+    #[cfg_attr(
+        feature = "spans",
+        tracing::instrument(name = "Puzzle::check_solutions", skip_all, fields(num_solutions = solutions.len()))
+    )]
     pub fn check_solutions(
         &self,
         solutions: &PuzzleSolutions<N>,
@@ -303,12 +307,14 @@ impl<N: Network> Puzzle<N> {
         expected_proof_target: u64,
     ) -> Result<()> {
         let timer = timer!("Puzzle::verify");
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
 
         // Ensure the solutions are not empty.
         ensure!(!solutions.is_empty(), "The solutions are empty");
         // Ensure the number of solutions does not exceed `MAX_SOLUTIONS`.
-        if solutions.len() > N::MAX_SOLUTIONS {
-            bail!("Exceed the maximum number of solutions ({} > {})", solutions.len(), N::MAX_SOLUTIONS)
+        if solutions.len() > N::max_solutions() {
+            bail!("Exceed the maximum number of solutions ({} > {})", solutions.len(), N::max_solutions())
         }
         // Ensure the solution IDs are unique.
         if has_duplicates(solutions.solution_ids()) {
@@ -336,6 +342,8 @@ impl<N: Network> Puzzle<N> {
             Ok(())
         })?;
         finish!(timer, "Verify each solution");
+        #[cfg(feature = "metrics")]
+        metrics::histogram(metrics::puzzle::CHECK_SOLUTIONS_LATENCY, start.elapsed().as_secs_f64());
         Ok(())
     }
 
@@ -436,7 +444,7 @@ mod tests {
         // Initialize an epoch hash.
         let epoch_hash = rng.gen();
 
-        for batch_size in 1..=CurrentNetwork::MAX_SOLUTIONS {
+        for batch_size in 1..=CurrentNetwork::max_solutions() {
             // Initialize the solutions.
             let solutions = (0..batch_size)
                 .map(|_| puzzle.prove(epoch_hash, rng.gen(), rng.gen(), None).unwrap())
@@ -554,7 +562,7 @@ mod tests {
         // Initialize an epoch hash.
         let epoch_hash = rng.gen();
 
-        for batch_size in 1..=CurrentNetwork::MAX_SOLUTIONS {
+        for batch_size in 1..=CurrentNetwork::max_solutions() {
             // Initialize the incorrect solutions.
             let incorrect_solutions = (0..batch_size)
                 .map(|_| {
@@ -587,7 +595,7 @@ mod tests {
         // Initialize a counter.
         let counter = rng.gen();
 
-        for batch_size in 1..=CurrentNetwork::MAX_SOLUTIONS {
+        for batch_size in 1..=CurrentNetwork::max_solutions() {
             // Initialize the solutions.
             let solutions =
                 (0..batch_size).map(|_| puzzle.prove(epoch_hash, address, counter, None).unwrap()).collect::<Vec<_>>();
@@ -613,7 +621,7 @@ mod tests {
         // Initialize an epoch hash.
         let epoch_hash = rng.gen();
 
-        for batch_size in 1..=CurrentNetwork::MAX_SOLUTIONS {
+        for batch_size in 1..=CurrentNetwork::max_solutions() {
             // Initialize a new puzzle.
             let puzzle = sample_puzzle();
             // Initialize the solutions.
@@ -642,7 +650,7 @@ mod tests {
         // Initialize an epoch hash.
         let epoch_hash = rng.gen();
 
-        for batch_size in 1..=CurrentNetwork::MAX_SOLUTIONS {
+        for batch_size in 1..=CurrentNetwork::max_solutions() {
             // Initialize a new puzzle.
             let puzzle = sample_puzzle();
             // Initialize the solutions.
@@ -692,7 +700,7 @@ mod tests {
         // Initialize an epoch hash.
         let epoch_hash = rng.gen();
 
-        for batch_size in [1, 2, <CurrentNetwork as Network>::MAX_SOLUTIONS] {
+        for batch_size in [1, 2, <CurrentNetwork as Network>::max_solutions()] {
             // Generate the solutions.
             let solutions = (0..batch_size)
                 .map(|_| {
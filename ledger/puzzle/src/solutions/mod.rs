@@ -34,8 +34,8 @@ impl<N: Network> PuzzleSolutions<N> {
         // Ensure the solutions are not empty.
         ensure!(!solutions.is_empty(), "There are no solutions to verify for the puzzle");
         // Ensure the number of solutions does not exceed `MAX_SOLUTIONS`.
-        if solutions.len() > N::MAX_SOLUTIONS {
-            bail!("Exceeded the maximum number of solutions ({} > {})", solutions.len(), N::MAX_SOLUTIONS);
+        if solutions.len() > N::max_solutions() {
+            bail!("Exceeded the maximum number of solutions ({} > {})", solutions.len(), N::max_solutions());
         }
         // Ensure the solution IDs are unique.
         if has_duplicates(solutions.iter().map(Solution::id)) {
@@ -70,7 +70,7 @@ impl<N: Network> PuzzleSolutions<N> {
         // Encode the solution IDs as field elements.
         let mut preimage = self.solution_ids().map(|id| Field::from_u64(**id)).collect::<Vec<_>>();
         // Pad the preimage to the required length.
-        preimage.resize(N::MAX_SOLUTIONS, Field::zero());
+        preimage.resize(N::max_solutions(), Field::zero());
         // Hash the preimage to obtain the accumulator point.
         N::hash_psd8(&preimage)
     }
@@ -122,7 +122,7 @@ mod tests {
     fn test_len() {
         let mut rng = TestRng::default();
 
-        for num_solutions in 1..<CurrentNetwork as Network>::MAX_SOLUTIONS {
+        for num_solutions in 1..<CurrentNetwork as Network>::max_solutions() {
             // Sample random solutions.
             let solutions = sample_solutions_with_count(num_solutions, &mut rng);
             // Ensure the number of solutions is correct.
@@ -134,7 +134,7 @@ mod tests {
     fn test_is_empty() {
         let mut rng = TestRng::default();
 
-        for num_solutions in 1..<CurrentNetwork as Network>::MAX_SOLUTIONS {
+        for num_solutions in 1..<CurrentNetwork as Network>::max_solutions() {
             // Sample random solutions.
             let solutions = sample_solutions_with_count(num_solutions, &mut rng);
             // Ensure the solutions are not empty.
@@ -146,7 +146,7 @@ mod tests {
     fn test_solution_ids() {
         let mut rng = TestRng::default();
 
-        for num_solutions in 1..<CurrentNetwork as Network>::MAX_SOLUTIONS {
+        for num_solutions in 1..<CurrentNetwork as Network>::max_solutions() {
             // Sample random solutions.
             let solutions = sample_solutions_with_count(num_solutions, &mut rng);
             // Ensure the solution IDs are unique.
@@ -158,7 +158,7 @@ mod tests {
     fn test_get_solution() {
         let mut rng = TestRng::default();
 
-        for num_solutions in 1..<CurrentNetwork as Network>::MAX_SOLUTIONS {
+        for num_solutions in 1..<CurrentNetwork as Network>::max_solutions() {
             // Sample random solutions.
             let solutions = sample_solutions_with_count(num_solutions, &mut rng);
             // Ensure the solutions are not empty.
@@ -172,13 +172,13 @@ mod tests {
     fn test_to_accumulator_point() {
         let mut rng = TestRng::default();
 
-        for num_solutions in 1..<CurrentNetwork as Network>::MAX_SOLUTIONS {
+        for num_solutions in 1..<CurrentNetwork as Network>::max_solutions() {
             // Sample random solutions.
             let solutions = crate::solutions::tests::sample_solutions_with_count(num_solutions, &mut rng);
             // Compute the candidate accumulator point.
             let candidate = solutions.to_accumulator_point().unwrap();
             // Compute the expected accumulator point.
-            let mut preimage = vec![Field::zero(); <CurrentNetwork as Network>::MAX_SOLUTIONS];
+            let mut preimage = vec![Field::zero(); <CurrentNetwork as Network>::max_solutions()];
             for (i, id) in solutions.keys().enumerate() {
                 preimage[i] = Field::from_u64(**id);
             }
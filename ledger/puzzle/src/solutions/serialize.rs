@@ -53,7 +53,7 @@ pub(super) mod tests {
     pub(crate) fn sample_solutions(rng: &mut TestRng) -> PuzzleSolutions<CurrentNetwork> {
         // Sample a new solutions.
         let mut solutions = vec![];
-        for _ in 0..rng.gen_range(1..CurrentNetwork::MAX_SOLUTIONS) {
+        for _ in 0..rng.gen_range(1..CurrentNetwork::max_solutions()) {
             let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
             let address = Address::try_from(private_key).unwrap();
 
@@ -19,10 +19,23 @@
 #[cfg_attr(feature = "async", macro_use)]
 extern crate async_trait;
 
+#[cfg(feature = "light-client")]
+mod light_client;
+#[cfg(feature = "light-client")]
+pub use light_client::*;
+
 #[cfg(feature = "query")]
 mod query;
 #[cfg(feature = "query")]
 pub use query::*;
 
+#[cfg(feature = "query")]
+mod rest_client;
+#[cfg(feature = "query")]
+pub use rest_client::*;
+
+mod static_query;
+pub use static_query::*;
+
 mod traits;
 pub use traits::*;
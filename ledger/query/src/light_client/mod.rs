@@ -0,0 +1,558 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod query;
+pub use query::*;
+
+mod verify;
+
+use console::{
+    network::prelude::*,
+    program::{BlockTree, StatePath},
+    types::Field,
+};
+use ledger_authority::Authority;
+use ledger_block::{Block, Header, Ratify};
+use ledger_committee::{Committee, CommitteeProof};
+
+use indexmap::IndexMap;
+use std::collections::BTreeMap;
+
+/// A light client follows the chain by verifying block headers against the committee lookback,
+/// without storing the block contents or executing the finalize state.
+///
+/// The light client stores the block hashes and headers, together with the committees that are needed
+/// for the committee lookback, and maintains the block tree in order to serve trusted state roots.
+///
+/// Note: The light client can not derive committee transitions on its own, as they are the result of
+/// finalizing `credits.aleo`. Committee transitions must be provided with a proof of the `credits.aleo`
+/// mappings against the mapping root of a verified block header. As such, committee transitions can only
+/// be verified from the height at which block headers commit to the mapping root.
+#[derive(Clone)]
+pub struct LightClient<N: Network> {
+    /// The block tree of the verified block hashes.
+    block_tree: BlockTree<N>,
+    /// The verified block hashes, indexed by block height.
+    hashes: Vec<N::BlockHash>,
+    /// The verified block headers, indexed by block height.
+    headers: Vec<Header<N>>,
+    /// The committees, keyed by their starting round.
+    /// Note: Committees that precede the committee lookback of the latest block are pruned.
+    committees: BTreeMap<u64, Committee<N>>,
+    /// The state roots, mapped to the block height that produced them.
+    state_roots: IndexMap<N::StateRoot, u32>,
+}
+
+impl<N: Network> LightClient<N> {
+    /// Initializes a new light client from the given genesis block.
+    /// Note: The caller is responsible for ensuring the genesis block hash is the expected one.
+    pub fn new(genesis_block: &Block<N>) -> Result<Self> {
+        // Retrieve the genesis committee from the genesis ratification.
+        let committee = match genesis_block.ratifications().iter().next() {
+            Some(Ratify::Genesis(committee, ..)) => (**committee).clone(),
+            _ => bail!("The genesis block is missing the genesis ratification"),
+        };
+        // Initialize the light client.
+        Self::from_genesis(genesis_block.hash(), *genesis_block.header(), genesis_block.authority(), committee)
+    }
+
+    /// Initializes a new light client from the given genesis block hash, header, authority, and committee.
+    /// Note: The caller is responsible for ensuring the genesis block hash is the expected one.
+    pub fn from_genesis(
+        block_hash: N::BlockHash,
+        header: Header<N>,
+        authority: &Authority<N>,
+        committee: Committee<N>,
+    ) -> Result<Self> {
+        // Ensure the header is a genesis block header.
+        ensure!(header.is_genesis(), "The light client must be initialized with a genesis block header");
+        // Ensure the genesis block hash is correct.
+        Self::check_block_hash(block_hash, N::BlockHash::default(), &header)?;
+        // Ensure the genesis authority is a beacon signature over the genesis block hash.
+        match authority {
+            Authority::Beacon(signature) => ensure!(
+                signature.verify(&signature.to_address(), &[*block_hash]),
+                "The genesis block signature is invalid"
+            ),
+            Authority::Quorum(..) => bail!("The genesis block must be a beacon block"),
+        }
+        // Ensure the committee is the genesis committee.
+        ensure!(committee.starting_round() == 0, "The genesis committee must start at round 0");
+
+        // Construct the block tree.
+        let block_tree = N::merkle_tree_bhp(&[block_hash.to_bits_le()])?;
+        // Compute the state root of the genesis block.
+        let state_root = N::StateRoot::from(*block_tree.root());
+
+        Ok(Self {
+            block_tree,
+            hashes: vec![block_hash],
+            headers: vec![header],
+            committees: [(0, committee)].into_iter().collect(),
+            state_roots: [(state_root, 0)].into_iter().collect(),
+        })
+    }
+}
+
+impl<N: Network> LightClient<N> {
+    /// Returns the latest block height.
+    pub fn latest_height(&self) -> u32 {
+        self.latest_header().height()
+    }
+
+    /// Returns the latest block hash.
+    pub fn latest_hash(&self) -> N::BlockHash {
+        self.hashes[self.headers.len() - 1]
+    }
+
+    /// Returns the latest block header.
+    pub fn latest_header(&self) -> &Header<N> {
+        // Note: The light client is always initialized with the genesis block.
+        &self.headers[self.headers.len() - 1]
+    }
+
+    /// Returns the latest round number.
+    pub fn latest_round(&self) -> u64 {
+        self.latest_header().round()
+    }
+
+    /// Returns the latest state root.
+    pub fn latest_state_root(&self) -> N::StateRoot {
+        N::StateRoot::from(*self.block_tree.root())
+    }
+
+    /// Returns the latest committee.
+    pub fn latest_committee(&self) -> &Committee<N> {
+        // Note: The light client is always initialized with the genesis committee.
+        self.committees.values().next_back().expect("The light client must contain the genesis committee")
+    }
+
+    /// Returns the block hash for the given block height, if it has been verified.
+    pub fn get_hash(&self, height: u32) -> Option<N::BlockHash> {
+        self.hashes.get(height as usize).copied()
+    }
+
+    /// Returns the block header for the given block height, if it has been verified.
+    pub fn get_header(&self, height: u32) -> Option<&Header<N>> {
+        self.headers.get(height as usize)
+    }
+
+    /// Returns the state root produced by the given block height, if it has been verified.
+    pub fn get_state_root(&self, height: u32) -> Option<N::StateRoot> {
+        self.state_roots.get_index(height as usize).map(|(state_root, _)| *state_root)
+    }
+
+    /// Returns the block height that produced the given state root, if it has been verified.
+    pub fn find_height_for_state_root(&self, state_root: &N::StateRoot) -> Option<u32> {
+        self.state_roots.get(state_root).copied()
+    }
+
+    /// Returns `true` if the given state root was produced by a verified block.
+    pub fn contains_state_root(&self, state_root: &N::StateRoot) -> bool {
+        self.state_roots.contains_key(state_root)
+    }
+
+    /// Returns the committee for the given round, if it has not been pruned.
+    pub fn get_committee_for_round(&self, round: u64) -> Option<&Committee<N>> {
+        self.committees.range(..=round).next_back().map(|(_, committee)| committee)
+    }
+
+    /// Returns the committee lookback for the given round, if it has not been pruned.
+    pub fn get_committee_lookback_for_round(&self, round: u64) -> Option<&Committee<N>> {
+        self.get_committee_for_round(Self::committee_lookback_round(round))
+    }
+}
+
+impl<N: Network> LightClient<N> {
+    /// Adds the given committee transition to the light client.
+    ///
+    /// The committee must start at the round of a verified block, and the proof must show it is the committee
+    /// under the mapping root of the next block header, which commits to the state after the block is finalized.
+    /// The committee must also not affect the committee lookback of any block that has already been verified.
+    pub fn add_committee(&mut self, committee: Committee<N>, proof: &CommitteeProof<N>) -> Result<()> {
+        // Retrieve the starting round.
+        let starting_round = committee.starting_round();
+        // Ensure the committee starts after the latest committee.
+        ensure!(
+            starting_round > self.latest_committee().starting_round(),
+            "Committee for round {starting_round} does not start after the latest committee (round {})",
+            self.latest_committee().starting_round()
+        );
+        // Retrieve the verified block header at the starting round.
+        let Some(header) = self.headers.iter().rev().find(|header| header.round() == starting_round) else {
+            bail!("Committee for round {starting_round} does not start at the round of a verified block")
+        };
+        // Retrieve the next block header, which commits to the mapping root after the block.
+        let next_height = header.height().saturating_add(1);
+        let Some(next_header) = self.get_header(next_height) else {
+            bail!("Committee for round {starting_round} requires block {next_height} to be verified")
+        };
+        // Ensure the next block header commits to the mapping root.
+        ensure!(
+            Header::<N>::commits_mapping_root(next_header.height()),
+            "Committee for round {starting_round} can not be verified, as block {} does not commit to the mapping root",
+            next_header.height()
+        );
+        // Ensure the committee is the committee under the mapping root.
+        proof.verify(&next_header.mapping_root(), &committee)?;
+        // Ensure the committee does not change the committee lookback of the latest block.
+        ensure!(
+            Self::committee_lookback_round(self.latest_round()) < starting_round,
+            "Committee for round {starting_round} changes the committee lookback of a verified block"
+        );
+        // Add the committee.
+        self.committees.insert(starting_round, committee);
+        Ok(())
+    }
+
+    /// Checks the given block header is a valid next block header, and advances the light client to it.
+    pub fn advance_to_next_header(
+        &mut self,
+        block_hash: N::BlockHash,
+        previous_hash: N::BlockHash,
+        header: Header<N>,
+        authority: &Authority<N>,
+    ) -> Result<()> {
+        // Ensure the block header is valid.
+        self.check_next_header(block_hash, previous_hash, &header, authority)?;
+
+        // Prepare the updated block tree.
+        let block_tree = self.block_tree.prepare_append(&[block_hash.to_bits_le()])?;
+        // Compute the new state root.
+        let state_root = N::StateRoot::from(*block_tree.root());
+
+        // Update the light client.
+        self.block_tree = block_tree;
+        self.state_roots.insert(state_root, header.height());
+        self.hashes.push(block_hash);
+        self.headers.push(header);
+
+        // Prune the committees that precede the committee lookback of the latest block, as they are no longer needed.
+        let lookback_round = Self::committee_lookback_round(self.latest_round());
+        if let Some(round) = self.committees.range(..=lookback_round).next_back().map(|(round, _)| *round) {
+            self.committees = self.committees.split_off(&round);
+        }
+        Ok(())
+    }
+
+    /// Checks the given block is a valid next block, and advances the light client to its header.
+    /// Note: Only the block header and authority are checked, the block contents are not verified.
+    pub fn advance_to_next_block(&mut self, block: &Block<N>) -> Result<()> {
+        self.advance_to_next_header(block.hash(), block.previous_hash(), *block.header(), block.authority())
+    }
+
+    /// Checks the given state path is anchored to a verified state root, and is valid for the given commitment.
+    pub fn verify_state_path(&self, commitment: &Field<N>, state_path: &StatePath<N>) -> Result<()> {
+        // Retrieve the global state root.
+        let global_state_root = state_path.global_state_root();
+        // Ensure the global state root was produced by a verified block.
+        ensure!(
+            self.contains_state_root(&global_state_root),
+            "State root '{global_state_root}' is not known to the light client (latest height {})",
+            self.latest_height()
+        );
+        // Ensure the state path is for the given commitment.
+        ensure!(
+            state_path.transition_leaf().id() == *commitment,
+            "State path is for commitment '{}' (expected '{commitment}')",
+            state_path.transition_leaf().id()
+        );
+        // Ensure the state path is valid.
+        state_path.verify(true, Field::zero())
+    }
+}
+
+impl<N: Network> LightClient<N> {
+    /// Returns the committee lookback round for the given round.
+    fn committee_lookback_round(round: u64) -> u64 {
+        // Determine the round number for the previous committee. Note, we subtract 2 from odd rounds,
+        // because committees are updated in even rounds.
+        let previous_round = match round % 2 == 0 {
+            true => round.saturating_sub(1),
+            false => round.saturating_sub(2),
+        };
+        // Determine the committee lookback round.
+        previous_round.saturating_sub(Committee::<N>::COMMITTEE_LOOKBACK_RANGE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QueryTrait;
+    use console::{
+        account::{Address, PrivateKey},
+        network::MainnetV0,
+        program::{Identifier, MappingTree, Plaintext, ProgramID, Value},
+    };
+    use ledger_block::{Metadata, Ratifications, Transactions};
+    use ledger_committee::MIN_VALIDATOR_STAKE;
+    use ledger_narwhal_batch_certificate::BatchCertificate;
+    use ledger_narwhal_batch_header::BatchHeader;
+    use ledger_narwhal_subdag::Subdag;
+
+    use indexmap::IndexSet;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Samples a light client with a genesis committee of 4 validators, and returns their private keys.
+    fn sample_light_client(rng: &mut TestRng) -> (LightClient<CurrentNetwork>, Vec<PrivateKey<CurrentNetwork>>) {
+        // Sample the committee members.
+        let private_keys = (0..4).map(|_| PrivateKey::new(rng).unwrap()).collect::<Vec<_>>();
+        let members = private_keys
+            .iter()
+            .map(|private_key| (Address::try_from(private_key).unwrap(), (MIN_VALIDATOR_STAKE, true, 0)))
+            .collect();
+        let committee = Committee::new_genesis(members).unwrap();
+
+        // Construct the genesis block header.
+        let ratifications = Ratifications::try_from(vec![]).unwrap();
+        let header = Header::<CurrentNetwork>::genesis(&ratifications, &Transactions::from(&[]), vec![]).unwrap();
+        // Compute the genesis block hash.
+        let previous_hash = <CurrentNetwork as Network>::BlockHash::default();
+        let block_hash: <CurrentNetwork as Network>::BlockHash =
+            CurrentNetwork::hash_bhp1024(&to_bits_le![previous_hash, header.to_root().unwrap()]).unwrap().into();
+        // Sign the genesis block hash.
+        let authority = Authority::new_beacon(&private_keys[0], *block_hash, rng).unwrap();
+
+        // Initialize the light client.
+        (LightClient::from_genesis(block_hash, header, &authority, committee).unwrap(), private_keys)
+    }
+
+    /// Samples the next block hash, previous hash, header, and quorum authority for the light client.
+    /// The leader certificate is signed by the given number of validators, besides the leader.
+    /// The header commits to the given mapping root, if the next block header commits to the mapping root.
+    fn sample_next_header(
+        light_client: &LightClient<CurrentNetwork>,
+        private_keys: &[PrivateKey<CurrentNetwork>],
+        num_signers: usize,
+        mapping_root: Field<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> (
+        <CurrentNetwork as Network>::BlockHash,
+        <CurrentNetwork as Network>::BlockHash,
+        Header<CurrentNetwork>,
+        Authority<CurrentNetwork>,
+    ) {
+        // Determine the anchor round.
+        let anchor_round = light_client.latest_round() + 2;
+        // Retrieve the committee lookback.
+        let committee = light_client.get_committee_lookback_for_round(anchor_round).unwrap();
+        let timestamp = CurrentNetwork::GENESIS_TIMESTAMP + 10;
+
+        // Returns a batch certificate signed by all other validators.
+        let certify = |author: &PrivateKey<CurrentNetwork>,
+                       round: u64,
+                       previous_certificate_ids: IndexSet<Field<CurrentNetwork>>,
+                       num_signers: usize,
+                       rng: &mut TestRng| {
+            let batch_header = BatchHeader::new(
+                author,
+                round,
+                timestamp,
+                committee.id(),
+                Default::default(),
+                previous_certificate_ids,
+                rng,
+            )
+            .unwrap();
+            let signatures = private_keys
+                .iter()
+                .filter(|private_key| *private_key != author)
+                .take(num_signers)
+                .map(|private_key| private_key.sign(&[batch_header.batch_id()], rng).unwrap())
+                .collect();
+            BatchCertificate::from(batch_header, signatures).unwrap()
+        };
+
+        // Construct the certificates for the round before the anchor round.
+        let certificates = private_keys
+            .iter()
+            .map(|private_key| certify(private_key, anchor_round - 1, Default::default(), 3, rng))
+            .collect::<IndexSet<_>>();
+        // Construct the leader certificate.
+        let leader = committee.get_leader(anchor_round).unwrap();
+        let leader_key =
+            private_keys.iter().find(|private_key| Address::try_from(*private_key).unwrap() == leader).unwrap();
+        let previous_certificate_ids = certificates.iter().map(|certificate| certificate.id()).collect();
+        let leader_certificate = certify(leader_key, anchor_round, previous_certificate_ids, num_signers, rng);
+        // Construct the subdag.
+        let subdag = Subdag::from(
+            [(anchor_round - 1, certificates), (anchor_round, [leader_certificate].into_iter().collect())]
+                .into_iter()
+                .collect(),
+        )
+        .unwrap();
+
+        // Construct the block header.
+        let height = light_client.latest_height() + 1;
+        let mapping_root = match Header::<CurrentNetwork>::commits_mapping_root(height) {
+            true => mapping_root,
+            false => Field::zero(),
+        };
+        let metadata = Metadata::new(
+            CurrentNetwork::ID,
+            anchor_round,
+            height,
+            0,
+            0,
+            CurrentNetwork::genesis_coinbase_target(),
+            CurrentNetwork::genesis_proof_target(),
+            CurrentNetwork::genesis_coinbase_target(),
+            CurrentNetwork::GENESIS_TIMESTAMP,
+            subdag.timestamp(committee),
+        )
+        .unwrap();
+        let header = Header::from(
+            light_client.latest_state_root(),
+            Field::rand(rng),
+            Field::rand(rng),
+            mapping_root,
+            Field::rand(rng),
+            Field::zero(),
+            subdag.to_subdag_root().unwrap(),
+            metadata,
+        )
+        .unwrap();
+        // Compute the block hash.
+        let previous_hash = light_client.latest_hash();
+        let block_hash =
+            CurrentNetwork::hash_bhp1024(&to_bits_le![previous_hash, header.to_root().unwrap()]).unwrap().into();
+
+        (block_hash, previous_hash, header, Authority::new_quorum(subdag))
+    }
+
+    #[test]
+    fn test_advance_to_next_header() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the light client.
+        let (mut light_client, private_keys) = sample_light_client(rng);
+        assert_eq!(light_client.latest_height(), 0);
+        let genesis_state_root = light_client.latest_state_root();
+        assert_eq!(light_client.current_state_root().unwrap(), genesis_state_root);
+
+        // Ensure a leader certificate without a quorum is rejected.
+        let (block_hash, previous_hash, header, authority) =
+            sample_next_header(&light_client, &private_keys, 1, Field::zero(), rng);
+        assert!(light_client.check_next_header(block_hash, previous_hash, &header, &authority).is_err());
+
+        // Sample the next header.
+        let (block_hash, previous_hash, header, authority) =
+            sample_next_header(&light_client, &private_keys, 3, Field::zero(), rng);
+        // Ensure an incorrect block hash is rejected.
+        assert!(light_client.check_next_header(previous_hash, previous_hash, &header, &authority).is_err());
+        // Ensure an incorrect previous hash is rejected.
+        assert!(light_client.check_next_header(block_hash, block_hash, &header, &authority).is_err());
+
+        // Advance to the next header.
+        light_client.advance_to_next_header(block_hash, previous_hash, header, &authority).unwrap();
+        assert_eq!(light_client.latest_height(), 1);
+        assert_eq!(light_client.latest_hash(), block_hash);
+        assert_eq!(light_client.current_block_height().unwrap(), 1);
+
+        // Ensure the state root matches the block tree of the verified block hashes.
+        let block_tree = CurrentNetwork::merkle_tree_bhp::<{ console::program::BLOCKS_DEPTH }>(&[
+            light_client.get_hash(0).unwrap().to_bits_le(),
+            block_hash.to_bits_le(),
+        ])
+        .unwrap();
+        let state_root = <CurrentNetwork as Network>::StateRoot::from(*block_tree.root());
+        assert_eq!(light_client.latest_state_root(), state_root);
+        assert_eq!(light_client.current_state_root().unwrap(), state_root);
+        assert_eq!(light_client.get_state_root(0), Some(genesis_state_root));
+        assert_eq!(light_client.find_height_for_state_root(&state_root), Some(1));
+        assert!(light_client.contains_state_root(&genesis_state_root));
+
+        // Ensure the light client does not serve state paths on its own.
+        assert!(light_client.get_state_path_for_commitment(&Field::rand(rng)).is_err());
+    }
+
+    #[test]
+    fn test_add_committee() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the light client.
+        let (mut light_client, private_keys) = sample_light_client(rng);
+        // Advance to the block before the first block header that commits to the mapping root.
+        while !Header::<CurrentNetwork>::commits_mapping_root(light_client.latest_height() + 1) {
+            let (block_hash, previous_hash, header, authority) =
+                sample_next_header(&light_client, &private_keys, 3, Field::zero(), rng);
+            light_client.advance_to_next_header(block_hash, previous_hash, header, &authority).unwrap();
+        }
+
+        // Sample a committee transition at the latest round.
+        let mut members = light_client.latest_committee().members().clone();
+        members[0].0 += 1;
+        let committee = Committee::new(light_client.latest_round(), members).unwrap();
+
+        // Construct the mapping tree with the `credits.aleo` entries of the committee.
+        const MEMBERS_KEY: &str = "aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc";
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let mut entries = vec![
+            ("metadata", MEMBERS_KEY.to_string(), format!("{}u32", committee.num_members())),
+            ("account", Address::try_from(&private_keys[0]).unwrap().to_string(), "100u64".to_string()),
+        ];
+        for (address, (microcredits, is_open, commission)) in committee.members() {
+            let state = format!("{{ is_open: {is_open}, commission: {commission}u8 }}");
+            entries.push(("committee", address.to_string(), state));
+            entries.push(("delegated", address.to_string(), format!("{microcredits}u64")));
+        }
+        let mapping_tree = MappingTree::new(entries.iter().map(|(mapping_name, key, value)| {
+            let mapping_name = Identifier::from_str(mapping_name).unwrap();
+            let key = Plaintext::from_str(key).unwrap();
+            let value = Value::from_str(value).unwrap();
+            (
+                MappingTree::to_key_id(&program_id, &mapping_name, &key).unwrap(),
+                MappingTree::to_value_hash(&value).unwrap(),
+            )
+        }))
+        .unwrap();
+        // Prove the committee against the mapping tree.
+        let proof = CommitteeProof::prove(&committee, |program_id, mapping_name, key| {
+            Ok(mapping_tree.prove(&MappingTree::to_key_id(program_id, mapping_name, key)?))
+        })
+        .unwrap();
+
+        // Ensure the committee is rejected before the next block header commits to the mapping root.
+        assert!(light_client.add_committee(committee.clone(), &proof).is_err());
+        let (block_hash, previous_hash, header, authority) =
+            sample_next_header(&light_client, &private_keys, 3, mapping_tree.root(), rng);
+        light_client.advance_to_next_header(block_hash, previous_hash, header, &authority).unwrap();
+
+        // Ensure a committee that does not start at the round of a verified block is rejected.
+        let invalid_committee = Committee::new(committee.starting_round() + 1, committee.members().clone()).unwrap();
+        assert!(light_client.add_committee(invalid_committee, &proof).is_err());
+        // Ensure a committee that is not the committee under the mapping root is rejected.
+        let members = light_client.latest_committee().members().clone();
+        let invalid_committee = Committee::new(committee.starting_round(), members).unwrap();
+        assert!(light_client.add_committee(invalid_committee, &proof).is_err());
+        // Ensure a proof with a missing entry is rejected.
+        let invalid_proof = CommitteeProof::new(proof.proofs()[1..].to_vec());
+        assert!(light_client.add_committee(committee.clone(), &invalid_proof).is_err());
+
+        // Ensure the committee is accepted.
+        light_client.add_committee(committee.clone(), &proof).unwrap();
+        assert_eq!(light_client.latest_committee(), &committee);
+        // Ensure the committee is only used after the committee lookback range.
+        let round = light_client.latest_round();
+        assert_ne!(light_client.get_committee_lookback_for_round(round), Some(&committee));
+        assert_eq!(
+            light_client
+                .get_committee_lookback_for_round(round + Committee::<CurrentNetwork>::COMMITTEE_LOOKBACK_RANGE + 2),
+            Some(&committee)
+        );
+    }
+}
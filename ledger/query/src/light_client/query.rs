@@ -0,0 +1,113 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::QueryTrait;
+
+/// A query that fetches state paths from an untrusted query, and verifies them against the light client.
+pub struct LightClientQuery<'a, N: Network, Q: QueryTrait<N>> {
+    /// The light client.
+    light_client: &'a LightClient<N>,
+    /// The untrusted query.
+    query: Q,
+}
+
+impl<N: Network> LightClient<N> {
+    /// Returns a query that serves the trusted state root of the light client,
+    /// and verifies the state paths fetched from the given query against it.
+    pub fn with_query<Q: QueryTrait<N>>(&self, query: Q) -> LightClientQuery<'_, N, Q> {
+        LightClientQuery { light_client: self, query }
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network> QueryTrait<N> for LightClient<N> {
+    /// Returns the current state root.
+    fn current_state_root(&self) -> Result<N::StateRoot> {
+        Ok(self.latest_state_root())
+    }
+
+    /// Returns the current state root.
+    #[cfg(feature = "async")]
+    async fn current_state_root_async(&self) -> Result<N::StateRoot> {
+        Ok(self.latest_state_root())
+    }
+
+    /// Returns a state path for the given `commitment`.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        bail!("The light client does not store state paths (commitment '{commitment}') - use 'LightClient::with_query'")
+    }
+
+    /// Returns a state path for the given `commitment`.
+    #[cfg(feature = "async")]
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.get_state_path_for_commitment(commitment)
+    }
+
+    /// Returns the current block height.
+    fn current_block_height(&self) -> Result<u32> {
+        Ok(self.latest_height())
+    }
+
+    /// Returns the current block height.
+    #[cfg(feature = "async")]
+    async fn current_block_height_async(&self) -> Result<u32> {
+        Ok(self.latest_height())
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network, Q: QueryTrait<N>> QueryTrait<N> for LightClientQuery<'_, N, Q> {
+    /// Returns the current state root.
+    fn current_state_root(&self) -> Result<N::StateRoot> {
+        Ok(self.light_client.latest_state_root())
+    }
+
+    /// Returns the current state root.
+    #[cfg(feature = "async")]
+    async fn current_state_root_async(&self) -> Result<N::StateRoot> {
+        Ok(self.light_client.latest_state_root())
+    }
+
+    /// Returns a state path for the given `commitment`.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        // Fetch the state path from the untrusted query.
+        let state_path = self.query.get_state_path_for_commitment(commitment)?;
+        // Ensure the state path is valid under a verified state root.
+        self.light_client.verify_state_path(commitment, &state_path)?;
+        Ok(state_path)
+    }
+
+    /// Returns a state path for the given `commitment`.
+    #[cfg(feature = "async")]
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        // Fetch the state path from the untrusted query.
+        let state_path = self.query.get_state_path_for_commitment_async(commitment).await?;
+        // Ensure the state path is valid under a verified state root.
+        self.light_client.verify_state_path(commitment, &state_path)?;
+        Ok(state_path)
+    }
+
+    /// Returns the current block height.
+    fn current_block_height(&self) -> Result<u32> {
+        Ok(self.light_client.latest_height())
+    }
+
+    /// Returns the current block height.
+    #[cfg(feature = "async")]
+    async fn current_block_height_async(&self) -> Result<u32> {
+        Ok(self.light_client.latest_height())
+    }
+}
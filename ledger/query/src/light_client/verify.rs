@@ -0,0 +1,185 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::HashSet;
+
+impl<N: Network> LightClient<N> {
+    /// Checks the given block header is a valid next block header.
+    pub fn check_next_header(
+        &self,
+        block_hash: N::BlockHash,
+        previous_hash: N::BlockHash,
+        header: &Header<N>,
+        authority: &Authority<N>,
+    ) -> Result<()> {
+        // Determine the expected height.
+        let expected_height = self.latest_height().saturating_add(1);
+
+        // Ensure the block height is correct.
+        ensure!(
+            header.height() == expected_height,
+            "Block height is incorrect (found '{}', expected '{expected_height}')",
+            header.height()
+        );
+        // Ensure the block header is well-formed.
+        ensure!(header.is_valid(), "Block header {expected_height} is malformed");
+        // Ensure the previous block hash matches.
+        ensure!(
+            previous_hash == self.latest_hash(),
+            "Previous block hash is incorrect in block {expected_height} (found '{previous_hash}', expected '{}')",
+            self.latest_hash()
+        );
+        // Ensure the previous state root matches.
+        ensure!(
+            header.previous_state_root() == self.latest_state_root(),
+            "Previous state root is incorrect in block {expected_height} (found '{}', expected '{}')",
+            header.previous_state_root(),
+            self.latest_state_root()
+        );
+        // Ensure the block hash is correct.
+        Self::check_block_hash(block_hash, previous_hash, header)?;
+        // Ensure the block authority is correct.
+        self.check_authority(block_hash, header, authority)
+    }
+
+    /// Ensures the block hash is correct for the given previous block hash and block header.
+    pub(super) fn check_block_hash(
+        block_hash: N::BlockHash,
+        previous_hash: N::BlockHash,
+        header: &Header<N>,
+    ) -> Result<()> {
+        // Compute the Merkle root of the block header.
+        let Ok(header_root) = header.to_root() else {
+            bail!("Failed to compute the Merkle root of block header {}", header.height());
+        };
+        // Compute the block hash.
+        let candidate_hash = N::hash_bhp1024(&to_bits_le![previous_hash, header_root])?;
+        // Ensure the block hash matches.
+        ensure!(
+            *block_hash == candidate_hash,
+            "Block hash is incorrect in block {} (found '{block_hash}', expected '{}')",
+            header.height(),
+            Into::<N::BlockHash>::into(candidate_hash)
+        );
+        Ok(())
+    }
+
+    /// Ensures the block authority is correct, by checking it against the committee lookback.
+    fn check_authority(&self, block_hash: N::BlockHash, header: &Header<N>, authority: &Authority<N>) -> Result<()> {
+        // Note: Do not remove this. This ensures that all blocks after genesis are quorum blocks.
+        #[cfg(not(any(test, feature = "test")))]
+        ensure!(authority.is_quorum(), "The next block must be a quorum block");
+
+        // Retrieve the height.
+        let height = header.height();
+        // Retrieve the previous round.
+        let previous_round = self.latest_round();
+
+        // Determine the expected round.
+        let expected_round = match authority {
+            // Beacon blocks increment the previous block round by 1.
+            Authority::Beacon(..) => previous_round.saturating_add(1),
+            // Quorum blocks use the subdag anchor round.
+            Authority::Quorum(subdag) => {
+                // Ensure the subdag anchor round is after the previous block round.
+                ensure!(
+                    subdag.anchor_round() > previous_round,
+                    "Subdag anchor round is not after previous block round in block {height} (found '{}', expected after '{previous_round}')",
+                    subdag.anchor_round(),
+                );
+                // Ensure that the rounds in the subdag are sequential.
+                if previous_round != 0 {
+                    for round in previous_round..=subdag.anchor_round() {
+                        ensure!(subdag.contains_key(&round), "Subdag is missing round {round} in block {height}");
+                    }
+                }
+                // Output the subdag anchor round.
+                subdag.anchor_round()
+            }
+        };
+        // Ensure the block round is correct.
+        ensure!(
+            header.round() == expected_round,
+            "Block round is incorrect in block {height} (found '{}', expected '{expected_round}')",
+            header.round()
+        );
+
+        // Retrieve the committee lookback.
+        let Some(committee_lookback) = self.get_committee_lookback_for_round(expected_round) else {
+            bail!("Failed to fetch the committee lookback for round {expected_round}")
+        };
+
+        match authority {
+            Authority::Beacon(signature) => {
+                // Retrieve the signer.
+                let signer = signature.to_address();
+                // Ensure the block is signed by a committee member.
+                ensure!(
+                    committee_lookback.is_committee_member(signer),
+                    "Beacon block {height} has a signer not in the committee (found '{signer}')",
+                );
+                // Ensure the signature is valid.
+                ensure!(signature.verify(&signer, &[*block_hash]), "Signature is invalid in block {height}");
+            }
+            Authority::Quorum(subdag) => {
+                // Retrieve the leader certificate.
+                let leader_certificate = subdag.leader_certificate();
+                // Compute the expected leader.
+                let expected_leader = committee_lookback.get_leader(expected_round)?;
+                // Ensure the block is authored by the expected leader.
+                ensure!(
+                    leader_certificate.author() == expected_leader,
+                    "Quorum block {height} is authored by an unexpected leader (found: {}, expected: {expected_leader})",
+                    leader_certificate.author()
+                );
+                // Ensure the committee ID of the leader certificate is correct.
+                ensure!(
+                    leader_certificate.committee_id() == committee_lookback.id(),
+                    "Leader certificate has an incorrect committee ID in block {height}"
+                );
+                // Collect the signers of the leader certificate.
+                // Note: The signatures are verified when the batch certificate is constructed.
+                let signers = leader_certificate
+                    .signatures()
+                    .map(|signature| signature.to_address())
+                    .chain([leader_certificate.author()])
+                    .collect::<HashSet<_>>();
+                // Ensure the leader certificate is signed by a quorum of the committee lookback.
+                ensure!(
+                    committee_lookback.is_quorum_threshold_reached(&signers),
+                    "Leader certificate in block {height} does not reach the quorum threshold"
+                );
+                // Ensure the subdag root is correct.
+                ensure!(header.subdag_root() == subdag.to_subdag_root()?, "Subdag root is incorrect in block {height}");
+
+                // Retrieve the previous committee lookback.
+                let penultimate_round = expected_round.saturating_sub(1);
+                let Some(previous_committee_lookback) = self.get_committee_lookback_for_round(penultimate_round) else {
+                    bail!("Failed to fetch the committee lookback for round {penultimate_round}")
+                };
+                // Ensure the block timestamp is the weighted median timestamp of the subdag.
+                ensure!(
+                    header.timestamp() == subdag.timestamp(previous_committee_lookback),
+                    "Block timestamp is incorrect in block {height} (found '{}', expected '{}')",
+                    header.timestamp(),
+                    subdag.timestamp(previous_committee_lookback)
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -13,7 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::QueryTrait;
+use crate::{QueryTrait, RestClient, StaticQuery};
 use console::{
     network::prelude::*,
     program::{ProgramID, StatePath},
@@ -28,6 +28,10 @@ pub enum Query<N: Network, B: BlockStorage<N>> {
     VM(BlockStore<N, B>),
     /// The base URL of the node.
     REST(String),
+    /// The REST client of one or more nodes, with timeouts, retries, and caching.
+    RESTClient(RestClient<N>),
+    /// The pre-fetched state of a node, for offline proving.
+    Static(StaticQuery<N>),
 }
 
 impl<N: Network, B: BlockStorage<N>> From<BlockStore<N, B>> for Query<N, B> {
@@ -42,6 +46,18 @@ impl<N: Network, B: BlockStorage<N>> From<&BlockStore<N, B>> for Query<N, B> {
     }
 }
 
+impl<N: Network, B: BlockStorage<N>> From<StaticQuery<N>> for Query<N, B> {
+    fn from(static_query: StaticQuery<N>) -> Self {
+        Self::Static(static_query)
+    }
+}
+
+impl<N: Network, B: BlockStorage<N>> From<RestClient<N>> for Query<N, B> {
+    fn from(client: RestClient<N>) -> Self {
+        Self::RESTClient(client)
+    }
+}
+
 impl<N: Network, B: BlockStorage<N>> From<String> for Query<N, B> {
     fn from(url: String) -> Self {
         Self::REST(url)
@@ -66,6 +82,8 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     fn current_state_root(&self) -> Result<N::StateRoot> {
         match self {
             Self::VM(block_store) => Ok(block_store.current_state_root()),
+            Self::Static(static_query) => static_query.current_state_root(),
+            Self::RESTClient(client) => client.current_state_root(),
             Self::REST(url) => match N::ID {
                 console::network::MainnetV0::ID => {
                     Ok(Self::get_request(&format!("{url}/mainnet/stateRoot/latest"))?.into_json()?)
@@ -76,6 +94,9 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 console::network::CanaryV0::ID => {
                     Ok(Self::get_request(&format!("{url}/canary/stateRoot/latest"))?.into_json()?)
                 }
+                console::network::DevnetV0::ID => {
+                    Ok(Self::get_request(&format!("{url}/devnet/stateRoot/latest"))?.into_json()?)
+                }
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
         }
@@ -86,6 +107,8 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     async fn current_state_root_async(&self) -> Result<N::StateRoot> {
         match self {
             Self::VM(block_store) => Ok(block_store.current_state_root()),
+            Self::Static(static_query) => static_query.current_state_root(),
+            Self::RESTClient(client) => client.current_state_root_async().await,
             Self::REST(url) => match N::ID {
                 console::network::MainnetV0::ID => {
                     Ok(Self::get_request_async(&format!("{url}/mainnet/stateRoot/latest")).await?.json().await?)
@@ -96,6 +119,9 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 console::network::CanaryV0::ID => {
                     Ok(Self::get_request_async(&format!("{url}/canary/stateRoot/latest")).await?.json().await?)
                 }
+                console::network::DevnetV0::ID => {
+                    Ok(Self::get_request_async(&format!("{url}/devnet/stateRoot/latest")).await?.json().await?)
+                }
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
         }
@@ -105,6 +131,8 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
         match self {
             Self::VM(block_store) => block_store.get_state_path_for_commitment(commitment),
+            Self::Static(static_query) => static_query.get_state_path_for_commitment(commitment),
+            Self::RESTClient(client) => client.get_state_path_for_commitment(commitment),
             Self::REST(url) => match N::ID {
                 console::network::MainnetV0::ID => {
                     Ok(Self::get_request(&format!("{url}/mainnet/statePath/{commitment}"))?.into_json()?)
@@ -115,6 +143,9 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 console::network::CanaryV0::ID => {
                     Ok(Self::get_request(&format!("{url}/canary/statePath/{commitment}"))?.into_json()?)
                 }
+                console::network::DevnetV0::ID => {
+                    Ok(Self::get_request(&format!("{url}/devnet/statePath/{commitment}"))?.into_json()?)
+                }
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
         }
@@ -125,6 +156,8 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
         match self {
             Self::VM(block_store) => block_store.get_state_path_for_commitment(commitment),
+            Self::Static(static_query) => static_query.get_state_path_for_commitment(commitment),
+            Self::RESTClient(client) => client.get_state_path_for_commitment_async(commitment).await,
             Self::REST(url) => match N::ID {
                 console::network::MainnetV0::ID => {
                     Ok(Self::get_request_async(&format!("{url}/mainnet/statePath/{commitment}")).await?.json().await?)
@@ -135,6 +168,9 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 console::network::CanaryV0::ID => {
                     Ok(Self::get_request_async(&format!("{url}/canary/statePath/{commitment}")).await?.json().await?)
                 }
+                console::network::DevnetV0::ID => {
+                    Ok(Self::get_request_async(&format!("{url}/devnet/statePath/{commitment}")).await?.json().await?)
+                }
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
         }
@@ -144,6 +180,8 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     fn current_block_height(&self) -> Result<u32> {
         match self {
             Self::VM(block_store) => Ok(block_store.max_height().unwrap_or_default()),
+            Self::Static(static_query) => static_query.current_block_height(),
+            Self::RESTClient(client) => client.current_block_height(),
             Self::REST(url) => match N::ID {
                 console::network::MainnetV0::ID => {
                     Ok(Self::get_request(&format!("{url}/mainnet/block/height/latest"))?.into_json()?)
@@ -154,6 +192,9 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 console::network::CanaryV0::ID => {
                     Ok(Self::get_request(&format!("{url}/canary/block/height/latest"))?.into_json()?)
                 }
+                console::network::DevnetV0::ID => {
+                    Ok(Self::get_request(&format!("{url}/devnet/block/height/latest"))?.into_json()?)
+                }
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
         }
@@ -164,6 +205,8 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
     async fn current_block_height_async(&self) -> Result<u32> {
         match self {
             Self::VM(block_store) => Ok(block_store.max_height().unwrap_or_default()),
+            Self::Static(static_query) => static_query.current_block_height(),
+            Self::RESTClient(client) => client.current_block_height_async().await,
             Self::REST(url) => match N::ID {
                 console::network::MainnetV0::ID => {
                     Ok(Self::get_request_async(&format!("{url}/mainnet/block/height/latest")).await?.json().await?)
@@ -174,6 +217,9 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 console::network::CanaryV0::ID => {
                     Ok(Self::get_request_async(&format!("{url}/canary/block/height/latest")).await?.json().await?)
                 }
+                console::network::DevnetV0::ID => {
+                    Ok(Self::get_request_async(&format!("{url}/devnet/block/height/latest")).await?.json().await?)
+                }
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
         }
@@ -187,6 +233,8 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
             Self::VM(block_store) => {
                 block_store.get_program(program_id)?.ok_or_else(|| anyhow!("Program {program_id} not found in storage"))
             }
+            Self::Static(_) => bail!("Program {program_id} is not available in a static query"),
+            Self::RESTClient(client) => client.get_program(program_id),
             Self::REST(url) => match N::ID {
                 console::network::MainnetV0::ID => {
                     Ok(Self::get_request(&format!("{url}/mainnet/program/{program_id}"))?.into_json()?)
@@ -197,6 +245,9 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
                 console::network::CanaryV0::ID => {
                     Ok(Self::get_request(&format!("{url}/canary/program/{program_id}"))?.into_json()?)
                 }
+                console::network::DevnetV0::ID => {
+                    Ok(Self::get_request(&format!("{url}/devnet/program/{program_id}"))?.into_json()?)
+                }
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
         }
@@ -209,6 +260,8 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
             Self::VM(block_store) => {
                 block_store.get_program(program_id)?.ok_or_else(|| anyhow!("Program {program_id} not found in storage"))
             }
+            Self::Static(_) => bail!("Program {program_id} is not available in a static query"),
+            Self::RESTClient(client) => client.get_program_async(program_id).await,
             Self::REST(url) => match N::ID {
                 console::network::MainnetV0::ID => {
                     Ok(Self::get_request_async(&format!("{url}/mainnet/program/{program_id}")).await?.json().await?)
@@ -219,6 +272,9 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
                 console::network::CanaryV0::ID => {
                     Ok(Self::get_request_async(&format!("{url}/canary/program/{program_id}")).await?.json().await?)
                 }
+                console::network::DevnetV0::ID => {
+                    Ok(Self::get_request_async(&format!("{url}/devnet/program/{program_id}")).await?.json().await?)
+                }
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
         }
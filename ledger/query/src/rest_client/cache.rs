@@ -0,0 +1,153 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexMap;
+
+/// An in-memory cache of state paths, keyed by their global state root and record commitment.
+///
+/// The cache holds the state paths of at most `capacity` state roots. When a new state root
+/// is inserted into a full cache, the state paths of the oldest state root are evicted.
+#[derive(Clone, Debug)]
+pub struct StatePathCache<N: Network> {
+    /// The maximum number of state roots in the cache.
+    capacity: usize,
+    /// The most recently observed state root.
+    latest_state_root: Option<N::StateRoot>,
+    /// The state paths, keyed by their global state root and record commitment.
+    state_paths: IndexMap<N::StateRoot, IndexMap<Field<N>, StatePath<N>>>,
+}
+
+impl<N: Network> StatePathCache<N> {
+    /// Initializes a new cache for the state paths of at most `capacity` state roots.
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, latest_state_root: None, state_paths: IndexMap::new() }
+    }
+
+    /// Returns the maximum number of state roots in the cache.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the most recently observed state root, if any.
+    pub const fn latest_state_root(&self) -> Option<N::StateRoot> {
+        self.latest_state_root
+    }
+
+    /// Returns the number of cached state paths.
+    pub fn len(&self) -> usize {
+        self.state_paths.values().map(|state_paths| state_paths.len()).sum()
+    }
+
+    /// Returns `true` if the cache does not contain any state paths.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the cached state path for the given commitment under the given state root, if any.
+    pub fn get(&self, state_root: &N::StateRoot, commitment: &Field<N>) -> Option<&StatePath<N>> {
+        self.state_paths.get(state_root).and_then(|state_paths| state_paths.get(commitment))
+    }
+
+    /// Returns the cached state path for the given commitment under the latest state root, if any.
+    pub fn get_latest(&self, commitment: &Field<N>) -> Option<&StatePath<N>> {
+        self.latest_state_root.as_ref().and_then(|state_root| self.get(state_root, commitment))
+    }
+
+    /// Records the given state root as the most recently observed state root.
+    pub fn observe_state_root(&mut self, state_root: N::StateRoot) {
+        self.latest_state_root = Some(state_root);
+    }
+
+    /// Inserts the given state path into the cache, under its global state root.
+    ///
+    /// The most recently observed state root is only set if none was observed yet,
+    /// so that a state path from a lagging node does not roll it back.
+    pub fn insert(&mut self, state_path: StatePath<N>) {
+        // If the cache is disabled, return early.
+        if self.capacity == 0 {
+            return;
+        }
+        // Retrieve the global state root and commitment.
+        let state_root = state_path.global_state_root();
+        let commitment = state_path.transition_leaf().id();
+        // If the state root is new and the cache is full, evict the oldest state root.
+        if !self.state_paths.contains_key(&state_root) && self.state_paths.len() >= self.capacity {
+            self.state_paths.shift_remove_index(0);
+        }
+        // Insert the state path.
+        self.state_paths.entry(state_root).or_default().insert(commitment, state_path);
+        // If no state root was observed yet, set the latest state root.
+        self.latest_state_root.get_or_insert(state_root);
+    }
+
+    /// Clears the cache.
+    pub fn clear(&mut self) {
+        self.latest_state_root = None;
+        self.state_paths.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::state_path::test_helpers::sample_global_state_path};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_state_path_cache() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let mut cache = StatePathCache::<CurrentNetwork>::new(2);
+        assert!(cache.is_empty());
+
+        // Observe and insert a state path for each of three state roots.
+        let state_paths = (0..3).map(|_| sample_global_state_path(None, rng)).collect::<Result<Vec<_>>>()?;
+        for state_path in &state_paths {
+            cache.observe_state_root(state_path.global_state_root());
+            cache.insert(state_path.clone());
+            let commitment = state_path.transition_leaf().id();
+            assert_eq!(cache.latest_state_root(), Some(state_path.global_state_root()));
+            assert_eq!(cache.get_latest(&commitment), Some(state_path));
+        }
+
+        // Ensure the oldest state root was evicted.
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&state_paths[0].global_state_root(), &state_paths[0].transition_leaf().id()).is_none());
+        assert!(cache.get(&state_paths[1].global_state_root(), &state_paths[1].transition_leaf().id()).is_some());
+
+        // Ensure inserting a state path of an older state root does not roll back the latest state root.
+        cache.insert(state_paths[1].clone());
+        assert_eq!(cache.latest_state_root(), Some(state_paths[2].global_state_root()));
+
+        // Ensure a newly observed state root does not serve the stale state paths.
+        cache.observe_state_root(state_paths[0].global_state_root());
+        assert!(cache.get_latest(&state_paths[2].transition_leaf().id()).is_none());
+
+        // Ensure the first state path sets the latest state root, if none was observed.
+        let mut cache = StatePathCache::<CurrentNetwork>::new(2);
+        cache.insert(state_paths[1].clone());
+        cache.insert(state_paths[0].clone());
+        assert_eq!(cache.latest_state_root(), Some(state_paths[1].global_state_root()));
+
+        // Ensure a disabled cache does not store anything.
+        let mut cache = StatePathCache::<CurrentNetwork>::new(0);
+        cache.insert(state_paths[0].clone());
+        assert!(cache.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,601 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod cache;
+pub use cache::*;
+
+use crate::QueryTrait;
+use console::{
+    network::prelude::*,
+    program::{ProgramID, StatePath},
+    types::Field,
+};
+use synthesizer_program::Program;
+
+use parking_lot::RwLock;
+use std::{sync::Arc, time::Duration};
+
+/// The default timeout of a request.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+/// The default number of retries of a request, after the first attempt.
+const DEFAULT_MAX_RETRIES: u32 = 3;
+/// The default delay before the first retry of a request.
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+/// The default maximum delay between two retries of a request.
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(5);
+/// The default number of state roots whose state paths are cached.
+const DEFAULT_CACHE_CAPACITY: usize = 4;
+
+/// A REST client for the query endpoints of a node, with timeouts, retries, fallback endpoints,
+/// and an in-memory cache of state paths.
+///
+/// Each request is attempted against the endpoints in order, and the whole round is retried
+/// with an exponential backoff if any endpoint failed with a transient error (e.g. a timeout,
+/// a connection failure, or a `429` or `5xx` status). If every endpoint failed with a non-transient
+/// status (e.g. `404`), the request fails without being retried.
+///
+/// The state paths are cached under their global state root. A cached state path is served while its
+/// state root is the most recently observed one, i.e. until `current_state_root` returns a new state root.
+/// A state path for a different state root than the most recently observed one is treated as a transient
+/// error, so that the state paths of an execution are all fetched at the state root of its inclusion proof.
+#[derive(Clone)]
+pub struct RestClient<N: Network> {
+    /// The base URLs of the nodes, in order of preference.
+    endpoints: Vec<String>,
+    /// The network path segment of the endpoints (e.g. `mainnet`).
+    network: &'static str,
+    /// The HTTP agent.
+    agent: ureq::Agent,
+    /// The async HTTP client.
+    #[cfg(feature = "async")]
+    client: reqwest::Client,
+    /// The timeout of a request.
+    timeout: Duration,
+    /// The number of retries of a request, after the first attempt.
+    max_retries: u32,
+    /// The delay before the first retry of a request.
+    initial_backoff: Duration,
+    /// The maximum delay between two retries of a request.
+    max_backoff: Duration,
+    /// The cache of state paths.
+    cache: Arc<RwLock<StatePathCache<N>>>,
+}
+
+impl<N: Network> RestClient<N> {
+    /// Initializes a new REST client for the given base URL of a node.
+    pub fn new(endpoint: impl Into<String>) -> Result<Self> {
+        // Retrieve the network path segment.
+        let network = match N::ID {
+            console::network::MainnetV0::ID => "mainnet",
+            console::network::TestnetV0::ID => "testnet",
+            console::network::CanaryV0::ID => "canary",
+            console::network::DevnetV0::ID => "devnet",
+            _ => bail!("Unsupported network ID in inclusion query"),
+        };
+        Ok(Self {
+            endpoints: vec![Self::normalize(endpoint)],
+            network,
+            agent: Self::build_agent(DEFAULT_TIMEOUT),
+            #[cfg(feature = "async")]
+            client: reqwest::Client::new(),
+            timeout: DEFAULT_TIMEOUT,
+            max_retries: DEFAULT_MAX_RETRIES,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            cache: Arc::new(RwLock::new(StatePathCache::new(DEFAULT_CACHE_CAPACITY))),
+        })
+    }
+
+    /// Adds the given base URL of a node as a fallback endpoint.
+    pub fn with_fallback(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoints.push(Self::normalize(endpoint));
+        self
+    }
+
+    /// Sets the timeout of a request.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.agent = Self::build_agent(timeout);
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the number of retries of a request, after the first attempt.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry of a request, and the maximum delay between two retries.
+    pub fn with_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff.max(initial_backoff);
+        self
+    }
+
+    /// Sets the number of state roots whose state paths are cached. A capacity of `0` disables the cache.
+    pub fn with_cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache = Arc::new(RwLock::new(StatePathCache::new(capacity)));
+        self
+    }
+}
+
+impl<N: Network> RestClient<N> {
+    /// Returns the base URLs of the nodes, in order of preference.
+    pub fn endpoints(&self) -> &[String] {
+        &self.endpoints
+    }
+
+    /// Returns the timeout of a request.
+    pub const fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns the number of retries of a request, after the first attempt.
+    pub const fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Returns the cache of state paths.
+    pub fn cache(&self) -> &Arc<RwLock<StatePathCache<N>>> {
+        &self.cache
+    }
+
+    /// Returns the program for the given program ID.
+    pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        self.get(&format!("program/{program_id}"))
+    }
+
+    /// Returns the program for the given program ID.
+    #[cfg(feature = "async")]
+    pub async fn get_program_async(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        self.get_async(&format!("program/{program_id}")).await
+    }
+}
+
+/// An error of a request to a single endpoint.
+enum RequestError {
+    /// An error that may not recur if the request is retried.
+    Transient(Error),
+    /// An error that recurs if the request is retried against the same endpoint.
+    Permanent(Error),
+}
+
+macro_rules! get_impl {
+    ($self:ident, $path:ident, $check:ident, $get_from:ident, $sleep:path $(, $await:ident)?) => {{
+        let mut backoff = $self.initial_backoff;
+        let mut last_error = None;
+        for attempt in 0..=$self.max_retries {
+            // Wait before retrying.
+            if attempt > 0 {
+                $sleep(backoff)$(.$await)?;
+                backoff = backoff.saturating_mul(2).min($self.max_backoff);
+            }
+            // Attempt the request against each endpoint, and track whether a retry may succeed.
+            let mut is_transient = false;
+            for endpoint in &$self.endpoints {
+                let url = format!("{endpoint}/{}/{}", $self.network, $path);
+                match $self.$get_from(&url)$(.$await)?.and_then(|value| $check(&value).map(|()| value)) {
+                    Ok(value) => return Ok(value),
+                    Err(RequestError::Transient(error)) => {
+                        is_transient = true;
+                        last_error = Some(error);
+                    }
+                    Err(RequestError::Permanent(error)) => last_error = Some(error),
+                }
+            }
+            // If every endpoint failed with a non-transient error, do not retry.
+            if !is_transient {
+                break;
+            }
+        }
+        Err(last_error.unwrap_or_else(|| anyhow!("Failed to fetch '{}' - no endpoints are configured", $path)))
+    }};
+}
+
+impl<N: Network> RestClient<N> {
+    /// Performs a GET request for the given path, against each endpoint in order,
+    /// and retries with an exponential backoff if an endpoint failed with a transient error.
+    fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        self.get_checked(path, |_: &T| Ok(()))
+    }
+
+    /// Performs a GET request for the given path, and accepts the first response that passes the given check.
+    fn get_checked<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        check: impl Fn(&T) -> Result<(), RequestError>,
+    ) -> Result<T> {
+        get_impl!(self, path, check, get_from, std::thread::sleep)
+    }
+
+    /// Performs a GET request for the given path, against each endpoint in order,
+    /// and retries with an exponential backoff if an endpoint failed with a transient error.
+    #[cfg(feature = "async")]
+    async fn get_async<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        self.get_checked_async(path, |_: &T| Ok(())).await
+    }
+
+    /// Performs a GET request for the given path, and accepts the first response that passes the given check.
+    #[cfg(feature = "async")]
+    async fn get_checked_async<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        check: impl Fn(&T) -> Result<(), RequestError>,
+    ) -> Result<T> {
+        get_impl!(self, path, check, get_from_async, tokio::time::sleep, await)
+    }
+
+    /// Performs a GET request against the given URL.
+    fn get_from<T: DeserializeOwned>(&self, url: &str) -> Result<T, RequestError> {
+        match self.agent.get(url).call() {
+            Ok(response) if response.status() == 200 => response.into_json().map_err(|error| {
+                // Note: A truncated response body is treated as a transient error.
+                RequestError::Transient(anyhow!("Failed to parse the response from {url} - {error}"))
+            }),
+            Ok(response) => Err(Self::status_error(url, response.status())),
+            Err(ureq::Error::Status(status, _)) => Err(Self::status_error(url, status)),
+            Err(error) => Err(RequestError::Transient(anyhow!("Failed to fetch from {url} - {error}"))),
+        }
+    }
+
+    /// Performs a GET request against the given URL.
+    #[cfg(feature = "async")]
+    async fn get_from_async<T: DeserializeOwned>(&self, url: &str) -> Result<T, RequestError> {
+        match self.client.get(url).timeout(self.timeout).send().await {
+            Ok(response) if response.status() == 200 => response.json().await.map_err(|error| {
+                // Note: A truncated response body is treated as a transient error.
+                RequestError::Transient(anyhow!("Failed to parse the response from {url} - {error}"))
+            }),
+            Ok(response) => Err(Self::status_error(url, response.status().as_u16())),
+            Err(error) => Err(RequestError::Transient(anyhow!("Failed to fetch from {url} - {error}"))),
+        }
+    }
+
+    /// Ensures the given state path belongs to the given commitment, and is for the given state root, if any.
+    fn check_state_path(
+        commitment: &Field<N>,
+        state_root: Option<N::StateRoot>,
+        state_path: &StatePath<N>,
+    ) -> Result<(), RequestError> {
+        // Ensure the state path is for the given commitment.
+        if state_path.transition_leaf().id() != *commitment {
+            return Err(RequestError::Permanent(anyhow!(
+                "The state path from the node does not belong to the commitment '{commitment}'"
+            )));
+        }
+        // Ensure the state path is for the given state root.
+        // Note: The endpoint may lag behind, or be ahead of, the endpoint that served the state root.
+        match state_root {
+            Some(state_root) if state_path.global_state_root() != state_root => Err(RequestError::Transient(anyhow!(
+                "The state path from the node is for the state root '{}', instead of '{state_root}'",
+                state_path.global_state_root()
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the error of a request that failed with the given status.
+    fn status_error(url: &str, status: u16) -> RequestError {
+        let error = anyhow!("Failed to fetch from {url} (status {status})");
+        match Self::is_transient(status) {
+            true => RequestError::Transient(error),
+            false => RequestError::Permanent(error),
+        }
+    }
+
+    /// Returns `true` if a request that failed with the given status may succeed when retried.
+    const fn is_transient(status: u16) -> bool {
+        status == 408 || status == 429 || status >= 500
+    }
+
+    /// Returns an HTTP agent with the given timeout.
+    fn build_agent(timeout: Duration) -> ureq::Agent {
+        ureq::AgentBuilder::new().timeout(timeout).build()
+    }
+
+    /// Returns the given base URL, without a trailing slash.
+    fn normalize(endpoint: impl Into<String>) -> String {
+        endpoint.into().trim_end_matches('/').to_string()
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network> QueryTrait<N> for RestClient<N> {
+    /// Returns the current state root.
+    fn current_state_root(&self) -> Result<N::StateRoot> {
+        let state_root = self.get("stateRoot/latest")?;
+        // Record the state root, so that the cached state paths of older state roots are no longer served.
+        self.cache.write().observe_state_root(state_root);
+        Ok(state_root)
+    }
+
+    /// Returns the current state root.
+    #[cfg(feature = "async")]
+    async fn current_state_root_async(&self) -> Result<N::StateRoot> {
+        let state_root = self.get_async("stateRoot/latest").await?;
+        // Record the state root, so that the cached state paths of older state roots are no longer served.
+        self.cache.write().observe_state_root(state_root);
+        Ok(state_root)
+    }
+
+    /// Returns a state path for the given `commitment`.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        // Retrieve the latest state root, and return the state path if it is cached under it.
+        let state_root = {
+            let cache = self.cache.read();
+            if let Some(state_path) = cache.get_latest(commitment) {
+                return Ok(state_path.clone());
+            }
+            cache.latest_state_root()
+        };
+        // Otherwise, fetch the state path at the latest state root.
+        let state_path = self.get_checked(&format!("statePath/{commitment}"), |state_path| {
+            Self::check_state_path(commitment, state_root, state_path)
+        })?;
+        // Cache the state path.
+        self.cache.write().insert(state_path.clone());
+        Ok(state_path)
+    }
+
+    /// Returns a state path for the given `commitment`.
+    #[cfg(feature = "async")]
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        // Retrieve the latest state root, and return the state path if it is cached under it.
+        let state_root = {
+            let cache = self.cache.read();
+            if let Some(state_path) = cache.get_latest(commitment) {
+                return Ok(state_path.clone());
+            }
+            cache.latest_state_root()
+        };
+        // Otherwise, fetch the state path at the latest state root.
+        let state_path = self
+            .get_checked_async(&format!("statePath/{commitment}"), |state_path| {
+                Self::check_state_path(commitment, state_root, state_path)
+            })
+            .await?;
+        // Cache the state path.
+        self.cache.write().insert(state_path.clone());
+        Ok(state_path)
+    }
+
+    /// Returns the current block height.
+    fn current_block_height(&self) -> Result<u32> {
+        self.get("block/height/latest")
+    }
+
+    /// Returns the current block height.
+    #[cfg(feature = "async")]
+    async fn current_block_height_async(&self) -> Result<u32> {
+        self.get_async("block/height/latest").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::state_path::test_helpers::sample_global_state_path};
+
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    type CurrentNetwork = MainnetV0;
+
+    /// A local HTTP stand-in for a node, that answers each request with the `(status, body)` of the handler.
+    /// The handler is given the number of previous requests and the requested path.
+    struct TestServer {
+        /// The base URL of the server.
+        url: String,
+        /// The number of requests received by the server.
+        requests: Arc<AtomicUsize>,
+    }
+
+    impl TestServer {
+        /// Starts a new server on a local port.
+        fn start(handler: impl Fn(usize, &str) -> (u16, String) + Send + Sync + 'static) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(AtomicUsize::new(0));
+            let handler = Arc::new(handler);
+
+            let counter = requests.clone();
+            std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let Ok(mut stream) = stream else { continue };
+                    let index = counter.fetch_add(1, Ordering::SeqCst);
+                    let handler = handler.clone();
+                    std::thread::spawn(move || {
+                        // Read the request line and headers.
+                        let mut reader = BufReader::new(stream.try_clone().unwrap());
+                        let mut request_line = String::new();
+                        reader.read_line(&mut request_line).unwrap();
+                        let mut line = String::new();
+                        while reader.read_line(&mut line).unwrap() > 2 {
+                            line.clear();
+                        }
+                        // Answer the request.
+                        let path = request_line.split_whitespace().nth(1).unwrap_or_default();
+                        let (status, body) = handler(index, path);
+                        let response = format!(
+                            "HTTP/1.1 {status} Status\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                            body.len()
+                        );
+                        let _ = stream.write_all(response.as_bytes());
+                    });
+                }
+            });
+            Self { url, requests }
+        }
+
+        /// Returns the number of requests received by the server.
+        fn requests(&self) -> usize {
+            self.requests.load(Ordering::SeqCst)
+        }
+    }
+
+    /// Returns a client for the given server, with short delays.
+    fn sample_client(url: &str) -> RestClient<CurrentNetwork> {
+        RestClient::new(url)
+            .unwrap()
+            .with_timeout(Duration::from_millis(500))
+            .with_backoff(Duration::from_millis(1), Duration::from_millis(10))
+    }
+
+    /// Returns the base URL of a local port that does not accept connections.
+    fn sample_closed_url() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        format!("http://{}", listener.local_addr().unwrap())
+    }
+
+    #[test]
+    fn test_rest_client_retries() {
+        let rng = &mut TestRng::default();
+        let state_root = sample_global_state_path::<CurrentNetwork>(None, rng).unwrap().global_state_root();
+        let body = serde_json::to_string(&state_root).unwrap();
+
+        // Fail the first two requests with a transient error.
+        let server = TestServer::start(move |index, path| {
+            assert_eq!(path, "/mainnet/stateRoot/latest");
+            match index < 2 {
+                true => (503, String::new()),
+                false => (200, body.clone()),
+            }
+        });
+        let client = sample_client(&server.url);
+        assert_eq!(client.current_state_root().unwrap(), state_root);
+        assert_eq!(server.requests(), 3);
+
+        // Ensure the client gives up after the maximum number of retries.
+        let server = TestServer::start(|_, _| (503, String::new()));
+        let client = sample_client(&server.url).with_max_retries(2);
+        assert!(client.current_state_root().is_err());
+        assert_eq!(server.requests(), 3);
+    }
+
+    #[test]
+    fn test_rest_client_non_transient_error() {
+        let server = TestServer::start(|_, _| (404, String::new()));
+        let client = sample_client(&server.url);
+        assert!(client.current_block_height().is_err());
+        assert_eq!(server.requests(), 1);
+
+        // Ensure the fallback endpoint is used when the primary endpoint fails with a non-transient error.
+        let fallback = TestServer::start(|_, _| (200, "7".to_string()));
+        let client = sample_client(&server.url).with_fallback(&fallback.url);
+        assert_eq!(client.current_block_height().unwrap(), 7);
+        assert_eq!(server.requests(), 2);
+        assert_eq!(fallback.requests(), 1);
+    }
+
+    #[test]
+    fn test_rest_client_timeout() {
+        // Delay the response to the first request beyond the timeout.
+        let server = TestServer::start(|index, _| {
+            if index == 0 {
+                std::thread::sleep(Duration::from_millis(1500));
+            }
+            (200, "5".to_string())
+        });
+        let client = sample_client(&server.url);
+        assert_eq!(client.current_block_height().unwrap(), 5);
+        assert_eq!(server.requests(), 2);
+    }
+
+    #[test]
+    fn test_rest_client_fallback() {
+        let server = TestServer::start(|_, path| {
+            assert_eq!(path, "/mainnet/block/height/latest");
+            (200, "10".to_string())
+        });
+        // Ensure the fallback endpoint is used when the primary endpoint is unreachable.
+        let client = sample_client(&sample_closed_url()).with_fallback(format!("{}/", server.url));
+        assert_eq!(client.current_block_height().unwrap(), 10);
+        assert_eq!(server.requests(), 1);
+    }
+
+    #[test]
+    fn test_rest_client_cache() {
+        let rng = &mut TestRng::default();
+        let state_path = sample_global_state_path::<CurrentNetwork>(None, rng).unwrap();
+        let commitment = state_path.transition_leaf().id();
+        let state_root = state_path.global_state_root();
+
+        let state_path_body = serde_json::to_string(&state_path).unwrap();
+        let state_root_body = serde_json::to_string(&state_root).unwrap();
+        let server = TestServer::start(move |_, path| match path {
+            "/mainnet/stateRoot/latest" => (200, state_root_body.clone()),
+            _ => (200, state_path_body.clone()),
+        });
+        let client = sample_client(&server.url);
+
+        // Ensure the state path is fetched once, and served from the cache afterwards.
+        assert_eq!(client.get_state_path_for_commitment(&commitment).unwrap(), state_path);
+        assert_eq!(client.get_state_path_for_commitment(&commitment).unwrap(), state_path);
+        assert_eq!(server.requests(), 1);
+
+        // Ensure the cache is still used while the state root is unchanged.
+        assert_eq!(client.current_state_root().unwrap(), state_root);
+        assert_eq!(client.get_state_path_for_commitment(&commitment).unwrap(), state_path);
+        assert_eq!(server.requests(), 2);
+
+        // Ensure a state path for a different commitment is rejected.
+        assert!(client.get_state_path_for_commitment(&Field::rand(rng)).is_err());
+
+        // Ensure a disabled cache fetches the state path every time.
+        let client = sample_client(&server.url).with_cache_capacity(0);
+        client.get_state_path_for_commitment(&commitment).unwrap();
+        client.get_state_path_for_commitment(&commitment).unwrap();
+        assert_eq!(server.requests(), 5);
+    }
+
+    #[test]
+    fn test_rest_client_state_root_mismatch() {
+        let rng = &mut TestRng::default();
+        let state_path = sample_global_state_path::<CurrentNetwork>(None, rng).unwrap();
+        let commitment = state_path.transition_leaf().id();
+        let state_root = state_path.global_state_root();
+        // Sample a state path for the same commitment, at a newer state root.
+        let newer_state_path = sample_global_state_path::<CurrentNetwork>(Some(commitment), rng).unwrap();
+        assert_ne!(newer_state_path.global_state_root(), state_root);
+
+        // Serve the state root from the primary endpoint, which has advanced by the time the state path is fetched.
+        let state_root_body = serde_json::to_string(&state_root).unwrap();
+        let newer_state_path_body = serde_json::to_string(&newer_state_path).unwrap();
+        let primary = TestServer::start(move |_, path| match path {
+            "/mainnet/stateRoot/latest" => (200, state_root_body.clone()),
+            _ => (200, newer_state_path_body.clone()),
+        });
+        // Serve the state path at the observed state root from the fallback endpoint.
+        let state_path_body = serde_json::to_string(&state_path).unwrap();
+        let fallback = TestServer::start(move |_, _| (200, state_path_body.clone()));
+
+        // Ensure the state path at the observed state root is returned.
+        let client = sample_client(&primary.url).with_fallback(&fallback.url);
+        assert_eq!(client.current_state_root().unwrap(), state_root);
+        assert_eq!(client.get_state_path_for_commitment(&commitment).unwrap(), state_path);
+        assert_eq!(primary.requests(), 2);
+        assert_eq!(fallback.requests(), 1);
+
+        // Ensure the request is retried, and fails, if no endpoint serves the observed state root.
+        let client = sample_client(&primary.url).with_max_retries(2);
+        assert_eq!(client.current_state_root().unwrap(), state_root);
+        assert!(client.get_state_path_for_commitment(&commitment).is_err());
+        assert_eq!(primary.requests(), 6);
+        assert!(client.cache().read().is_empty());
+    }
+}
@@ -0,0 +1,284 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod serialize;
+
+use crate::QueryTrait;
+use console::{network::prelude::*, program::StatePath, types::Field};
+
+use indexmap::IndexMap;
+use std::path::Path;
+
+/// The maximum number of attempts to fetch a consistent static query from a query.
+const MAX_FETCH_ATTEMPTS: usize = 3;
+
+/// A static query serves a fixed state root, block height, and set of state paths,
+/// so that inclusion proofs can be produced without access to a node (e.g. by an air-gapped prover).
+///
+/// The state paths are keyed by the record commitment they prove the inclusion of,
+/// and must all be anchored to the same global state root.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StaticQuery<N: Network> {
+    /// The global state root of the state paths.
+    state_root: N::StateRoot,
+    /// The block height at which the state paths were exported.
+    block_height: u32,
+    /// The state paths, keyed by their record commitment.
+    state_paths: IndexMap<Field<N>, StatePath<N>>,
+}
+
+impl<N: Network> StaticQuery<N> {
+    /// Initializes a new static query from the given state root, block height, and state paths.
+    pub fn new(state_root: N::StateRoot, block_height: u32, state_paths: Vec<StatePath<N>>) -> Result<Self> {
+        // Ensure the global state root is not zero.
+        ensure!(*state_root != Field::zero(), "The state root of a static query must not be zero");
+
+        // Initialize a map for the state paths.
+        let mut map = IndexMap::with_capacity(state_paths.len());
+        for state_path in state_paths {
+            // Retrieve the commitment.
+            let commitment = state_path.transition_leaf().id();
+            // Ensure the state path is anchored to the state root.
+            ensure!(
+                state_path.global_state_root() == state_root,
+                "The state path for '{commitment}' does not belong to the state root '{state_root}'"
+            );
+            // Ensure the state path is a valid global state path.
+            state_path.verify(true, Field::zero())?;
+            // Ensure the commitment is not duplicated.
+            ensure!(map.insert(commitment, state_path).is_none(), "Found a duplicate state path for '{commitment}'");
+        }
+
+        Ok(Self { state_root, block_height, state_paths: map })
+    }
+
+    /// Fetches the state paths for the given commitments from the given query (e.g. a full node),
+    /// and returns a static query that can serve them offline.
+    ///
+    /// If the query advances to a new block in between the requests, the state is fetched again,
+    /// up to `MAX_FETCH_ATTEMPTS` times.
+    pub fn fetch<'a>(query: &impl QueryTrait<N>, commitments: impl IntoIterator<Item = &'a Field<N>>) -> Result<Self> {
+        let commitments = commitments.into_iter().collect::<Vec<_>>();
+        let mut last_error = None;
+        for _ in 0..MAX_FETCH_ATTEMPTS {
+            match Self::try_fetch(query, &commitments) {
+                Ok(static_query) => return Ok(static_query),
+                Err(error) => last_error = Some(error),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| anyhow!("Failed to fetch the static query")))
+    }
+
+    /// Fetches the state paths for the given commitments from the given query,
+    /// and ensures they were all fetched at the same state root and block height.
+    fn try_fetch(query: &impl QueryTrait<N>, commitments: &[&Field<N>]) -> Result<Self> {
+        // Retrieve the state root.
+        let state_root = query.current_state_root()?;
+        // Retrieve the block height.
+        let block_height = query.current_block_height()?;
+        // Retrieve the state paths.
+        let state_paths = commitments
+            .iter()
+            .map(|commitment| query.get_state_path_for_commitment(commitment))
+            .collect::<Result<Vec<_>>>()?;
+        // Ensure the query did not advance to a new block in between the requests.
+        // Note: As the state root changes with every block, the block height is for the same block as the state root.
+        let latest_state_root = query.current_state_root()?;
+        ensure!(
+            latest_state_root == state_root,
+            "The state root advanced from '{state_root}' to '{latest_state_root}' while fetching the static query"
+        );
+        // Construct the static query.
+        Self::new(state_root, block_height, state_paths)
+    }
+
+    /// Loads a static query from the JSON file at the given path.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        // Read the file.
+        let contents = std::fs::read_to_string(path)
+            .map_err(|error| anyhow!("Failed to read the static query from '{}' - {error}", path.display()))?;
+        // Parse the static query.
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// Saves the static query as a JSON file at the given path.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        // Write the file.
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+            .map_err(|error| anyhow!("Failed to write the static query to '{}' - {error}", path.display()))
+    }
+
+    /// Returns the global state root.
+    pub const fn state_root(&self) -> N::StateRoot {
+        self.state_root
+    }
+
+    /// Returns the block height.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the state paths, keyed by their record commitment.
+    pub const fn state_paths(&self) -> &IndexMap<Field<N>, StatePath<N>> {
+        &self.state_paths
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network> QueryTrait<N> for StaticQuery<N> {
+    /// Returns the current state root.
+    fn current_state_root(&self) -> Result<N::StateRoot> {
+        Ok(self.state_root)
+    }
+
+    /// Returns the current state root.
+    #[cfg(feature = "async")]
+    async fn current_state_root_async(&self) -> Result<N::StateRoot> {
+        self.current_state_root()
+    }
+
+    /// Returns a state path for the given `commitment`.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        match self.state_paths.get(commitment) {
+            Some(state_path) => Ok(state_path.clone()),
+            None => bail!("The state path for '{commitment}' is missing from the static query"),
+        }
+    }
+
+    /// Returns a state path for the given `commitment`.
+    #[cfg(feature = "async")]
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.get_state_path_for_commitment(commitment)
+    }
+
+    /// Returns the current block height.
+    fn current_block_height(&self) -> Result<u32> {
+        Ok(self.block_height)
+    }
+
+    /// Returns the current block height.
+    #[cfg(feature = "async")]
+    async fn current_block_height_async(&self) -> Result<u32> {
+        self.current_block_height()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::state_path::test_helpers::sample_global_state_path};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_static_query() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a state path.
+        let state_path = sample_global_state_path::<CurrentNetwork>(None, rng)?;
+        let commitment = state_path.transition_leaf().id();
+        let state_root = state_path.global_state_root();
+
+        // Construct the static query.
+        let query = StaticQuery::new(state_root, 10, vec![state_path.clone()])?;
+        assert_eq!(query.current_state_root()?, state_root);
+        assert_eq!(query.current_block_height()?, 10);
+        assert_eq!(query.get_state_path_for_commitment(&commitment)?, state_path);
+        assert!(query.get_state_path_for_commitment(&Field::rand(rng)).is_err());
+
+        // Ensure a state path with a different state root is rejected.
+        let other = sample_global_state_path::<CurrentNetwork>(None, rng)?;
+        assert!(StaticQuery::new(state_root, 10, vec![state_path.clone(), other]).is_err());
+        // Ensure a duplicate state path is rejected.
+        assert!(StaticQuery::new(state_root, 10, vec![state_path.clone(), state_path]).is_err());
+
+        // Ensure the static query can be fetched from another query.
+        assert_eq!(StaticQuery::fetch(&query, [&commitment])?, query);
+        Ok(())
+    }
+
+    /// A query that advances to the next static query on every request for the state root, until the last one.
+    struct AdvancingQuery {
+        /// The static queries, in the order they are served.
+        queries: Vec<StaticQuery<CurrentNetwork>>,
+        /// The number of requests for the state root.
+        requests: std::cell::Cell<usize>,
+    }
+
+    impl AdvancingQuery {
+        /// Returns the static query that is currently served.
+        fn current(&self) -> &StaticQuery<CurrentNetwork> {
+            &self.queries[self.requests.get().saturating_sub(1).min(self.queries.len() - 1)]
+        }
+    }
+
+    #[cfg_attr(feature = "async", async_trait(?Send))]
+    impl QueryTrait<CurrentNetwork> for AdvancingQuery {
+        fn current_state_root(&self) -> Result<<CurrentNetwork as Network>::StateRoot> {
+            self.requests.set(self.requests.get() + 1);
+            self.current().current_state_root()
+        }
+
+        #[cfg(feature = "async")]
+        async fn current_state_root_async(&self) -> Result<<CurrentNetwork as Network>::StateRoot> {
+            self.current_state_root()
+        }
+
+        fn get_state_path_for_commitment(
+            &self,
+            commitment: &Field<CurrentNetwork>,
+        ) -> Result<StatePath<CurrentNetwork>> {
+            // Note: The state path is served from the next block, to emulate a node that advances in between requests.
+            self.queries[self.requests.get().min(self.queries.len() - 1)].get_state_path_for_commitment(commitment)
+        }
+
+        #[cfg(feature = "async")]
+        async fn get_state_path_for_commitment_async(
+            &self,
+            commitment: &Field<CurrentNetwork>,
+        ) -> Result<StatePath<CurrentNetwork>> {
+            self.get_state_path_for_commitment(commitment)
+        }
+
+        fn current_block_height(&self) -> Result<u32> {
+            self.current().current_block_height()
+        }
+
+        #[cfg(feature = "async")]
+        async fn current_block_height_async(&self) -> Result<u32> {
+            self.current_block_height()
+        }
+    }
+
+    #[test]
+    fn test_static_query_fetch_retries() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the state paths for the same commitment, at two consecutive blocks.
+        let state_path = sample_global_state_path::<CurrentNetwork>(None, rng)?;
+        let commitment = state_path.transition_leaf().id();
+        let next_state_path = sample_global_state_path::<CurrentNetwork>(Some(commitment), rng)?;
+        let query = StaticQuery::new(state_path.global_state_root(), 10, vec![state_path])?;
+        let next_query = StaticQuery::new(next_state_path.global_state_root(), 11, vec![next_state_path])?;
+
+        // Ensure the fetch is retried if the query advanced in between the requests.
+        let advancing = AdvancingQuery { queries: vec![query, next_query.clone()], requests: Default::default() };
+        assert_eq!(StaticQuery::fetch(&advancing, [&commitment])?, next_query);
+        assert_eq!(advancing.requests.get(), 4);
+        Ok(())
+    }
+}
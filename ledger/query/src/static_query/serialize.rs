@@ -0,0 +1,64 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for StaticQuery<N> {
+    /// Serializes the static query to a JSON-string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut query = serializer.serialize_struct("StaticQuery", 3)?;
+        query.serialize_field("state_root", &self.state_root)?;
+        query.serialize_field("block_height", &self.block_height)?;
+        query.serialize_field("state_paths", &self.state_paths.values().collect::<Vec<_>>())?;
+        query.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for StaticQuery<N> {
+    /// Deserializes the static query from a JSON-string.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut query = serde_json::Value::deserialize(deserializer)?;
+        Self::new(
+            DeserializeExt::take_from_value::<D>(&mut query, "state_root")?,
+            DeserializeExt::take_from_value::<D>(&mut query, "block_height")?,
+            DeserializeExt::take_from_value::<D>(&mut query, "state_paths")?,
+        )
+        .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::state_path::test_helpers::sample_global_state_path};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the static query.
+        let state_path = sample_global_state_path::<CurrentNetwork>(None, rng)?;
+        let expected = StaticQuery::new(state_path.global_state_root(), 5, vec![state_path])?;
+
+        // Serialize
+        let candidate_string = serde_json::to_string(&expected)?;
+
+        // Deserialize
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        Ok(())
+    }
+}
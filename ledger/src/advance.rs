@@ -28,8 +28,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
         // Decouple the transmissions into ratifications, solutions, and transactions.
         let (ratifications, solutions, transactions) = decouple_transmissions(transmissions.into_iter())?;
-        // Currently, we do not support ratifications from the memory pool.
-        ensure!(ratifications.is_empty(), "Ratifications are currently unsupported from the memory pool");
+        // Currently, we only support restrictions updates from the memory pool.
+        ensure!(
+            ratifications.iter().all(|ratify| matches!(ratify, Ratify::Restrictions(..))),
+            "Only restrictions updates are currently supported from the memory pool"
+        );
         // Construct the block template.
         let (header, ratifications, solutions, aborted_solution_ids, transactions, aborted_transaction_ids) =
             self.construct_block_template(&previous_block, Some(&subdag), ratifications, solutions, transactions, rng)?;
@@ -56,8 +59,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         candidate_transactions: Vec<Transaction<N>>,
         rng: &mut R,
     ) -> Result<Block<N>> {
-        // Currently, we do not support ratifications from the memory pool.
-        ensure!(candidate_ratifications.is_empty(), "Ratifications are currently unsupported from the memory pool");
+        // Currently, we only support restrictions updates from the memory pool.
+        ensure!(
+            candidate_ratifications.iter().all(|ratify| matches!(ratify, Ratify::Restrictions(..))),
+            "Only restrictions updates are currently supported from the memory pool"
+        );
 
         // Retrieve the latest block as the previous block (for the next block).
         let previous_block = self.latest_block();
@@ -104,7 +110,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
 
         // If the block is the start of a new epoch, or the epoch hash has not been set, update the current epoch hash.
-        if block.height() % N::NUM_BLOCKS_PER_EPOCH == 0 || self.current_epoch_hash.read().is_none() {
+        if block.height() % N::num_blocks_per_epoch() == 0 || self.current_epoch_hash.read().is_none() {
             // Update and log the current epoch hash.
             match self.get_epoch_hash(block.height()).ok() {
                 Some(epoch_hash) => {
@@ -119,6 +125,76 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
         Ok(())
     }
+
+    /// Reverts the ledger to the given block height, removing all of the blocks above it.
+    /// This atomically removes the blocks, transactions, transitions, and committees,
+    /// and undoes the finalize operations of the removed blocks.
+    pub fn revert_to_height(&self, height: u32) -> Result<()> {
+        // Acquire the write lock on the current block.
+        let mut current_block = self.current_block.write();
+        // Revert the VM.
+        self.vm.revert_to_height(height)?;
+        // Update the current block.
+        *current_block = self.get_block(height)?;
+        // Drop the write lock on the current block.
+        drop(current_block);
+
+        // Update the cached committee from storage.
+        *self.current_committee.write() = self.vm.finalize_store().committee_store().current_committee().ok();
+
+        // Update the current epoch hash.
+        match self.get_epoch_hash(height).ok() {
+            Some(epoch_hash) => {
+                trace!("Updating the current epoch hash at block {height} to '{epoch_hash}'");
+                *self.current_epoch_hash.write() = Some(epoch_hash);
+            }
+            None => {
+                error!("Failed to update the current epoch hash at block {height}");
+                *self.current_epoch_hash.write() = None;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sets the number of most recent blocks to keep unpruned, or disables pruning if `None`.
+    /// When enabled, the proofs and transition values of older blocks are pruned from storage,
+    /// starting with the blocks that are already older than the pruning depth.
+    /// The pruning depth is persisted, so pruning resumes when the ledger is reloaded.
+    pub fn set_pruning_depth(&self, pruning_depth: Option<u32>) -> Result<()> {
+        // Update the pruning depth.
+        self.vm.block_store().set_pruning_depth(pruning_depth)?;
+        let Some(pruning_depth) = pruning_depth else {
+            return Ok(());
+        };
+        // Prune the blocks that are older than the pruning depth, one batch at a time.
+        loop {
+            // Acquire the read lock on the current block, to ensure the ledger does not advance during the batch.
+            // Note: The lock is released between batches, so that the ledger may advance while pruning.
+            let current_block = self.current_block.read();
+            let Some(prune_height) = current_block.height().checked_sub(pruning_depth) else {
+                return Ok(());
+            };
+            if let Some(pruned_height) =
+                self.vm.block_store().pruned_height()?.filter(|pruned_height| *pruned_height >= prune_height)
+            {
+                // Prune the rollback entries of the pruned blocks, as the ledger can no longer be reverted to them.
+                // Note: Afterwards, the rollback entries are pruned along with each block that is pruned.
+                return self.vm.finalize_store().prune_rollback_entries(pruned_height);
+            }
+            self.vm.block_store().prune_batch(prune_height)?;
+        }
+    }
+
+    /// Indexes the transitions of all unpruned blocks by public address, fee payer, and function,
+    /// and keeps the indexes up to date as the ledger advances.
+    ///
+    /// Note: The blocks are indexed in batches, without holding the lock on the current block, and an interrupted
+    /// backfill resumes from the last indexed batch. The indexes are enabled once the backfill reaches the latest block.
+    pub fn enable_transition_indexes(&self) -> Result<()> {
+        while !self.vm.block_store().index_transitions_batch()? {}
+        Ok(())
+    }
 }
 
 /// Splits candidate solutions into a collection of accepted ones and aborted ones.
@@ -204,7 +280,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
                 let latest_proof_target = self.latest_proof_target();
                 // Separate the candidate solutions into valid and aborted solutions.
                 let (valid_candidate_solutions, aborted_candidate_solutions) =
-                    split_candidate_solutions(candidate_solutions, N::MAX_SOLUTIONS, |solution| {
+                    split_candidate_solutions(candidate_solutions, N::max_solutions(), |solution| {
                         self.puzzle().check_solution_mut(solution, latest_epoch_hash, latest_proof_target).is_ok()
                     });
 
@@ -250,6 +326,23 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         };
         // Compute the next height.
         let next_height = previous_block.height().saturating_add(1);
+        // Select the first valid restrictions update, as a block may contain at most one.
+        // Note: The remaining restrictions updates are dropped, and may be resubmitted in a later block.
+        let candidate_ratifications = {
+            let restrictions = self.vm.restrictions();
+            let restrictions = restrictions.read();
+            candidate_ratifications
+                .into_iter()
+                .filter(|ratify| match restrictions.check_update(next_height, ratify) {
+                    Ok(_) => true,
+                    Err(error) => {
+                        warn!("Dropping an invalid restrictions update at block {next_height} - {error}");
+                        false
+                    }
+                })
+                .take(1)
+                .collect::<Vec<_>>()
+        };
         // Determine the timestamp for the next block.
         let next_timestamp = match subdag {
             Some(subdag) => {
@@ -299,10 +392,10 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             next_height,
             next_timestamp,
             N::GENESIS_TIMESTAMP,
-            N::STARTING_SUPPLY,
-            N::ANCHOR_TIME,
-            N::ANCHOR_HEIGHT,
-            N::BLOCK_TIME,
+            N::starting_supply(),
+            N::anchor_time(),
+            N::anchor_height(),
+            N::block_time(),
             combined_proof_target,
             u64::try_from(latest_cumulative_proof_target)?,
             latest_coinbase_target,
@@ -330,6 +423,13 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Compute the ratifications root.
         let ratifications_root = ratifications.to_ratifications_root()?;
 
+        // Construct the mapping root.
+        // Note: The mapping root is only committed from `N::CONSENSUS_V3_HEIGHT` onwards.
+        let mapping_root = match Header::<N>::commits_mapping_root(next_height) {
+            true => self.latest_mapping_root(),
+            false => Field::zero(),
+        };
+
         // Construct the subdag root.
         let subdag_root = match subdag {
             Some(subdag) => subdag.to_subdag_root()?,
@@ -355,6 +455,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             latest_state_root,
             transactions.to_transactions_root()?,
             transactions.to_finalize_root(ratified_finalize_operations)?,
+            mapping_root,
             ratifications_root,
             solutions_root,
             subdag_root,
@@ -17,6 +17,14 @@ use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given block is valid next block.
+    #[cfg_attr(
+        feature = "spans",
+        tracing::instrument(
+            name = "Ledger::check_next_block",
+            skip_all,
+            fields(height = block.height(), hash = %block.hash(), num_transactions = block.transactions().len())
+        )
+    )]
     pub fn check_next_block<R: CryptoRng + Rng>(&self, block: &Block<N>, rng: &mut R) -> Result<()> {
         let height = block.height();
 
@@ -117,6 +125,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let (expected_existing_solution_ids, expected_existing_transaction_ids) = block.verify(
             &self.latest_block(),
             self.latest_state_root(),
+            self.latest_mapping_root(),
             &previous_committee_lookback,
             &committee_lookback,
             self.puzzle(),
@@ -0,0 +1,47 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::path::Path;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Creates a point-in-time checkpoint of the ledger storage in the given (non-existent) directory.
+    ///
+    /// The checkpoint is taken between blocks, while the node keeps running.
+    pub fn create_checkpoint<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        // Acquire the read lock on the current block, to ensure the ledger does not advance during the checkpoint.
+        let current_block = self.current_block.read();
+        info!("Creating a checkpoint of the ledger at block {}...", current_block.height());
+        // Create the checkpoint.
+        self.vm.create_checkpoint(path.as_ref())
+    }
+
+    /// Restores the ledger storage from the checkpoint in the given directory, and loads the ledger.
+    ///
+    /// The checkpoint must have been created for the same network and storage schema,
+    /// and the ledger storage must be empty.
+    pub fn load_from_checkpoint<P: AsRef<Path>>(
+        genesis_block: Block<N>,
+        checkpoint: P,
+        storage_mode: StorageMode,
+    ) -> Result<Self> {
+        info!("Restoring the ledger from the checkpoint in '{}'...", checkpoint.as_ref().display());
+        // Restore the checkpoint into the ledger storage.
+        C::restore_checkpoint(checkpoint.as_ref(), storage_mode.clone())?;
+        // Load the ledger.
+        Self::load(genesis_block, storage_mode)
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A simulated clock, which only moves forward when the devnet advances it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SimulatedClock {
+    /// The current UNIX timestamp, in seconds.
+    timestamp: i64,
+}
+
+impl SimulatedClock {
+    /// Initializes a new clock at the given UNIX timestamp.
+    pub const fn new(timestamp: i64) -> Self {
+        Self { timestamp }
+    }
+
+    /// Returns the current UNIX timestamp, in seconds.
+    pub const fn now(&self) -> i64 {
+        self.timestamp
+    }
+
+    /// Advances the clock by the given number of seconds.
+    pub fn advance(&mut self, seconds: i64) {
+        self.timestamp = self.timestamp.saturating_add(seconds);
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A fault that a scenario can inject into a devnet validator.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Fault {
+    /// The validator is offline: it does not propose or sign batches, and does not receive blocks.
+    /// Once recovered, the validator syncs the blocks it missed from the rest of the devnet.
+    Crash,
+    /// The validator proposes batches, but does not sign the batches of other validators.
+    WithholdSignatures,
+    /// The batches proposed by the validator never reach the other validators, so they are never certified.
+    DropBatches,
+}
+
+impl Fault {
+    /// Returns `true` if the batches proposed by a validator with this fault reach the other validators.
+    pub const fn delivers_batches(&self) -> bool {
+        matches!(self, Self::WithholdSignatures)
+    }
+
+    /// Returns `true` if a validator with this fault signs the batches of other validators.
+    pub const fn signs_batches(&self) -> bool {
+        matches!(self, Self::DropBatches)
+    }
+}
@@ -0,0 +1,554 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod clock;
+pub use clock::*;
+
+mod fault;
+pub use fault::*;
+
+mod validator;
+pub use validator::*;
+
+use crate::Ledger;
+use aleo_std::StorageMode;
+use console::{
+    account::{Address, PrivateKey},
+    network::prelude::*,
+    types::Field,
+};
+use ledger_block::{Block, Transaction};
+use ledger_committee::{Committee, MIN_VALIDATOR_STAKE};
+use ledger_narwhal::{BatchCertificate, BatchHeader, Data, Subdag, Transmission, TransmissionID};
+use ledger_store::{ConsensusStore, helpers::memory::ConsensusMemory};
+use synthesizer::vm::VM;
+
+use anyhow::Result;
+use indexmap::{IndexMap, IndexSet};
+use rand::{SeedableRng, rngs::StdRng};
+use std::collections::{BTreeMap, HashSet};
+
+/// The maximum number of rounds without a block, after which the devnet is considered stalled.
+const MAX_ROUNDS_WITHOUT_BLOCK: usize = 100;
+
+/// The configuration of a devnet.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DevnetConfig {
+    /// The number of validators in the genesis committee.
+    pub num_validators: usize,
+    /// The amount of microcredits bonded by each genesis validator.
+    pub stake_per_validator: u64,
+    /// The seed from which the private keys and all other randomness of the devnet are derived.
+    pub seed: u64,
+    /// The number of seconds the simulated clock advances in each round.
+    pub round_interval_in_secs: i64,
+}
+
+impl Default for DevnetConfig {
+    /// Returns a devnet of 4 validators with the minimum stake, and a round every 5 seconds.
+    fn default() -> Self {
+        Self { num_validators: 4, stake_per_validator: MIN_VALIDATOR_STAKE, seed: 0, round_interval_in_secs: 5 }
+    }
+}
+
+/// A deterministic in-process devnet, in which every validator maintains its own ledger.
+///
+/// In each round, the validators propose batches of the transmissions in their memory pools, and sign
+/// the batches of the other validators. A batch with a quorum of signatures becomes a certificate in the DAG.
+/// Once the leader certificate of an even round has the votes of an availability threshold in the next round,
+/// it is committed as a quorum block, which every online validator checks and adds to its ledger.
+///
+/// Scenarios drive the devnet by submitting transactions, injecting faults, and advancing rounds.
+pub struct Devnet<N: Network> {
+    /// The configuration of the devnet.
+    config: DevnetConfig,
+    /// The genesis block.
+    genesis: Block<N>,
+    /// The validators, by address.
+    validators: IndexMap<Address<N>, DevnetValidator<N>>,
+    /// The simulated clock.
+    clock: SimulatedClock,
+    /// The current round of the DAG.
+    round: u64,
+    /// The certificates of the DAG, by round and author.
+    dag: BTreeMap<u64, IndexMap<Address<N>, BatchCertificate<N>>>,
+    /// The IDs of the committed certificates.
+    committed_certificate_ids: HashSet<Field<N>>,
+    /// The round of the last committed leader certificate.
+    last_committed_round: u64,
+    /// The transmissions of the certificates that are not committed yet.
+    certified_transmissions: IndexMap<TransmissionID<N>, Transmission<N>>,
+    /// The random number generator of the devnet.
+    rng: StdRng,
+}
+
+impl<N: Network> Devnet<N> {
+    /// Initializes a new devnet, with a genesis block derived from the given configuration.
+    pub fn new(config: DevnetConfig) -> Result<Self> {
+        // Ensure the committee is large enough to tolerate a fault.
+        ensure!(config.num_validators >= 4, "A devnet must have at least 4 validators");
+        // Ensure the clock moves forward in each round.
+        ensure!(config.round_interval_in_secs > 0, "The round interval must be positive");
+
+        // Initialize the random number generator.
+        let mut rng = StdRng::seed_from_u64(config.seed);
+
+        // Sample the private keys of the validators.
+        let private_keys = (0..config.num_validators).map(|_| PrivateKey::new(&mut rng)).collect::<Result<Vec<_>>>()?;
+        let addresses = private_keys.iter().map(Address::try_from).collect::<Result<Vec<_>>>()?;
+
+        // Construct the committee.
+        let members = addresses.iter().map(|address| (*address, (config.stake_per_validator, true, 0u8))).collect();
+        let committee = Committee::<N>::new_genesis(members)?;
+
+        // Compute the remaining supply.
+        let num_validators = config.num_validators as u64;
+        let remaining_supply = config
+            .stake_per_validator
+            .checked_mul(num_validators)
+            .and_then(|total_stake| N::starting_supply().checked_sub(total_stake))
+            .ok_or_else(|| anyhow!("The total stake of the validators exceeds the starting supply"))?;
+        // Construct the public balances, assigning the remainder of the division to the first validator.
+        let mut public_balances: IndexMap<_, _> =
+            addresses.iter().map(|address| (*address, remaining_supply / num_validators)).collect();
+        if let Some(balance) = public_balances.get_mut(&addresses[0]) {
+            *balance += remaining_supply % num_validators;
+        }
+        // Construct the bonded balances.
+        let bonded_balances =
+            addresses.iter().map(|address| (*address, (*address, *address, config.stake_per_validator))).collect();
+
+        // Construct the genesis block.
+        let vm = VM::from(ConsensusStore::<N, ConsensusMemory<N>>::open(StorageMode::Production)?)?;
+        let genesis = vm.genesis_quorum(&private_keys[0], committee, public_balances, bonded_balances, &mut rng)?;
+
+        // Initialize the validators.
+        let validators = private_keys
+            .into_iter()
+            .map(|private_key| {
+                let validator = DevnetValidator::new(private_key, genesis.clone())?;
+                Ok((validator.address(), validator))
+            })
+            .collect::<Result<IndexMap<_, _>>>()?;
+
+        Ok(Self {
+            config,
+            clock: SimulatedClock::new(genesis.timestamp()),
+            round: genesis.round(),
+            genesis,
+            validators,
+            dag: Default::default(),
+            committed_certificate_ids: Default::default(),
+            last_committed_round: 0,
+            certified_transmissions: Default::default(),
+            rng,
+        })
+    }
+
+    /// Returns the configuration of the devnet.
+    pub const fn config(&self) -> &DevnetConfig {
+        &self.config
+    }
+
+    /// Returns the genesis block.
+    pub const fn genesis(&self) -> &Block<N> {
+        &self.genesis
+    }
+
+    /// Returns the simulated clock.
+    pub const fn clock(&self) -> &SimulatedClock {
+        &self.clock
+    }
+
+    /// Returns the current round of the DAG.
+    pub const fn round(&self) -> u64 {
+        self.round
+    }
+
+    /// Returns the validators.
+    pub fn validators(&self) -> impl Iterator<Item = &DevnetValidator<N>> {
+        self.validators.values()
+    }
+
+    /// Returns the validator with the given address, if it exists.
+    pub fn validator(&self, address: &Address<N>) -> Option<&DevnetValidator<N>> {
+        self.validators.get(address)
+    }
+
+    /// Returns the ledger of the online validators.
+    pub fn ledger(&self) -> Result<&Ledger<N, ConsensusMemory<N>>> {
+        self.validators
+            .values()
+            .filter(|validator| validator.is_online())
+            .map(DevnetValidator::ledger)
+            .max_by_key(|ledger| ledger.latest_height())
+            .ok_or_else(|| anyhow!("All of the devnet validators are offline"))
+    }
+
+    /// Returns the certificates of the DAG for the given round, by author.
+    pub fn certificates_for_round(&self, round: u64) -> Option<&IndexMap<Address<N>, BatchCertificate<N>>> {
+        self.dag.get(&round)
+    }
+
+    /// Advances the simulated clock by the given number of seconds, e.g. to simulate a network delay.
+    pub fn advance_clock(&mut self, seconds: i64) {
+        self.clock.advance(seconds);
+    }
+}
+
+impl<N: Network> Devnet<N> {
+    /// Adds a validator with the given private key, which syncs the ledger from the other validators.
+    ///
+    /// The validator only proposes and signs batches once it is in the committee lookback for the round,
+    /// e.g. after its `bond_validator` transaction is committed.
+    pub fn add_validator(&mut self, private_key: PrivateKey<N>) -> Result<Address<N>> {
+        let validator = DevnetValidator::new(private_key, self.genesis.clone())?;
+        let address = validator.address();
+        // Ensure the validator does not exist.
+        ensure!(!self.validators.contains_key(&address), "Validator '{address}' already exists in the devnet");
+        // Sync the ledger of the validator.
+        let ledger = self.ledger()?.clone();
+        validator.sync_from(&ledger, &mut self.rng)?;
+        self.validators.insert(address, validator);
+        Ok(address)
+    }
+
+    /// Injects the given fault into the validator with the given address.
+    pub fn inject_fault(&mut self, address: &Address<N>, fault: Fault) -> Result<()> {
+        match self.validators.get_mut(address) {
+            Some(validator) => validator.set_fault(Some(fault)),
+            None => bail!("Validator '{address}' does not exist in the devnet"),
+        }
+        Ok(())
+    }
+
+    /// Removes the fault of the validator with the given address, syncing its ledger if it was offline.
+    pub fn recover(&mut self, address: &Address<N>) -> Result<()> {
+        let validator =
+            self.validators.get_mut(address).ok_or_else(|| anyhow!("Validator '{address}' does not exist"))?;
+        validator.set_fault(None);
+        // Sync the blocks the validator missed while it was offline.
+        let ledger = self.ledger()?.clone();
+        self.validators[address].sync_from(&ledger, &mut self.rng)
+    }
+
+    /// Submits the given transaction to the memory pool of an online validator, and returns its transmission ID.
+    ///
+    /// The transaction is sent to the validator with the fewest pending transmissions, which delivers its batches.
+    pub fn submit_transaction(&mut self, transaction: Transaction<N>) -> Result<TransmissionID<N>> {
+        let committee = self.ledger()?.latest_committee()?;
+        let address = self
+            .validators
+            .values()
+            .filter(|validator| validator.is_online() && validator.delivers_batches())
+            .filter(|validator| committee.is_committee_member(validator.address()))
+            .min_by_key(|validator| validator.mempool().len())
+            .map(DevnetValidator::address)
+            .ok_or_else(|| anyhow!("There are no validators in the devnet to submit the transaction to"))?;
+        self.submit_transaction_to(&address, transaction)
+    }
+
+    /// Submits the given transaction to the memory pool of the given validator, and returns its transmission ID.
+    pub fn submit_transaction_to(
+        &mut self,
+        address: &Address<N>,
+        transaction: Transaction<N>,
+    ) -> Result<TransmissionID<N>> {
+        // Compute the transmission ID.
+        let checksum = Data::Object(transaction.clone()).to_checksum::<N>()?;
+        let transmission_id = TransmissionID::from((&transaction.id(), &checksum));
+        // Ensure the transaction is not pending already.
+        ensure!(
+            !self.certified_transmissions.contains_key(&transmission_id)
+                && !self.validators.values().any(|validator| validator.mempool().contains_key(&transmission_id)),
+            "Transaction '{}' is already pending in the devnet",
+            transaction.id()
+        );
+
+        let validator =
+            self.validators.get_mut(address).ok_or_else(|| anyhow!("Validator '{address}' does not exist"))?;
+        // Ensure the validator is online.
+        ensure!(validator.is_online(), "Validator '{address}' is offline");
+        // Ensure the transaction is well-formed and unique.
+        validator.ledger().check_transaction_basic(&transaction, None, &mut self.rng)?;
+        // Add the transaction to the memory pool.
+        validator.insert_transmission(transmission_id, transaction.into());
+        Ok(transmission_id)
+    }
+}
+
+impl<N: Network> Devnet<N> {
+    /// Advances the devnet until at least one block is committed, and returns the committed blocks.
+    pub fn advance_to_next_block(&mut self) -> Result<Vec<Block<N>>> {
+        for _ in 0..MAX_ROUNDS_WITHOUT_BLOCK {
+            let blocks = self.advance_round()?;
+            if !blocks.is_empty() {
+                return Ok(blocks);
+            }
+        }
+        bail!("The devnet stalled for {MAX_ROUNDS_WITHOUT_BLOCK} rounds without committing a block")
+    }
+
+    /// Advances the devnet until the ledger reaches the given block height.
+    pub fn advance_to_height(&mut self, height: u32) -> Result<()> {
+        while self.ledger()?.latest_height() < height {
+            self.advance_to_next_block()?;
+        }
+        Ok(())
+    }
+
+    /// Advances the devnet by one round of batch proposals, and returns the blocks committed in the round.
+    ///
+    /// The DAG only moves to the next round once the certificates of the current round reach a quorum.
+    /// Until then, each call lets the validators without a certificate in the round propose again.
+    pub fn advance_round(&mut self) -> Result<Vec<Block<N>>> {
+        // Advance the clock.
+        self.clock.advance(self.config.round_interval_in_secs);
+
+        // Retrieve the committee lookback for the round.
+        let ledger = self.ledger()?.clone();
+        let round = self.round.saturating_add(1);
+        let committee = ledger
+            .get_committee_lookback_for_round(round)?
+            .ok_or_else(|| anyhow!("No committee lookback found for round {round}"))?;
+
+        // Retrieve the certificate IDs of the previous round.
+        let previous_certificate_ids: IndexSet<Field<N>> = match round {
+            0 | 1 => IndexSet::new(),
+            _ => self
+                .dag
+                .get(&self.round)
+                .map(|certificates| certificates.values().map(|c| c.id()).collect())
+                .unwrap_or_default(),
+        };
+
+        // Determine the committee members that sign batches.
+        let signers = self
+            .validators
+            .values()
+            .filter(|validator| validator.is_online() && validator.signs_batches())
+            .filter(|validator| committee.is_committee_member(validator.address()))
+            .map(|validator| (validator.address(), *validator.private_key()))
+            .collect::<Vec<_>>();
+        // Determine the committee members that propose a batch, excluding those with a certificate for the round.
+        let proposers = self
+            .validators
+            .values()
+            .filter(|validator| validator.is_online() && validator.delivers_batches())
+            .filter(|validator| committee.is_committee_member(validator.address()))
+            .filter(|validator| {
+                !self.dag.get(&round).is_some_and(|certificates| certificates.contains_key(&validator.address()))
+            })
+            .map(DevnetValidator::address)
+            .collect::<Vec<_>>();
+
+        for address in proposers {
+            let validator = &self.validators[&address];
+            // Select the transmissions for the batch.
+            let transmissions = validator
+                .mempool()
+                .iter()
+                .take(BatchHeader::<N>::MAX_TRANSMISSIONS_PER_BATCH)
+                .map(|(transmission_id, transmission)| (*transmission_id, transmission.clone()))
+                .collect::<IndexMap<_, _>>();
+            // Propose the batch.
+            let batch_header = BatchHeader::new(
+                validator.private_key(),
+                round,
+                self.clock.now(),
+                committee.id(),
+                transmissions.keys().copied().collect(),
+                previous_certificate_ids.clone(),
+                &mut self.rng,
+            )?;
+            // Collect the signatures of the other validators.
+            let mut signatures = IndexSet::new();
+            let mut addresses = HashSet::from([address]);
+            for (signer, private_key) in signers.iter().filter(|(signer, _)| *signer != address) {
+                signatures.insert(private_key.sign(&[batch_header.batch_id()], &mut self.rng)?);
+                addresses.insert(*signer);
+            }
+            // Ensure the batch reached a quorum, otherwise its transmissions stay in the memory pool.
+            if !committee.is_quorum_threshold_reached(&addresses) {
+                debug!("The batch of '{address}' for round {round} did not reach a quorum of signatures");
+                continue;
+            }
+            // Certify the batch.
+            let certificate = BatchCertificate::from(batch_header, signatures)?;
+            self.validators[&address].remove_transmissions(transmissions.keys());
+            self.certified_transmissions.extend(transmissions);
+            self.dag.entry(round).or_default().insert(address, certificate);
+        }
+
+        // Ensure the certificates of the round reached a quorum.
+        let authors: HashSet<_> =
+            self.dag.get(&round).map(|certificates| certificates.keys().copied().collect()).unwrap_or_default();
+        if !committee.is_quorum_threshold_reached(&authors) {
+            return Ok(vec![]);
+        }
+        // Advance to the next round.
+        self.round = round;
+
+        // Commit the leader certificate of the previous round, if it is an even round.
+        match round % 2 == 1 && round > 2 {
+            true => self.try_commit_leader(round - 1, &ledger),
+            false => Ok(vec![]),
+        }
+    }
+}
+
+impl<N: Network> Devnet<N> {
+    /// Commits the leader certificate of the given round if it has enough votes, and returns the committed blocks.
+    ///
+    /// Any uncommitted leader certificate of an earlier round that is linked to it is committed first.
+    fn try_commit_leader(
+        &mut self,
+        leader_round: u64,
+        ledger: &Ledger<N, ConsensusMemory<N>>,
+    ) -> Result<Vec<Block<N>>> {
+        // Ensure the leader round is not committed yet.
+        if leader_round <= self.last_committed_round {
+            return Ok(vec![]);
+        }
+
+        // Retrieve the leader certificate.
+        let Some(leader_certificate) = self.leader_certificate(leader_round, ledger)? else {
+            return Ok(vec![]);
+        };
+
+        // Determine the authors of the next round that voted for the leader certificate.
+        let voters: HashSet<_> = self
+            .dag
+            .get(&(leader_round + 1))
+            .map(|certificates| {
+                certificates
+                    .values()
+                    .filter(|certificate| certificate.previous_certificate_ids().contains(&leader_certificate.id()))
+                    .map(|certificate| certificate.author())
+                    .collect()
+            })
+            .unwrap_or_default();
+        // Ensure the votes reach the availability threshold.
+        let committee = ledger
+            .get_committee_lookback_for_round(leader_round + 1)?
+            .ok_or_else(|| anyhow!("No committee lookback found for round {}", leader_round + 1))?;
+        if !committee.is_availability_threshold_reached(&voters) {
+            return Ok(vec![]);
+        }
+
+        // Collect the uncommitted leader certificates of the earlier rounds that are linked to the leader certificate.
+        let mut leader_certificates = vec![leader_certificate];
+        for round in (self.last_committed_round.saturating_add(2)..=leader_round.saturating_sub(2)).rev().step_by(2) {
+            if let Some(previous_leader_certificate) = self.leader_certificate(round, ledger)? {
+                if self.is_linked(&previous_leader_certificate, &leader_certificates[leader_certificates.len() - 1]) {
+                    leader_certificates.push(previous_leader_certificate);
+                }
+            }
+        }
+
+        // Commit the leader certificates, starting from the earliest round.
+        leader_certificates.into_iter().rev().map(|leader_certificate| self.commit(leader_certificate)).collect()
+    }
+
+    /// Returns the leader certificate of the given round, if it exists.
+    fn leader_certificate(
+        &self,
+        round: u64,
+        ledger: &Ledger<N, ConsensusMemory<N>>,
+    ) -> Result<Option<BatchCertificate<N>>> {
+        let committee = ledger
+            .get_committee_lookback_for_round(round)?
+            .ok_or_else(|| anyhow!("No committee lookback found for round {round}"))?;
+        let leader = committee.get_leader(round)?;
+        Ok(self.dag.get(&round).and_then(|certificates| certificates.get(&leader)).cloned())
+    }
+
+    /// Returns `true` if there is a path in the DAG from the current certificate to the previous certificate.
+    fn is_linked(&self, previous_certificate: &BatchCertificate<N>, current_certificate: &BatchCertificate<N>) -> bool {
+        let mut traversal = vec![current_certificate];
+        for round in (previous_certificate.round()..current_certificate.round()).rev() {
+            let Some(certificates) = self.dag.get(&round) else {
+                return false;
+            };
+            traversal = certificates
+                .values()
+                .filter(|p| traversal.iter().any(|c| c.previous_certificate_ids().contains(&p.id())))
+                .collect();
+        }
+        traversal.iter().any(|certificate| certificate.id() == previous_certificate.id())
+    }
+
+    /// Commits the given leader certificate as the next block, which every online validator checks and adds.
+    fn commit(&mut self, leader_certificate: BatchCertificate<N>) -> Result<Block<N>> {
+        let leader_round = leader_certificate.round();
+
+        // Order the uncommitted certificates that are reachable from the leader certificate.
+        let mut subdag = BTreeMap::<u64, IndexSet<BatchCertificate<N>>>::new();
+        let mut already_ordered = HashSet::new();
+        let mut buffer = vec![leader_certificate];
+        while let Some(certificate) = buffer.pop() {
+            for previous_certificate_id in certificate.previous_certificate_ids() {
+                let Some(previous_certificate) = self.dag.get(&(certificate.round() - 1)).and_then(|certificates| {
+                    certificates.values().find(|certificate| certificate.id() == *previous_certificate_id)
+                }) else {
+                    continue;
+                };
+                // Skip the certificates that are already committed or ordered.
+                if self.committed_certificate_ids.contains(previous_certificate_id)
+                    || !already_ordered.insert(*previous_certificate_id)
+                {
+                    continue;
+                }
+                buffer.push(previous_certificate.clone());
+            }
+            subdag.entry(certificate.round()).or_default().insert(certificate);
+        }
+        let subdag = Subdag::from(subdag)?;
+
+        // Collect the transmissions of the subdag.
+        let certificate_ids = subdag.certificate_ids().collect::<Vec<_>>();
+        let transmissions = subdag
+            .transmission_ids()
+            .map(|transmission_id| match self.certified_transmissions.get(transmission_id) {
+                Some(transmission) => Ok((*transmission_id, transmission.clone())),
+                None => bail!("Missing transmission '{transmission_id}' in the devnet"),
+            })
+            .collect::<Result<IndexMap<_, _>>>()?;
+
+        let transmission_ids = transmissions.keys().copied().collect::<Vec<_>>();
+
+        // Construct the block.
+        let ledger = self.ledger()?.clone();
+        let block = ledger.prepare_advance_to_next_quorum_block(subdag, transmissions, &mut self.rng)?;
+
+        // Add the block to the ledger of every online validator.
+        for validator in self.validators.values().filter(|validator| validator.is_online()) {
+            validator.ledger().check_next_block(&block, &mut self.rng)?;
+            validator.ledger().advance_to_next_block(&block)?;
+        }
+
+        // Mark the certificates and transmissions as committed.
+        self.committed_certificate_ids.extend(certificate_ids);
+        for transmission_id in &transmission_ids {
+            self.certified_transmissions.shift_remove(transmission_id);
+        }
+        self.last_committed_round = leader_round;
+        // Garbage collect the rounds that can no longer be referenced.
+        let gc_round = leader_round.saturating_sub(BatchHeader::<N>::MAX_GC_ROUNDS as u64);
+        self.dag.retain(|round, _| *round > gc_round);
+
+        Ok(block)
+    }
+}
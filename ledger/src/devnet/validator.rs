@@ -0,0 +1,110 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A validator in the devnet, with its own private key, ledger and memory pool.
+pub struct DevnetValidator<N: Network> {
+    /// The private key of the validator.
+    private_key: PrivateKey<N>,
+    /// The address of the validator.
+    address: Address<N>,
+    /// The ledger of the validator.
+    ledger: Ledger<N, ConsensusMemory<N>>,
+    /// The transmissions waiting to be proposed in a batch.
+    mempool: IndexMap<TransmissionID<N>, Transmission<N>>,
+    /// The fault injected into the validator, if any.
+    fault: Option<Fault>,
+}
+
+impl<N: Network> DevnetValidator<N> {
+    /// Initializes a new validator, with a ledger loaded from the given genesis block.
+    pub(super) fn new(private_key: PrivateKey<N>, genesis: Block<N>) -> Result<Self> {
+        let address = Address::try_from(&private_key)?;
+        let ledger = Ledger::load(genesis, StorageMode::Production)?;
+        Ok(Self { private_key, address, ledger, mempool: Default::default(), fault: None })
+    }
+
+    /// Returns the private key of the validator.
+    pub const fn private_key(&self) -> &PrivateKey<N> {
+        &self.private_key
+    }
+
+    /// Returns the address of the validator.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns the ledger of the validator.
+    pub const fn ledger(&self) -> &Ledger<N, ConsensusMemory<N>> {
+        &self.ledger
+    }
+
+    /// Returns the transmissions waiting to be proposed in a batch.
+    pub const fn mempool(&self) -> &IndexMap<TransmissionID<N>, Transmission<N>> {
+        &self.mempool
+    }
+
+    /// Returns the fault injected into the validator, if any.
+    pub const fn fault(&self) -> Option<Fault> {
+        self.fault
+    }
+
+    /// Returns `true` if the validator is online.
+    pub fn is_online(&self) -> bool {
+        self.fault != Some(Fault::Crash)
+    }
+
+    /// Returns `true` if the batches proposed by the validator reach the other validators.
+    pub fn delivers_batches(&self) -> bool {
+        self.fault.map_or(true, |fault| fault.delivers_batches())
+    }
+
+    /// Returns `true` if the validator signs the batches of other validators.
+    pub fn signs_batches(&self) -> bool {
+        self.fault.map_or(true, |fault| fault.signs_batches())
+    }
+
+    /// Sets the fault of the validator.
+    pub(super) fn set_fault(&mut self, fault: Option<Fault>) {
+        self.fault = fault;
+    }
+
+    /// Adds the given transmission to the memory pool.
+    pub(super) fn insert_transmission(&mut self, transmission_id: TransmissionID<N>, transmission: Transmission<N>) {
+        self.mempool.insert(transmission_id, transmission);
+    }
+
+    /// Removes the given transmissions from the memory pool.
+    pub(super) fn remove_transmissions<'a>(&mut self, transmission_ids: impl Iterator<Item = &'a TransmissionID<N>>) {
+        for transmission_id in transmission_ids {
+            self.mempool.shift_remove(transmission_id);
+        }
+    }
+
+    /// Adds the blocks of the given ledger that this validator is missing.
+    pub(super) fn sync_from<R: Rng + CryptoRng>(
+        &self,
+        ledger: &Ledger<N, ConsensusMemory<N>>,
+        rng: &mut R,
+    ) -> Result<()> {
+        for height in self.ledger.latest_height().saturating_add(1)..=ledger.latest_height() {
+            let block = ledger.get_block(height)?;
+            self.ledger.check_next_block(&block, rng)?;
+            self.ledger.advance_to_next_block(&block)?;
+        }
+        Ok(())
+    }
+}
@@ -49,7 +49,45 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.transition_store().find_transition_id(id)
     }
 
+    /// Returns up to `limit` transition IDs with the given public `address` in their inputs or outputs,
+    /// in chronological order, starting after the given cursor, or from the first transition if no cursor is given.
+    /// The position of the last transition of a page is the cursor of the next page.
+    pub fn find_transition_ids_for_address(
+        &self,
+        address: &Address<N>,
+        cursor: Option<TransitionPosition>,
+        limit: usize,
+    ) -> Result<Vec<(TransitionPosition, N::TransitionID)>> {
+        self.vm.block_store().find_transition_ids_for_address(address, cursor, limit)
+    }
+
+    /// Returns up to `limit` transition IDs in transactions with a public fee paid by the given `fee payer`,
+    /// in chronological order, starting after the given cursor, or from the first transition if no cursor is given.
+    /// The position of the last transition of a page is the cursor of the next page.
+    pub fn find_transition_ids_for_fee_payer(
+        &self,
+        fee_payer: &Address<N>,
+        cursor: Option<TransitionPosition>,
+        limit: usize,
+    ) -> Result<Vec<(TransitionPosition, N::TransitionID)>> {
+        self.vm.block_store().find_transition_ids_for_fee_payer(fee_payer, cursor, limit)
+    }
+
+    /// Returns up to `limit` transition IDs for the given program function,
+    /// in chronological order, starting after the given cursor, or from the first transition if no cursor is given.
+    /// The position of the last transition of a page is the cursor of the next page.
+    pub fn find_transition_ids_for_function(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        cursor: Option<TransitionPosition>,
+        limit: usize,
+    ) -> Result<Vec<(TransitionPosition, N::TransitionID)>> {
+        self.vm.block_store().find_transition_ids_for_function(program_id, function_name, cursor, limit)
+    }
+
     /// Returns the record ciphertexts that belong to the given view key.
+    /// Note: The records of pruned blocks are no longer in storage, and are skipped.
     pub fn find_record_ciphertexts<'a>(
         &'a self,
         view_key: &'a ViewKey<N>,
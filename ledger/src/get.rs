@@ -55,9 +55,9 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Returns the epoch hash for the given block height.
     pub fn get_epoch_hash(&self, block_height: u32) -> Result<N::BlockHash> {
         // Compute the epoch number from the current block height.
-        let epoch_number = block_height.saturating_div(N::NUM_BLOCKS_PER_EPOCH);
+        let epoch_number = block_height.saturating_div(N::num_blocks_per_epoch());
         // Compute the epoch starting height (a multiple of `NUM_BLOCKS_PER_EPOCH`).
-        let epoch_starting_height = epoch_number.saturating_mul(N::NUM_BLOCKS_PER_EPOCH);
+        let epoch_starting_height = epoch_number.saturating_mul(N::num_blocks_per_epoch());
         // Retrieve the epoch hash, defined as the 'previous block hash' from the epoch starting height.
         let epoch_hash = self.get_previous_hash(epoch_starting_height)?;
         // Construct the epoch hash.
@@ -259,6 +259,69 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().get_batch_certificate(certificate_id)
     }
 
+    /// Returns up to `limit` entries of the mapping for the given `program ID` and `mapping name`,
+    /// starting after the given cursor, or from the first key if no cursor is given.
+    /// The key of the last entry of a page is the cursor of the next page.
+    pub fn get_mapping_page(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        cursor: Option<&Plaintext<N>>,
+        limit: usize,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        self.vm.finalize_store().get_mapping_page_confirmed(program_id, mapping_name, cursor, limit)
+    }
+
+    /// Returns up to `limit` entries of the mapping for the given `program ID` and `mapping name`,
+    /// with a key in the given range.
+    /// Note: The keys are ordered by their serialized representation, not by their value.
+    pub fn get_mapping_range(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        start: Bound<&Plaintext<N>>,
+        end: Bound<&Plaintext<N>>,
+        limit: usize,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        self.vm.finalize_store().get_mapping_range_confirmed(program_id, mapping_name, start, end, limit)
+    }
+
+    /// Returns the value for the given `program ID`, `mapping name`, and `key` as of the given block height.
+    /// Note: This requires archive mode to be enabled in the finalize store.
+    pub fn get_value_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        height: u32,
+    ) -> Result<Option<Value<N>>> {
+        self.vm.finalize_store().get_value_at_height(program_id, mapping_name, key, height)
+    }
+
+    /// Returns the mapping for the given `program ID` and `mapping name` as of the given block height.
+    /// Note: This requires archive mode to be enabled in the finalize store.
+    pub fn get_mapping_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        height: u32,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        self.vm.finalize_store().get_mapping_at_height(program_id, mapping_name, height)
+    }
+
+    /// Returns the inclusion proof for the given `program ID`, `mapping name`, and `key` against the latest mapping root,
+    /// or the exclusion proof if the key does not exist.
+    /// Note: The latest mapping root is committed in the header of the next block.
+    /// Proofs against the mapping root of an earlier block are not supported, as only the latest mapping tree is stored.
+    pub fn get_mapping_proof(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<MappingProof<N>> {
+        self.vm.finalize_store().get_mapping_proof(program_id, mapping_name, key)
+    }
+
     /// Returns the delegators for the given validator.
     pub fn get_delegators_for_validator(&self, validator: &Address<N>) -> Result<Vec<Address<N>>> {
         // Construct the credits.aleo program ID.
@@ -0,0 +1,163 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{fmt, ops::RangeInclusive};
+
+/// An inconsistency found in the ledger storage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Inconsistency {
+    /// The height of the inconsistent block.
+    height: u32,
+    /// The name of the inconsistent map.
+    map: &'static str,
+    /// The reason for the inconsistency.
+    reason: String,
+}
+
+impl Inconsistency {
+    /// Initializes a new inconsistency.
+    pub fn new(height: u32, map: &'static str, reason: impl fmt::Display) -> Self {
+        Self { height, map, reason: reason.to_string() }
+    }
+
+    /// Returns the height of the inconsistent block.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the name of the inconsistent map.
+    pub const fn map(&self) -> &'static str {
+        self.map
+    }
+
+    /// Returns the reason for the inconsistency.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl fmt::Display for Inconsistency {
+    /// Prints the inconsistency.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Block {} is inconsistent in the '{}' map: {}", self.height, self.map, self.reason)
+    }
+}
+
+/// A range of blocks whose roots were not checked, as the entries needed to recompute them are not in storage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UncheckedBlocks {
+    /// The heights of the unchecked blocks.
+    heights: RangeInclusive<u32>,
+    /// The name of the map that is missing the entries.
+    map: &'static str,
+    /// The reason the blocks were not checked.
+    reason: &'static str,
+}
+
+impl UncheckedBlocks {
+    /// Initializes a new range of unchecked blocks.
+    pub const fn new(heights: RangeInclusive<u32>, map: &'static str, reason: &'static str) -> Self {
+        Self { heights, map, reason }
+    }
+
+    /// Returns the heights of the unchecked blocks.
+    pub const fn heights(&self) -> &RangeInclusive<u32> {
+        &self.heights
+    }
+
+    /// Returns the name of the map that is missing the entries.
+    pub const fn map(&self) -> &'static str {
+        self.map
+    }
+
+    /// Returns the reason the blocks were not checked.
+    pub const fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl fmt::Display for UncheckedBlocks {
+    /// Prints the unchecked blocks.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Blocks {} to {} are unchecked, as they are missing from the '{}' map ({})",
+            self.heights.start(),
+            self.heights.end(),
+            self.map,
+            self.reason
+        )
+    }
+}
+
+/// The report of a ledger integrity check.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// The number of blocks that were found to be consistent.
+    num_blocks: u32,
+    /// The first inconsistency, if the ledger is inconsistent.
+    inconsistency: Option<Inconsistency>,
+    /// The blocks whose roots were not checked, as the entries needed to recompute them are not in storage.
+    unchecked: Vec<UncheckedBlocks>,
+}
+
+impl IntegrityReport {
+    /// Initializes a new integrity report.
+    pub const fn new(num_blocks: u32, inconsistency: Option<Inconsistency>, unchecked: Vec<UncheckedBlocks>) -> Self {
+        Self { num_blocks, inconsistency, unchecked }
+    }
+
+    /// Returns the number of blocks that were found to be consistent.
+    pub const fn num_blocks(&self) -> u32 {
+        self.num_blocks
+    }
+
+    /// Returns the first inconsistency, if the ledger is inconsistent.
+    pub const fn inconsistency(&self) -> Option<&Inconsistency> {
+        self.inconsistency.as_ref()
+    }
+
+    /// Returns the blocks whose roots were not checked.
+    pub fn unchecked(&self) -> &[UncheckedBlocks] {
+        &self.unchecked
+    }
+
+    /// Returns `true` if the ledger is consistent.
+    /// Note: The unchecked blocks are consistent as far as they were checked, see `is_fully_checked`.
+    pub const fn is_consistent(&self) -> bool {
+        self.inconsistency.is_none()
+    }
+
+    /// Returns `true` if the roots of every block were checked.
+    pub fn is_fully_checked(&self) -> bool {
+        self.unchecked.is_empty()
+    }
+}
+
+impl fmt::Display for IntegrityReport {
+    /// Prints the integrity report.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.inconsistency {
+            None => write!(f, "The ledger is consistent ({} blocks)", self.num_blocks)?,
+            Some(inconsistency) => {
+                write!(f, "{inconsistency} (the {} blocks before it are consistent)", self.num_blocks)?
+            }
+        }
+        for unchecked in &self.unchecked {
+            write!(f, "\n{unchecked}")?;
+        }
+        Ok(())
+    }
+}
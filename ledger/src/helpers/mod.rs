@@ -16,5 +16,14 @@
 mod bft;
 pub use bft::*;
 
+mod integrity;
+pub use integrity::*;
+
+mod scanner;
+pub use scanner::*;
+
+mod snapshot;
+pub use snapshot::*;
+
 mod supply;
 pub use supply::*;
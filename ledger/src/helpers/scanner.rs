@@ -0,0 +1,315 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    account::{GraphKey, ViewKey},
+    network::prelude::*,
+    program::{Plaintext, Record},
+    types::Field,
+};
+use ledger_block::Transactions;
+
+use anyhow::Result;
+use indexmap::IndexMap;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// A record found by a record scanner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScannedRecord<N: Network> {
+    /// The height of the block that created the record.
+    height: u32,
+    /// The tag of the record.
+    tag: Field<N>,
+    /// The height of the block that spent the record, if the record is spent.
+    spent_height: Option<u32>,
+    /// The record.
+    record: Record<N, Plaintext<N>>,
+}
+
+impl<N: Network> ScannedRecord<N> {
+    /// Returns the height of the block that created the record.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the tag of the record.
+    pub const fn tag(&self) -> Field<N> {
+        self.tag
+    }
+
+    /// Returns the height of the block that spent the record, if the record is spent.
+    pub const fn spent_height(&self) -> Option<u32> {
+        self.spent_height
+    }
+
+    /// Returns `true` if the record is spent.
+    pub const fn is_spent(&self) -> bool {
+        self.spent_height.is_some()
+    }
+
+    /// Returns the record.
+    pub const fn record(&self) -> &Record<N, Plaintext<N>> {
+        &self.record
+    }
+}
+
+/// A resumable scanner for the records of an account.
+///
+/// The scanner tracks a cursor over the ledger, and only processes the blocks after it.
+/// Records are decrypted with the view key, and their spent status is tracked by tag with the graph key,
+/// so a scanner can be persisted (with `ToBytes`) and resumed (with `FromBytes`) without rescanning the ledger.
+/// Note: The persisted scanner contains the view key of the account.
+#[derive(Clone)]
+pub struct RecordScanner<N: Network> {
+    /// The view key of the account.
+    view_key: ViewKey<N>,
+    /// The `sk_tag` of the account.
+    sk_tag: Field<N>,
+    /// The height of the next block to scan.
+    next_height: u32,
+    /// The hash of the latest scanned block, if a block has been scanned.
+    latest_hash: Option<N::BlockHash>,
+    /// The mapping of `commitment` to `scanned record`.
+    records: IndexMap<Field<N>, ScannedRecord<N>>,
+    /// The mapping of `tag` to `commitment`, for the unspent records.
+    unspent_tags: IndexMap<Field<N>, Field<N>>,
+}
+
+impl<N: Network> RecordScanner<N> {
+    /// Initializes a new record scanner for the given view key, starting from the given block height.
+    pub fn new(view_key: ViewKey<N>, start_height: u32) -> Result<Self> {
+        Self::from(view_key, start_height, None, IndexMap::new())
+    }
+
+    /// Initializes a record scanner from its cursor and scanned records.
+    fn from(
+        view_key: ViewKey<N>,
+        next_height: u32,
+        latest_hash: Option<N::BlockHash>,
+        records: IndexMap<Field<N>, ScannedRecord<N>>,
+    ) -> Result<Self> {
+        // Derive the `sk_tag` from the graph key.
+        let sk_tag = match GraphKey::try_from(view_key) {
+            Ok(graph_key) => graph_key.sk_tag(),
+            Err(e) => bail!("Failed to derive the graph key from the view key: {e}"),
+        };
+        // Collect the tags of the unspent records.
+        let unspent_tags = records
+            .iter()
+            .filter(|(_, scanned)| !scanned.is_spent())
+            .map(|(commitment, scanned)| (scanned.tag, *commitment))
+            .collect();
+        // Return the record scanner.
+        Ok(Self { view_key, sk_tag, next_height, latest_hash, records, unspent_tags })
+    }
+
+    /// Returns the view key of the account.
+    pub const fn view_key(&self) -> &ViewKey<N> {
+        &self.view_key
+    }
+
+    /// Returns the height of the next block to scan.
+    pub const fn next_height(&self) -> u32 {
+        self.next_height
+    }
+
+    /// Returns the hash of the latest scanned block, if a block has been scanned.
+    pub const fn latest_hash(&self) -> Option<N::BlockHash> {
+        self.latest_hash
+    }
+
+    /// Returns the scanned records, as a mapping of `commitment` to `scanned record`.
+    pub const fn records(&self) -> &IndexMap<Field<N>, ScannedRecord<N>> {
+        &self.records
+    }
+
+    /// Returns the unspent records.
+    pub fn unspent_records(&self) -> impl '_ + Iterator<Item = (&Field<N>, &Record<N, Plaintext<N>>)> {
+        self.records
+            .iter()
+            .filter(|(_, scanned)| !scanned.is_spent())
+            .map(|(commitment, scanned)| (commitment, &scanned.record))
+    }
+
+    /// Returns the spent records.
+    pub fn spent_records(&self) -> impl '_ + Iterator<Item = (&Field<N>, &Record<N, Plaintext<N>>)> {
+        self.records
+            .iter()
+            .filter(|(_, scanned)| scanned.is_spent())
+            .map(|(commitment, scanned)| (commitment, &scanned.record))
+    }
+
+    /// Scans the transactions of the block at the cursor, and advances the cursor past the block.
+    pub fn scan_block(
+        &mut self,
+        height: u32,
+        previous_hash: N::BlockHash,
+        block_hash: N::BlockHash,
+        transactions: &Transactions<N>,
+    ) -> Result<()> {
+        // Ensure the block is the next block to scan.
+        ensure!(
+            height == self.next_height,
+            "Record scanner expected block {} (found block {height})",
+            self.next_height
+        );
+        // Ensure the block extends the latest scanned block.
+        if let Some(latest_hash) = self.latest_hash {
+            ensure!(
+                previous_hash == latest_hash,
+                "Block {height} does not extend the latest scanned block '{latest_hash}' - rewind the record scanner"
+            );
+        }
+
+        // Derive the x-coordinate of the address corresponding to the view key.
+        let address_x_coordinate = self.view_key.to_address().to_x_coordinate();
+        // Decrypt the records that belong to the account.
+        let records = transactions.records().collect::<Vec<_>>();
+        let records = cfg_into_iter!(records)
+            .filter(|(_, record)| record.is_owner_with_address_x_coordinate(&self.view_key, &address_x_coordinate))
+            .map(|(commitment, record)| {
+                // Compute the tag of the record.
+                let tag = Record::<N, Plaintext<N>>::tag(self.sk_tag, *commitment)?;
+                // Decrypt the record.
+                let record = record.decrypt(&self.view_key)?;
+                Ok((*commitment, ScannedRecord { height, tag, spent_height: None, record }))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Store the records.
+        for (commitment, scanned) in records {
+            self.unspent_tags.insert(scanned.tag, commitment);
+            self.records.insert(commitment, scanned);
+        }
+        // Mark the records with a tag in the block as spent.
+        for tag in transactions.tags() {
+            if let Some(commitment) = self.unspent_tags.swap_remove(tag) {
+                if let Some(scanned) = self.records.get_mut(&commitment) {
+                    scanned.spent_height = Some(height);
+                }
+            }
+        }
+
+        // Advance the cursor.
+        self.next_height = height.saturating_add(1);
+        self.latest_hash = Some(block_hash);
+        Ok(())
+    }
+
+    /// Rewinds the record scanner to the given block height, so the blocks from that height onwards are scanned again.
+    /// The given `previous_hash` is the hash of the block before the given height, if the height is non-zero.
+    pub fn rewind(&mut self, height: u32, previous_hash: Option<N::BlockHash>) {
+        // If the height is at or after the cursor, there is nothing to rewind.
+        if height >= self.next_height {
+            return;
+        }
+        // Remove the records created from the given height onwards.
+        self.records.retain(|_, scanned| scanned.height < height);
+        // Unspend the records spent from the given height onwards.
+        for scanned in self.records.values_mut() {
+            if scanned.spent_height.is_some_and(|spent_height| spent_height >= height) {
+                scanned.spent_height = None;
+            }
+        }
+        // Recollect the tags of the unspent records.
+        self.unspent_tags = self
+            .records
+            .iter()
+            .filter(|(_, scanned)| !scanned.is_spent())
+            .map(|(commitment, scanned)| (scanned.tag, *commitment))
+            .collect();
+        // Rewind the cursor.
+        self.next_height = height;
+        self.latest_hash = previous_hash;
+    }
+}
+
+impl<N: Network> FromBytes for RecordScanner<N> {
+    /// Reads the record scanner from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid record scanner version"));
+        }
+
+        // Read the view key.
+        let view_key = ViewKey::read_le(&mut reader)?;
+        // Read the cursor.
+        let next_height = u32::read_le(&mut reader)?;
+        let latest_hash = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some(N::BlockHash::read_le(&mut reader)?),
+            variant => return Err(error(format!("Invalid latest block hash variant '{variant}'"))),
+        };
+        // Read the records.
+        let num_records = u32::read_le(&mut reader)?;
+        let mut records = IndexMap::new();
+        for _ in 0..num_records {
+            let commitment = Field::read_le(&mut reader)?;
+            let height = u32::read_le(&mut reader)?;
+            let tag = Field::read_le(&mut reader)?;
+            let spent_height = match u8::read_le(&mut reader)? {
+                0 => None,
+                1 => Some(u32::read_le(&mut reader)?),
+                variant => return Err(error(format!("Invalid spent height variant '{variant}'"))),
+            };
+            let record = Record::read_le(&mut reader)?;
+            records.insert(commitment, ScannedRecord { height, tag, spent_height, record });
+        }
+
+        // Construct the record scanner.
+        Self::from(view_key, next_height, latest_hash, records).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for RecordScanner<N> {
+    /// Writes the record scanner to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the view key.
+        self.view_key.write_le(&mut writer)?;
+        // Write the cursor.
+        self.next_height.write_le(&mut writer)?;
+        match self.latest_hash {
+            None => 0u8.write_le(&mut writer)?,
+            Some(latest_hash) => {
+                1u8.write_le(&mut writer)?;
+                latest_hash.write_le(&mut writer)?;
+            }
+        }
+        // Write the records.
+        u32::try_from(self.records.len()).map_err(error)?.write_le(&mut writer)?;
+        for (commitment, scanned) in &self.records {
+            commitment.write_le(&mut writer)?;
+            scanned.height.write_le(&mut writer)?;
+            scanned.tag.write_le(&mut writer)?;
+            match scanned.spent_height {
+                None => 0u8.write_le(&mut writer)?,
+                Some(spent_height) => {
+                    1u8.write_le(&mut writer)?;
+                    spent_height.write_le(&mut writer)?;
+                }
+            }
+            scanned.record.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,476 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{BlockTree, Identifier, MappingTree, Plaintext, ProgramID, TransactionsPath, Value},
+    types::Field,
+};
+use ledger_authority::Authority;
+use ledger_block::{Block, Header, Transaction};
+use ledger_committee::{Committee, CommitteeProof};
+use ledger_store::compute_mapping_tree;
+
+use anyhow::Result;
+use std::collections::HashSet;
+
+/// The key-value pairs of a mapping, as `(program ID, mapping name, entries)`.
+pub type SnapshotMapping<N> = (ProgramID<N>, Identifier<N>, Vec<(Plaintext<N>, Value<N>)>);
+
+/// The deployment of a program, as `(block header, transactions path, deployment transaction)`,
+/// where the transactions path proves the deployment transaction is in the block with the given header.
+pub type SnapshotDeployment<N> = (Header<N>, TransactionsPath<N>, Transaction<N>);
+
+/// The committee after a block, as `(block header, committee, committee proof)`, where the committee proof
+/// proves the committee is in the `credits.aleo` mappings committed to by the header of the next block.
+pub type SnapshotCommittee<N> = (Header<N>, Committee<N>, CommitteeProof<N>);
+
+/// A snapshot of the ledger state prior to a given block, which allows a node to bootstrap
+/// from the snapshot instead of replaying every block from genesis.
+///
+/// The snapshot is bound to its block, whose hash must be obtained from a trusted source: the block header
+/// commits to the ancestor block hashes (via the `previous_state_root`), and to the mappings prior to the
+/// block (via the `mapping_root`). The deployments are bound to the ancestor block hashes by the headers of
+/// their blocks, and each committee is bound to the `credits.aleo` mappings after its block by the header
+/// of the next block.
+/// When the snapshot is loaded, the block is finalized on top of the mappings.
+///
+/// Note: Every ancestor block hash is included, as the block tree is reconstructed from them.
+/// The committees are only included from the committee lookback of the snapshot block onwards.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Snapshot<N: Network> {
+    /// The block at the snapshot height.
+    block: Block<N>,
+    /// The block hashes from genesis up to (excluding) the snapshot block.
+    ancestor_hashes: Vec<N::BlockHash>,
+    /// The committees from the committee lookback of the snapshot block up to (excluding) the snapshot block.
+    committees: Vec<SnapshotCommittee<N>>,
+    /// The deployments of the programs prior to the snapshot block.
+    deployments: Vec<SnapshotDeployment<N>>,
+    /// The key-value pairs of every mapping prior to the snapshot block.
+    mappings: Vec<SnapshotMapping<N>>,
+}
+
+impl<N: Network> Snapshot<N> {
+    /// Initializes a new snapshot, ensuring it is well-formed.
+    pub fn new(
+        block: Block<N>,
+        ancestor_hashes: Vec<N::BlockHash>,
+        committees: Vec<SnapshotCommittee<N>>,
+        deployments: Vec<SnapshotDeployment<N>>,
+        mappings: Vec<SnapshotMapping<N>>,
+    ) -> Result<Self> {
+        // Construct the snapshot.
+        let snapshot = Self { block, ancestor_hashes, committees, deployments, mappings };
+        // Ensure the snapshot is well-formed.
+        snapshot.check_well_formed()?;
+        // Return the snapshot.
+        Ok(snapshot)
+    }
+
+    /// Ensures the snapshot is well-formed.
+    fn check_well_formed(&self) -> Result<()> {
+        // Retrieve the snapshot height.
+        let height = self.height();
+        // Ensure the block header commits to the mappings.
+        ensure!(
+            Header::<N>::commits_mapping_root(height),
+            "Snapshot at block {height} is not supported (the block headers commit to the mappings from block {})",
+            N::CONSENSUS_V3_HEIGHT.max(1)
+        );
+        // Ensure the number of ancestor hashes matches the block height.
+        ensure!(
+            self.ancestor_hashes.len() == height as usize,
+            "Snapshot at block {height} must contain {height} ancestor block hashes, found {}",
+            self.ancestor_hashes.len()
+        );
+        // Ensure the ancestor hashes match the previous block hash.
+        ensure!(
+            self.ancestor_hashes.last() == Some(&self.block.previous_hash()),
+            "Snapshot block {height} does not extend the ancestor block hashes"
+        );
+        // Ensure there is at least one committee, and at most one committee for every ancestor block.
+        ensure!(
+            !self.committees.is_empty() && self.committees.len() <= height as usize,
+            "Snapshot at block {height} must contain between 1 and {height} committees, found {}",
+            self.committees.len()
+        );
+        // Ensure the committees are for consecutive blocks, up to (excluding) the snapshot block.
+        let start_height = self.committees_start_height();
+        ensure!(
+            self.committees.iter().zip(start_height..).all(|((header, _, _), expected)| header.height() == expected),
+            "Snapshot at block {height} contains committees for non-consecutive blocks"
+        );
+        // Ensure the deployments are all deployment transactions, in blocks prior to the snapshot block.
+        ensure!(
+            self.deployments.iter().all(|(header, _, transaction)| header.height() < height && transaction.is_deploy()),
+            "Snapshot at block {height} contains a transaction that is not a prior deployment"
+        );
+        Ok(())
+    }
+
+    /// Ensures the snapshot matches the given trusted block hash, by checking the block hash,
+    /// the block authority, and that the block header commits to the ancestor block hashes and the mappings.
+    pub fn verify(&self, trusted_block_hash: N::BlockHash) -> Result<()> {
+        // Retrieve the snapshot height.
+        let height = self.height();
+        // Ensure the snapshot is well-formed.
+        self.check_well_formed()?;
+
+        // Recompute the block hash.
+        let header_root = self.block.header().to_root()?;
+        let block_hash = N::hash_bhp1024(&to_bits_le![self.block.previous_hash(), header_root])?;
+        // Ensure the block hash matches the trusted block hash.
+        ensure!(
+            *trusted_block_hash == block_hash && self.block.hash() == trusted_block_hash,
+            "Snapshot block {height} does not match the trusted block hash '{trusted_block_hash}'"
+        );
+        // Ensure the block transactions and ratifications match the block header.
+        // Note: The solutions and the subdag are checked against the block header when the block is initialized.
+        ensure!(
+            self.block.transactions().to_transactions_root()? == self.block.header().transactions_root(),
+            "Snapshot block {height} does not match the transactions root of the block"
+        );
+        ensure!(
+            self.block.ratifications().to_ratifications_root()? == self.block.header().ratifications_root(),
+            "Snapshot block {height} does not match the ratifications root of the block"
+        );
+        // Ensure the block authority is valid.
+        self.verify_authority()?;
+
+        // Reconstruct the block tree of the ancestor block hashes.
+        let leaves = self.ancestor_hashes.iter().map(|hash| hash.to_bits_le()).collect::<Vec<_>>();
+        let block_tree: BlockTree<N> = N::merkle_tree_bhp(&leaves)?;
+        // Ensure the block tree matches the previous state root of the block.
+        ensure!(
+            N::StateRoot::from(*block_tree.root()) == self.block.header().previous_state_root(),
+            "Snapshot at block {height} does not match the previous state root of the block"
+        );
+
+        // Ensure the mappings match the mapping root of the block.
+        self.verify_mappings()?;
+        // Ensure the committees match the ancestor blocks and the mappings after them.
+        self.verify_committees()?;
+        // Ensure the deployments belong to the ancestor blocks.
+        self.verify_deployments()
+    }
+
+    /// Returns the mapping tree of the given mappings.
+    pub(crate) fn to_mapping_tree(mappings: &[SnapshotMapping<N>]) -> Result<MappingTree<N>> {
+        let entries = mappings
+            .iter()
+            .flat_map(|(program_id, mapping_name, entries)| {
+                entries.iter().map(move |(key, value)| ((*program_id, *mapping_name), key.clone(), value.clone()))
+            })
+            .collect();
+        compute_mapping_tree(entries)
+    }
+
+    /// Ensures the mappings match the mapping root of the block.
+    fn verify_mappings(&self) -> Result<()> {
+        // Retrieve the snapshot height.
+        let height = self.height();
+        // Ensure the mapping tree matches the mapping root.
+        ensure!(
+            Self::to_mapping_tree(&self.mappings)?.root() == self.block.header().mapping_root(),
+            "Snapshot at block {height} does not match the mapping root of the block"
+        );
+        Ok(())
+    }
+
+    /// Ensures the committees cover the committee lookback of the block, and each committee is bound to the block:
+    /// the header of the committee block hashes to the ancestor block hash, and the committee proof proves the
+    /// committee is in the `credits.aleo` mappings committed to by the header of the next block.
+    fn verify_committees(&self) -> Result<()> {
+        // Retrieve the snapshot height.
+        let height = self.height();
+        // Ensure the committees start at or before the committee lookback round of the block.
+        // Note: The snapshot is well-formed, so there is at least one committee.
+        let lookback_round = self.block.round().saturating_sub(Committee::<N>::COMMITTEE_LOOKBACK_RANGE);
+        ensure!(
+            self.committees[0].1.starting_round() <= lookback_round,
+            "Snapshot at block {height} is missing the committee for round {lookback_round}"
+        );
+
+        for (index, (header, committee, proof)) in self.committees.iter().enumerate() {
+            // Retrieve the committee height.
+            let committee_height = header.height();
+            // Retrieve the previous block hash.
+            let previous_hash = match committee_height.checked_sub(1) {
+                Some(previous_height) => self.ancestor_hashes[previous_height as usize],
+                None => N::BlockHash::default(),
+            };
+            // Ensure the block header matches the ancestor block hash.
+            // Note: The snapshot is well-formed, so the committee height precedes the snapshot height.
+            let block_hash = N::hash_bhp1024(&to_bits_le![previous_hash, header.to_root()?])?;
+            ensure!(
+                *self.ancestor_hashes[committee_height as usize] == block_hash,
+                "Snapshot at block {height} contains a committee with an invalid header for block {committee_height}"
+            );
+            // Ensure the committee starts at the round of its block.
+            ensure!(
+                committee.starting_round() == header.round(),
+                "Snapshot at block {height} has an incorrect committee starting round in block {committee_height}"
+            );
+
+            // Retrieve the header of the next block, which commits to the mappings after the committee block.
+            let next_header = self.committees.get(index + 1).map_or(self.block.header(), |(header, _, _)| header);
+            // Ensure the header of the next block commits to the mappings.
+            ensure!(
+                Header::<N>::commits_mapping_root(next_header.height()),
+                "Snapshot at block {height} contains a committee for block {committee_height} that cannot be verified \
+                 (block {} does not commit to the mappings)",
+                next_header.height()
+            );
+            // Ensure the committee is in the mappings after the committee block.
+            proof.verify(&next_header.mapping_root(), committee).map_err(|error| {
+                anyhow!("Snapshot at block {height} has an invalid committee in block {committee_height} - {error}")
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Ensures every deployment is in an ancestor block, by checking the block header of the deployment
+    /// hashes to the ancestor block hash, and the transactions path to the transactions root of the header.
+    fn verify_deployments(&self) -> Result<()> {
+        // Retrieve the snapshot height.
+        let height = self.height();
+        for (header, transactions_path, transaction) in &self.deployments {
+            // Retrieve the deployment height.
+            let deployment_height = header.height();
+            // Retrieve the previous block hash.
+            let previous_hash = match deployment_height.checked_sub(1) {
+                Some(previous_height) => self.ancestor_hashes[previous_height as usize],
+                None => N::BlockHash::default(),
+            };
+            // Ensure the block header matches the ancestor block hash.
+            // Note: The snapshot is well-formed, so the deployment height precedes the snapshot height.
+            let block_hash = N::hash_bhp1024(&to_bits_le![previous_hash, header.to_root()?])?;
+            ensure!(
+                *self.ancestor_hashes[deployment_height as usize] == block_hash,
+                "Snapshot at block {height} contains a deployment with an invalid header for block {deployment_height}"
+            );
+            // Ensure the deployment is in the block.
+            ensure!(
+                N::verify_merkle_path_bhp(
+                    transactions_path,
+                    &header.transactions_root(),
+                    &transaction.id().to_bits_le()
+                ),
+                "Snapshot at block {height} contains a deployment '{}' that is not in block {deployment_height}",
+                transaction.id()
+            );
+        }
+        Ok(())
+    }
+
+    /// Ensures the block authority is valid for the committee lookback of the block.
+    fn verify_authority(&self) -> Result<()> {
+        // Retrieve the snapshot height.
+        let height = self.height();
+        // Retrieve the committee lookback, which is the latest committee starting at or before the lookback round.
+        let lookback_round = self.block.round().saturating_sub(Committee::<N>::COMMITTEE_LOOKBACK_RANGE);
+        let Some((_, committee, _)) =
+            self.committees.iter().rev().find(|(_, committee, _)| committee.starting_round() <= lookback_round)
+        else {
+            bail!("Snapshot at block {height} is missing the committee for round {lookback_round}")
+        };
+
+        match self.block.authority() {
+            Authority::Beacon(signature) => {
+                // Retrieve the signer.
+                let signer = signature.to_address();
+                // Ensure the block is signed by a committee member.
+                ensure!(
+                    committee.members().contains_key(&signer),
+                    "Snapshot block {height} has a signer not in the committee (found '{signer}')"
+                );
+                // Ensure the signature is valid.
+                ensure!(
+                    signature.verify(&signer, &[*self.block.hash()]),
+                    "Snapshot block {height} has an invalid signature"
+                );
+            }
+            Authority::Quorum(subdag) => {
+                // Ensure the block is authored by the expected leader.
+                let expected_leader = committee.get_leader(subdag.anchor_round())?;
+                ensure!(
+                    subdag.leader_address() == expected_leader,
+                    "Snapshot block {height} is authored by an unexpected leader (found '{}', expected '{expected_leader}')",
+                    subdag.leader_address()
+                );
+                // Ensure the leader certificate belongs to the committee.
+                let certificate = subdag.leader_certificate();
+                ensure!(
+                    certificate.committee_id() == committee.id(),
+                    "Snapshot block {height} has a leader certificate with an incorrect committee ID"
+                );
+                // Ensure the leader certificate is signed by a quorum of the committee.
+                // Note: The signatures of a certificate are verified when the certificate is deserialized.
+                let signers = certificate
+                    .signatures()
+                    .map(|signature| signature.to_address())
+                    .chain(std::iter::once(certificate.author()))
+                    .collect::<HashSet<_>>();
+                ensure!(
+                    committee.is_quorum_threshold_reached(&signers),
+                    "Snapshot block {height} has a leader certificate without a quorum of signatures"
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the block height of the snapshot.
+    pub fn height(&self) -> u32 {
+        self.block.height()
+    }
+
+    /// Returns the block at the snapshot height.
+    pub const fn block(&self) -> &Block<N> {
+        &self.block
+    }
+
+    /// Returns the block hashes from genesis up to (excluding) the snapshot block.
+    pub fn ancestor_hashes(&self) -> &[N::BlockHash] {
+        &self.ancestor_hashes
+    }
+
+    /// Returns the mapping root prior to the snapshot block.
+    pub const fn mapping_root(&self) -> Field<N> {
+        self.block.header().mapping_root()
+    }
+
+    /// Returns the block height of the first committee in the snapshot.
+    pub fn committees_start_height(&self) -> u32 {
+        // Note: The snapshot is well-formed, so the number of committees does not exceed the block height.
+        self.height() - self.committees.len() as u32
+    }
+
+    /// Returns the committees from the committee lookback of the snapshot block up to (excluding) the snapshot block.
+    pub fn committees(&self) -> &[SnapshotCommittee<N>] {
+        &self.committees
+    }
+
+    /// Returns the deployments of the programs prior to the snapshot block.
+    pub fn deployments(&self) -> &[SnapshotDeployment<N>] {
+        &self.deployments
+    }
+
+    /// Returns the key-value pairs of every mapping prior to the snapshot block.
+    pub fn mappings(&self) -> &[SnapshotMapping<N>] {
+        &self.mappings
+    }
+}
+
+impl<N: Network> FromBytes for Snapshot<N> {
+    /// Reads the snapshot from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid snapshot version"));
+        }
+
+        // Read the block.
+        let block = Block::read_le(&mut reader)?;
+        // Read the ancestor hashes.
+        let num_ancestors = u32::read_le(&mut reader)?;
+        let ancestor_hashes =
+            (0..num_ancestors).map(|_| N::BlockHash::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the committees.
+        let num_committees = u32::read_le(&mut reader)?;
+        let committees = (0..num_committees)
+            .map(|_| {
+                Ok((
+                    Header::read_le(&mut reader)?,
+                    Committee::read_le(&mut reader)?,
+                    CommitteeProof::read_le(&mut reader)?,
+                ))
+            })
+            .collect::<IoResult<Vec<_>>>()?;
+        // Read the deployments.
+        let num_deployments = u32::read_le(&mut reader)?;
+        let deployments = (0..num_deployments)
+            .map(|_| {
+                Ok((
+                    Header::read_le(&mut reader)?,
+                    TransactionsPath::read_le(&mut reader)?,
+                    Transaction::read_le(&mut reader)?,
+                ))
+            })
+            .collect::<IoResult<Vec<_>>>()?;
+        // Read the mappings.
+        let num_mappings = u32::read_le(&mut reader)?;
+        let mut mappings = Vec::new();
+        for _ in 0..num_mappings {
+            // Read the program ID and mapping name.
+            let program_id = ProgramID::read_le(&mut reader)?;
+            let mapping_name = Identifier::read_le(&mut reader)?;
+            // Read the key-value pairs.
+            let num_entries = u32::read_le(&mut reader)?;
+            let entries = (0..num_entries)
+                .map(|_| Ok((Plaintext::read_le(&mut reader)?, Value::read_le(&mut reader)?)))
+                .collect::<IoResult<Vec<_>>>()?;
+            mappings.push((program_id, mapping_name, entries));
+        }
+
+        // Construct the snapshot.
+        Self::new(block, ancestor_hashes, committees, deployments, mappings).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for Snapshot<N> {
+    /// Writes the snapshot to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the block.
+        self.block.write_le(&mut writer)?;
+        // Write the ancestor hashes.
+        u32::try_from(self.ancestor_hashes.len()).map_err(error)?.write_le(&mut writer)?;
+        self.ancestor_hashes.write_le(&mut writer)?;
+        // Write the committees.
+        u32::try_from(self.committees.len()).map_err(error)?.write_le(&mut writer)?;
+        for (header, committee, proof) in &self.committees {
+            header.write_le(&mut writer)?;
+            committee.write_le(&mut writer)?;
+            proof.write_le(&mut writer)?;
+        }
+        // Write the deployments.
+        u32::try_from(self.deployments.len()).map_err(error)?.write_le(&mut writer)?;
+        for (header, transactions_path, transaction) in &self.deployments {
+            header.write_le(&mut writer)?;
+            transactions_path.write_le(&mut writer)?;
+            transaction.write_le(&mut writer)?;
+        }
+        // Write the mappings.
+        u32::try_from(self.mappings.len()).map_err(error)?.write_le(&mut writer)?;
+        for (program_id, mapping_name, entries) in &self.mappings {
+            // Write the program ID and mapping name.
+            program_id.write_le(&mut writer)?;
+            mapping_name.write_le(&mut writer)?;
+            // Write the key-value pairs.
+            u32::try_from(entries.len()).map_err(error)?.write_le(&mut writer)?;
+            for (key, value) in entries {
+                key.write_le(&mut writer)?;
+                value.write_le(&mut writer)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -13,10 +13,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use console::network::Network;
-use ledger_block::Transactions;
+use console::{
+    network::Network,
+    program::{Literal, Plaintext},
+};
+use ledger_block::{Input, Transactions, Transition};
 
-use anyhow::{Result, anyhow};
+use anyhow::{Result, anyhow, bail};
 
 /// Returns the next total supply in microcredits, given the starting total supply and newly-confirmed transactions.
 pub fn update_total_supply<N: Network>(
@@ -43,7 +46,6 @@ pub fn update_total_supply<N: Network>(
         for transition in confirmed.transaction().transitions() {
             // If the transition contains a split, subtract the amount from the total supply.
             if transition.is_split() {
-                // TODO (howardwu): Add a test that calls `split`, checks the output records - input records == 10_000u64.
                 // Subtract the amount split from the total supply.
                 next_total_supply = next_total_supply
                     .checked_sub(10_000u64)
@@ -54,3 +56,177 @@ pub fn update_total_supply<N: Network>(
     // Return the final total supply in microcredits.
     Ok(next_total_supply)
 }
+
+/// Returns the next private supply in microcredits, given the starting private supply and newly-confirmed transactions.
+/// The private supply is the amount of microcredits held in `credits.aleo` records.
+/// Note: The private supply is signed, so that an inconsistent record flow is reported instead of underflowing.
+pub fn update_private_supply<N: Network>(
+    starting_private_supply_in_microcredits: i128,
+    transactions: &Transactions<N>,
+) -> Result<i128> {
+    // Initialize the next private supply of microcredits.
+    let mut next_private_supply = starting_private_supply_in_microcredits;
+
+    // Iterate through the transactions to calculate the next private supply of microcredits.
+    for confirmed in transactions.iter() {
+        // If the fee is private, subtract the fee from the private supply.
+        if confirmed.transaction().fee_transition().is_some_and(|fee| fee.is_fee_private()) {
+            next_private_supply -= i128::from(*confirmed.fee_amount()?);
+        }
+
+        // If the transaction is rejected, its transitions were not finalized.
+        if !confirmed.is_accepted() {
+            continue;
+        }
+        // Iterate over the transitions in the transaction.
+        for transition in confirmed.transaction().transitions() {
+            if transition.is_transfer_public_to_private() {
+                // Add the amount converted into a record to the private supply.
+                next_private_supply += i128::from(public_amount(transition)?);
+            } else if transition.is_transfer_private_to_public() {
+                // Subtract the amount converted from a record from the private supply.
+                next_private_supply -= i128::from(public_amount(transition)?);
+            } else if transition.is_split() {
+                // Subtract the amount burned by the split from the private supply.
+                next_private_supply -= 10_000;
+            }
+        }
+    }
+    // Return the final private supply in microcredits.
+    Ok(next_private_supply)
+}
+
+/// Returns the public `u64` amount input of the given `credits.aleo` transition.
+fn public_amount<N: Network>(transition: &Transition<N>) -> Result<u64> {
+    // Find the public amount input.
+    for input in transition.inputs() {
+        if let Input::Public(_, Some(Plaintext::Literal(Literal::U64(amount), _))) = input {
+            return Ok(**amount);
+        }
+    }
+    bail!("Missing the public amount in transition '{}'", transition.id())
+}
+
+/// The supply of microcredits at a block height, as reconstructed from the ledger.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SupplyAuditEntry {
+    /// The block height.
+    height: u32,
+    /// The total supply, as tracked from the block rewards, puzzle rewards, fees, and burns.
+    tracked_supply: u64,
+    /// The sum of the `credits.aleo/account` balances.
+    public_supply: u64,
+    /// The sum of the `credits.aleo/bonded` amounts.
+    bonded_supply: u64,
+    /// The sum of the `credits.aleo/unbonding` amounts.
+    unbonding_supply: u64,
+    /// The amount held in records, as reconstructed from the record flow of the `credits.aleo` transitions.
+    private_supply: i128,
+    /// The number of stakers in `credits.aleo/bonded` or `credits.aleo/unbonding` without a `credits.aleo/withdraw` address.
+    stakers_without_withdraw: usize,
+}
+
+impl SupplyAuditEntry {
+    /// Initializes a new supply audit entry.
+    pub const fn new(
+        height: u32,
+        tracked_supply: u64,
+        public_supply: u64,
+        bonded_supply: u64,
+        unbonding_supply: u64,
+        private_supply: i128,
+        stakers_without_withdraw: usize,
+    ) -> Self {
+        Self {
+            height,
+            tracked_supply,
+            public_supply,
+            bonded_supply,
+            unbonding_supply,
+            private_supply,
+            stakers_without_withdraw,
+        }
+    }
+
+    /// Returns the block height.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the total supply, as tracked from the block rewards, puzzle rewards, fees, and burns.
+    pub const fn tracked_supply(&self) -> u64 {
+        self.tracked_supply
+    }
+
+    /// Returns the sum of the `credits.aleo/account` balances.
+    pub const fn public_supply(&self) -> u64 {
+        self.public_supply
+    }
+
+    /// Returns the sum of the `credits.aleo/bonded` amounts.
+    pub const fn bonded_supply(&self) -> u64 {
+        self.bonded_supply
+    }
+
+    /// Returns the sum of the `credits.aleo/unbonding` amounts.
+    pub const fn unbonding_supply(&self) -> u64 {
+        self.unbonding_supply
+    }
+
+    /// Returns the amount held in records, as reconstructed from the record flow of the `credits.aleo` transitions.
+    pub const fn private_supply(&self) -> i128 {
+        self.private_supply
+    }
+
+    /// Returns the number of stakers without a withdraw address.
+    pub const fn stakers_without_withdraw(&self) -> usize {
+        self.stakers_without_withdraw
+    }
+
+    /// Returns the reconstructed supply, as the sum of the public, bonded, unbonding, and private supply.
+    pub fn reconstructed_supply(&self) -> i128 {
+        i128::from(self.public_supply)
+            + i128::from(self.bonded_supply)
+            + i128::from(self.unbonding_supply)
+            + self.private_supply
+    }
+
+    /// Returns the tracked supply minus the reconstructed supply.
+    pub fn discrepancy(&self) -> i128 {
+        i128::from(self.tracked_supply) - self.reconstructed_supply()
+    }
+
+    /// Returns `true` if the reconstructed supply matches the tracked supply, and every staker has a withdraw address.
+    pub fn is_consistent(&self) -> bool {
+        self.discrepancy() == 0 && self.stakers_without_withdraw == 0
+    }
+}
+
+/// The report of a supply audit, with an entry for each audited block height.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SupplyAudit {
+    /// The audit entries, in ascending order of block height.
+    entries: Vec<SupplyAuditEntry>,
+}
+
+impl SupplyAudit {
+    /// Initializes a new supply audit.
+    pub const fn new(entries: Vec<SupplyAuditEntry>) -> Self {
+        Self { entries }
+    }
+
+    /// Returns the audit entries, in ascending order of block height.
+    pub fn entries(&self) -> &[SupplyAuditEntry] {
+        &self.entries
+    }
+
+    /// Returns the audit entries with a discrepancy.
+    pub fn discrepancies(&self) -> impl '_ + Iterator<Item = &SupplyAuditEntry> {
+        self.entries.iter().filter(|entry| !entry.is_consistent())
+    }
+
+    /// Returns `true` if every audit entry is consistent.
+    pub fn is_consistent(&self) -> bool {
+        self.entries.iter().all(SupplyAuditEntry::is_consistent)
+    }
+}
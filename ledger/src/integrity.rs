@@ -0,0 +1,394 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::{BlockTree, MappingTree};
+
+use core::ops::RangeInclusive;
+
+/// Returns an inconsistency for the given block height and map, if the condition does not hold.
+macro_rules! ensure_consistent {
+    ($condition:expr, $height:expr, $map:expr, $($reason:tt)+) => {
+        if !$condition {
+            return Err(Inconsistency::new($height, $map, format!($($reason)+)));
+        }
+    };
+}
+
+/// The maximum number of attempts to check the finalize storage against the latest block.
+const MAX_FINALIZE_ATTEMPTS: usize = 3;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Checks the consistency of the ledger storage, and returns a report with the first inconsistency found.
+    ///
+    /// For every block, this recomputes the block hash and the block tree, and checks them against the stored
+    /// hashes and state roots. For every unpruned block, this checks that the transactions, transitions,
+    /// and input and output IDs are indexed to the block, and recomputes the header roots, including the
+    /// finalize root from the stored finalize operations (for blocks that stored their ratified operations).
+    /// As a header commits to the mappings prior to its block, the mapping root of each header is recomputed
+    /// by rolling back the mapping tree one block at a time, for as long as the rollback entries are in storage.
+    /// Lastly, this recomputes the mapping root from the finalize storage, and checks that the finalize operations
+    /// of the latest block commit to the values in the finalize storage.
+    ///
+    /// The blocks whose finalize root or mapping root could not be recomputed, as they predate the ratified
+    /// operations or their rollback entries are not in storage, are returned as unchecked in the report.
+    ///
+    /// Note: The ledger may advance during the check, in which case the blocks up to the height at the start
+    /// of the check are verified.
+    pub fn verify_integrity(&self) -> Result<IntegrityReport> {
+        // Retrieve the latest block and the mapping tree as of the latest block.
+        let (latest_block, mapping_tree) = self.latest_block_and_mapping_tree();
+        let latest_height = latest_block.height();
+
+        // Roll back the mapping tree to find the first block whose mapping root does not match.
+        let (mapping_inconsistency, unchecked_mapping_roots) =
+            match self.verify_mapping_roots(latest_height, mapping_tree) {
+                Ok(unchecked_mapping_roots) => (None, unchecked_mapping_roots),
+                Err(inconsistency) => (Some(inconsistency), None),
+            };
+
+        // Initialize the block tree.
+        let mut tree: BlockTree<N> = N::merkle_tree_bhp(&[])?;
+        // Track whether a block stored its ratified operations, as blocks that predate the map lack them.
+        let mut has_ratified_operations = false;
+        // Track the blocks that lack their ratified operations, whose finalize root is unchecked.
+        let mut unchecked_finalize_roots = None;
+        // Check each block.
+        for height in 0..=latest_height {
+            let result = match &mapping_inconsistency {
+                Some(inconsistency) if inconsistency.height() == height => Err(inconsistency.clone()),
+                _ => self.verify_block_integrity(
+                    height,
+                    &mut tree,
+                    &mut has_ratified_operations,
+                    &mut unchecked_finalize_roots,
+                ),
+            };
+            if let Err(inconsistency) = result {
+                return Ok(IntegrityReport::new(height, Some(inconsistency), vec![]));
+            }
+        }
+
+        // Collect the unchecked blocks.
+        let unchecked = [
+            unchecked_finalize_roots.map(|heights| {
+                UncheckedBlocks::new(heights, "RatifiedOperations", "the finalize roots were not recomputed")
+            }),
+            unchecked_mapping_roots
+                .map(|heights| UncheckedBlocks::new(heights, "Rollback", "the mapping roots were not recomputed")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        // Ensure the finalize storage matches the latest block.
+        // Note: If the ledger advances during this check, it is repeated against the new latest block.
+        for attempt in 1..=MAX_FINALIZE_ATTEMPTS {
+            let (latest_block, mapping_tree) = self.latest_block_and_mapping_tree();
+            let result = self.verify_finalize_storage(&latest_block, &mapping_tree);
+            // If the ledger did not advance during the check, return the result.
+            if self.latest_hash() == latest_block.hash() {
+                if let Err(inconsistency) = result {
+                    return Ok(IntegrityReport::new(inconsistency.height(), Some(inconsistency), unchecked));
+                }
+                return Ok(IntegrityReport::new(latest_height.saturating_add(1), None, unchecked));
+            }
+            debug!(
+                "The ledger advanced while checking the finalize storage (attempt {attempt}/{MAX_FINALIZE_ATTEMPTS})"
+            );
+        }
+        bail!("The ledger advanced while checking the finalize storage ({MAX_FINALIZE_ATTEMPTS} attempts)")
+    }
+
+    /// Returns the latest block and the mapping tree as of the latest block.
+    fn latest_block_and_mapping_tree(&self) -> (Block<N>, MappingTree<N>) {
+        // Note: The read lock on the current block ensures the ledger does not advance in between,
+        // and is only held while the (cheaply cloned) mapping tree is retrieved.
+        let current_block = self.current_block.read();
+        (current_block.clone(), self.vm.finalize_store().current_mapping_tree())
+    }
+
+    /// Checks the mapping root of each header against the given mapping tree as of the latest block,
+    /// by applying the rollback entries of each block in descending order of block height.
+    /// The check stops at the first block without a mapping root, or without rollback entries,
+    /// in which case the heights of the blocks whose mapping root is unchecked are returned.
+    fn verify_mapping_roots(
+        &self,
+        latest_height: u32,
+        mut mapping_tree: MappingTree<N>,
+    ) -> Result<Option<RangeInclusive<u32>>, Inconsistency> {
+        let finalize_store = self.vm.finalize_store();
+        for height in (1..=latest_height).rev() {
+            if !Header::<N>::commits_mapping_root(height) {
+                break;
+            }
+            // Roll back the mapping tree to its state prior to the block.
+            match finalize_store.rollback_mapping_tree(&mut mapping_tree, height) {
+                Ok(true) => (),
+                // Note: The mapping root is committed from `N::CONSENSUS_V3_HEIGHT` onwards, excluding genesis.
+                Ok(false) => return Ok(Some(N::CONSENSUS_V3_HEIGHT.max(1)..=height)),
+                Err(e) => return Err(Inconsistency::new(height, "Rollback", e)),
+            }
+            // Ensure the mapping root of the header matches.
+            let header = self.get_header(height).map_err(|e| Inconsistency::new(height, "Header", e))?;
+            let mapping_root = mapping_tree.root();
+            ensure_consistent!(
+                header.mapping_root() == mapping_root,
+                height,
+                "KeyValue",
+                "found mapping root '{}', expected '{mapping_root}'",
+                header.mapping_root()
+            );
+        }
+        Ok(None)
+    }
+
+    /// Checks that the finalize storage matches the given mapping tree, and that the finalize operations
+    /// of the given latest block commit to the values that the block modified.
+    fn verify_finalize_storage(
+        &self,
+        latest_block: &Block<N>,
+        mapping_tree: &MappingTree<N>,
+    ) -> Result<(), Inconsistency> {
+        let finalize_store = self.vm.finalize_store();
+        let height = latest_block.height();
+
+        // Ensure the mapping tree matches the finalize storage.
+        let mapping_root =
+            finalize_store.compute_mapping_root().map_err(|e| Inconsistency::new(height, "KeyValue", e))?;
+        ensure_consistent!(
+            mapping_tree.root() == mapping_root,
+            height,
+            "KeyValue",
+            "found mapping root '{}', expected '{mapping_root}'",
+            mapping_tree.root()
+        );
+
+        // Ensure the finalize operations of the latest block commit to the finalize storage.
+        // Note: Blocks prior to the ratified operations map lack the rollback entries needed for this check.
+        let ratified_operations = match finalize_store.get_ratified_operations(height) {
+            Ok(Some(ratified_operations)) => ratified_operations,
+            Ok(None) => return Ok(()),
+            Err(e) => return Err(Inconsistency::new(height, "RatifiedOperations", e)),
+        };
+        let operations =
+            latest_block.transactions().finalize_operations().cloned().chain(ratified_operations).collect::<Vec<_>>();
+        finalize_store
+            .verify_finalize_operations(height, &operations)
+            .map_err(|e| Inconsistency::new(height, "KeyValue", e))
+    }
+
+    /// Checks the consistency of the block at the given height, and appends its hash to the given block tree.
+    /// The `has_ratified_operations` flag is set once a block with ratified operations in storage is found,
+    /// and the heights of the blocks prior to it are tracked in `unchecked_finalize_roots`.
+    fn verify_block_integrity(
+        &self,
+        height: u32,
+        tree: &mut BlockTree<N>,
+        has_ratified_operations: &mut bool,
+        unchecked_finalize_roots: &mut Option<RangeInclusive<u32>>,
+    ) -> Result<(), Inconsistency> {
+        let block_store = self.vm.block_store();
+
+        // Retrieve the block hash.
+        let block_hash = match block_store.get_block_hash(height) {
+            Ok(Some(block_hash)) => block_hash,
+            Ok(None) => return Err(Inconsistency::new(height, "ID", "missing block hash")),
+            Err(e) => return Err(Inconsistency::new(height, "ID", e)),
+        };
+        // Ensure the reverse block hash matches.
+        let reverse_height =
+            block_store.get_block_height(&block_hash).map_err(|e| Inconsistency::new(height, "ReverseID", e))?;
+        ensure_consistent!(reverse_height == Some(height), height, "ReverseID", "found height {reverse_height:?}");
+
+        // Retrieve the block header.
+        let header = match block_store.get_block_header(&block_hash) {
+            Ok(Some(header)) => header,
+            Ok(None) => return Err(Inconsistency::new(height, "Header", "missing block header")),
+            Err(e) => return Err(Inconsistency::new(height, "Header", e)),
+        };
+        ensure_consistent!(header.height() == height, height, "Header", "found height {}", header.height());
+        // Ensure the previous block hash matches.
+        let previous_hash = match height {
+            0 => N::BlockHash::default(),
+            _ => match block_store.get_block_hash(height - 1) {
+                Ok(Some(previous_hash)) => previous_hash,
+                _ => return Err(Inconsistency::new(height, "ID", "missing previous block hash")),
+            },
+        };
+        let stored_previous_hash =
+            block_store.get_previous_block_hash(height).map_err(|e| Inconsistency::new(height, "Header", e))?;
+        ensure_consistent!(
+            height == 0 || stored_previous_hash == Some(previous_hash),
+            height,
+            "Header",
+            "found previous block hash {stored_previous_hash:?}, expected '{previous_hash}'"
+        );
+        // Recompute the block hash.
+        let header_root = header.to_root().map_err(|e| Inconsistency::new(height, "Header", e))?;
+        let candidate_hash = N::hash_bhp1024(&to_bits_le![previous_hash, header_root])
+            .map_err(|e| Inconsistency::new(height, "Header", e))?;
+        ensure_consistent!(*block_hash == candidate_hash, height, "Header", "block hash does not match the header");
+
+        // Ensure the previous state root matches the block tree.
+        if height > 0 {
+            let previous_state_root = N::StateRoot::from(*tree.root());
+            ensure_consistent!(
+                header.previous_state_root() == previous_state_root,
+                height,
+                "Header",
+                "found previous state root '{}', expected '{previous_state_root}'",
+                header.previous_state_root()
+            );
+        }
+        // Append the block hash to the block tree.
+        tree.append(&[block_hash.to_bits_le()]).map_err(|e| Inconsistency::new(height, "BlockTree", e))?;
+        // Ensure the state root matches the block tree.
+        let state_root = N::StateRoot::from(*tree.root());
+        let stored_state_root =
+            block_store.get_state_root(height).map_err(|e| Inconsistency::new(height, "StateRoot", e))?;
+        ensure_consistent!(
+            stored_state_root == Some(state_root),
+            height,
+            "StateRoot",
+            "found state root {stored_state_root:?}, expected '{state_root}'"
+        );
+        let reverse_height = block_store
+            .find_block_height_from_state_root(state_root)
+            .map_err(|e| Inconsistency::new(height, "ReverseStateRoot", e))?;
+        ensure_consistent!(
+            reverse_height == Some(height),
+            height,
+            "ReverseStateRoot",
+            "found height {reverse_height:?}"
+        );
+
+        // If the block is pruned, its transactions are no longer in storage.
+        let pruned_height = block_store.pruned_height().map_err(|e| Inconsistency::new(height, "PrunedHeight", e))?;
+        if pruned_height.is_some_and(|pruned_height| height <= pruned_height) {
+            return Ok(());
+        }
+
+        // Retrieve the block.
+        let block = match block_store.get_block(&block_hash) {
+            Ok(Some(block)) => block,
+            Ok(None) => return Err(Inconsistency::new(height, "Transactions", "missing block")),
+            Err(e) => return Err(Inconsistency::new(height, "Transactions", e)),
+        };
+        // Ensure the header roots match the block contents.
+        let transactions_root = block.transactions().to_transactions_root();
+        ensure_consistent!(
+            transactions_root.is_ok_and(|root| root == header.transactions_root()),
+            height,
+            "Transactions",
+            "transactions root does not match the header"
+        );
+        let ratifications_root = block.ratifications().to_ratifications_root();
+        ensure_consistent!(
+            ratifications_root.is_ok_and(|root| root == header.ratifications_root()),
+            height,
+            "Ratifications",
+            "ratifications root does not match the header"
+        );
+        let solutions_root = block.solutions().to_solutions_root();
+        ensure_consistent!(
+            solutions_root.is_ok_and(|root| root == header.solutions_root()),
+            height,
+            "Solutions",
+            "solutions root does not match the header"
+        );
+        // Ensure the finalize root matches the finalize operations in storage.
+        // Note: Blocks prior to the ratified operations map are skipped, until the first block that stored them.
+        match self.vm.finalize_store().get_ratified_operations(height) {
+            Ok(Some(ratified_operations)) => {
+                *has_ratified_operations = true;
+                let finalize_root = block.transactions().to_finalize_root(ratified_operations);
+                ensure_consistent!(
+                    finalize_root.is_ok_and(|root| root == header.finalize_root()),
+                    height,
+                    "RatifiedOperations",
+                    "finalize root does not match the header"
+                );
+            }
+            Ok(None) => {
+                ensure_consistent!(
+                    !*has_ratified_operations,
+                    height,
+                    "RatifiedOperations",
+                    "missing ratified operations"
+                );
+                let start = unchecked_finalize_roots.as_ref().map_or(height, |heights| *heights.start());
+                *unchecked_finalize_roots = Some(start..=height);
+            }
+            Err(e) => return Err(Inconsistency::new(height, "RatifiedOperations", e)),
+        }
+
+        // Ensure the transactions are indexed to the block.
+        for confirmed in block.transactions().iter() {
+            let transaction = confirmed.transaction();
+            let transaction_id = transaction.id();
+            let found_hash = block_store
+                .find_block_hash(&transaction_id)
+                .map_err(|e| Inconsistency::new(height, "ConfirmedTransactions", e))?;
+            ensure_consistent!(
+                found_hash == Some(block_hash),
+                height,
+                "ConfirmedTransactions",
+                "transaction '{transaction_id}' is indexed to block {found_hash:?}"
+            );
+            // Ensure the transitions are indexed to the transaction.
+            for transition in transaction.transitions() {
+                let transition_id = transition.id();
+                let found_id = self
+                    .find_transaction_id_from_transition_id(transition_id)
+                    .map_err(|e| Inconsistency::new(height, "Transition", e))?;
+                ensure_consistent!(
+                    found_id == Some(transaction_id),
+                    height,
+                    "Transition",
+                    "transition '{transition_id}' is indexed to transaction {found_id:?}"
+                );
+                // Ensure the inputs and outputs are indexed to the transition.
+                for input_id in transition.input_ids() {
+                    let found_id = self
+                        .vm
+                        .transition_store()
+                        .find_transition_id_from_input_id(input_id)
+                        .map_err(|e| Inconsistency::new(height, "Input", e))?;
+                    ensure_consistent!(
+                        found_id == Some(*transition_id),
+                        height,
+                        "Input",
+                        "input '{input_id}' is indexed to transition {found_id:?}"
+                    );
+                }
+                for output_id in transition.output_ids() {
+                    let found_id = self
+                        .vm
+                        .transition_store()
+                        .find_transition_id_from_output_id(output_id)
+                        .map_err(|e| Inconsistency::new(height, "Output", e))?;
+                    ensure_consistent!(
+                        found_id == Some(*transition_id),
+                        height,
+                        "Output",
+                        "output '{output_id}' is indexed to transition {found_id:?}"
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+}
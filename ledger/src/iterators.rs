@@ -85,6 +85,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     }
 
     /// Returns an iterator over the `(commitment, record)` pairs, for all transition outputs that are records.
+    /// Note: The records of pruned blocks are not included.
     pub fn records(&self) -> impl '_ + Iterator<Item = (Cow<'_, Field<N>>, Cow<'_, Record<N, Ciphertext<N>>>)> {
         self.vm.transition_store().records()
     }
@@ -35,13 +35,22 @@ pub use ledger_test_helpers;
 mod helpers;
 pub use helpers::*;
 
+#[cfg(any(test, feature = "test-helpers"))]
+pub mod devnet;
+
 mod advance;
 mod check_next_block;
 mod check_transaction_basic;
+mod checkpoint;
 mod contains;
 mod find;
 mod get;
+mod integrity;
 mod iterators;
+mod scan;
+mod snapshot;
+mod storage;
+mod supply;
 
 #[cfg(test)]
 mod tests;
@@ -49,7 +58,7 @@ mod tests;
 use console::{
     account::{Address, GraphKey, PrivateKey, ViewKey},
     network::prelude::*,
-    program::{Ciphertext, Entry, Identifier, Literal, Plaintext, ProgramID, Record, StatePath, Value},
+    program::{Ciphertext, Entry, Identifier, Literal, MappingProof, Plaintext, ProgramID, Record, StatePath, Value},
     types::{Field, Group},
 };
 use ledger_authority::Authority;
@@ -57,7 +66,7 @@ use ledger_committee::Committee;
 use ledger_narwhal::{BatchCertificate, Subdag, Transmission, TransmissionID};
 use ledger_puzzle::{Puzzle, PuzzleSolutions, Solution, SolutionID};
 use ledger_query::Query;
-use ledger_store::{ConsensusStorage, ConsensusStore};
+use ledger_store::{ConsensusStorage, ConsensusStore, TransitionPosition};
 use synthesizer::{
     program::{FinalizeGlobalState, Program},
     vm::VM,
@@ -68,7 +77,7 @@ use aleo_std::{
     prelude::{finish, lap, timer},
 };
 use anyhow::Result;
-use core::ops::Range;
+use core::ops::{Bound, Range};
 use indexmap::IndexMap;
 use parking_lot::RwLock;
 use rand::{prelude::IteratorRandom, rngs::OsRng};
@@ -128,9 +137,14 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Retrieve the latest height.
         let latest_height = ledger.current_block.read().height();
         debug_assert_eq!(latest_height, ledger.vm.block_store().max_height().unwrap(), "Mismatch in latest height");
+        // Determine the first unpruned block height.
+        let start_height = match ledger.vm.block_store().pruned_height()? {
+            Some(pruned_height) => pruned_height + 1,
+            None => 0,
+        };
         // Sample random block heights.
-        let block_heights: Vec<u32> =
-            (0..=latest_height).choose_multiple(&mut OsRng, (latest_height as usize).min(NUM_BLOCKS));
+        let block_heights: Vec<u32> = (start_height..=latest_height)
+            .choose_multiple(&mut OsRng, ((latest_height - start_height) as usize).min(NUM_BLOCKS));
         cfg_into_iter!(block_heights).try_for_each(|height| {
             ledger.get_block(height)?;
             Ok::<_, Error>(())
@@ -153,10 +167,32 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         };
         lap!(timer, "Load consensus store");
 
+        // Initialize the ledger from the consensus store.
+        let ledger = Self::from_store(genesis_block, store)?;
+
+        finish!(timer);
+        Ok(ledger)
+    }
+
+    /// Initializes the ledger from the given consensus store, without performing integrity checks.
+    fn from_store(genesis_block: Block<N>, store: ConsensusStore<N, C>) -> Result<Self> {
+        let timer = timer!("Ledger::from_store");
+
         // Initialize a new VM.
         let vm = VM::from(store)?;
         lap!(timer, "Initialize a new VM");
 
+        // Initialize the ledger from the VM.
+        let ledger = Self::from_vm(genesis_block, vm)?;
+
+        finish!(timer);
+        Ok(ledger)
+    }
+
+    /// Initializes the ledger from the given VM, without performing integrity checks.
+    fn from_vm(genesis_block: Block<N>, vm: VM<N, C>) -> Result<Self> {
+        let timer = timer!("Ledger::from_vm");
+
         // Retrieve the current committee.
         let current_committee = vm.finalize_store().committee_store().current_committee().ok();
 
@@ -218,9 +254,14 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().current_state_root()
     }
 
+    /// Returns the latest mapping root.
+    pub fn latest_mapping_root(&self) -> Field<N> {
+        self.vm.finalize_store().current_mapping_root()
+    }
+
     /// Returns the latest epoch number.
     pub fn latest_epoch_number(&self) -> u32 {
-        self.current_block.read().height() / N::NUM_BLOCKS_PER_EPOCH
+        self.current_block.read().height() / N::num_blocks_per_epoch()
     }
 
     /// Returns the latest epoch hash.
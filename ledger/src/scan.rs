@@ -0,0 +1,58 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Advances the given record scanners through up to `max_blocks` blocks after their cursors,
+    /// and returns the height of the next block to scan for the furthest behind scanner.
+    /// Each block is fetched once, and scanned by every scanner whose cursor is at the block.
+    pub fn scan_records(&self, scanners: &mut [RecordScanner<N>], max_blocks: u32) -> Result<u32> {
+        // Retrieve the latest height.
+        let latest_height = self.latest_height();
+        // Determine the first block height to scan.
+        let Some(start_height) = scanners.iter().map(|scanner| scanner.next_height()).min() else {
+            return Ok(latest_height.saturating_add(1));
+        };
+        // Ensure the first block has not been pruned, as pruned blocks no longer contain their records.
+        if let Some(pruned_height) = self.vm.block_store().pruned_height()? {
+            ensure!(
+                start_height > pruned_height,
+                "Cannot scan block {start_height}: blocks up to {pruned_height} have been pruned"
+            );
+        }
+        // Determine the last block height to scan.
+        let end_height = latest_height.min(start_height.saturating_add(max_blocks).saturating_sub(1));
+
+        for height in start_height..=end_height {
+            // Skip the block if no scanner is at it.
+            if !scanners.iter().any(|scanner| scanner.next_height() == height) {
+                continue;
+            }
+            // Retrieve the block hash and the previous block hash.
+            let block_hash = self.get_hash(height)?;
+            let previous_hash = self.get_previous_hash(height)?;
+            // Retrieve the block transactions.
+            let transactions = self.get_transactions(height)?;
+            // Scan the block with each scanner at the block.
+            for scanner in scanners.iter_mut().filter(|scanner| scanner.next_height() == height) {
+                scanner.scan_block(height, previous_hash, block_hash, &transactions)?;
+            }
+        }
+
+        // Return the next block height to scan.
+        Ok(scanners.iter().map(|scanner| scanner.next_height()).min().unwrap_or(end_height.saturating_add(1)))
+    }
+}
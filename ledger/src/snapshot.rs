@@ -0,0 +1,231 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::MappingTree;
+use ledger_committee::CommitteeProof;
+use synthesizer::program::FinalizeStoreTrait;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns a snapshot of the ledger state prior to the given block height.
+    ///
+    /// The snapshot contains the block at the given height, which commits to the ledger state prior to it.
+    ///
+    /// Note: The lock on the current block is not held during the export, so the ledger may advance meanwhile.
+    /// As the mappings are reconstructed from the latest mappings, they are read again if a block is added
+    /// while they are read, which is detected by checking them against the mapping root of the block.
+    /// The committees are proven against the mappings after their blocks, by rolling back the mapping tree,
+    /// which requires the rollback entries of the blocks in the committee lookback.
+    pub fn export_snapshot(&self, height: u32) -> Result<Snapshot<N>> {
+        /// The maximum number of attempts to read the mappings prior to the block.
+        const MAX_MAPPINGS_ATTEMPTS: usize = 3;
+
+        // Ensure the block height is not in the future.
+        let latest_height = self.latest_height();
+        ensure!(
+            height <= latest_height,
+            "Cannot export a snapshot at block {height} (the latest block is {latest_height})"
+        );
+        // Ensure the block header commits to the mappings.
+        ensure!(
+            Header::<N>::commits_mapping_root(height),
+            "Cannot export a snapshot at block {height} (the block headers commit to the mappings from block {})",
+            N::CONSENSUS_V3_HEIGHT.max(1)
+        );
+        // Retrieve the previous block height.
+        let previous_height = height - 1;
+
+        // Retrieve the block.
+        let block = self.get_block(height)?;
+        // Retrieve the ancestor block hashes.
+        let ancestor_hashes = (0..height).map(|height| self.get_hash(height)).collect::<Result<Vec<_>>>()?;
+        // Retrieve the committees from the committee lookback of the block, with the headers of their blocks,
+        // as the prior committees are not needed to verify the block and the blocks after it.
+        let lookback_round = block.round().saturating_sub(Committee::<N>::COMMITTEE_LOOKBACK_RANGE);
+        let Some(start_height) = self.vm.finalize_store().committee_store().get_height_for_round(lookback_round)?
+        else {
+            bail!("Missing the committee for round {lookback_round}")
+        };
+        let committees = (start_height..height)
+            .map(|height| match self.get_committee(height)? {
+                Some(committee) => Ok((self.get_header(height)?, committee)),
+                None => bail!("Missing the committee for block {height}"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Retrieve the deployments prior to the block height, in the order they were deployed.
+        let mut deployments = Vec::new();
+        for transaction_id in self.vm.transaction_store().deployment_transaction_ids() {
+            let Some(block_hash) = self.find_block_hash(&transaction_id)? else {
+                bail!("Missing the block hash for deployment '{transaction_id}'")
+            };
+            let deployment_height = self.get_height(&block_hash)?;
+            if deployment_height < height {
+                // Note: The deployment is retrieved from the transaction store, as the deployments are never pruned.
+                let Some(transaction) = self.vm.transaction_store().get_transaction(&transaction_id)? else {
+                    bail!("Missing the deployment transaction '{transaction_id}'")
+                };
+                // Retrieve the block header, and the path of the deployment to its transactions root.
+                let header = self.get_header(deployment_height)?;
+                let Some(transactions_path) =
+                    self.vm.block_store().get_block_transactions_path(&block_hash, &transaction_id)?
+                else {
+                    bail!("Missing the transactions path for deployment '{transaction_id}'")
+                };
+                deployments.push((header, transactions_path, transaction));
+            }
+        }
+        deployments.sort_by_key(|(header, _, _)| header.height());
+
+        // Retrieve the mappings prior to the block, and ensure they match the mapping root of the block.
+        let mut attempt = 1;
+        let (mappings, mut mapping_tree) = loop {
+            let mappings = self.vm.finalize_store().get_mappings_at_height(previous_height)?;
+            let mapping_tree = Snapshot::to_mapping_tree(&mappings)?;
+            match mapping_tree.root() == block.header().mapping_root() {
+                true => break (mappings, mapping_tree),
+                false if attempt < MAX_MAPPINGS_ATTEMPTS => {
+                    debug!("Reading the mappings prior to block {height} again - mismatching mapping root");
+                    attempt += 1;
+                }
+                false => bail!("Cannot export a snapshot at block {height} (mismatching mapping root)"),
+            }
+        };
+
+        // Prove each committee against the mappings after its block, in descending order of block height,
+        // by rolling back the mapping tree to the mappings prior to each block.
+        let mut snapshot_committees = Vec::with_capacity(committees.len());
+        for (header, committee) in committees.into_iter().rev() {
+            let committee_height = header.height();
+            let proof = CommitteeProof::prove(&committee, |program_id, mapping_name, key| {
+                Ok(mapping_tree.prove(&MappingTree::to_key_id(program_id, mapping_name, key)?))
+            })?;
+            snapshot_committees.push((header, committee, proof));
+            // Roll back the mapping tree, unless this is the first committee.
+            if committee_height > start_height {
+                ensure!(
+                    self.vm.finalize_store().rollback_mapping_tree(&mut mapping_tree, committee_height)?,
+                    "Cannot export a snapshot at block {height} (missing rollback entries of block {committee_height})"
+                );
+            }
+        }
+        snapshot_committees.reverse();
+
+        // Construct the snapshot.
+        let snapshot = Snapshot::new(block.clone(), ancestor_hashes, snapshot_committees, deployments, mappings)?;
+
+        // Ensure the block was not reverted during the export.
+        ensure!(
+            self.get_hash(height)? == block.hash(),
+            "Cannot export a snapshot at block {height} (the block was reverted during the export)"
+        );
+        Ok(snapshot)
+    }
+
+    /// Initializes the ledger from the given snapshot, so that only the blocks after the snapshot need to be synced.
+    ///
+    /// The snapshot is verified against the given block hash, which must be obtained from a trusted source,
+    /// and the snapshot block is finalized on top of the snapshot mappings.
+    ///
+    /// Note: The blocks and transactions prior to the snapshot height are not in storage,
+    /// and are reported as pruned by the block store.
+    pub fn load_from_snapshot(
+        genesis_block: Block<N>,
+        snapshot: &Snapshot<N>,
+        trusted_block_hash: N::BlockHash,
+        storage_mode: StorageMode,
+    ) -> Result<Self> {
+        let timer = timer!("Ledger::load_from_snapshot");
+
+        // Ensure the snapshot matches the trusted block hash.
+        snapshot.verify(trusted_block_hash)?;
+        // Ensure the snapshot extends the genesis block.
+        ensure!(
+            snapshot.ancestor_hashes().first() == Some(&genesis_block.hash()),
+            "The snapshot does not extend the given genesis block"
+        );
+        lap!(timer, "Verify the snapshot");
+
+        info!("Loading the ledger from the snapshot at block {}...", snapshot.height());
+        // Initialize the consensus store.
+        let store = ConsensusStore::<N, C>::open(storage_mode)?;
+        // Ensure the consensus store is empty.
+        ensure!(store.block_store().max_height().is_none(), "Cannot load a snapshot into a non-empty ledger");
+
+        // Store the genesis block.
+        store.block_store().insert(&genesis_block)?;
+        // Store the deployments that are not already stored.
+        for (_, _, transaction) in snapshot.deployments() {
+            if !store.transaction_store().contains_transaction_id(&transaction.id())? {
+                store.transaction_store().insert(transaction)?;
+            }
+        }
+        lap!(timer, "Store the deployments");
+
+        // Store the committees, starting from the first committee in the snapshot.
+        let committee_store = store.finalize_store().committee_store();
+        let start_height = snapshot.committees_start_height();
+        for (height, (_, committee, _)) in (start_height..).zip(snapshot.committees()) {
+            match height == start_height {
+                true => committee_store.insert_starting_committee(height, committee.clone())?,
+                false => committee_store.insert(height, committee.clone())?,
+            }
+        }
+        // Store the mappings.
+        store.finalize_store().start_atomic();
+        let result = snapshot.mappings().iter().try_for_each(|(program_id, mapping_name, entries)| {
+            store.finalize_store().initialize_mapping(*program_id, *mapping_name)?;
+            for (key, value) in entries {
+                store.finalize_store().insert_key_value(*program_id, *mapping_name, key.clone(), value.clone())?;
+            }
+            Ok::<_, Error>(())
+        });
+        match result {
+            Ok(()) => store.finalize_store().finish_atomic()?,
+            Err(error) => {
+                store.finalize_store().abort_atomic();
+                bail!("Failed to load the snapshot (run 'snarkos clean' and try again): {error}")
+            }
+        }
+        // Ensure the finalize store matches the mapping root of the snapshot block.
+        ensure!(
+            store.finalize_store().current_mapping_root() == snapshot.mapping_root(),
+            "Failed to load the snapshot (run 'snarkos clean' and try again): mismatching mapping root"
+        );
+        lap!(timer, "Store the finalize state");
+
+        // Initialize a new VM.
+        let vm = VM::from(store)?;
+        // Add the snapshot block, finalizing it on top of the snapshot mappings.
+        let block = snapshot.block();
+        vm.add_next_block_with_ancestor_hashes(snapshot.ancestor_hashes(), block)?;
+        // Ensure the finalize operations match the finalize root of the snapshot block.
+        let Some(ratified_operations) = vm.finalize_store().get_ratified_operations(block.height())? else {
+            bail!("Failed to load the snapshot (run 'snarkos clean' and try again): missing finalize operations")
+        };
+        ensure!(
+            block.transactions().to_finalize_root(ratified_operations)? == block.header().finalize_root(),
+            "Failed to load the snapshot (run 'snarkos clean' and try again): mismatching finalize root"
+        );
+        lap!(timer, "Finalize the snapshot block");
+
+        // Initialize the ledger from the VM.
+        let ledger = Self::from_vm(genesis_block, vm)?;
+
+        finish!(timer);
+        Ok(ledger)
+    }
+}
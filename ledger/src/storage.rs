@@ -0,0 +1,59 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ledger_store::StorageReport;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the usage report of the ledger storage, covering the storage maps and the program mappings.
+    ///
+    /// If the `metrics` feature is enabled, the report is also exported through the storage gauges.
+    pub fn storage_report(&self) -> Result<StorageReport<N>> {
+        let report = self.vm.storage_report()?;
+
+        #[cfg(feature = "metrics")]
+        {
+            for map in &report.maps {
+                let label = format!("{}.{}", map.category, map.name);
+                metrics::gauge_label(metrics::storage::MAP_KEYS, "map", label.clone(), map.num_keys as f64);
+                metrics::gauge_label(
+                    metrics::storage::MAP_SIZE_IN_BYTES,
+                    "map",
+                    label.clone(),
+                    map.size_in_bytes as f64,
+                );
+                metrics::gauge_label(metrics::storage::MAP_WRITES_PER_SECOND, "map", label, map.writes_per_second);
+            }
+            for mapping in &report.mappings {
+                let label = format!("{}/{}", mapping.program_id, mapping.mapping_name);
+                metrics::gauge_label(
+                    metrics::storage::MAPPING_ENTRIES,
+                    "mapping",
+                    label.clone(),
+                    mapping.num_entries as f64,
+                );
+                metrics::gauge_label(
+                    metrics::storage::MAPPING_SIZE_IN_BYTES,
+                    "mapping",
+                    label,
+                    mapping.size_in_bytes as f64,
+                );
+            }
+        }
+
+        Ok(report)
+    }
+}
@@ -0,0 +1,271 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexSet;
+
+/// The number of mapping entries to read at a time, when summing the public supply.
+const PUBLIC_SUPPLY_PAGE_SIZE: usize = 1024;
+/// The number of attempts to sum the public supply without the block lock, before holding it for the final attempt.
+const MAX_PUBLIC_SUPPLY_ATTEMPTS: usize = 3;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Audits the supply of microcredits, by walking the ledger from genesis and reconciling the
+    /// public balances, bonded and unbonding amounts, and private record flow against the tracked total supply.
+    ///
+    /// The public state is audited at the latest height, and at every height in archive mode.
+    /// Note: This requires every block to be unpruned, as the record flow is reconstructed from the transitions.
+    pub fn audit_supply(&self) -> Result<SupplyAudit> {
+        // Ensure no blocks have been pruned.
+        if let Some(pruned_height) = self.vm.block_store().pruned_height()? {
+            bail!("Cannot audit the supply: blocks up to {pruned_height} have been pruned");
+        }
+        // Retrieve the archive height.
+        let archive_height = self.vm.finalize_store().get_archive_height()?;
+
+        // Sum the public supply as of the latest height.
+        let (latest_height, mut public_supply) = self.latest_public_supply()?;
+        // Determine the first height at which the public state is audited.
+        let first_height = archive_height.map_or(latest_height, |archive_height| archive_height.min(latest_height));
+
+        // Initialize the tracked and private supply.
+        let mut tracked_supply = N::starting_supply();
+        let mut private_supply = 0i128;
+        // Walk the blocks up to the latest height, recording the tracked and private supply at each audited height.
+        let mut supplies = Vec::with_capacity((latest_height - first_height + 1) as usize);
+        for height in 0..=latest_height {
+            // Retrieve the block.
+            let block = self.get_block(height)?;
+            // Retrieve the block reward and puzzle reward.
+            let mut block_reward = 0;
+            let mut puzzle_reward = 0;
+            for ratification in block.ratifications().iter() {
+                match ratification {
+                    Ratify::BlockReward(reward) => block_reward = *reward,
+                    Ratify::PuzzleReward(reward) => puzzle_reward = *reward,
+                    Ratify::Genesis(..) | Ratify::Restrictions(..) => (),
+                }
+            }
+            // Update the tracked and private supply.
+            tracked_supply = update_total_supply(tracked_supply, block_reward, puzzle_reward, block.transactions())?;
+            private_supply = update_private_supply(private_supply, block.transactions())?;
+            if height >= first_height {
+                supplies.push((tracked_supply, private_supply));
+            }
+        }
+
+        // Audit each height from the latest height down, rolling the public supply back one block at a time.
+        let mut entries = Vec::with_capacity(supplies.len());
+        for (index, (tracked_supply, private_supply)) in supplies.into_iter().enumerate().rev() {
+            let height = first_height + index as u32;
+            entries.push(public_supply.to_entry(height, tracked_supply, private_supply));
+            if height > first_height {
+                self.rollback_public_supply(&mut public_supply, height)?;
+            }
+        }
+        entries.reverse();
+        Ok(SupplyAudit::new(entries))
+    }
+
+    /// Returns the latest height and the public supply as of that height.
+    fn latest_public_supply(&self) -> Result<(u32, PublicSupply<N>)> {
+        // Sum the mappings without the block lock, and only accept the sums if the ledger did not advance meanwhile.
+        for _ in 1..MAX_PUBLIC_SUPPLY_ATTEMPTS {
+            let (latest_hash, latest_height) = {
+                let current_block = self.current_block.read();
+                (current_block.hash(), current_block.height())
+            };
+            let public_supply = self.sum_public_supply()?;
+            if self.latest_hash() == latest_hash {
+                return Ok((latest_height, public_supply));
+            }
+            debug!("The ledger advanced while summing the public supply, retrying");
+        }
+        // Hold the block lock for the final attempt, to ensure the ledger does not advance.
+        let current_block = self.current_block.read();
+        Ok((current_block.height(), self.sum_public_supply()?))
+    }
+
+    /// Returns the public supply, by summing the confirmed `credits.aleo` mappings a page at a time.
+    fn sum_public_supply(&self) -> Result<PublicSupply<N>> {
+        let mut public_supply = PublicSupply::new()?;
+        for mapping_name in public_supply.names {
+            let mut cursor = None;
+            loop {
+                let page = self.vm.finalize_store().get_mapping_page_confirmed(
+                    public_supply.program_id,
+                    mapping_name,
+                    cursor.as_ref(),
+                    PUBLIC_SUPPLY_PAGE_SIZE,
+                )?;
+                for (key, value) in &page {
+                    public_supply.update(&mapping_name, key, None, Some(value))?;
+                }
+                if page.len() < PUBLIC_SUPPLY_PAGE_SIZE {
+                    break;
+                }
+                cursor = page.last().map(|(key, _)| key.clone());
+            }
+        }
+        Ok(public_supply)
+    }
+
+    /// Rolls the given public supply back from the given height to the previous height,
+    /// by restoring the prior value of each `credits.aleo` entry that the block modified.
+    fn rollback_public_supply(&self, public_supply: &mut PublicSupply<N>, height: u32) -> Result<()> {
+        let finalize_store = self.vm.finalize_store();
+        let Some((_, key_value_entries)) = finalize_store.get_rollback_entries(height)? else {
+            bail!("Cannot audit the supply: missing the rollback entries for block {height}");
+        };
+        for ((program_id, mapping_name), key, prior_value) in key_value_entries {
+            if program_id != public_supply.program_id {
+                continue;
+            }
+            let value = finalize_store.get_value_at_height(program_id, mapping_name, &key, height)?;
+            public_supply.update(&mapping_name, &key, value.as_ref(), prior_value.as_ref())?;
+        }
+        Ok(())
+    }
+}
+
+/// The public supply of microcredits, as held in the `credits.aleo` mappings.
+struct PublicSupply<N: Network> {
+    /// The `credits.aleo` program ID.
+    program_id: ProgramID<N>,
+    /// The `account`, `bonded`, `unbonding`, and `withdraw` mapping names.
+    names: [Identifier<N>; 4],
+    /// The sum of the `credits.aleo/account` balances.
+    account: u64,
+    /// The sum of the `credits.aleo/bonded` amounts.
+    bonded: u64,
+    /// The sum of the `credits.aleo/unbonding` amounts.
+    unbonding: u64,
+    /// The stakers in `credits.aleo/bonded` or `credits.aleo/unbonding`, with the number of mappings they are in.
+    stakers: IndexMap<Address<N>, u8>,
+    /// The addresses in `credits.aleo/withdraw`.
+    withdraw: IndexSet<Address<N>>,
+    /// The number of stakers without a withdraw address, if it is up to date.
+    stakers_without_withdraw: Option<usize>,
+}
+
+impl<N: Network> PublicSupply<N> {
+    /// Initializes an empty public supply.
+    fn new() -> Result<Self> {
+        Ok(Self {
+            program_id: ProgramID::from_str("credits.aleo")?,
+            names: [
+                Identifier::from_str("account")?,
+                Identifier::from_str("bonded")?,
+                Identifier::from_str("unbonding")?,
+                Identifier::from_str("withdraw")?,
+            ],
+            account: 0,
+            bonded: 0,
+            unbonding: 0,
+            stakers: Default::default(),
+            withdraw: Default::default(),
+            stakers_without_withdraw: Some(0),
+        })
+    }
+
+    /// Replaces the value of the given key in the given `credits.aleo` mapping, from `old` to `new`.
+    /// A `None` indicates the key does not exist.
+    fn update(
+        &mut self,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+        old: Option<&Value<N>>,
+        new: Option<&Value<N>>,
+    ) -> Result<()> {
+        let [account, bonded, unbonding, withdraw] = &self.names;
+        // Update the sum of the mapping.
+        let sum = match mapping_name {
+            name if name == account => &mut self.account,
+            name if name == bonded => &mut self.bonded,
+            name if name == unbonding => &mut self.unbonding,
+            name if name == withdraw => {
+                match new {
+                    Some(_) => self.withdraw.insert(to_address(key)?),
+                    None => self.withdraw.swap_remove(&to_address(key)?),
+                };
+                self.stakers_without_withdraw = None;
+                return Ok(());
+            }
+            _ => return Ok(()),
+        };
+        let old_amount = old.map(|value| to_microcredits(key, value)).transpose()?.unwrap_or(0);
+        let new_amount = new.map(|value| to_microcredits(key, value)).transpose()?.unwrap_or(0);
+        *sum = sum
+            .checked_sub(old_amount)
+            .and_then(|sum| sum.checked_add(new_amount))
+            .ok_or_else(|| anyhow!("The microcredits in '{mapping_name}' are out of bounds"))?;
+
+        // Update the stakers, if the key was inserted into or removed from `bonded` or `unbonding`.
+        if mapping_name != account && old.is_some() != new.is_some() {
+            let staker = to_address(key)?;
+            match new {
+                Some(_) => *self.stakers.entry(staker).or_default() += 1,
+                None => {
+                    if let Some(count) = self.stakers.get_mut(&staker) {
+                        *count -= 1;
+                        if *count == 0 {
+                            self.stakers.swap_remove(&staker);
+                        }
+                    }
+                }
+            }
+            self.stakers_without_withdraw = None;
+        }
+        Ok(())
+    }
+
+    /// Returns the supply audit entry at the given height, given the tracked and private supply.
+    fn to_entry(&mut self, height: u32, tracked_supply: u64, private_supply: i128) -> SupplyAuditEntry {
+        // Count the stakers without a withdraw address, only if the stakers or withdraw addresses changed.
+        let stakers_without_withdraw = *self
+            .stakers_without_withdraw
+            .get_or_insert_with(|| self.stakers.keys().filter(|staker| !self.withdraw.contains(*staker)).count());
+        SupplyAuditEntry::new(
+            height,
+            tracked_supply,
+            self.account,
+            self.bonded,
+            self.unbonding,
+            private_supply,
+            stakers_without_withdraw,
+        )
+    }
+}
+
+/// Returns the microcredits in the given `credits.aleo` mapping value.
+fn to_microcredits<N: Network>(key: &Plaintext<N>, value: &Value<N>) -> Result<u64> {
+    match value {
+        Value::Plaintext(Plaintext::Literal(Literal::U64(amount), _)) => Ok(**amount),
+        Value::Plaintext(Plaintext::Struct(members, _)) => match members.get(&Identifier::from_str("microcredits")?) {
+            Some(Plaintext::Literal(Literal::U64(amount), _)) => Ok(**amount),
+            _ => bail!("Invalid microcredits for '{key}' in finalize storage"),
+        },
+        _ => bail!("Invalid value for '{key}' in finalize storage"),
+    }
+}
+
+/// Returns the address of the given `credits.aleo` mapping key.
+fn to_address<N: Network>(key: &Plaintext<N>) -> Result<Address<N>> {
+    match key {
+        Plaintext::Literal(Literal::Address(address), _) => Ok(*address),
+        _ => bail!("Invalid address '{key}' in finalize storage"),
+    }
+}
@@ -15,6 +15,7 @@
 
 use crate::{
     Ledger,
+    RecordScanner,
     RecordsFilter,
     advance::split_candidate_solutions,
     test_helpers::{CurrentAleo, CurrentLedger, CurrentNetwork},
@@ -23,16 +24,16 @@ use aleo_std::StorageMode;
 use console::{
     account::{Address, PrivateKey},
     network::{MainnetV0, prelude::*},
-    program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
-    types::U16,
+    program::{Entry, Identifier, Literal, Locator, Plaintext, ProgramID, Record, Value},
+    types::{Field, U16},
 };
 use ledger_authority::Authority;
-use ledger_block::{Block, ConfirmedTransaction, Execution, Ratify, Rejected, Transaction};
+use ledger_block::{Block, ConfirmedTransaction, Execution, Header, Ratify, Rejected, Transaction};
 use ledger_committee::{Committee, MIN_VALIDATOR_STAKE};
 use ledger_narwhal::{BatchCertificate, BatchHeader, Data, Subdag, Transmission, TransmissionID};
-use ledger_store::{ConsensusStore, helpers::memory::ConsensusMemory};
+use ledger_store::{ConsensusStore, TransitionPosition, helpers::memory::ConsensusMemory};
 use snarkvm_utilities::try_vm_runtime;
-use synthesizer::{Stack, program::Program, vm::VM};
+use synthesizer::{BlockRange, Restrictions, RestrictionsHistory, Stack, program::{FinalizeStoreTrait, Program}, vm::VM};
 
 use indexmap::{IndexMap, IndexSet};
 use rand::seq::SliceRandom;
@@ -230,6 +231,27 @@ fn test_state_path() {
     let _state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
 }
 
+#[test]
+fn test_storage_report() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let ledger = crate::test_helpers::sample_ledger(PrivateKey::<CurrentNetwork>::new(rng).unwrap(), rng);
+
+    // Ensure the report covers the mappings of the credits program.
+    let report = ledger.storage_report().unwrap();
+    let credits = ProgramID::from_str("credits.aleo").unwrap();
+    let account = report
+        .mappings
+        .iter()
+        .find(|mapping| {
+            mapping.program_id == credits && mapping.mapping_name == Identifier::from_str("account").unwrap()
+        })
+        .unwrap();
+    assert!(account.num_entries > 0);
+    assert!(account.size_in_bytes > 0);
+}
+
 #[test]
 fn test_insufficient_private_fees() {
     let rng = &mut TestRng::default();
@@ -633,6 +655,684 @@ finalize foo:
     assert_eq!(program, ledger.get_program(program_id).unwrap())
 }
 
+#[test]
+fn test_revert_to_height() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Retrieve the genesis state.
+    let genesis_hash = ledger.latest_hash();
+    let genesis_state_root = ledger.latest_state_root();
+    let genesis_checksum = ledger.vm().finalize_store().get_checksum_confirmed().unwrap();
+
+    // Deploy a test program to the ledger.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("dummy_program.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+function foo:
+    input r0 as u8.private;
+    async foo r0 into r1;
+    output r1 as {program_id}/foo.future;
+finalize foo:
+    input r0 as u8.public;
+    add r0 r0 into r1;",
+    ))
+    .unwrap();
+    let transaction = ledger.vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    let transaction_id = transaction.id();
+
+    // Advance to the next block.
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), 1);
+    assert!(ledger.contains_program_id(&program_id).unwrap());
+
+    // Ensure reverting to a future height fails.
+    assert!(ledger.revert_to_height(2).is_err());
+    assert_eq!(ledger.latest_height(), 1);
+
+    // Revert to the genesis block.
+    ledger.revert_to_height(0).unwrap();
+    assert_eq!(ledger.latest_height(), 0);
+    assert_eq!(ledger.latest_hash(), genesis_hash);
+    assert_eq!(ledger.latest_state_root(), genesis_state_root);
+    assert_eq!(ledger.vm().finalize_store().get_checksum_confirmed().unwrap(), genesis_checksum);
+    assert!(!ledger.contains_block_hash(&block.hash()).unwrap());
+    assert!(!ledger.contains_transaction_id(&transaction_id).unwrap());
+    assert!(!ledger.contains_program_id(&program_id).unwrap());
+    assert!(!ledger.vm().contains_program(&program_id));
+
+    // Ensure the same block can be added again.
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), 1);
+    assert_eq!(program, ledger.get_program(program_id).unwrap());
+}
+
+#[test]
+#[cfg(feature = "test")]
+fn test_snapshot() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+    let genesis = ledger.get_block(0).unwrap();
+
+    // Deploy a test program with a mapping to the ledger.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("dummy_program.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+mapping counts:
+    key as u8.public;
+    value as u8.public;
+function foo:
+    input r0 as u8.private;
+    async foo r0 into r1;
+    output r1 as {program_id}/foo.future;
+finalize foo:
+    input r0 as u8.public;
+    set r0 into counts[r0];",
+    ))
+    .unwrap();
+    let transaction = ledger.vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    let block_1 =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block_1).unwrap();
+    // Advance past the consensus V3 height and the committee lookback range, so that the headers commit
+    // to the mapping root from before the committee lookback of the snapshot block.
+    let lookback_height =
+        CurrentNetwork::CONSENSUS_V3_HEIGHT + Committee::<CurrentNetwork>::COMMITTEE_LOOKBACK_RANGE as u32;
+    let mut blocks = vec![];
+    while ledger.latest_height() <= lookback_height + 1 {
+        let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+        blocks.push(block);
+    }
+    let height = ledger.latest_height();
+
+    // Ensure a snapshot cannot be exported for a future block, or a block that does not commit to the mappings.
+    assert!(ledger.export_snapshot(height + 1).is_err());
+    assert!(ledger.export_snapshot(CurrentNetwork::CONSENSUS_V3_HEIGHT - 1).is_err());
+    // Ensure a snapshot is rejected if its committees cannot be verified against the mappings.
+    let early_height = CurrentNetwork::CONSENSUS_V3_HEIGHT + 1;
+    let early_snapshot = ledger.export_snapshot(early_height).unwrap();
+    assert!(early_snapshot.verify(ledger.get_hash(early_height).unwrap()).is_err());
+
+    // Export the snapshot prior to the latest block.
+    let snapshot = ledger.export_snapshot(height - 1).unwrap();
+    let snapshot_block = ledger.get_block(height - 1).unwrap();
+    assert_eq!(snapshot.height(), height - 1);
+    assert_eq!(snapshot.block(), &snapshot_block);
+    assert_eq!(snapshot.mapping_root(), snapshot_block.header().mapping_root());
+    assert_eq!(snapshot.deployments().len(), 1);
+    assert!(snapshot.committees_start_height() >= CurrentNetwork::CONSENSUS_V3_HEIGHT - 1);
+    assert_eq!(snapshot.committees()[0].1, ledger.get_committee(snapshot.committees_start_height()).unwrap().unwrap());
+    snapshot.verify(snapshot_block.hash()).unwrap();
+
+    // Ensure the snapshot is rejected if the first or the latest committee does not match the mappings after its block.
+    for index in [0, snapshot.committees().len() - 1] {
+        let mut committees = snapshot.committees().to_vec();
+        let (_, committee, _) = &mut committees[index];
+        let mut members = committee.members().clone();
+        if let Some((_, (stake, _, _))) = members.get_index_mut(0) {
+            *stake += 1;
+        }
+        *committee = Committee::new(committee.starting_round(), members).unwrap();
+        let forged_snapshot = crate::Snapshot::new(
+            snapshot_block.clone(),
+            snapshot.ancestor_hashes().to_vec(),
+            committees,
+            snapshot.deployments().to_vec(),
+            snapshot.mappings().to_vec(),
+        )
+        .unwrap();
+        assert!(forged_snapshot.verify(snapshot_block.hash()).is_err());
+    }
+
+    // Ensure the snapshot is rejected if a deployment is not in the block of its header.
+    let mut deployments = snapshot.deployments().to_vec();
+    deployments[0].0 = ledger.get_header(2).unwrap();
+    let forged_snapshot = crate::Snapshot::new(
+        snapshot_block.clone(),
+        snapshot.ancestor_hashes().to_vec(),
+        snapshot.committees().to_vec(),
+        deployments,
+        snapshot.mappings().to_vec(),
+    )
+    .unwrap();
+    assert!(forged_snapshot.verify(snapshot_block.hash()).is_err());
+
+    // Check the byte representation.
+    let snapshot_bytes = snapshot.to_bytes_le().unwrap();
+    assert!(snapshot == crate::Snapshot::read_le(&snapshot_bytes[..]).unwrap());
+
+    // Ensure the snapshot cannot be loaded with an untrusted block hash.
+    let untrusted_hash = ledger.latest_hash();
+    assert!(snapshot.verify(untrusted_hash).is_err());
+    assert!(
+        CurrentLedger::load_from_snapshot(genesis.clone(), &snapshot, untrusted_hash, StorageMode::Production).is_err()
+    );
+
+    // Load a new ledger from the snapshot.
+    let bootstrapped =
+        CurrentLedger::load_from_snapshot(genesis, &snapshot, snapshot_block.hash(), StorageMode::Production).unwrap();
+    assert_eq!(bootstrapped.latest_height(), height - 1);
+    assert_eq!(bootstrapped.latest_hash(), snapshot_block.hash());
+    assert_eq!(bootstrapped.latest_state_root(), ledger.get_state_root(height - 1).unwrap().unwrap());
+    assert_eq!(program, bootstrapped.get_program(program_id).unwrap());
+
+    // Ensure the bootstrapped ledger can sync the blocks after the snapshot.
+    let latest_block = ledger.latest_block();
+    bootstrapped.check_next_block(&latest_block, rng).unwrap();
+    bootstrapped.advance_to_next_block(&latest_block).unwrap();
+    assert_eq!(bootstrapped.latest_state_root(), ledger.latest_state_root());
+    assert_eq!(
+        bootstrapped.vm().finalize_store().current_mapping_root(),
+        ledger.vm().finalize_store().current_mapping_root()
+    );
+}
+
+#[test]
+fn test_pruning() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, address } =
+        crate::test_helpers::sample_test_env(rng);
+
+    // Create a transaction with a record output.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("10u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public_to_private"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let transaction_id = transaction.id();
+    let transition_id = *transaction.transitions().next().unwrap().id();
+    let (commitment, _) = transaction.records().next().unwrap();
+    let commitment = *commitment;
+
+    // Advance to block 2.
+    let block_1 =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block_1).unwrap();
+    let block_2 = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block_2).unwrap();
+    assert_eq!(ledger.vm().block_store().pruned_height().unwrap(), None);
+
+    // Ensure the pruning depth must be non-zero.
+    assert!(ledger.set_pruning_depth(Some(0)).is_err());
+
+    // Enable pruning, which prunes blocks 0 and 1.
+    ledger.set_pruning_depth(Some(1)).unwrap();
+    assert_eq!(ledger.vm().block_store().pruned_height().unwrap(), Some(1));
+    assert_eq!(ledger.vm().block_store().pruning_depth(), Some(1));
+    // Ensure the rollback entries of the pruned blocks are removed.
+    assert!(ledger.vm().finalize_store().get_rollback_entries(1).unwrap().is_none());
+    assert!(ledger.vm().finalize_store().get_rollback_entries(2).unwrap().is_some());
+
+    // Ensure the pruned data is no longer available.
+    assert!(ledger.get_block(1).is_err());
+    assert!(ledger.get_transaction(transaction_id).is_err());
+    assert!(ledger.get_confirmed_transaction(transaction_id).is_err());
+    assert!(ledger.vm().transition_store().get_transition(&transition_id).is_err());
+    assert!(ledger.vm().transition_store().get_record(&commitment).is_err());
+    // Ensure the records of pruned blocks are skipped.
+    let mut records = ledger.find_records(&view_key, RecordsFilter::All).unwrap();
+    assert!(records.all(|(found, _)| found != commitment));
+    // Ensure the unpruned blocks are still available.
+    assert_eq!(ledger.get_block(2).unwrap(), block_2);
+    assert_eq!(ledger.get_header(1).unwrap(), *block_1.header());
+
+    // Ensure the transaction ID and commitment are still in storage, and a state path can be constructed.
+    assert!(ledger.contains_transaction_id(&transaction_id).unwrap());
+    assert!(ledger.contains_commitment(&commitment).unwrap());
+    let state_path = ledger.get_state_path_for_commitment(&commitment).unwrap();
+    assert_eq!(state_path.global_state_root(), ledger.latest_state_root());
+
+    // Ensure the next block prunes block 2.
+    let block_3 = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block_3).unwrap();
+    assert_eq!(ledger.vm().block_store().pruned_height().unwrap(), Some(2));
+    assert!(ledger.get_block(2).is_err());
+    assert!(ledger.vm().finalize_store().get_rollback_entries(2).unwrap().is_none());
+    assert!(ledger.vm().finalize_store().get_rollback_entries(3).unwrap().is_some());
+
+    // Ensure the ledger cannot be reverted to a pruned block.
+    assert!(ledger.revert_to_height(1).is_err());
+    assert_eq!(ledger.latest_height(), 3);
+}
+
+#[test]
+fn test_transition_indexes() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Sample a recipient.
+    let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    // Prepare the `credits.aleo/transfer_public` locator.
+    let program_id = ProgramID::from_str("credits.aleo").unwrap();
+    let function_name = Identifier::from_str("transfer_public").unwrap();
+
+    // Ensure the indexes cannot be queried before they are enabled.
+    assert!(ledger.find_transition_ids_for_address(&recipient, None, 10).is_err());
+    assert_eq!(ledger.vm().block_store().transition_index_height().unwrap(), None);
+
+    // Enable the transition indexes.
+    ledger.enable_transition_indexes().unwrap();
+    assert_eq!(ledger.vm().block_store().transition_index_height().unwrap(), Some(0));
+    assert!(ledger.find_transition_ids_for_address(&recipient, None, 10).unwrap().is_empty());
+
+    // Create two public transfers to the recipient.
+    let mut transition_ids = Vec::new();
+    for _ in 0..2 {
+        let inputs = [Value::from_str(&format!("{recipient}")).unwrap(), Value::from_str("10u64").unwrap()];
+        let transaction = ledger
+            .vm
+            .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+            .unwrap();
+        transition_ids.push(*transaction.transitions().next().unwrap().id());
+        // Advance to the next block.
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+
+    // Returns the transition IDs of the given page.
+    let to_ids = |page: Vec<(TransitionPosition, _)>| page.into_iter().map(|(_, id)| id).collect::<Vec<_>>();
+
+    // Ensure the transfers are indexed by the recipient, in chronological order.
+    let page = ledger.find_transition_ids_for_address(&recipient, None, 10).unwrap();
+    assert_eq!(page.iter().map(|(position, _)| position.0).collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(to_ids(page), transition_ids);
+    let first_page = ledger.find_transition_ids_for_address(&recipient, None, 1).unwrap();
+    let cursor = first_page.last().map(|(position, _)| *position);
+    assert_eq!(to_ids(first_page), transition_ids[..1]);
+    assert_eq!(to_ids(ledger.find_transition_ids_for_address(&recipient, cursor, 10).unwrap()), transition_ids[1..]);
+    // Ensure the transfers are indexed by the fee payer and function.
+    let fee_payer_transition_ids =
+        to_ids(ledger.find_transition_ids_for_fee_payer(&address, None, usize::MAX).unwrap());
+    assert!(transition_ids.iter().all(|id| fee_payer_transition_ids.contains(id)));
+    let function_transition_ids =
+        to_ids(ledger.find_transition_ids_for_function(&program_id, &function_name, None, usize::MAX).unwrap());
+    assert!(transition_ids.iter().all(|id| function_transition_ids.contains(id)));
+
+    // Ensure the indexes are updated when blocks are removed.
+    ledger.revert_to_height(1).unwrap();
+    assert_eq!(to_ids(ledger.find_transition_ids_for_address(&recipient, None, 10).unwrap()), transition_ids[..1]);
+}
+
+#[test]
+fn test_transition_indexes_backfill() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Sample a recipient.
+    let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    // Create a public transfer to the recipient, before the transition indexes are enabled.
+    let inputs = [Value::from_str(&format!("{recipient}")).unwrap(), Value::from_str("10u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let transition_id = *transaction.transitions().next().unwrap().id();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Index the blocks, which fit in a single batch, and ensure the indexes are enabled.
+    let block_store = ledger.vm().block_store();
+    assert!(block_store.index_transitions_batch().unwrap());
+    assert_eq!(block_store.transition_index_height().unwrap(), Some(0));
+
+    // Ensure the backfilled transfer is indexed.
+    let page = ledger.find_transition_ids_for_address(&recipient, None, 10).unwrap();
+    assert_eq!(page, vec![(TransitionPosition(1, 0), transition_id)]);
+
+    // Ensure the backfilled entries are removed when blocks are removed.
+    ledger.revert_to_height(0).unwrap();
+    assert!(ledger.find_transition_ids_for_address(&recipient, None, 10).unwrap().is_empty());
+}
+
+#[test]
+fn test_record_scanner() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, address } =
+        crate::test_helpers::sample_test_env(rng);
+
+    // Scan the genesis block.
+    let mut scanners = vec![RecordScanner::new(view_key, 0).unwrap()];
+    assert_eq!(ledger.scan_records(&mut scanners, 10).unwrap(), 1);
+    // Ensure the scanner found the same records as the ledger.
+    let records = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().collect::<Vec<_>>();
+    assert_eq!(scanners[0].records().len(), records.len());
+    assert_eq!(scanners[0].unspent_records().count(), records.len());
+
+    // Spend a record.
+    let (commitment, record) = records[0].clone();
+    let inputs =
+        [Value::Record(record), Value::from_str(&format!("{address}")).unwrap(), Value::from_str("100u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_private"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Persist and resume the scanner.
+    let bytes = scanners[0].to_bytes_le().unwrap();
+    let mut scanners = vec![RecordScanner::<CurrentNetwork>::from_bytes_le(&bytes).unwrap()];
+    assert_eq!(scanners[0].next_height(), 1);
+
+    // Scan the next block.
+    assert_eq!(ledger.scan_records(&mut scanners, 10).unwrap(), 2);
+    assert_eq!(scanners[0].latest_hash(), Some(block.hash()));
+    // Ensure the spent record is tracked, and the new records are found.
+    assert_eq!(scanners[0].records().get(&commitment).unwrap().spent_height(), Some(1));
+    let unspent = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().count();
+    assert_eq!(scanners[0].unspent_records().count(), unspent);
+    assert_eq!(scanners[0].spent_records().count(), 1);
+
+    // Ensure the scanner can be rewound.
+    scanners[0].rewind(1, Some(ledger.get_hash(0).unwrap()));
+    assert_eq!(scanners[0].next_height(), 1);
+    assert_eq!(scanners[0].records().len(), records.len());
+    assert_eq!(scanners[0].records().get(&commitment).unwrap().spent_height(), None);
+}
+
+#[test]
+fn test_verify_integrity() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Advance to block 2.
+    for _ in 0..2 {
+        let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+
+    // Ensure the ledger is consistent.
+    let report = ledger.verify_integrity().unwrap();
+    assert!(report.is_consistent());
+    assert!(report.is_fully_checked(), "{report}");
+    assert_eq!(report.num_blocks(), 3);
+
+    // Ensure the pruned blocks are still checked.
+    ledger.set_pruning_depth(Some(1)).unwrap();
+    let report = ledger.verify_integrity().unwrap();
+    assert!(report.is_consistent(), "{report}");
+    assert_eq!(report.num_blocks(), 3);
+}
+
+#[test]
+#[cfg(feature = "test")]
+fn test_verify_integrity_with_mapping_root() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Advance past the consensus V3 height, so that the headers commit to the mapping root.
+    let num_blocks = CurrentNetwork::CONSENSUS_V3_HEIGHT + 2;
+    for _ in 0..num_blocks {
+        let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+    assert_ne!(ledger.latest_block().header().mapping_root(), Field::zero());
+
+    // Ensure the ledger is consistent, including the mapping root of every block with rollback entries.
+    let report = ledger.verify_integrity().unwrap();
+    assert!(report.is_consistent(), "{report}");
+    assert!(report.is_fully_checked(), "{report}");
+    assert_eq!(report.num_blocks(), num_blocks + 1);
+
+    // Ensure the blocks without rollback entries are reported as unchecked.
+    let unchecked_height = CurrentNetwork::CONSENSUS_V3_HEIGHT + 1;
+    ledger.vm().finalize_store().prune_rollback_entries(unchecked_height).unwrap();
+    let report = ledger.verify_integrity().unwrap();
+    assert!(report.is_consistent(), "{report}");
+    assert!(!report.is_fully_checked());
+    assert_eq!(report.num_blocks(), num_blocks + 1);
+    assert_eq!(report.unchecked().len(), 1);
+    assert_eq!(report.unchecked()[0].map(), "Rollback");
+    assert_eq!(report.unchecked()[0].heights(), &(CurrentNetwork::CONSENSUS_V3_HEIGHT..=unchecked_height));
+}
+
+#[test]
+fn test_audit_supply() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+    ledger.vm().finalize_store().enable_archive_mode().unwrap();
+
+    // Ensure the genesis supply is consistent.
+    let audit = ledger.audit_supply().unwrap();
+    assert_eq!(audit.entries().len(), 1);
+    let genesis_entry = audit.entries()[0].clone();
+    assert_eq!(genesis_entry.height(), 0);
+    assert_eq!(genesis_entry.discrepancy(), 0);
+
+    // Returns the microcredits in the given record.
+    let microcredits = |record: &Record<CurrentNetwork, Plaintext<CurrentNetwork>>| match record
+        .data()
+        .get(&Identifier::from_str("microcredits").unwrap())
+    {
+        Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => **amount,
+        _ => panic!("Invalid microcredits"),
+    };
+
+    // Split a record.
+    let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+    let input_amount = microcredits(&record);
+    let inputs = [Value::Record(record), Value::from_str("100u64").unwrap()];
+    let transaction =
+        ledger.vm.execute(&private_key, ("credits.aleo", "split"), inputs.iter(), None, 0, None, rng).unwrap();
+    // Ensure the split burns 10_000 microcredits.
+    let output_amount =
+        transaction.records().map(|(_, record)| microcredits(&record.decrypt(&view_key).unwrap())).sum::<u64>();
+    assert_eq!(input_amount - output_amount, 10_000);
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the private supply accounts for the split.
+    let audit = ledger.audit_supply().unwrap();
+    let entry = audit.entries().last().unwrap();
+    assert_eq!(entry.height(), 1);
+    assert_eq!(entry.private_supply(), genesis_entry.private_supply() - 10_000);
+    // Ensure the public supply is rolled back to the genesis entry.
+    assert_eq!(audit.entries().len(), 2);
+    assert_eq!(audit.entries()[0], genesis_entry);
+    assert_eq!(entry.discrepancy(), 0);
+}
+
+#[test]
+fn test_audit_supply_mismatch() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, address, .. } = crate::test_helpers::sample_test_env(rng);
+    let audit = ledger.audit_supply().unwrap();
+    assert_eq!(audit.entries().last().unwrap().discrepancy(), 0);
+
+    // Mint a microcredit into the public balance, outside of a block.
+    let program_id = ProgramID::from_str("credits.aleo").unwrap();
+    let mapping_name = Identifier::from_str("account").unwrap();
+    let key = Plaintext::from(Literal::Address(address));
+    let balance = match ledger.vm().finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap() {
+        Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) => *balance,
+        _ => 0,
+    };
+    let value = Value::from_str(&format!("{}u64", balance + 1)).unwrap();
+    ledger.vm().finalize_store().update_key_value(program_id, mapping_name, key, value).unwrap();
+
+    // Ensure the audit reports the minted microcredit.
+    let audit = ledger.audit_supply().unwrap();
+    assert!(!audit.is_consistent());
+    let discrepancies = audit.discrepancies().collect::<Vec<_>>();
+    assert_eq!(discrepancies.len(), 1);
+    assert_eq!(discrepancies[0].height(), ledger.latest_height());
+    assert_eq!(discrepancies[0].discrepancy(), -1);
+}
+
+#[test]
+fn test_mapping_proof() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Prepare the `credits.aleo/account` mapping.
+    let program_id = ProgramID::from_str("credits.aleo").unwrap();
+    let mapping_name = Identifier::from_str("account").unwrap();
+
+    // Ensure the proof for an existing key verifies against the latest mapping root.
+    let key = Plaintext::from(Literal::Address(address));
+    let value = ledger.vm().finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap().unwrap();
+    let proof = ledger.get_mapping_proof(&program_id, &mapping_name, &key).unwrap();
+    assert!(proof.verify(&ledger.latest_mapping_root(), &program_id, &mapping_name, &key, Some(&value)));
+    assert!(!proof.verify(&ledger.latest_mapping_root(), &program_id, &mapping_name, &key, None));
+
+    // Ensure the proof for a non-existent key verifies as an exclusion proof.
+    let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let other_key = Plaintext::from(Literal::Address(other_address));
+    let proof = ledger.get_mapping_proof(&program_id, &mapping_name, &other_key).unwrap();
+    assert!(proof.verify(&ledger.latest_mapping_root(), &program_id, &mapping_name, &other_key, None));
+
+    // Ensure a proof cannot be retrieved for a mapping that does not exist.
+    assert!(ledger.get_mapping_proof(&program_id, &Identifier::from_str("missing").unwrap(), &key).is_err());
+
+    // Ensure the mapping root is only committed from the consensus V3 height onwards.
+    while ledger.latest_height() + 1 < CurrentNetwork::CONSENSUS_V3_HEIGHT {
+        let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        assert_eq!(block.header().mapping_root(), Field::zero());
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+
+    // Prepare the next block.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    let header = *block.header();
+    assert_eq!(header.version(), 2);
+    assert_eq!(header.mapping_root(), ledger.latest_mapping_root());
+
+    // Ensure a block with an incorrect mapping root is rejected.
+    let forged_header = Header::from(
+        header.previous_state_root(),
+        header.transactions_root(),
+        header.finalize_root(),
+        Field::rand(rng),
+        header.ratifications_root(),
+        header.solutions_root(),
+        header.subdag_root(),
+        *header.metadata(),
+    )
+    .unwrap();
+    let forged_block = Block::new_beacon(
+        &private_key,
+        block.previous_hash(),
+        forged_header,
+        block.ratifications().clone(),
+        block.solutions().clone(),
+        block.aborted_solution_ids().clone(),
+        block.transactions().clone(),
+        block.aborted_transaction_ids().clone(),
+        rng,
+    )
+    .unwrap();
+    assert!(ledger.check_next_block(&forged_block, rng).is_err());
+    assert!(ledger.check_next_block(&block, rng).is_ok());
+}
+
+#[test]
+fn test_restrictions_update() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Enable restrictions updates, with a sampled restrictions governor.
+    let governor_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let governor = Address::try_from(&governor_private_key).unwrap();
+    *ledger.vm().restrictions().write() = RestrictionsHistory::new(Restrictions::load().unwrap(), Some(governor));
+
+    // Prepare a restrictions list that restricts `credits.aleo/transfer_public`.
+    let mut functions = IndexMap::new();
+    functions.insert(Locator::from_str("credits.aleo/transfer_public").unwrap(), BlockRange::FullRange);
+    let restrictions_id = Restrictions::<CurrentNetwork>::compute_restrictions_id(
+        &IndexMap::new(),
+        &functions,
+        &IndexMap::new(),
+        &IndexMap::new(),
+        &IndexMap::new(),
+        &IndexMap::new(),
+    )
+    .unwrap();
+    let restrictions = Restrictions::<CurrentNetwork>::from_str(
+        &serde_json::json!({ "restrictions_id": restrictions_id, "programs": {}, "functions": functions, "arguments": {} })
+            .to_string(),
+    )
+    .unwrap();
+
+    // Sign the restrictions update, which activates two blocks after the consensus V3 height.
+    let activation_height = CurrentNetwork::CONSENSUS_V3_HEIGHT + 2;
+    let ratify = ledger
+        .vm()
+        .restrictions()
+        .read()
+        .sign_update(&governor_private_key, &restrictions, activation_height, rng)
+        .unwrap();
+
+    // Ensure the restrictions update is dropped before the consensus V3 height.
+    while ledger.latest_height() + 1 < CurrentNetwork::CONSENSUS_V3_HEIGHT {
+        let block = ledger
+            .prepare_advance_to_next_beacon_block(&private_key, vec![ratify.clone()], vec![], vec![], rng)
+            .unwrap();
+        assert_eq!(block.ratifications().len(), 2);
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+
+    // Ratify the restrictions update.
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![ratify.clone()], vec![], vec![], rng).unwrap();
+    assert_eq!(block.ratifications().len(), 3);
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.vm().restrictions().read().versions().len(), 2);
+    assert_eq!(ledger.vm().block_store().restrictions_updates().count(), 1);
+
+    // Ensure the restrictions update is not ratified twice.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![ratify], vec![], vec![], rng).unwrap();
+    assert_eq!(block.ratifications().len(), 2);
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure `credits.aleo/transfer_public` is only restricted from the activation height.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+    let transaction = ledger
+        .vm()
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    assert!(ledger.vm().check_transaction(&transaction, None, rng).is_ok());
+
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), activation_height);
+    assert!(ledger.vm().check_transaction(&transaction, None, rng).is_err());
+}
+
 #[test]
 fn test_bond_and_unbond_validator() {
     let rng = &mut TestRng::default();
@@ -1902,7 +2602,7 @@ finalize foo2:
 fn test_split_candidate_solutions() {
     let rng = &mut TestRng::default();
 
-    let max_solutions = CurrentNetwork::MAX_SOLUTIONS;
+    let max_solutions = CurrentNetwork::max_solutions();
 
     const ITERATIONS: usize = 1_000;
 
@@ -1960,7 +2660,7 @@ fn test_max_committee_limit_with_bonds() {
     for (private_key, _) in &validators {
         public_balances.insert(Address::try_from(private_key).unwrap(), 0);
     }
-    let remaining_supply = <CurrentNetwork as Network>::STARTING_SUPPLY - allocated_amount;
+    let remaining_supply = <CurrentNetwork as Network>::starting_supply() - allocated_amount;
     let amount = remaining_supply / 5;
     public_balances.insert(Address::try_from(validators.keys().next().unwrap()).unwrap(), amount);
     public_balances.insert(first_address, amount);
@@ -2680,7 +3380,7 @@ mod valid_solutions {
             let latest_proof_target = ledger.latest_proof_target();
 
             // Sample the number of solutions to generate.
-            let num_solutions = rng.gen_range(1..=CurrentNetwork::MAX_SOLUTIONS);
+            let num_solutions = rng.gen_range(1..=CurrentNetwork::max_solutions());
 
             // Initialize a vector for valid solutions for this block.
             let mut solutions = Vec::with_capacity(num_solutions);
@@ -2741,9 +3441,9 @@ mod valid_solutions {
 
     #[test]
     fn test_excess_invalid_solution_ids() {
-        // Note that the sum of `NUM_INVALID_SOLUTIONS` and `NUM_VALID_SOLUTIONS` should exceed the maximum number of solutions.
-        const NUM_INVALID_SOLUTIONS: usize = CurrentNetwork::MAX_SOLUTIONS;
-        const NUM_VALID_SOLUTIONS: usize = CurrentNetwork::MAX_SOLUTIONS;
+        // Note that the sum of `num_invalid_solutions` and `num_valid_solutions` should exceed the maximum number of solutions.
+        let num_invalid_solutions = CurrentNetwork::max_solutions();
+        let num_valid_solutions = CurrentNetwork::max_solutions();
 
         // Initialize an RNG.
         let rng = &mut TestRng::default();
@@ -2758,14 +3458,14 @@ mod valid_solutions {
         let minimum_proof_target = ledger.latest_proof_target();
 
         // Initialize storage for the valid and invalid solutions
-        let mut valid_solutions = Vec::with_capacity(NUM_VALID_SOLUTIONS);
-        let mut invalid_solutions = Vec::with_capacity(NUM_INVALID_SOLUTIONS);
+        let mut valid_solutions = Vec::with_capacity(num_valid_solutions);
+        let mut invalid_solutions = Vec::with_capacity(num_invalid_solutions);
 
         // Create solutions that are greater than the minimum proof target.
-        while valid_solutions.len() < NUM_VALID_SOLUTIONS {
+        while valid_solutions.len() < num_valid_solutions {
             let solution = puzzle.prove(latest_epoch_hash, address, rng.gen(), None).unwrap();
             if puzzle.get_proof_target(&solution).unwrap() < minimum_proof_target {
-                if invalid_solutions.len() < NUM_INVALID_SOLUTIONS {
+                if invalid_solutions.len() < num_invalid_solutions {
                     invalid_solutions.push(solution);
                 }
             } else {
@@ -2773,7 +3473,7 @@ mod valid_solutions {
             }
         }
         // Create the remaining solutions that are less than the minimum proof target.
-        while invalid_solutions.len() < NUM_INVALID_SOLUTIONS {
+        while invalid_solutions.len() < num_invalid_solutions {
             let solution = puzzle.prove(latest_epoch_hash, address, rng.gen(), None).unwrap();
             if puzzle.get_proof_target(&solution).unwrap() < minimum_proof_target {
                 invalid_solutions.push(solution);
@@ -2781,8 +3481,8 @@ mod valid_solutions {
         }
 
         // Check the length of the valid and invalid solutions.
-        assert_eq!(valid_solutions.len(), NUM_VALID_SOLUTIONS);
-        assert_eq!(invalid_solutions.len(), NUM_INVALID_SOLUTIONS);
+        assert_eq!(valid_solutions.len(), num_valid_solutions);
+        assert_eq!(invalid_solutions.len(), num_invalid_solutions);
 
         // Concatenate and shuffle the solutions.
         let mut candidate_solutions = valid_solutions.clone();
@@ -2814,8 +3514,8 @@ mod valid_solutions {
         ledger.advance_to_next_block(&block).unwrap();
 
         // Check that the block's solutions are well-formed.
-        assert_eq!(block.aborted_solution_ids().len(), NUM_INVALID_SOLUTIONS);
-        assert_eq!(block.solutions().len(), NUM_VALID_SOLUTIONS);
+        assert_eq!(block.aborted_solution_ids().len(), num_invalid_solutions);
+        assert_eq!(block.solutions().len(), num_valid_solutions);
 
         let block_solutions = block.solutions().solution_ids().cloned().collect::<HashSet<_>>();
         let valid_solutions = valid_solutions.iter().map(|s| s.id()).collect::<HashSet<_>>();
@@ -2829,7 +3529,7 @@ mod valid_solutions {
     #[test]
     fn test_excess_valid_solution_ids() {
         // Note that this should be greater than the maximum number of solutions.
-        const NUM_VALID_SOLUTIONS: usize = 2 * CurrentNetwork::MAX_SOLUTIONS;
+        let num_valid_solutions = 2 * CurrentNetwork::max_solutions();
 
         // Initialize an RNG.
         let rng = &mut TestRng::default();
@@ -2844,10 +3544,10 @@ mod valid_solutions {
         let minimum_proof_target = ledger.latest_proof_target();
 
         // Initialize storage for the valid solutions
-        let mut valid_solutions = Vec::with_capacity(NUM_VALID_SOLUTIONS);
+        let mut valid_solutions = Vec::with_capacity(num_valid_solutions);
 
         // Create solutions that are greater than the minimum proof target.
-        while valid_solutions.len() < NUM_VALID_SOLUTIONS {
+        while valid_solutions.len() < num_valid_solutions {
             let solution = puzzle.prove(latest_epoch_hash, address, rng.gen(), None).unwrap();
             if puzzle.get_proof_target(&solution).unwrap() >= minimum_proof_target {
                 valid_solutions.push(solution);
@@ -2855,7 +3555,7 @@ mod valid_solutions {
         }
 
         // Check the length of the valid solutions.
-        assert_eq!(valid_solutions.len(), NUM_VALID_SOLUTIONS);
+        assert_eq!(valid_solutions.len(), num_valid_solutions);
 
         // Shuffle the solutions.
         let mut candidate_solutions = valid_solutions;
@@ -2886,17 +3586,17 @@ mod valid_solutions {
         ledger.advance_to_next_block(&block).unwrap();
 
         // Check that the block's solutions are well-formed.
-        assert_eq!(block.solutions().len(), CurrentNetwork::MAX_SOLUTIONS);
-        assert_eq!(block.aborted_solution_ids().len(), NUM_VALID_SOLUTIONS - CurrentNetwork::MAX_SOLUTIONS);
+        assert_eq!(block.solutions().len(), CurrentNetwork::max_solutions());
+        assert_eq!(block.aborted_solution_ids().len(), num_valid_solutions - CurrentNetwork::max_solutions());
 
         let block_solutions = block.solutions().solution_ids().cloned().collect::<HashSet<_>>();
         let expected_accepted_solutions =
-            candidate_solutions.iter().take(CurrentNetwork::MAX_SOLUTIONS).map(|s| s.id()).collect::<HashSet<_>>();
+            candidate_solutions.iter().take(CurrentNetwork::max_solutions()).map(|s| s.id()).collect::<HashSet<_>>();
         assert_eq!(block_solutions, expected_accepted_solutions, "Accepted solutions do not match");
 
         let block_aborted_solution_ids = block.aborted_solution_ids().iter().cloned().collect::<HashSet<_>>();
         let expected_aborted_solutions =
-            candidate_solutions.iter().skip(CurrentNetwork::MAX_SOLUTIONS).map(|s| s.id()).collect::<HashSet<_>>();
+            candidate_solutions.iter().skip(CurrentNetwork::max_solutions()).map(|s| s.id()).collect::<HashSet<_>>();
         assert_eq!(block_aborted_solution_ids, expected_aborted_solutions, "Aborted solutions do not match");
     }
 
@@ -3142,3 +3842,225 @@ fn test_forged_block_subdags() {
         assert!(ledger.check_next_block(&forged_block_2_from_both_subdags, &mut rand::thread_rng()).is_err());
     }
 }
+
+#[test]
+fn test_devnet_quorum_blocks() {
+    use crate::devnet::{Devnet, DevnetConfig};
+
+    // Initialize the devnet.
+    let mut devnet = Devnet::<CurrentNetwork>::new(DevnetConfig::default()).unwrap();
+    assert_eq!(devnet.validators().count(), 4);
+
+    // Advance the devnet to block 3.
+    devnet.advance_to_height(3).unwrap();
+
+    // Ensure every validator added the same quorum blocks.
+    let latest_block = devnet.ledger().unwrap().latest_block();
+    assert_eq!(latest_block.height(), 3);
+    assert!(latest_block.authority().is_quorum());
+    for validator in devnet.validators() {
+        assert_eq!(validator.ledger().latest_hash(), latest_block.hash());
+    }
+    // Ensure the block timestamps follow the simulated clock.
+    assert!(latest_block.timestamp() > devnet.genesis().timestamp());
+    assert!(latest_block.timestamp() <= devnet.clock().now());
+
+    // Ensure the devnet is deterministic.
+    let mut other_devnet = Devnet::<CurrentNetwork>::new(DevnetConfig::default()).unwrap();
+    assert_eq!(other_devnet.genesis(), devnet.genesis());
+    other_devnet.advance_to_height(3).unwrap();
+    let other_latest_block = other_devnet.ledger().unwrap().latest_block();
+    assert_eq!(other_latest_block.authority(), latest_block.authority());
+    assert_eq!(other_latest_block.timestamp(), latest_block.timestamp());
+}
+
+#[test]
+fn test_devnet_faults() {
+    use crate::devnet::{Devnet, DevnetConfig, Fault};
+
+    let rng = &mut TestRng::default();
+
+    // Initialize the devnet.
+    let mut devnet = Devnet::<CurrentNetwork>::new(DevnetConfig::default()).unwrap();
+    let addresses = devnet.validators().map(|validator| validator.address()).collect::<Vec<_>>();
+    let private_key = *devnet.validator(&addresses[0]).unwrap().private_key();
+
+    // Crash one validator.
+    devnet.inject_fault(&addresses[3], Fault::Crash).unwrap();
+
+    // Submit a transaction.
+    let inputs = [Value::from_str(&format!("{}", addresses[1])).unwrap(), Value::from_str("1u64").unwrap()];
+    let transaction = devnet
+        .ledger()
+        .unwrap()
+        .vm()
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+        .unwrap();
+    let transaction_id = transaction.id();
+    devnet.submit_transaction(transaction.clone()).unwrap();
+    assert!(devnet.submit_transaction(transaction).is_err());
+
+    // Ensure the devnet commits the transaction despite the fault.
+    while !devnet.ledger().unwrap().contains_transaction_id(&transaction_id).unwrap() {
+        devnet.advance_to_next_block().unwrap();
+    }
+    // Ensure the crashed validator did not receive the blocks.
+    let latest_height = devnet.ledger().unwrap().latest_height();
+    assert_eq!(devnet.validator(&addresses[3]).unwrap().ledger().latest_height(), 0);
+
+    // Crash a second validator, which leaves the committee without a quorum.
+    devnet.inject_fault(&addresses[1], Fault::Crash).unwrap();
+    assert!(devnet.advance_to_next_block().is_err());
+
+    // Recover the validators, and ensure they sync the missed blocks and the devnet makes progress.
+    devnet.recover(&addresses[1]).unwrap();
+    devnet.recover(&addresses[3]).unwrap();
+    assert_eq!(devnet.validator(&addresses[3]).unwrap().ledger().latest_height(), latest_height);
+    devnet.advance_to_height(latest_height + 1).unwrap();
+    for validator in devnet.validators() {
+        assert_eq!(validator.ledger().latest_height(), devnet.ledger().unwrap().latest_height());
+    }
+}
+
+#[cfg(feature = "rocks")]
+mod checkpoint {
+    use super::*;
+    use crate::test_helpers::CurrentConsensusStore;
+
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+        process::Command,
+        sync::{
+            Arc,
+            atomic::{AtomicBool, Ordering},
+        },
+    };
+
+    /// The environment variable with the directory of a child process of the checkpoint test.
+    const CHILD_DIR_ENV: &str = "SNARKVM_LEDGER_CHECKPOINT_TEST_DIR";
+    /// The number of blocks to add while the checkpoints are taken.
+    const NUM_BLOCKS: u32 = 4;
+
+    /// Runs the given ignored test in a child process, with the given directory,
+    /// as the persistent storage can only be opened in a single directory per process.
+    fn run_in_child_process(test_name: &str, dir: &Path) {
+        let status = Command::new(std::env::current_exe().unwrap())
+            .args([test_name, "--exact", "--ignored", "--test-threads=1"])
+            .env(CHILD_DIR_ENV, dir)
+            .status()
+            .unwrap();
+        assert!(status.success(), "The child process of '{test_name}' failed for '{}'", dir.display());
+    }
+
+    /// Returns the directory of the child process, if the test runs in a child process.
+    fn child_dir() -> Option<PathBuf> {
+        std::env::var_os(CHILD_DIR_ENV).map(PathBuf::from)
+    }
+
+    #[test]
+    fn test_checkpoint_during_add_next_block() {
+        let dir = std::env::temp_dir().join(format!("snarkvm_ledger_checkpoint_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        // Add blocks to a ledger while taking checkpoints.
+        run_in_child_process("tests::checkpoint::create_checkpoints", &dir);
+
+        // Ensure every checkpoint is restored and loaded.
+        let checkpoints = fs::read_dir(dir.join("checkpoints")).unwrap().map(|entry| entry.unwrap().path());
+        let mut num_checkpoints = 0;
+        for checkpoint in checkpoints {
+            run_in_child_process("tests::checkpoint::load_checkpoint", &checkpoint);
+            num_checkpoints += 1;
+        }
+        assert!(num_checkpoints > 0);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    #[ignore = "Runs in a child process of 'test_checkpoint_during_add_next_block'"]
+    fn create_checkpoints() {
+        let Some(dir) = child_dir() else { return };
+        let rng = &mut TestRng::default();
+
+        // Initialize the ledger.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let storage_mode = StorageMode::from(dir.join("ledger"));
+        let store = CurrentConsensusStore::open(storage_mode.clone()).unwrap();
+        let genesis = VM::from(store).unwrap().genesis_beacon(&private_key, rng).unwrap();
+        let ledger = Arc::new(CurrentLedger::load(genesis.clone(), storage_mode).unwrap());
+        // Write the genesis block and the private key, to load the checkpoints.
+        fs::write(dir.join("genesis"), genesis.to_bytes_le().unwrap()).unwrap();
+        fs::write(dir.join("private_key"), private_key.to_string()).unwrap();
+
+        // Add the blocks in the background.
+        let is_done = Arc::new(AtomicBool::new(false));
+        let handle = {
+            let (ledger, is_done) = (ledger.clone(), is_done.clone());
+            std::thread::spawn(move || {
+                let rng = &mut TestRng::default();
+                for _ in 0..NUM_BLOCKS {
+                    let block =
+                        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+                    ledger.advance_to_next_block(&block).unwrap();
+                }
+                is_done.store(true, Ordering::SeqCst);
+            })
+        };
+
+        // Take checkpoints until the blocks are added, alternating between the checkpoints of the ledger, which
+        // are taken between blocks, and the checkpoints of the VM storage, which may be taken while a block is added.
+        for index in 0.. {
+            let is_last = is_done.load(Ordering::SeqCst);
+            let checkpoint = dir.join("checkpoints").join(index.to_string());
+            fs::create_dir_all(&checkpoint).unwrap();
+            let min_height = ledger.latest_height();
+            let max_height = if index % 2 == 0 {
+                ledger.create_checkpoint(checkpoint.join("checkpoint")).unwrap();
+                ledger.latest_height()
+            } else {
+                ledger.vm().create_checkpoint(&checkpoint.join("checkpoint")).unwrap();
+                // The VM may have added a block, before the ledger is updated.
+                ledger.latest_height() + 1
+            };
+            // Write the heights the checkpoint may be taken at.
+            fs::write(checkpoint.join("heights"), format!("{min_height} {max_height}")).unwrap();
+            if is_last {
+                break;
+            }
+        }
+        handle.join().unwrap();
+        assert_eq!(ledger.latest_height(), NUM_BLOCKS);
+    }
+
+    #[test]
+    #[ignore = "Runs in a child process of 'test_checkpoint_during_add_next_block'"]
+    fn load_checkpoint() {
+        let Some(dir) = child_dir() else { return };
+        let rng = &mut TestRng::default();
+
+        // Read the genesis block, the private key, and the heights the checkpoint may be taken at.
+        let parent_dir = dir.parent().and_then(Path::parent).unwrap();
+        let genesis = Block::<CurrentNetwork>::from_bytes_le(&fs::read(parent_dir.join("genesis")).unwrap()).unwrap();
+        let private_key = fs::read_to_string(parent_dir.join("private_key")).unwrap();
+        let private_key = PrivateKey::<CurrentNetwork>::from_str(&private_key).unwrap();
+        let heights = fs::read_to_string(dir.join("heights")).unwrap();
+        let (min_height, max_height) = heights.split_once(' ').unwrap();
+        let heights = min_height.parse::<u32>().unwrap()..=max_height.parse::<u32>().unwrap();
+
+        // Restore the checkpoint, and load the ledger.
+        let storage_mode = StorageMode::from(dir.join("ledger"));
+        let ledger = CurrentLedger::load_from_checkpoint(genesis, dir.join("checkpoint"), storage_mode).unwrap();
+        // Ensure the checkpoint contains whole blocks.
+        let height = ledger.latest_height();
+        assert!(heights.contains(&height), "The checkpoint is at height {height}, expected {heights:?}");
+        assert_eq!(ledger.vm().block_store().max_height(), Some(height));
+        assert_eq!(ledger.vm().finalize_store().committee_store().current_height().unwrap(), height);
+
+        // Ensure the ledger advances from the checkpoint.
+        let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+        assert_eq!(ledger.latest_height(), height + 1);
+    }
+}
@@ -24,19 +24,34 @@ use crate::{
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{Map, MapRead, NestedMap, NestedMapRead},
 };
 use console::{
     network::prelude::*,
-    program::{BlockTree, HeaderLeaf, ProgramID, StatePath},
-    types::Field,
+    program::{
+        Argument,
+        BlockTree,
+        HeaderLeaf,
+        Identifier,
+        Literal,
+        Owner,
+        Plaintext,
+        ProgramID,
+        StatePath,
+        TRANSACTIONS_DEPTH,
+        TransactionsPath,
+    },
+    types::{Address, Field},
 };
 use ledger_authority::Authority;
 use ledger_block::{
     Block,
     ConfirmedTransaction,
     Header,
+    Input,
+    Output,
     Ratifications,
+    Ratify,
     Rejected,
     Solutions,
     Transaction,
@@ -48,12 +63,153 @@ use synthesizer_program::{FinalizeOperation, Program};
 
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
+use indexmap::IndexSet;
 use parking_lot::RwLock;
 use std::{borrow::Cow, sync::Arc};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+const PRUNED_HEIGHT_KEY: u8 = 0;
+const PRUNING_DEPTH_KEY: u8 = 0;
+const TRANSITION_INDEX_HEIGHT_KEY: u8 = 0;
+const TRANSITION_INDEX_START_KEY: u8 = 1;
+const TRANSITION_INDEX_NEXT_KEY: u8 = 2;
+
+/// The maximum number of blocks to prune in a single atomic batch.
+pub const PRUNING_BATCH_SIZE: u32 = 100;
+/// The maximum number of blocks to index in a single atomic batch, when enabling the transition indexes.
+pub const TRANSITION_INDEX_BATCH_SIZE: u32 = 100;
+
+/// The position of a transition in the ledger, as `(block height, transition index in the block)`.
+///
+/// Note: The position is serialized as big-endian bytes, so that the entries of a transition index
+/// are ordered chronologically, and a page of the index is a range seek from the previous position.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TransitionPosition(pub u32, pub u32);
+
+impl Serialize for TransitionPosition {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        let mut bytes = [0u8; 8];
+        bytes[..4].copy_from_slice(&self.0.to_be_bytes());
+        bytes[4..].copy_from_slice(&self.1.to_be_bytes());
+        bytes.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TransitionPosition {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        let bytes = <[u8; 8]>::deserialize(deserializer)?;
+        let block_height = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        let index = u32::from_be_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+        Ok(Self(block_height, index))
+    }
+}
+
+/// The secondary index entries of a transition.
+struct TransitionIndexEntries<N: Network> {
+    /// The transition ID.
+    transition_id: N::TransitionID,
+    /// The position of the transition.
+    position: TransitionPosition,
+    /// The program ID and function name of the transition.
+    locator: (ProgramID<N>, Identifier<N>),
+    /// The public fee payer of the transaction, if the fee is public.
+    fee_payer: Option<Address<N>>,
+    /// The addresses in the public inputs and outputs of the transition.
+    addresses: IndexSet<Address<N>>,
+}
+
+impl<N: Network> TransitionIndexEntries<N> {
+    /// Stores the index entries in the given block storage.
+    fn insert<B: BlockStorage<N>>(self, storage: &B) -> Result<()> {
+        for address in self.addresses {
+            storage.address_index_map().insert(address, self.position, self.transition_id)?;
+        }
+        if let Some(fee_payer) = self.fee_payer {
+            storage.fee_payer_index_map().insert(fee_payer, self.position, self.transition_id)?;
+        }
+        storage.function_index_map().insert(self.locator, self.position, self.transition_id)
+    }
+
+    /// Removes the index entries from the given block storage.
+    fn remove<B: BlockStorage<N>>(self, storage: &B) -> Result<()> {
+        for address in self.addresses {
+            storage.address_index_map().remove_key(&address, &self.position)?;
+        }
+        if let Some(fee_payer) = self.fee_payer {
+            storage.fee_payer_index_map().remove_key(&fee_payer, &self.position)?;
+        }
+        storage.function_index_map().remove_key(&self.locator, &self.position)
+    }
+}
+
+/// Returns the secondary index entries for the transitions in the given block transactions.
+fn to_transition_index_entries<N: Network>(
+    block_height: u32,
+    transactions: &Transactions<N>,
+) -> Result<Vec<TransitionIndexEntries<N>>> {
+    /// Collects the addresses in the given plaintext.
+    fn collect_plaintext<N: Network>(plaintext: &Plaintext<N>, addresses: &mut IndexSet<Address<N>>) {
+        match plaintext {
+            Plaintext::Literal(Literal::Address(address), _) => {
+                addresses.insert(*address);
+            }
+            Plaintext::Literal(..) => (),
+            Plaintext::Struct(members, _) => members.values().for_each(|member| collect_plaintext(member, addresses)),
+            Plaintext::Array(elements, _) => elements.iter().for_each(|element| collect_plaintext(element, addresses)),
+        }
+    }
+
+    /// Collects the addresses in the given future arguments.
+    fn collect_arguments<N: Network>(arguments: &[Argument<N>], addresses: &mut IndexSet<Address<N>>) {
+        for argument in arguments {
+            match argument {
+                Argument::Plaintext(plaintext) => collect_plaintext(plaintext, addresses),
+                Argument::Future(future) => collect_arguments(future.arguments(), addresses),
+            }
+        }
+    }
+
+    let mut entries = Vec::new();
+    for confirmed in transactions.iter() {
+        // Retrieve the public fee payer of the transaction, if the fee is public.
+        // Note: The signer of a transition is not public, so the transitions are indexed by the fee payer instead.
+        let fee_payer = confirmed.transaction().fee_transition().and_then(|fee| fee.payer());
+        // Collect the index entries of each transition.
+        for transition in confirmed.transaction().transitions() {
+            // Collect the addresses in the public inputs and outputs.
+            let mut addresses = IndexSet::new();
+            for input in transition.inputs() {
+                if let Input::Public(_, Some(plaintext)) = input {
+                    collect_plaintext(plaintext, &mut addresses);
+                }
+            }
+            for output in transition.outputs() {
+                match output {
+                    Output::Public(_, Some(plaintext)) => collect_plaintext(plaintext, &mut addresses),
+                    Output::Record(_, _, Some(record)) => {
+                        if let Owner::Public(owner) = record.owner() {
+                            addresses.insert(*owner);
+                        }
+                    }
+                    Output::Future(_, Some(future)) => collect_arguments(future.arguments(), &mut addresses),
+                    _ => (),
+                }
+            }
+            // Store the index entries.
+            entries.push(TransitionIndexEntries {
+                transition_id: *transition.id(),
+                position: TransitionPosition(block_height, u32::try_from(entries.len())?),
+                locator: (*transition.program_id(), *transition.function_name()),
+                fee_payer,
+                addresses,
+            });
+        }
+    }
+    Ok(entries)
+}
+
 /// Separates the confirmed transaction into a tuple.
 #[allow(clippy::type_complexity)]
 fn to_confirmed_tuple<N: Network>(
@@ -140,6 +296,21 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type ConfirmedTransactionsMap: for<'a> Map<'a, N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     /// The rejected deployment or execution map.
     type RejectedDeploymentOrExecutionMap: for<'a> Map<'a, Field<N>, Rejected<N>>;
+    /// The mapping of a constant key to the height of the latest pruned block.
+    type PrunedHeightMap: for<'a> Map<'a, u8, u32>;
+    /// The mapping of a constant key to the number of most recent blocks to keep unpruned.
+    type PruningDepthMap: for<'a> Map<'a, u8, u32>;
+    /// The mapping of public `address` to `transition position` to `transition ID`.
+    type AddressIndexMap: for<'a> NestedMap<'a, Address<N>, TransitionPosition, N::TransitionID>;
+    /// The mapping of public fee payer `address` to `transition position` to `transition ID`.
+    type FeePayerIndexMap: for<'a> NestedMap<'a, Address<N>, TransitionPosition, N::TransitionID>;
+    /// The mapping of `(program ID, function name)` to `transition position` to `transition ID`.
+    type FunctionIndexMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), TransitionPosition, N::TransitionID>;
+    /// The mapping of constant keys to the block height from which transitions are indexed,
+    /// and to the start and next block height of the backfill while the indexes are being enabled.
+    type TransitionIndexHeightMap: for<'a> Map<'a, u8, u32>;
+    /// The mapping of `block height` to `restrictions update`.
+    type RestrictionsMap: for<'a> Map<'a, u32, Ratify<N>>;
     /// The transaction storage.
     type TransactionStorage: TransactionStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
@@ -182,6 +353,20 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn confirmed_transactions_map(&self) -> &Self::ConfirmedTransactionsMap;
     /// Returns the rejected deployment or execution map.
     fn rejected_deployment_or_execution_map(&self) -> &Self::RejectedDeploymentOrExecutionMap;
+    /// Returns the pruned height map.
+    fn pruned_height_map(&self) -> &Self::PrunedHeightMap;
+    /// Returns the pruning depth map.
+    fn pruning_depth_map(&self) -> &Self::PruningDepthMap;
+    /// Returns the address index map.
+    fn address_index_map(&self) -> &Self::AddressIndexMap;
+    /// Returns the fee payer index map.
+    fn fee_payer_index_map(&self) -> &Self::FeePayerIndexMap;
+    /// Returns the function index map.
+    fn function_index_map(&self) -> &Self::FunctionIndexMap;
+    /// Returns the transition index height map.
+    fn transition_index_height_map(&self) -> &Self::TransitionIndexHeightMap;
+    /// Returns the restrictions map.
+    fn restrictions_map(&self) -> &Self::RestrictionsMap;
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage>;
 
@@ -214,6 +399,13 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().start_atomic();
         self.confirmed_transactions_map().start_atomic();
         self.rejected_deployment_or_execution_map().start_atomic();
+        self.pruned_height_map().start_atomic();
+        self.pruning_depth_map().start_atomic();
+        self.address_index_map().start_atomic();
+        self.fee_payer_index_map().start_atomic();
+        self.function_index_map().start_atomic();
+        self.transition_index_height_map().start_atomic();
+        self.restrictions_map().start_atomic();
         self.transaction_store().start_atomic();
     }
 
@@ -236,6 +428,13 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.rejected_or_aborted_transaction_id_map().is_atomic_in_progress()
             || self.confirmed_transactions_map().is_atomic_in_progress()
             || self.rejected_deployment_or_execution_map().is_atomic_in_progress()
+            || self.pruned_height_map().is_atomic_in_progress()
+            || self.pruning_depth_map().is_atomic_in_progress()
+            || self.address_index_map().is_atomic_in_progress()
+            || self.fee_payer_index_map().is_atomic_in_progress()
+            || self.function_index_map().is_atomic_in_progress()
+            || self.transition_index_height_map().is_atomic_in_progress()
+            || self.restrictions_map().is_atomic_in_progress()
             || self.transaction_store().is_atomic_in_progress()
     }
 
@@ -258,6 +457,13 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_checkpoint();
         self.confirmed_transactions_map().atomic_checkpoint();
         self.rejected_deployment_or_execution_map().atomic_checkpoint();
+        self.pruned_height_map().atomic_checkpoint();
+        self.pruning_depth_map().atomic_checkpoint();
+        self.address_index_map().atomic_checkpoint();
+        self.fee_payer_index_map().atomic_checkpoint();
+        self.function_index_map().atomic_checkpoint();
+        self.transition_index_height_map().atomic_checkpoint();
+        self.restrictions_map().atomic_checkpoint();
         self.transaction_store().atomic_checkpoint();
     }
 
@@ -280,6 +486,13 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().clear_latest_checkpoint();
         self.confirmed_transactions_map().clear_latest_checkpoint();
         self.rejected_deployment_or_execution_map().clear_latest_checkpoint();
+        self.pruned_height_map().clear_latest_checkpoint();
+        self.pruning_depth_map().clear_latest_checkpoint();
+        self.address_index_map().clear_latest_checkpoint();
+        self.fee_payer_index_map().clear_latest_checkpoint();
+        self.function_index_map().clear_latest_checkpoint();
+        self.transition_index_height_map().clear_latest_checkpoint();
+        self.restrictions_map().clear_latest_checkpoint();
         self.transaction_store().clear_latest_checkpoint();
     }
 
@@ -302,6 +515,13 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_rewind();
         self.confirmed_transactions_map().atomic_rewind();
         self.rejected_deployment_or_execution_map().atomic_rewind();
+        self.pruned_height_map().atomic_rewind();
+        self.pruning_depth_map().atomic_rewind();
+        self.address_index_map().atomic_rewind();
+        self.fee_payer_index_map().atomic_rewind();
+        self.function_index_map().atomic_rewind();
+        self.transition_index_height_map().atomic_rewind();
+        self.restrictions_map().atomic_rewind();
         self.transaction_store().atomic_rewind();
     }
 
@@ -324,6 +544,13 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().abort_atomic();
         self.confirmed_transactions_map().abort_atomic();
         self.rejected_deployment_or_execution_map().abort_atomic();
+        self.pruned_height_map().abort_atomic();
+        self.pruning_depth_map().abort_atomic();
+        self.address_index_map().abort_atomic();
+        self.fee_payer_index_map().abort_atomic();
+        self.function_index_map().abort_atomic();
+        self.transition_index_height_map().abort_atomic();
+        self.restrictions_map().abort_atomic();
         self.transaction_store().abort_atomic();
     }
 
@@ -346,6 +573,13 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().finish_atomic()?;
         self.confirmed_transactions_map().finish_atomic()?;
         self.rejected_deployment_or_execution_map().finish_atomic()?;
+        self.pruned_height_map().finish_atomic()?;
+        self.pruning_depth_map().finish_atomic()?;
+        self.address_index_map().finish_atomic()?;
+        self.fee_payer_index_map().finish_atomic()?;
+        self.function_index_map().finish_atomic()?;
+        self.transition_index_height_map().finish_atomic()?;
+        self.restrictions_map().finish_atomic()?;
         self.transaction_store().finish_atomic()
     }
 
@@ -389,6 +623,12 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             .map(|tx| tx.to_unconfirmed_transaction_id())
             .collect::<Result<Vec<_>>>()?;
 
+        // Prepare the transition index entries, if the transitions are indexed.
+        let transition_index_entries = match self.get_transition_index_height()? {
+            Some(_) => to_transition_index_entries(block.height(), block.transactions())?,
+            None => Vec::new(),
+        };
+
         atomic_batch_scope!(self, {
             // Store the (block height, state root) pair.
             self.state_root_map().insert(block.height(), state_root)?;
@@ -412,6 +652,13 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
 
             // Store the block ratifications.
             self.ratifications_map().insert(block.hash(), block.ratifications().clone())?;
+            // Store the restrictions update.
+            // Note: The restrictions updates are retained when the block is pruned.
+            for ratify in block.ratifications().iter() {
+                if let Ratify::Restrictions(..) = ratify {
+                    self.restrictions_map().insert(block.height(), ratify.clone())?;
+                }
+            }
 
             // Store the block solutions.
             self.solutions_map().insert(block.hash(), block.solutions().clone())?;
@@ -458,6 +705,9 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.transaction_store().insert(&transaction)?;
             }
 
+            // Store the transition index entries.
+            transition_index_entries.into_iter().try_for_each(|entries| entries.insert(self))?;
+
             Ok(())
         })
     }
@@ -493,8 +743,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         // Retrieve the aborted transaction IDs.
         let aborted_transaction_ids = (self.get_block_aborted_transaction_ids(block_hash)?).unwrap_or_default();
 
+        // Retrieve the block transactions.
+        let transactions = self.get_block_transactions(block_hash)?;
+
         // Retrieve the rejected transaction IDs, and the deployment or execution ID.
-        let rejected_transaction_ids_and_deployment_or_execution_id = match self.get_block_transactions(block_hash)? {
+        let rejected_transaction_ids_and_deployment_or_execution_id = match &transactions {
             Some(transactions) => transactions
                 .iter()
                 .filter(|tx| tx.is_rejected())
@@ -503,6 +756,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             None => Vec::new(),
         };
 
+        // Prepare the transition index entries to remove, if the transitions of the block are indexed.
+        let transition_index_entries = match &transactions {
+            Some(transactions) if self.is_transition_indexed(block_height)? => {
+                to_transition_index_entries(block_height, transactions)?
+            }
+            _ => Vec::new(),
+        };
+
         // Determine the certificate IDs to remove.
         let certificate_ids_to_remove = match self.authority_map().get_confirmed(block_hash)? {
             Some(authority) => match authority {
@@ -537,6 +798,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
 
             // Remove the block ratifications.
             self.ratifications_map().remove(block_hash)?;
+            // Remove the restrictions update.
+            self.restrictions_map().remove(&block_height)?;
 
             // Remove the block solutions.
             self.solutions_map().remove(block_hash)?;
@@ -581,10 +844,172 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.transaction_store().remove(transaction_id)?;
             }
 
+            // Remove the transition index entries.
+            transition_index_entries.into_iter().try_for_each(|entries| entries.remove(self))?;
+
             Ok(())
         })
     }
 
+    /// Prunes the transactions of the blocks after the latest pruned block, up to (and including) the given
+    /// `block height`, by removing their proofs and transition values. To bound the size of the atomic batch,
+    /// at most `PRUNING_BATCH_SIZE` blocks are pruned, and the height of the latest pruned block is returned.
+    /// Note: The block headers, transaction IDs, serial numbers, tags, and commitments are kept in storage,
+    /// so that state paths can still be constructed for the commitments in the pruned blocks.
+    fn prune_batch(&self, block_height: u32) -> Result<Option<u32>> {
+        // Determine the first block height to prune.
+        let start_height = match self.get_pruned_height()? {
+            Some(pruned_height) => pruned_height.saturating_add(1),
+            None => 0,
+        };
+        // If the blocks are already pruned, there is nothing to prune.
+        if start_height > block_height {
+            return self.get_pruned_height();
+        }
+        // Determine the last block height to prune in this batch.
+        let end_height = block_height.min(start_height.saturating_add(PRUNING_BATCH_SIZE - 1));
+
+        // Retrieve the transaction IDs to prune.
+        let mut transaction_ids = Vec::new();
+        for height in start_height..=end_height {
+            // Retrieve the block hash.
+            let block_hash = match self.get_block_hash(height)? {
+                Some(block_hash) => block_hash,
+                None => bail!("Failed to prune block {height}: missing block hash"),
+            };
+            // Retrieve the transaction IDs.
+            match self.transactions_map().get_confirmed(&block_hash)? {
+                Some(ids) => transaction_ids.extend(cow_to_cloned!(ids)),
+                None => bail!("Failed to prune block {height}: missing transactions for block '{block_hash}'"),
+            }
+        }
+
+        atomic_batch_scope!(self, {
+            // Prune the transactions.
+            for transaction_id in transaction_ids.iter() {
+                self.transaction_store().prune(transaction_id)?;
+            }
+            // Update the pruned height.
+            self.pruned_height_map().insert(PRUNED_HEIGHT_KEY, end_height)?;
+
+            Ok(())
+        })?;
+
+        Ok(Some(end_height))
+    }
+
+    /// Returns the height of the latest pruned block, if any blocks have been pruned.
+    fn get_pruned_height(&self) -> Result<Option<u32>> {
+        match self.pruned_height_map().get_confirmed(&PRUNED_HEIGHT_KEY)? {
+            Some(block_height) => Ok(Some(cow_to_copied!(block_height))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the number of most recent blocks to keep unpruned, if pruning is enabled.
+    fn get_pruning_depth(&self) -> Result<Option<u32>> {
+        match self.pruning_depth_map().get_confirmed(&PRUNING_DEPTH_KEY)? {
+            Some(pruning_depth) => Ok(Some(cow_to_copied!(pruning_depth))),
+            None => Ok(None),
+        }
+    }
+
+    /// Stores the number of most recent blocks to keep unpruned, or disables pruning if `None`.
+    fn set_pruning_depth(&self, pruning_depth: Option<u32>) -> Result<()> {
+        match pruning_depth {
+            Some(pruning_depth) => self.pruning_depth_map().insert(PRUNING_DEPTH_KEY, pruning_depth),
+            None => self.pruning_depth_map().remove(&PRUNING_DEPTH_KEY),
+        }
+    }
+
+    /// Indexes the transitions of the next batch of at most `TRANSITION_INDEX_BATCH_SIZE` unpruned blocks,
+    /// up to (and including) the given latest block height, and enables the transition indexes once every
+    /// block is indexed, so that they are kept up to date as blocks are inserted and removed.
+    /// Returns `true` if the transition indexes are enabled.
+    ///
+    /// Note: The public values of pruned transitions are no longer in storage,
+    /// so the indexes start from the first unpruned block.
+    fn index_transitions_batch(&self, latest_height: u32) -> Result<bool> {
+        // If the transitions are already indexed, there is nothing to do.
+        if self.get_transition_index_height()?.is_some() {
+            return Ok(true);
+        }
+
+        // Retrieve the first block height of the backfill, and the next block height to index.
+        let pruned_height = self.get_pruned_height()?;
+        let start_height = match self.transition_index_height_map().get_confirmed(&TRANSITION_INDEX_START_KEY)? {
+            Some(start_height) => cow_to_copied!(start_height),
+            None => pruned_height.map_or(0, |pruned_height| pruned_height.saturating_add(1)),
+        };
+        let next_height = match self.transition_index_height_map().get_confirmed(&TRANSITION_INDEX_NEXT_KEY)? {
+            Some(next_height) => cow_to_copied!(next_height),
+            None => start_height,
+        };
+        let end_height = latest_height.min(next_height.saturating_add(TRANSITION_INDEX_BATCH_SIZE - 1));
+
+        // Prepare the transition index entries of each block in the batch.
+        let mut transition_index_entries = Vec::new();
+        for height in next_height..=end_height {
+            // Skip the blocks that were pruned since the backfill started.
+            if pruned_height.is_some_and(|pruned_height| height <= pruned_height) {
+                continue;
+            }
+            // Retrieve the block hash.
+            let block_hash = match self.get_block_hash(height)? {
+                Some(block_hash) => block_hash,
+                None => bail!("Failed to index block {height}: missing block hash"),
+            };
+            // Retrieve the block transactions.
+            let transactions = match self.get_block_transactions(&block_hash)? {
+                Some(transactions) => transactions,
+                None => bail!("Failed to index block {height}: missing transactions for block '{block_hash}'"),
+            };
+            transition_index_entries.extend(to_transition_index_entries(height, &transactions)?);
+        }
+        let is_complete = end_height >= latest_height;
+
+        atomic_batch_scope!(self, {
+            // Store the transition index entries.
+            transition_index_entries.into_iter().try_for_each(|entries| entries.insert(self))?;
+            match is_complete {
+                // Set the transition index height, once every block is indexed.
+                true => {
+                    self.transition_index_height_map().insert(TRANSITION_INDEX_HEIGHT_KEY, start_height)?;
+                    self.transition_index_height_map().remove(&TRANSITION_INDEX_START_KEY)?;
+                    self.transition_index_height_map().remove(&TRANSITION_INDEX_NEXT_KEY)?;
+                }
+                // Otherwise, store the progress of the backfill, so that it resumes from the next block.
+                false => {
+                    self.transition_index_height_map().insert(TRANSITION_INDEX_START_KEY, start_height)?;
+                    self.transition_index_height_map().insert(TRANSITION_INDEX_NEXT_KEY, end_height + 1)?;
+                }
+            }
+
+            Ok(())
+        })?;
+        Ok(is_complete)
+    }
+
+    /// Returns `true` if the transitions of the block at the given `block height` are indexed,
+    /// including the blocks that are already indexed by an unfinished backfill.
+    fn is_transition_indexed(&self, block_height: u32) -> Result<bool> {
+        if let Some(index_height) = self.get_transition_index_height()? {
+            return Ok(block_height >= index_height);
+        }
+        match self.transition_index_height_map().get_confirmed(&TRANSITION_INDEX_NEXT_KEY)? {
+            Some(next_height) => Ok(block_height < cow_to_copied!(next_height)),
+            None => Ok(false),
+        }
+    }
+
+    /// Returns the block height from which transitions are indexed, if the transition indexes are enabled.
+    fn get_transition_index_height(&self) -> Result<Option<u32>> {
+        match self.transition_index_height_map().get_confirmed(&TRANSITION_INDEX_HEIGHT_KEY)? {
+            Some(block_height) => Ok(Some(cow_to_copied!(block_height))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns `true` if the given transaction ID exists.
     fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool> {
         Ok(self.transaction_store().contains_transaction_id(transaction_id)?
@@ -662,7 +1087,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         };
 
         // Retrieve the transition.
-        let transition = match self.transition_store().get_transition(&transition_id)? {
+        let transition = match self.transition_store().get_transition_allow_pruned(&transition_id)? {
             Some(transition) => transition,
             None => bail!("The transition '{transition_id}' for commitment '{commitment}' is missing in storage"),
         };
@@ -865,6 +1290,29 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             .collect::<Result<Option<Transactions<_>>>>()
     }
 
+    /// Returns the Merkle path of the given `transaction ID` to the transactions root of the given `block hash`.
+    fn get_block_transactions_path(
+        &self,
+        block_hash: &N::BlockHash,
+        transaction_id: &N::TransactionID,
+    ) -> Result<Option<TransactionsPath<N>>> {
+        // Retrieve the transaction IDs.
+        let transaction_ids = match self.transactions_map().get_confirmed(block_hash)? {
+            Some(transaction_ids) => transaction_ids,
+            None => return Ok(None),
+        };
+        // Retrieve the index of the transaction ID.
+        let Some(index) = transaction_ids.iter().position(|id| id == transaction_id) else {
+            return Ok(None);
+        };
+        // Compute the transactions tree from the transaction IDs.
+        // Note: The leaves match those of `Transactions::to_tree`, so the transactions need not be retrieved.
+        let leaves = transaction_ids.iter().map(|id| id.to_bits_le()).collect::<Vec<_>>();
+        let transactions_tree = N::merkle_tree_bhp::<TRANSACTIONS_DEPTH>(&leaves)?;
+        // Return the Merkle path.
+        Ok(Some(transactions_tree.prove(index, &leaves[index])?))
+    }
+
     /// Returns the block aborted transaction IDs for the given `block hash`.
     fn get_block_aborted_transaction_ids(&self, block_hash: &N::BlockHash) -> Result<Option<Vec<N::TransactionID>>> {
         match self.aborted_transaction_ids_map().get_confirmed(block_hash)? {
@@ -999,6 +1447,8 @@ pub struct BlockStore<N: Network, B: BlockStorage<N>> {
     storage: B,
     /// The block tree.
     tree: Arc<RwLock<BlockTree<N>>>,
+    /// The number of most recent blocks to keep unpruned, if pruning is enabled.
+    pruning_depth: Arc<RwLock<Option<u32>>>,
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
@@ -1020,8 +1470,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
             Arc::new(RwLock::new(N::merkle_tree_bhp(&hashes)?))
         };
 
+        // Load the pruning depth.
+        let pruning_depth = Arc::new(RwLock::new(storage.get_pruning_depth()?));
+
         // Return the block store.
-        Ok(Self { storage, tree })
+        Ok(Self { storage, tree, pruning_depth })
     }
 
     /// Stores the given block into storage.
@@ -1034,8 +1487,82 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         if block.height() != u32::try_from(updated_tree.number_of_leaves())? - 1 {
             bail!("Attempted to insert a block at the incorrect height into storage")
         }
-        // Insert the (state root, block height) pair.
-        self.storage.insert((*updated_tree.root()).into(), block)?;
+        // Determine the block height to prune to, if pruning is enabled.
+        let prune_height = self.pruning_depth.read().and_then(|depth| block.height().checked_sub(depth));
+
+        atomic_batch_scope!(self, {
+            // Insert the (state root, block height) pair.
+            self.storage.insert((*updated_tree.root()).into(), block)?;
+            // Prune the next batch of blocks that are older than the pruning depth.
+            if let Some(prune_height) = prune_height {
+                self.storage.prune_batch(prune_height)?;
+            }
+            Ok(())
+        })?;
+
+        // Update the block tree.
+        *tree = updated_tree;
+        // Return success.
+        Ok(())
+    }
+
+    /// Stores the given block into storage, along with the block hashes (and state roots) of its ancestors,
+    /// without storing the ancestor blocks themselves. This is used to bootstrap the block store from a snapshot.
+    ///
+    /// The given `ancestor_hashes` must contain the block hashes from genesis up to (excluding) the given block,
+    /// and must extend the block hashes that are already in storage. As the ancestor blocks are not stored,
+    /// they are marked as pruned.
+    pub fn insert_with_ancestor_hashes(&self, ancestor_hashes: &[N::BlockHash], block: &Block<N>) -> Result<()> {
+        // Acquire the write lock on the block tree.
+        let mut tree = self.tree.write();
+
+        // Ensure the number of ancestor hashes matches the block height.
+        ensure!(
+            ancestor_hashes.len() == block.height() as usize,
+            "Expected {} ancestor block hashes for block {}, found {}",
+            block.height(),
+            block.height(),
+            ancestor_hashes.len()
+        );
+        // Retrieve the number of blocks in storage.
+        let num_blocks = tree.number_of_leaves();
+        ensure!(
+            num_blocks <= ancestor_hashes.len(),
+            "Attempted to insert a block at the incorrect height into storage"
+        );
+        // Ensure the ancestor hashes extend the block hashes in storage.
+        for (height, hash) in ancestor_hashes.iter().enumerate().take(num_blocks) {
+            if self.storage.get_block_hash(u32::try_from(height)?)?.as_ref() != Some(hash) {
+                bail!("The ancestor hash for block {height} does not match the block hash in storage")
+            }
+        }
+
+        // Compute the state root after each of the missing ancestor blocks.
+        let mut updated_tree = tree.clone();
+        let mut ancestors = Vec::with_capacity(ancestor_hashes.len() - num_blocks);
+        for (height, hash) in ancestor_hashes.iter().enumerate().skip(num_blocks) {
+            updated_tree = updated_tree.prepare_append(&[hash.to_bits_le()])?;
+            ancestors.push((u32::try_from(height)?, *hash, N::StateRoot::from(*updated_tree.root())));
+        }
+        // Prepare an updated Merkle tree containing the new block hash.
+        let updated_tree = updated_tree.prepare_append(&[block.hash().to_bits_le()])?;
+
+        atomic_batch_scope!(self, {
+            for (height, hash, state_root) in ancestors {
+                // Store the block hash and state root of the ancestor block.
+                self.storage.state_root_map().insert(height, state_root)?;
+                self.storage.reverse_state_root_map().insert(state_root, height)?;
+                self.storage.id_map().insert(height, hash)?;
+                self.storage.reverse_id_map().insert(hash, height)?;
+            }
+            // Mark the ancestor blocks as pruned.
+            if let Some(pruned_height) = block.height().checked_sub(1) {
+                self.storage.pruned_height_map().insert(PRUNED_HEIGHT_KEY, pruned_height)?;
+            }
+            // Insert the block.
+            self.storage.insert((*updated_tree.root()).into(), block)
+        })?;
+
         // Update the block tree.
         *tree = updated_tree;
         // Return success.
@@ -1073,6 +1600,13 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
                     .ok_or_else(|| anyhow!("Failed to remove last '{n}' blocks: block height underflow"))?;
                 // Ensure the block height matches the number of leaves in the Merkle tree.
                 ensure!(end_height == u32::try_from(tree.number_of_leaves())? - 1, "Block height mismatch");
+                // Ensure the blocks to remove have not been pruned.
+                if let Some(pruned_height) = self.storage.get_pruned_height()? {
+                    ensure!(
+                        start_height > pruned_height,
+                        "Failed to remove last '{n}' blocks: blocks up to {pruned_height} have been pruned"
+                    );
+                }
                 // Output the block heights.
                 start_height..=end_height
             }
@@ -1103,6 +1637,134 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         Ok(())
     }
 
+    /// Sets the number of most recent blocks to keep unpruned, or disables pruning if `None`.
+    /// When enabled, the next batch of older blocks is pruned as each new block is inserted.
+    /// The pruning depth is persisted, and restored when the block store is reopened.
+    pub fn set_pruning_depth(&self, pruning_depth: Option<u32>) -> Result<()> {
+        // Ensure the pruning depth is non-zero, as the latest block is never pruned.
+        if let Some(depth) = pruning_depth {
+            ensure!(depth > 0, "The pruning depth must be greater than zero");
+        }
+        // Acquire the write lock on the pruning depth.
+        let mut current_pruning_depth = self.pruning_depth.write();
+        // Persist the pruning depth, so it is retained when the block store is reopened.
+        self.storage.set_pruning_depth(pruning_depth)?;
+        *current_pruning_depth = pruning_depth;
+        Ok(())
+    }
+
+    /// Returns the number of most recent blocks to keep unpruned, if pruning is enabled.
+    pub fn pruning_depth(&self) -> Option<u32> {
+        *self.pruning_depth.read()
+    }
+
+    /// Prunes the transactions of the next batch of at most `PRUNING_BATCH_SIZE` blocks,
+    /// up to (and including) the given `block height`, and returns the height of the latest pruned block.
+    pub fn prune_batch(&self, block_height: u32) -> Result<Option<u32>> {
+        // Acquire the write lock on the block tree, to ensure no blocks are inserted during the batch.
+        let tree = self.tree.write();
+        // Ensure the latest block is not pruned.
+        let latest_height = u32::try_from(tree.number_of_leaves())?.saturating_sub(1);
+        ensure!(block_height < latest_height, "Cannot prune block {block_height}: the latest block cannot be pruned");
+        self.storage.prune_batch(block_height)
+    }
+
+    /// Returns the height of the latest pruned block, if any blocks have been pruned.
+    pub fn pruned_height(&self) -> Result<Option<u32>> {
+        self.storage.get_pruned_height()
+    }
+
+    /// Indexes the transitions of the next batch of at most `TRANSITION_INDEX_BATCH_SIZE` unpruned blocks,
+    /// and enables the transition indexes once every block is indexed. Returns `true` if the indexes are enabled.
+    pub fn index_transitions_batch(&self) -> Result<bool> {
+        // Acquire the read lock on the block tree, to ensure no blocks are inserted or removed during the batch.
+        let tree = self.tree.read();
+        // Retrieve the latest block height.
+        let latest_height = u32::try_from(tree.number_of_leaves())?.saturating_sub(1);
+        self.storage.index_transitions_batch(latest_height)
+    }
+
+    /// Returns the block height from which transitions are indexed, if the transition indexes are enabled.
+    pub fn transition_index_height(&self) -> Result<Option<u32>> {
+        self.storage.get_transition_index_height()
+    }
+
+    /// Returns up to `limit` transition IDs with the given public `address` in their inputs or outputs,
+    /// in chronological order, starting after the given cursor, or from the first transition if no cursor is given.
+    /// The position of the last transition of a page is the cursor of the next page.
+    pub fn find_transition_ids_for_address(
+        &self,
+        address: &Address<N>,
+        cursor: Option<TransitionPosition>,
+        limit: usize,
+    ) -> Result<Vec<(TransitionPosition, N::TransitionID)>> {
+        self.ensure_transition_indexes_enabled()?;
+        self.storage.address_index_map().get_map_page_confirmed(address, cursor.as_ref(), limit)
+    }
+
+    /// Returns up to `limit` transition IDs in transactions with a public fee paid by the given `fee payer`,
+    /// in chronological order, starting after the given cursor, or from the first transition if no cursor is given.
+    /// The position of the last transition of a page is the cursor of the next page.
+    pub fn find_transition_ids_for_fee_payer(
+        &self,
+        fee_payer: &Address<N>,
+        cursor: Option<TransitionPosition>,
+        limit: usize,
+    ) -> Result<Vec<(TransitionPosition, N::TransitionID)>> {
+        self.ensure_transition_indexes_enabled()?;
+        self.storage.fee_payer_index_map().get_map_page_confirmed(fee_payer, cursor.as_ref(), limit)
+    }
+
+    /// Returns up to `limit` transition IDs for the given program function,
+    /// in chronological order, starting after the given cursor, or from the first transition if no cursor is given.
+    /// The position of the last transition of a page is the cursor of the next page.
+    pub fn find_transition_ids_for_function(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        cursor: Option<TransitionPosition>,
+        limit: usize,
+    ) -> Result<Vec<(TransitionPosition, N::TransitionID)>> {
+        self.ensure_transition_indexes_enabled()?;
+        self.storage.function_index_map().get_map_page_confirmed(&(*program_id, *function_name), cursor.as_ref(), limit)
+    }
+
+    /// Ensures the transition indexes are enabled.
+    fn ensure_transition_indexes_enabled(&self) -> Result<()> {
+        ensure!(
+            self.storage.get_transition_index_height()?.is_some(),
+            "Transition indexes are not enabled - use 'enable_transition_indexes'"
+        );
+        Ok(())
+    }
+
+    /// Ensures the block for the given `block height` has not been pruned.
+    fn ensure_not_pruned(&self, block_height: u32) -> Result<()> {
+        if let Some(pruned_height) = self.storage.get_pruned_height()? {
+            ensure!(
+                block_height > pruned_height,
+                "Block {block_height} has been pruned from storage (blocks up to {pruned_height} are pruned)"
+            );
+        }
+        Ok(())
+    }
+
+    /// Ensures the block for the given `block hash` has not been pruned.
+    fn ensure_block_hash_not_pruned(&self, block_hash: &N::BlockHash) -> Result<()> {
+        match self.storage.get_block_height(block_hash)? {
+            Some(block_height) => self.ensure_not_pruned(block_height),
+            None => Ok(()),
+        }
+    }
+
+    /// Ensures the block containing the given `transaction ID` has not been pruned.
+    fn ensure_transaction_not_pruned(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        match self.storage.find_block_hash(transaction_id)? {
+            Some(block_hash) => self.ensure_block_hash_not_pruned(&block_hash),
+            None => Ok(()),
+        }
+    }
+
     /// Returns the transaction store.
     pub fn transaction_store(&self) -> &TransactionStore<N, B::TransactionStorage> {
         self.storage.transaction_store()
@@ -1244,9 +1906,21 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
 
     /// Returns the block transactions for the given `block hash`.
     pub fn get_block_transactions(&self, block_hash: &N::BlockHash) -> Result<Option<Transactions<N>>> {
+        self.ensure_block_hash_not_pruned(block_hash)?;
         self.storage.get_block_transactions(block_hash)
     }
 
+    /// Returns the Merkle path of the given `transaction ID` to the transactions root of the given `block hash`.
+    ///
+    /// Note: The path is computed from the transaction IDs of the block, so it is available for pruned blocks.
+    pub fn get_block_transactions_path(
+        &self,
+        block_hash: &N::BlockHash,
+        transaction_id: &N::TransactionID,
+    ) -> Result<Option<TransactionsPath<N>>> {
+        self.storage.get_block_transactions_path(block_hash, transaction_id)
+    }
+
     /// Returns the block aborted transaction IDs for the given `block hash`.
     pub fn get_block_aborted_transaction_ids(
         &self,
@@ -1257,6 +1931,7 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
 
     /// Returns the transaction for the given `transaction ID`.
     pub fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
+        self.ensure_transaction_not_pruned(transaction_id)?;
         self.storage.get_transaction(transaction_id)
     }
 
@@ -1265,16 +1940,19 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         &self,
         transaction_id: &N::TransactionID,
     ) -> Result<Option<ConfirmedTransaction<N>>> {
+        self.ensure_transaction_not_pruned(transaction_id)?;
         self.storage.get_confirmed_transaction(*transaction_id)
     }
 
     /// Returns the unconfirmed transaction for the given `transaction ID`.
     pub fn get_unconfirmed_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
+        self.ensure_transaction_not_pruned(transaction_id)?;
         self.storage.get_unconfirmed_transaction(transaction_id)
     }
 
     /// Returns the block for the given `block hash`.
     pub fn get_block(&self, block_hash: &N::BlockHash) -> Result<Option<Block<N>>> {
+        self.ensure_block_hash_not_pruned(block_hash)?;
         self.storage.get_block(block_hash)
     }
 
@@ -1362,6 +2040,12 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn solution_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, SolutionID<N>>> {
         self.storage.solution_ids_map().keys_confirmed()
     }
+
+    /// Returns an iterator over the restrictions updates, as `(block height, restrictions update)`, for all blocks in `self`.
+    /// Note: The restrictions updates of pruned blocks are retained.
+    pub fn restrictions_updates(&self) -> impl '_ + Iterator<Item = (Cow<'_, u32>, Cow<'_, Ratify<N>>)> {
+        self.storage.restrictions_map().iter_confirmed()
+    }
 }
 
 #[cfg(test)]
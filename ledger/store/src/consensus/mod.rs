@@ -13,6 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod stats;
+pub use stats::*;
+
 use crate::{
     BlockStorage,
     BlockStore,
@@ -28,6 +31,7 @@ use console::network::prelude::*;
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
 use core::marker::PhantomData;
+use std::path::Path;
 
 /// A trait for consensus storage.
 pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
@@ -102,6 +106,24 @@ pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
         self.finalize_store().finish_atomic()?;
         self.block_store().finish_atomic()
     }
+
+    /// Creates a point-in-time checkpoint of the storage in the given directory.
+    fn create_checkpoint(&self, path: &Path) -> Result<()> {
+        bail!("Cannot create a checkpoint in '{}': checkpoints are not supported by this storage", path.display())
+    }
+
+    /// Restores the checkpoint in the given directory into the given storage.
+    fn restore_checkpoint<S: Clone + Into<StorageMode>>(checkpoint: &Path, _storage: S) -> Result<()> {
+        bail!(
+            "Cannot restore the checkpoint in '{}': checkpoints are not supported by this storage",
+            checkpoint.display()
+        )
+    }
+
+    /// Returns the usage statistics of the storage maps, if they are supported by the storage.
+    fn map_stats(&self) -> Result<Vec<MapStats>> {
+        Ok(Vec::new())
+    }
 }
 
 /// The consensus store.
@@ -182,6 +204,16 @@ impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
         self.storage.finish_atomic()
     }
 
+    /// Creates a point-in-time checkpoint of the consensus store in the given directory.
+    pub fn create_checkpoint(&self, path: &Path) -> Result<()> {
+        self.storage.create_checkpoint(path)
+    }
+
+    /// Returns the usage report of the consensus store, covering the storage maps and the program mappings.
+    pub fn storage_report(&self) -> Result<StorageReport<N>> {
+        Ok(StorageReport { maps: self.storage.map_stats()?, mappings: self.finalize_store().mapping_stats()? })
+    }
+
     /// Returns the storage mode.
     pub fn storage_mode(&self) -> &StorageMode {
         self.storage.storage_mode()
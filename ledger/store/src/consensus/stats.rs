@@ -0,0 +1,70 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+
+/// The usage statistics of a single storage map.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MapStats {
+    /// The category of the map, e.g. `block` or `program`.
+    pub category: &'static str,
+    /// The name of the map within its category, e.g. `Header`.
+    pub name: String,
+    /// The estimated number of keys in the map.
+    pub num_keys: u64,
+    /// The estimated size of the map in bytes, i.e. the size of its entries in the storage files.
+    pub size_in_bytes: u64,
+    /// The number of writes to the map since the storage was opened.
+    pub num_writes: u64,
+    /// The number of writes to the map per second, since the previous statistics were collected.
+    pub writes_per_second: f64,
+}
+
+/// The usage statistics of a single program mapping.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MappingStats<N: Network> {
+    /// The program ID.
+    pub program_id: ProgramID<N>,
+    /// The mapping name.
+    pub mapping_name: Identifier<N>,
+    /// The number of entries in the mapping.
+    pub num_entries: u64,
+    /// The approximate size of the mapping in bytes, i.e. the size of its serialized keys and values.
+    pub size_in_bytes: u64,
+}
+
+/// The usage report of the consensus storage.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StorageReport<N: Network> {
+    /// The usage statistics of the storage maps, if they are supported by the storage.
+    pub maps: Vec<MapStats>,
+    /// The usage statistics of the program mappings.
+    pub mappings: Vec<MappingStats<N>>,
+}
+
+impl<N: Network> StorageReport<N> {
+    /// Returns the total number of keys in the storage maps.
+    pub fn num_keys(&self) -> u64 {
+        self.maps.iter().map(|map| map.num_keys).sum()
+    }
+
+    /// Returns the approximate total size of the storage maps in bytes.
+    pub fn size_in_bytes(&self) -> u64 {
+        self.maps.iter().map(|map| map.size_in_bytes).sum()
+    }
+}
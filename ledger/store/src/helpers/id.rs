@@ -13,7 +13,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-/// The RocksDB map prefix broken down into the entry category and the specific type of the entry.
+pub const PREFIX_LEN: usize = 4; // N::ID (u16) + DataID (u16)
+
+/// The version of the storage schema, i.e. the layout of the maps defined by `MapID`.
+///
+/// - Version 1: The unversioned schema, as written prior to the storage metadata.
+/// - Version 2: Adds the rollback, value history, archive, ratified operations, mapping node, pruning,
+///   transition index, restrictions, pruned transition, and storage metadata maps.
+pub const SCHEMA_VERSION: u16 = 2;
+
+/// The storage map prefix broken down into the entry category and the specific type of the entry.
 // Note: the order of these variants can be changed at any point in time.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[repr(u16)]
@@ -29,6 +38,7 @@ pub enum MapID {
     TransitionInput(TransitionInputMap),
     TransitionOutput(TransitionOutputMap),
     Program(ProgramMap),
+    Metadata(MetadataMap),
     #[cfg(test)]
     Test(TestMap),
 }
@@ -47,13 +57,135 @@ impl From<MapID> for u16 {
             MapID::TransitionInput(id) => id as u16,
             MapID::TransitionOutput(id) => id as u16,
             MapID::Program(id) => id as u16,
+            MapID::Metadata(id) => id as u16,
             #[cfg(test)]
             MapID::Test(id) => id as u16,
         }
     }
 }
 
-/// The RocksDB map prefix for BFT-related entries.
+impl MapID {
+    /// The IDs of all the storage maps.
+    pub const ALL: &'static [MapID] = &[
+        MapID::BFT(BFTMap::Transmissions),
+        MapID::BFT(BFTMap::AbortedTransmissionIDs),
+        MapID::Block(BlockMap::StateRoot),
+        MapID::Block(BlockMap::ReverseStateRoot),
+        MapID::Block(BlockMap::ID),
+        MapID::Block(BlockMap::ReverseID),
+        MapID::Block(BlockMap::Header),
+        MapID::Block(BlockMap::Authority),
+        MapID::Block(BlockMap::Certificate),
+        MapID::Block(BlockMap::Ratifications),
+        MapID::Block(BlockMap::Solutions),
+        MapID::Block(BlockMap::PuzzleCommitments),
+        MapID::Block(BlockMap::AbortedSolutionIDs),
+        MapID::Block(BlockMap::AbortedSolutionHeights),
+        MapID::Block(BlockMap::Transactions),
+        MapID::Block(BlockMap::AbortedTransactionIDs),
+        MapID::Block(BlockMap::RejectedOrAbortedTransactionID),
+        MapID::Block(BlockMap::ConfirmedTransactions),
+        MapID::Block(BlockMap::RejectedDeploymentOrExecution),
+        MapID::Block(BlockMap::PrunedHeight),
+        MapID::Block(BlockMap::PruningDepth),
+        MapID::Block(BlockMap::AddressIndex),
+        MapID::Block(BlockMap::FeePayerIndex),
+        MapID::Block(BlockMap::FunctionIndex),
+        MapID::Block(BlockMap::TransitionIndexHeight),
+        MapID::Block(BlockMap::Restrictions),
+        MapID::Committee(CommitteeMap::CurrentRound),
+        MapID::Committee(CommitteeMap::RoundToHeight),
+        MapID::Committee(CommitteeMap::Committee),
+        MapID::Deployment(DeploymentMap::ID),
+        MapID::Deployment(DeploymentMap::Edition),
+        MapID::Deployment(DeploymentMap::ReverseID),
+        MapID::Deployment(DeploymentMap::Owner),
+        MapID::Deployment(DeploymentMap::Program),
+        MapID::Deployment(DeploymentMap::VerifyingKey),
+        MapID::Deployment(DeploymentMap::Certificate),
+        MapID::Execution(ExecutionMap::ID),
+        MapID::Execution(ExecutionMap::ReverseID),
+        MapID::Execution(ExecutionMap::Inclusion),
+        MapID::Fee(FeeMap::Fee),
+        MapID::Fee(FeeMap::ReverseFee),
+        MapID::Transaction(TransactionMap::ID),
+        MapID::Transition(TransitionMap::Locator),
+        MapID::Transition(TransitionMap::TPK),
+        MapID::Transition(TransitionMap::ReverseTPK),
+        MapID::Transition(TransitionMap::TCM),
+        MapID::Transition(TransitionMap::ReverseTCM),
+        MapID::Transition(TransitionMap::SCM),
+        MapID::Transition(TransitionMap::Pruned),
+        MapID::TransitionInput(TransitionInputMap::ID),
+        MapID::TransitionInput(TransitionInputMap::ReverseID),
+        MapID::TransitionInput(TransitionInputMap::Constant),
+        MapID::TransitionInput(TransitionInputMap::Public),
+        MapID::TransitionInput(TransitionInputMap::Private),
+        MapID::TransitionInput(TransitionInputMap::Record),
+        MapID::TransitionInput(TransitionInputMap::RecordTag),
+        MapID::TransitionInput(TransitionInputMap::ExternalRecord),
+        MapID::TransitionOutput(TransitionOutputMap::ID),
+        MapID::TransitionOutput(TransitionOutputMap::ReverseID),
+        MapID::TransitionOutput(TransitionOutputMap::Constant),
+        MapID::TransitionOutput(TransitionOutputMap::Public),
+        MapID::TransitionOutput(TransitionOutputMap::Private),
+        MapID::TransitionOutput(TransitionOutputMap::Record),
+        MapID::TransitionOutput(TransitionOutputMap::RecordNonce),
+        MapID::TransitionOutput(TransitionOutputMap::ExternalRecord),
+        MapID::TransitionOutput(TransitionOutputMap::Future),
+        MapID::Program(ProgramMap::ProgramID),
+        MapID::Program(ProgramMap::KeyValueID),
+        MapID::Program(ProgramMap::Rollback),
+        MapID::Program(ProgramMap::ValueHistory),
+        MapID::Program(ProgramMap::ArchiveHeight),
+        MapID::Program(ProgramMap::RatifiedOperations),
+        MapID::Program(ProgramMap::MappingNode),
+        MapID::Metadata(MetadataMap::SchemaVersion),
+        MapID::Metadata(MetadataMap::MigrationProgress),
+    ];
+
+    /// Returns the name of the category of the storage map.
+    pub const fn category(&self) -> &'static str {
+        match self {
+            MapID::BFT(_) => "bft",
+            MapID::Block(_) => "block",
+            MapID::Committee(_) => "committee",
+            MapID::Deployment(_) => "deployment",
+            MapID::Execution(_) => "execution",
+            MapID::Fee(_) => "fee",
+            MapID::Transaction(_) => "transaction",
+            MapID::Transition(_) => "transition",
+            MapID::TransitionInput(_) => "input",
+            MapID::TransitionOutput(_) => "output",
+            MapID::Program(_) => "program",
+            MapID::Metadata(_) => "metadata",
+            #[cfg(test)]
+            MapID::Test(_) => "test",
+        }
+    }
+
+    /// Returns the name of the storage map within its category.
+    pub fn name(&self) -> String {
+        match self {
+            MapID::BFT(id) => format!("{id:?}"),
+            MapID::Block(id) => format!("{id:?}"),
+            MapID::Committee(id) => format!("{id:?}"),
+            MapID::Deployment(id) => format!("{id:?}"),
+            MapID::Execution(id) => format!("{id:?}"),
+            MapID::Fee(id) => format!("{id:?}"),
+            MapID::Transaction(id) => format!("{id:?}"),
+            MapID::Transition(id) => format!("{id:?}"),
+            MapID::TransitionInput(id) => format!("{id:?}"),
+            MapID::TransitionOutput(id) => format!("{id:?}"),
+            MapID::Program(id) => format!("{id:?}"),
+            MapID::Metadata(id) => format!("{id:?}"),
+            #[cfg(test)]
+            MapID::Test(id) => format!("{id:?}"),
+        }
+    }
+}
+
+/// The storage map prefix for BFT-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -63,7 +195,7 @@ pub enum BFTMap {
     AbortedTransmissionIDs = DataID::BFTAbortedTransmissionIDsMap as u16,
 }
 
-/// The RocksDB map prefix for block-related entries.
+/// The storage map prefix for block-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -86,9 +218,16 @@ pub enum BlockMap {
     RejectedOrAbortedTransactionID = DataID::BlockRejectedOrAbortedTransactionIDMap as u16,
     ConfirmedTransactions = DataID::BlockConfirmedTransactionsMap as u16,
     RejectedDeploymentOrExecution = DataID::BlockRejectedDeploymentOrExecutionMap as u16,
+    PrunedHeight = DataID::BlockPrunedHeightMap as u16,
+    PruningDepth = DataID::BlockPruningDepthMap as u16,
+    AddressIndex = DataID::BlockAddressIndexMap as u16,
+    FeePayerIndex = DataID::BlockFeePayerIndexMap as u16,
+    FunctionIndex = DataID::BlockFunctionIndexMap as u16,
+    TransitionIndexHeight = DataID::BlockTransitionIndexHeightMap as u16,
+    Restrictions = DataID::BlockRestrictionsMap as u16,
 }
 
-/// The RocksDB map prefix for committee-related entries.
+/// The storage map prefix for committee-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -99,7 +238,7 @@ pub enum CommitteeMap {
     Committee = DataID::CommitteeMap as u16,
 }
 
-/// The RocksDB map prefix for deployment-related entries.
+/// The storage map prefix for deployment-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -114,7 +253,7 @@ pub enum DeploymentMap {
     Certificate = DataID::DeploymentCertificateMap as u16,
 }
 
-/// The RocksDB map prefix for execution-related entries.
+/// The storage map prefix for execution-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -125,7 +264,7 @@ pub enum ExecutionMap {
     Inclusion = DataID::ExecutionInclusionMap as u16,
 }
 
-/// The RocksDB map prefix for fee-related entries.
+/// The storage map prefix for fee-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -135,7 +274,7 @@ pub enum FeeMap {
     ReverseFee = DataID::FeeReverseFeeMap as u16,
 }
 
-/// The RocksDB map prefix for transition input entries.
+/// The storage map prefix for transition input entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -151,7 +290,7 @@ pub enum TransitionInputMap {
     ExternalRecord = DataID::InputExternalRecordMap as u16,
 }
 
-/// The RocksDB map prefix for transition output entries.
+/// The storage map prefix for transition output entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -168,7 +307,7 @@ pub enum TransitionOutputMap {
     Future = DataID::OutputFutureMap as u16,
 }
 
-/// The RocksDB map prefix for transaction-related entries.
+/// The storage map prefix for transaction-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -177,7 +316,7 @@ pub enum TransactionMap {
     ID = DataID::TransactionIDMap as u16,
 }
 
-/// The RocksDB map prefix for transition-related entries.
+/// The storage map prefix for transition-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -189,9 +328,10 @@ pub enum TransitionMap {
     TCM = DataID::TransitionTCMMap as u16,
     ReverseTCM = DataID::TransitionReverseTCMMap as u16,
     SCM = DataID::TransitionSCMMap as u16,
+    Pruned = DataID::TransitionPrunedMap as u16,
 }
 
-/// The RocksDB map prefix for program-related entries.
+/// The storage map prefix for program-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -199,9 +339,24 @@ pub enum TransitionMap {
 pub enum ProgramMap {
     ProgramID = DataID::ProgramIDMap as u16,
     KeyValueID = DataID::KeyValueMap as u16,
+    Rollback = DataID::RollbackMap as u16,
+    ValueHistory = DataID::ValueHistoryMap as u16,
+    ArchiveHeight = DataID::ArchiveHeightMap as u16,
+    RatifiedOperations = DataID::RatifiedOperationsMap as u16,
+    MappingNode = DataID::MappingNodeMap as u16,
+}
+
+/// The storage map prefix for storage metadata entries.
+// Note: the order of these variants can be changed at any point in time,
+// as long as the corresponding DataID values remain the same.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum MetadataMap {
+    SchemaVersion = DataID::SchemaVersionMap as u16,
+    MigrationProgress = DataID::MigrationProgressMap as u16,
 }
 
-/// The RocksDB map prefix for test-related entries.
+/// The storage map prefix for test-related entries.
 // Note: the order of these variants can be changed at any point in time.
 #[cfg(test)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -214,7 +369,7 @@ pub enum TestMap {
     Test5 = DataID::Test5 as u16,
 }
 
-/// The RocksDB map prefix.
+/// The storage map prefix.
 // Note: the order of these variants can NOT be changed once the database is populated:
 // - any new variant MUST be added as the last one (ignoring the Test one)
 // - any deprecated variant MUST remain in its position (it can't be removed)
@@ -293,6 +448,24 @@ enum DataID {
     // Program
     ProgramIDMap,
     KeyValueMap,
+    RollbackMap,
+    ValueHistoryMap,
+    ArchiveHeightMap,
+    RatifiedOperationsMap,
+    MappingNodeMap,
+    // Block
+    BlockPrunedHeightMap,
+    BlockPruningDepthMap,
+    BlockAddressIndexMap,
+    BlockFeePayerIndexMap,
+    BlockFunctionIndexMap,
+    BlockTransitionIndexHeightMap,
+    BlockRestrictionsMap,
+    // Transition
+    TransitionPrunedMap,
+    // Metadata
+    SchemaVersionMap,
+    MigrationProgressMap,
 
     // Testing
     #[cfg(test)]
@@ -17,12 +17,17 @@ use crate::{
     BlockStorage,
     ConfirmedTxType,
     TransactionStore,
+    TransitionPosition,
     TransitionStore,
-    helpers::memory::{MemoryMap, TransactionMemory, TransitionMemory},
+    helpers::memory::{MemoryMap, NestedMemoryMap, TransactionMemory, TransitionMemory},
+};
+use console::{
+    prelude::*,
+    program::{Identifier, ProgramID},
+    types::{Address, Field},
 };
-use console::{prelude::*, types::Field};
 use ledger_authority::Authority;
-use ledger_block::{Header, Ratifications, Rejected, Solutions};
+use ledger_block::{Header, Ratifications, Ratify, Rejected, Solutions};
 use ledger_puzzle::SolutionID;
 use synthesizer_program::FinalizeOperation;
 
@@ -67,6 +72,20 @@ pub struct BlockMemory<N: Network> {
         MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: MemoryMap<Field<N>, Rejected<N>>,
+    /// The pruned height map.
+    pruned_height_map: MemoryMap<u8, u32>,
+    /// The pruning depth map.
+    pruning_depth_map: MemoryMap<u8, u32>,
+    /// The address index map.
+    address_index_map: NestedMemoryMap<Address<N>, TransitionPosition, N::TransitionID>,
+    /// The fee payer index map.
+    fee_payer_index_map: NestedMemoryMap<Address<N>, TransitionPosition, N::TransitionID>,
+    /// The function index map.
+    function_index_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), TransitionPosition, N::TransitionID>,
+    /// The transition index height map.
+    transition_index_height_map: MemoryMap<u8, u32>,
+    /// The restrictions map.
+    restrictions_map: MemoryMap<u32, Ratify<N>>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionMemory<N>>,
 }
@@ -90,6 +109,13 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type RejectedOrAbortedTransactionIDMap = MemoryMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     type RejectedDeploymentOrExecutionMap = MemoryMap<Field<N>, Rejected<N>>;
+    type PrunedHeightMap = MemoryMap<u8, u32>;
+    type PruningDepthMap = MemoryMap<u8, u32>;
+    type AddressIndexMap = NestedMemoryMap<Address<N>, TransitionPosition, N::TransitionID>;
+    type FeePayerIndexMap = NestedMemoryMap<Address<N>, TransitionPosition, N::TransitionID>;
+    type FunctionIndexMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), TransitionPosition, N::TransitionID>;
+    type TransitionIndexHeightMap = MemoryMap<u8, u32>;
+    type RestrictionsMap = MemoryMap<u32, Ratify<N>>;
     type TransactionStorage = TransactionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
 
@@ -118,6 +144,13 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             rejected_or_aborted_transaction_id_map: MemoryMap::default(),
             confirmed_transactions_map: MemoryMap::default(),
             rejected_deployment_or_execution_map: MemoryMap::default(),
+            pruned_height_map: MemoryMap::default(),
+            pruning_depth_map: MemoryMap::default(),
+            address_index_map: NestedMemoryMap::default(),
+            fee_payer_index_map: NestedMemoryMap::default(),
+            function_index_map: NestedMemoryMap::default(),
+            transition_index_height_map: MemoryMap::default(),
+            restrictions_map: MemoryMap::default(),
             transaction_store,
         })
     }
@@ -207,6 +240,41 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the pruned height map.
+    fn pruned_height_map(&self) -> &Self::PrunedHeightMap {
+        &self.pruned_height_map
+    }
+
+    /// Returns the pruning depth map.
+    fn pruning_depth_map(&self) -> &Self::PruningDepthMap {
+        &self.pruning_depth_map
+    }
+
+    /// Returns the address index map.
+    fn address_index_map(&self) -> &Self::AddressIndexMap {
+        &self.address_index_map
+    }
+
+    /// Returns the fee payer index map.
+    fn fee_payer_index_map(&self) -> &Self::FeePayerIndexMap {
+        &self.fee_payer_index_map
+    }
+
+    /// Returns the function index map.
+    fn function_index_map(&self) -> &Self::FunctionIndexMap {
+        &self.function_index_map
+    }
+
+    /// Returns the transition index height map.
+    fn transition_index_height_map(&self) -> &Self::TransitionIndexHeightMap {
+        &self.transition_index_height_map
+    }
+
+    /// Returns the restrictions map.
+    fn restrictions_map(&self) -> &Self::RestrictionsMap {
+        &self.restrictions_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
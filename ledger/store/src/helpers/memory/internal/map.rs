@@ -15,11 +15,11 @@
 
 #![allow(clippy::type_complexity)]
 
-use crate::helpers::{Map, MapRead};
+use crate::helpers::{Map, MapRead, serialize_range};
 use console::network::prelude::*;
 use indexmap::IndexMap;
 
-use core::{borrow::Borrow, hash::Hash};
+use core::{borrow::Borrow, hash::Hash, ops::Bound};
 use parking_lot::{Mutex, RwLock};
 use std::{
     borrow::Cow,
@@ -367,6 +367,23 @@ impl<
     fn values_confirmed(&'a self) -> Self::Values {
         self.map.read().clone().into_values().map(Cow::Owned)
     }
+
+    ///
+    /// Returns up to `limit` confirmed key-value pairs with a key in the given range, in serialized key order.
+    ///
+    fn get_range_confirmed(&'a self, start: Bound<&K>, end: Bound<&K>, limit: usize) -> Result<Vec<(K, V)>> {
+        // Serialize the range, and return early if it is empty.
+        let Some(range) = serialize_range(start, end)? else {
+            return Ok(Vec::new());
+        };
+        // Collect the key-value pairs in the range.
+        self.map
+            .read()
+            .range::<Vec<u8>, _>(range)
+            .take(limit)
+            .map(|(k, v)| -> Result<(K, V)> { Ok((bincode::deserialize(k)?, v.clone())) })
+            .collect()
+    }
 }
 
 impl<
@@ -437,6 +454,14 @@ mod tests {
         crate::helpers::test_helpers::map::check_iterators_match(map);
     }
 
+    #[test]
+    fn test_get_range() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = Default::default();
+
+        crate::helpers::test_helpers::map::check_get_range(map);
+    }
+
     #[test]
     fn test_atomic_writes_are_batched() {
         // Initialize a map.
@@ -15,10 +15,10 @@
 
 #![allow(clippy::type_complexity)]
 
-use crate::helpers::{NestedMap, NestedMapRead};
+use crate::helpers::{NestedMap, NestedMapRead, serialize_range};
 use console::network::prelude::*;
 
-use core::hash::Hash;
+use core::{hash::Hash, ops::Bound};
 use parking_lot::{Mutex, RwLock};
 use std::{
     borrow::Cow,
@@ -501,6 +501,43 @@ impl<
     fn values_confirmed(&'a self) -> Self::Values {
         self.map_inner.read().clone().into_values().map(Cow::Owned)
     }
+
+    ///
+    /// Returns up to `limit` confirmed key-value pairs for the given map with a key in the given range,
+    /// in serialized key order.
+    ///
+    fn get_map_range_confirmed(
+        &'a self,
+        map: &M,
+        start: Bound<&K>,
+        end: Bound<&K>,
+        limit: usize,
+    ) -> Result<Vec<(K, V)>> {
+        // Serialize the range, and return early if it is empty.
+        let Some(range) = serialize_range(start, end)? else {
+            return Ok(Vec::new());
+        };
+        // Serialize 'm'.
+        let m = bincode::serialize(map)?;
+        // Acquire the read locks.
+        let (map_keys, map_inner) = (self.map.read(), self.map_inner.read());
+        // Retrieve the keys for the serialized map.
+        let Some(keys) = map_keys.get(&m) else {
+            return Ok(Vec::new());
+        };
+        // Collect the key-value pairs in the range.
+        keys.range::<Vec<u8>, _>(range)
+            .take(limit)
+            .map(|k| -> Result<(K, V)> {
+                // Concatenate 'm' and 'k' with a 0-byte separator.
+                let mk = to_map_key(&m, k);
+                // Retrieve the value.
+                let value = map_inner.get(&mk).ok_or_else(|| anyhow!("Missing value in the nested memory map"))?;
+                // Return the key-value pair.
+                Ok((bincode::deserialize(k)?, value.clone()))
+            })
+            .collect()
+    }
 }
 
 /// Inserts the given map-key-value pair.
@@ -648,6 +685,14 @@ mod tests {
         crate::helpers::test_helpers::nested_map::check_get_map(map);
     }
 
+    #[test]
+    fn test_get_map_range() {
+        // Initialize a map.
+        let map: NestedMemoryMap<usize, usize, String> = Default::default();
+
+        crate::helpers::test_helpers::nested_map::check_get_map_range(map);
+    }
+
     #[test]
     fn test_check_iterators_match() {
         // Initialize a map.
@@ -19,13 +19,16 @@ use crate::{
     CommitteeStorage,
     CommitteeStore,
     FinalizeStorage,
+    HistoryHeight,
+    RollbackEntries,
     helpers::memory::{MemoryMap, NestedMemoryMap},
 };
 use console::{
     prelude::*,
-    program::{Identifier, Plaintext, ProgramID, Value},
+    program::{Identifier, MappingNodeEntry, MappingNodePosition, Plaintext, ProgramID, Value},
 };
 use ledger_committee::Committee;
+use synthesizer_program::FinalizeOperation;
 
 use aleo_std_storage::StorageMode;
 use indexmap::IndexSet;
@@ -39,6 +42,16 @@ pub struct FinalizeMemory<N: Network> {
     program_id_map: MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The rollback map.
+    rollback_map: MemoryMap<u32, RollbackEntries<N>>,
+    /// The value history map.
+    value_history_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), (Plaintext<N>, HistoryHeight), Option<Value<N>>>,
+    /// The archive height map.
+    archive_height_map: MemoryMap<u8, u32>,
+    /// The ratified operations map.
+    ratified_operations_map: MemoryMap<u32, Vec<FinalizeOperation<N>>>,
+    /// The mapping node map.
+    mapping_node_map: MemoryMap<MappingNodePosition<N>, MappingNodeEntry<N>>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -48,6 +61,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     type CommitteeStorage = CommitteeMemory<N>;
     type ProgramIDMap = MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type RollbackMap = MemoryMap<u32, RollbackEntries<N>>;
+    type ValueHistoryMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), (Plaintext<N>, HistoryHeight), Option<Value<N>>>;
+    type ArchiveHeightMap = MemoryMap<u8, u32>;
+    type RatifiedOperationsMap = MemoryMap<u32, Vec<FinalizeOperation<N>>>;
+    type MappingNodeMap = MemoryMap<MappingNodePosition<N>, MappingNodeEntry<N>>;
 
     /// Initializes the finalize storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -58,6 +76,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
             committee_store,
             program_id_map: MemoryMap::default(),
             key_value_map: NestedMemoryMap::default(),
+            rollback_map: MemoryMap::default(),
+            value_history_map: NestedMemoryMap::default(),
+            archive_height_map: MemoryMap::default(),
+            ratified_operations_map: MemoryMap::default(),
+            mapping_node_map: MemoryMap::default(),
             storage_mode: storage.into(),
         })
     }
@@ -83,6 +106,31 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
         &self.key_value_map
     }
 
+    /// Returns the rollback map.
+    fn rollback_map(&self) -> &Self::RollbackMap {
+        &self.rollback_map
+    }
+
+    /// Returns the value history map.
+    fn value_history_map(&self) -> &Self::ValueHistoryMap {
+        &self.value_history_map
+    }
+
+    /// Returns the archive height map.
+    fn archive_height_map(&self) -> &Self::ArchiveHeightMap {
+        &self.archive_height_map
+    }
+
+    /// Returns the ratified operations map.
+    fn ratified_operations_map(&self) -> &Self::RatifiedOperationsMap {
+        &self.ratified_operations_map
+    }
+
+    /// Returns the mapping node map.
+    fn mapping_node_map(&self) -> &Self::MappingNodeMap {
+        &self.mapping_node_map
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
@@ -41,6 +41,8 @@ pub struct TransitionMemory<N: Network> {
     reverse_tcm_map: MemoryMap<Field<N>, N::TransitionID>,
     /// The signer commitments.
     scm_map: MemoryMap<N::TransitionID, Field<N>>,
+    /// The pruned transition IDs.
+    pruned_map: MemoryMap<N::TransitionID, ()>,
 }
 
 #[rustfmt::skip]
@@ -53,6 +55,7 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     type TCMMap = MemoryMap<N::TransitionID, Field<N>>;
     type ReverseTCMMap = MemoryMap<Field<N>, N::TransitionID>;
     type SCMMap = MemoryMap<N::TransitionID, Field<N>>;
+    type PrunedMap = MemoryMap<N::TransitionID, ()>;
 
     /// Initializes the transition storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -65,6 +68,7 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
             tcm_map: MemoryMap::default(),
             reverse_tcm_map: MemoryMap::default(),
             scm_map: MemoryMap::default(),
+            pruned_map: MemoryMap::default(),
         })
     }
 
@@ -107,6 +111,11 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     fn scm_map(&self) -> &Self::SCMMap {
         &self.scm_map
     }
+
+    /// Returns the pruned transition IDs.
+    fn pruned_map(&self) -> &Self::PrunedMap {
+        &self.pruned_map
+    }
 }
 
 /// An in-memory transition input storage.
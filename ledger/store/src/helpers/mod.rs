@@ -14,9 +14,16 @@
 // limitations under the License.
 
 pub mod memory;
+#[cfg(feature = "embedded")]
+pub mod redb;
 #[cfg(feature = "rocks")]
 pub mod rocksdb;
 
+#[cfg(any(feature = "rocks", feature = "embedded"))]
+mod id;
+#[cfg(any(feature = "rocks", feature = "embedded"))]
+mod persistent;
+
 #[cfg(test)]
 pub(crate) mod test_helpers;
 
@@ -16,43 +16,62 @@
 #![allow(clippy::type_complexity)]
 
 use super::*;
-use crate::helpers::{Map, MapRead};
+use crate::helpers::{Map, MapRead, is_after_start, is_before_end, serialize_range};
 
 use core::{fmt, fmt::Debug, hash::Hash, mem};
 use indexmap::IndexMap;
 use smallvec::SmallVec;
-use std::{borrow::Cow, ops::Deref, sync::atomic::Ordering};
+use std::{
+    borrow::Cow,
+    ops::{Bound, Deref},
+    sync::atomic::Ordering,
+};
 use tracing::error;
 
 #[derive(Clone)]
-pub struct DataMap<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned>(
-    pub(super) Arc<InnerDataMap<K, V>>,
+pub struct DataMap<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, D: RawDatabase>(
+    pub(crate) Arc<InnerDataMap<K, V, D>>,
 );
 
-impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> Deref for DataMap<K, V> {
-    type Target = InnerDataMap<K, V>;
+impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, D: RawDatabase> Deref for DataMap<K, V, D> {
+    type Target = InnerDataMap<K, V, D>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
 
-pub struct InnerDataMap<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> {
-    pub(super) database: RocksDB,
-    pub(super) context: Vec<u8>,
+pub struct InnerDataMap<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, D: RawDatabase> {
+    pub(crate) database: D,
+    pub(crate) context: Vec<u8>,
     /// The tracker for whether a database transaction is in progress.
-    pub(super) batch_in_progress: AtomicBool,
+    pub(crate) batch_in_progress: AtomicBool,
     /// The database transaction.
-    pub(super) atomic_batch: Mutex<Vec<(K, Option<V>)>>,
+    pub(crate) atomic_batch: Mutex<Vec<(K, Option<V>)>>,
     /// The checkpoint stack for the batched operations within the map.
-    pub(super) checkpoints: Mutex<Vec<usize>>,
+    pub(crate) checkpoints: Mutex<Vec<usize>>,
+}
+
+impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, D: RawDatabase> DataMap<K, V, D> {
+    /// Initializes the map with the given ID in the given database.
+    pub(crate) fn new<T: Into<u16>>(database: D, map_id: T) -> Self {
+        let context = map_context(&database, map_id.into());
+        Self(Arc::new(InnerDataMap {
+            database,
+            context,
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+            checkpoints: Default::default(),
+        }))
+    }
 }
 
 impl<
     'a,
     K: 'a + Copy + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
     V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
-> Map<'a, K, V> for DataMap<K, V>
+    D: 'a + RawDatabase,
+> Map<'a, K, V> for DataMap<K, V, D>
 {
     ///
     /// Inserts the given key-value pair into the map.
@@ -69,7 +88,8 @@ impl<
                 // Prepare the prefixed key and serialized value.
                 let raw_key = self.create_prefixed_key(&key)?;
                 let raw_value = bincode::serialize(&value)?;
-                self.database.put(raw_key, raw_value)?;
+                self.database.put_raw(&raw_key, &raw_value)?;
+                self.database.record_writes(&self.context, 1);
             }
         }
 
@@ -90,7 +110,8 @@ impl<
             false => {
                 // Prepare the prefixed key.
                 let raw_key = self.create_prefixed_key(key)?;
-                self.database.delete(raw_key)?;
+                self.database.delete_raw(&raw_key)?;
+                self.database.record_writes(&self.context, 1);
             }
         }
 
@@ -105,14 +126,14 @@ impl<
         // Set the atomic batch flag to `true`.
         self.batch_in_progress.store(true, Ordering::SeqCst);
         // Increment the atomic depth index.
-        self.database.atomic_depth.fetch_add(1, Ordering::SeqCst);
+        self.database.atomic_depth().fetch_add(1, Ordering::SeqCst);
 
         // Ensure that the atomic batch is empty.
         assert!(self.atomic_batch.lock().is_empty());
         // Ensure that the database atomic batch is empty; skip this check if the atomic
         // writes are paused, as there may be pending operations.
         if !self.database.are_atomic_writes_paused() {
-            assert!(self.database.atomic_batch.lock().is_empty());
+            assert!(self.database.atomic_batch().lock().is_empty());
         }
     }
 
@@ -168,9 +189,9 @@ impl<
         // Set the atomic batch flag to `false`.
         self.batch_in_progress.store(false, Ordering::SeqCst);
         // Clear the database-wide atomic batch.
-        self.database.atomic_batch.lock().clear();
+        self.database.atomic_batch().lock().clear();
         // Reset the atomic batch depth.
-        self.database.atomic_depth.store(0, Ordering::SeqCst);
+        self.database.atomic_depth().store(0, Ordering::SeqCst);
     }
 
     ///
@@ -199,12 +220,15 @@ impl<
                 })
                 .collect::<Result<Vec<_>>>()?;
 
+            // Record the writes to the map.
+            self.database.record_writes(&self.context, prepared_operations.len());
+
             // Enqueue all the operations from the map in the database-wide batch.
-            let mut atomic_batch = self.database.atomic_batch.lock();
+            let mut atomic_batch = self.database.atomic_batch().lock();
             for (raw_key, raw_value) in prepared_operations {
                 match raw_value {
-                    Some(raw_value) => atomic_batch.put(raw_key, raw_value),
-                    None => atomic_batch.delete(raw_key),
+                    Some(raw_value) => atomic_batch.put(&raw_key, &raw_value),
+                    None => atomic_batch.delete(&raw_key),
                 };
             }
         }
@@ -215,7 +239,7 @@ impl<
         self.batch_in_progress.store(false, Ordering::SeqCst);
 
         // Subtract the atomic depth index.
-        let previous_atomic_depth = self.database.atomic_depth.fetch_sub(1, Ordering::SeqCst);
+        let previous_atomic_depth = self.database.atomic_depth().fetch_sub(1, Ordering::SeqCst);
 
         // Ensure that the value of `atomic_depth` doesn't overflow, meaning that all the
         // calls to `start_atomic` have corresponding calls to `finish_atomic`.
@@ -226,11 +250,14 @@ impl<
         // atomic writes are paused.
         if previous_atomic_depth == 1 && !self.database.are_atomic_writes_paused() {
             // Empty the collection of pending operations.
-            let batch = mem::take(&mut *self.database.atomic_batch.lock());
+            // Note: The lock on the atomic batch is held until the operations are executed,
+            // so that a concurrent checkpoint never observes a partially-committed batch.
+            let mut atomic_batch = self.database.atomic_batch().lock();
+            let batch = mem::take(&mut *atomic_batch);
             // Execute all the operations atomically.
-            self.database.rocksdb.write(batch)?;
+            self.database.write_batch(batch)?;
             // Ensure that the database atomic batch is empty.
-            assert!(self.database.atomic_batch.lock().is_empty());
+            assert!(atomic_batch.is_empty());
         }
 
         Ok(())
@@ -258,20 +285,21 @@ impl<
     'a,
     K: 'a + Copy + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
     V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
-> MapRead<'a, K, V> for DataMap<K, V>
+    D: 'a + RawDatabase,
+> MapRead<'a, K, V> for DataMap<K, V, D>
 {
-    type Iterator = Iter<'a, K, V>;
-    type Keys = Keys<'a, K>;
+    type Iterator = Iter<'a, K, V, D>;
+    type Keys = Keys<'a, K, D>;
     type PendingIterator =
         core::iter::Map<indexmap::map::IntoIter<K, Option<V>>, fn((K, Option<V>)) -> (Cow<'a, K>, Option<Cow<'a, V>>)>;
-    type Values = Values<'a, V>;
+    type Values = Values<'a, V, D>;
 
     ///
     /// Returns the number of confirmed entries in the map.
     ///
     fn len_confirmed(&self) -> usize {
         // A raw iterator doesn't allocate.
-        let mut iter = self.database.raw_iterator();
+        let mut iter = self.database.raw_cursor();
         // Find the first key with the map prefix.
         iter.seek(&self.context);
 
@@ -381,21 +409,66 @@ impl<
     /// Returns an iterator visiting each key-value pair in the map.
     ///
     fn iter_confirmed(&'a self) -> Self::Iterator {
-        Iter::new(self.database.prefix_iterator(&self.context))
+        Iter::new(self.database.prefix_cursor(&self.context))
     }
 
     ///
     /// Returns an iterator over each key in the map.
     ///
     fn keys_confirmed(&'a self) -> Self::Keys {
-        Keys::new(self.database.prefix_iterator(&self.context))
+        Keys::new(self.database.prefix_cursor(&self.context))
     }
 
     ///
     /// Returns an iterator over each value in the map.
     ///
     fn values_confirmed(&'a self) -> Self::Values {
-        Values::new(self.database.prefix_iterator(&self.context))
+        Values::new(self.database.prefix_cursor(&self.context))
+    }
+
+    ///
+    /// Returns up to `limit` confirmed key-value pairs with a key in the given range, in serialized key order.
+    ///
+    fn get_range_confirmed(&'a self, start: Bound<&K>, end: Bound<&K>, limit: usize) -> Result<Vec<(K, V)>> {
+        // Serialize the range, and return early if it is empty.
+        let Some((start, end)) = serialize_range(start, end)? else {
+            return Ok(Vec::new());
+        };
+
+        // A raw iterator doesn't allocate.
+        let mut iter = self.database.raw_cursor();
+        // Find the first key in the range.
+        let mut raw_start = self.context.clone();
+        if let Bound::Included(key) | Bound::Excluded(key) = &start {
+            raw_start.extend_from_slice(key);
+        }
+        iter.seek(&raw_start);
+
+        // Collect the key-value pairs in the range.
+        let mut entries = Vec::new();
+        while iter.valid() && entries.len() < limit {
+            let (Some(raw_key), Some(raw_value)) = (iter.key(), iter.value()) else {
+                break;
+            };
+            // If the map ID is different, it's the end of iteration.
+            if raw_key.get(..PREFIX_LEN) != Some(&self.context[..]) {
+                break;
+            }
+            let key = &raw_key[PREFIX_LEN..];
+            // If the key is past the end of the range, it's the end of iteration.
+            if !is_before_end(key, &end) {
+                break;
+            }
+            // Skip the start key, if it is excluded.
+            if is_after_start(key, &start) {
+                entries.push((bincode::deserialize(key)?, bincode::deserialize(raw_value)?));
+            }
+            iter.next();
+        }
+        // Ensure the iteration did not fail.
+        iter.status()?;
+
+        Ok(entries)
     }
 }
 
@@ -404,8 +477,9 @@ pub struct Iter<
     'a,
     K: 'a + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
     V: 'a + PartialEq + Eq + Serialize + DeserializeOwned,
+    D: 'a + RawDatabase,
 > {
-    db_iter: rocksdb::DBRawIterator<'a>,
+    db_iter: D::Cursor<'a>,
     _phantom: PhantomData<(K, V)>,
 }
 
@@ -413,10 +487,11 @@ impl<
     'a,
     K: 'a + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
     V: 'a + PartialEq + Eq + Serialize + DeserializeOwned,
-> Iter<'a, K, V>
+    D: 'a + RawDatabase,
+> Iter<'a, K, V, D>
 {
-    pub(super) fn new(db_iter: rocksdb::DBIterator<'a>) -> Self {
-        Self { db_iter: db_iter.into(), _phantom: PhantomData }
+    pub(super) fn new(db_iter: D::Cursor<'a>) -> Self {
+        Self { db_iter, _phantom: PhantomData }
     }
 }
 
@@ -424,7 +499,8 @@ impl<
     'a,
     K: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
     V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned,
-> Iterator for Iter<'a, K, V>
+    D: 'a + RawDatabase,
+> Iterator for Iter<'a, K, V, D>
 {
     type Item = (Cow<'a, K>, Cow<'a, V>);
 
@@ -438,12 +514,12 @@ impl<
         // Deserialize the key and value.
         let key = bincode::deserialize(&key[PREFIX_LEN..])
             .map_err(|e| {
-                error!("RocksDB Iter deserialize(key) error: {e}");
+                error!("Iter deserialize(key) error: {e}");
             })
             .ok()?;
         let value = bincode::deserialize(value)
             .map_err(|e| {
-                error!("RocksDB Iter deserialize(value) error: {e}");
+                error!("Iter deserialize(value) error: {e}");
             })
             .ok()?;
 
@@ -454,18 +530,20 @@ impl<
 }
 
 /// An iterator over the keys of a prefix.
-pub struct Keys<'a, K: 'a + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned> {
-    db_iter: rocksdb::DBRawIterator<'a>,
+pub struct Keys<'a, K: 'a + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned, D: 'a + RawDatabase> {
+    db_iter: D::Cursor<'a>,
     _phantom: PhantomData<K>,
 }
 
-impl<'a, K: 'a + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned> Keys<'a, K> {
-    pub(crate) fn new(db_iter: rocksdb::DBIterator<'a>) -> Self {
-        Self { db_iter: db_iter.into(), _phantom: PhantomData }
+impl<'a, K: 'a + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned, D: 'a + RawDatabase> Keys<'a, K, D> {
+    pub(crate) fn new(db_iter: D::Cursor<'a>) -> Self {
+        Self { db_iter, _phantom: PhantomData }
     }
 }
 
-impl<'a, K: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned> Iterator for Keys<'a, K> {
+impl<'a, K: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned, D: 'a + RawDatabase> Iterator
+    for Keys<'a, K, D>
+{
     type Item = Cow<'a, K>;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -476,7 +554,7 @@ impl<'a, K: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + Deserialize
         // Deserialize the key.
         let key = bincode::deserialize(&self.db_iter.key()?[PREFIX_LEN..])
             .map_err(|e| {
-                error!("RocksDB Keys deserialize(key) error: {e}");
+                error!("Keys deserialize(key) error: {e}");
             })
             .ok()?;
 
@@ -487,18 +565,20 @@ impl<'a, K: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + Deserialize
 }
 
 /// An iterator over the values of a prefix.
-pub struct Values<'a, V: 'a + PartialEq + Eq + Serialize + DeserializeOwned> {
-    db_iter: rocksdb::DBRawIterator<'a>,
+pub struct Values<'a, V: 'a + PartialEq + Eq + Serialize + DeserializeOwned, D: 'a + RawDatabase> {
+    db_iter: D::Cursor<'a>,
     _phantom: PhantomData<V>,
 }
 
-impl<'a, V: 'a + PartialEq + Eq + Serialize + DeserializeOwned> Values<'a, V> {
-    pub(crate) fn new(db_iter: rocksdb::DBIterator<'a>) -> Self {
-        Self { db_iter: db_iter.into(), _phantom: PhantomData }
+impl<'a, V: 'a + PartialEq + Eq + Serialize + DeserializeOwned, D: 'a + RawDatabase> Values<'a, V, D> {
+    pub(crate) fn new(db_iter: D::Cursor<'a>) -> Self {
+        Self { db_iter, _phantom: PhantomData }
     }
 }
 
-impl<'a, V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned> Iterator for Values<'a, V> {
+impl<'a, V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned, D: 'a + RawDatabase> Iterator
+    for Values<'a, V, D>
+{
     type Item = Cow<'a, V>;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -509,7 +589,7 @@ impl<'a, V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned> Iterator
         // Deserialize the value.
         let value = bincode::deserialize(self.db_iter.value()?)
             .map_err(|e| {
-                error!("RocksDB Values deserialize(value) error: {e}");
+                error!("Values deserialize(value) error: {e}");
             })
             .ok()?;
 
@@ -519,7 +599,7 @@ impl<'a, V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned> Iterator
     }
 }
 
-impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> DataMap<K, V> {
+impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, D: RawDatabase> DataMap<K, V, D> {
     #[inline]
     fn create_prefixed_key<Q>(&self, key: &Q) -> Result<SmallVec<[u8; 64]>>
     where
@@ -532,20 +612,17 @@ impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> DataMap<K
         Ok(raw_key)
     }
 
-    fn get_raw<Q>(&self, key: &Q) -> Result<Option<rocksdb::DBPinnableSlice>>
+    fn get_raw<Q>(&self, key: &Q) -> Result<Option<D::Value<'_>>>
     where
         K: Borrow<Q>,
         Q: Serialize + ?Sized,
     {
         let raw_key = self.create_prefixed_key(key)?;
-        match self.database.get_pinned_opt(&raw_key, &self.database.default_readopts)? {
-            Some(data) => Ok(Some(data)),
-            None => Ok(None),
-        }
+        self.database.get_raw(&raw_key)
     }
 }
 
-impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> fmt::Debug for DataMap<K, V> {
+impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, D: RawDatabase> fmt::Debug for DataMap<K, V, D> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("DataMap").field("context", &self.context).finish()
     }
@@ -558,52 +635,56 @@ mod tests {
         FinalizeMode,
         atomic_batch_scope,
         atomic_finalize,
-        helpers::rocksdb::{MapID, TestMap, internal::tests::temp_dir},
+        helpers::{
+            id::{MapID, TestMap},
+            persistent::tests::{TestDatabase, database_tests},
+        },
     };
     use console::{
         account::{Address, FromStr},
         network::MainnetV0,
     };
 
-    use anyhow::anyhow;
-    use serial_test::serial;
-    use tracing_test::traced_test;
+    use anyhow::{anyhow, bail};
 
     type CurrentNetwork = MainnetV0;
 
+    database_tests!(
+        test_contains_key_sanity_check,
+        test_insert_and_get_speculative,
+        test_remove_and_get_speculative,
+        test_contains_key,
+        test_check_iterators_match,
+        test_get_range,
+        test_atomic_writes_are_batched,
+        test_atomic_writes_can_be_aborted,
+        test_checkpoint_and_rewind,
+        test_nested_atomic_batch_scope -> anyhow::Result<()>,
+        test_failed_nested_atomic_batch_scope,
+        test_atomic_finalize -> anyhow::Result<()>,
+        test_atomic_finalize_failing_internal_scope -> anyhow::Result<()>,
+        test_atomic_finalize_fails_to_start,
+        test_atomic_checkpoint_truncation,
+        test_atomic_finalize_with_nested_batch_scope -> anyhow::Result<()>,
+        test_nested_atomic_write_batch_success -> anyhow::Result<()>,
+        test_nested_atomic_write_batch_failure,
+    );
+
     // Below are a few objects that mimic the way our DataMaps are organized,
     // in order to provide a more accurate test setup for some scenarios.
 
-    fn open_map_testing_from_db<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
-        database: RocksDB,
-        map_id: T,
-    ) -> DataMap<K, V> {
-        // Combine contexts to create a new scope.
-        let mut context = database.network_id.to_le_bytes().to_vec();
-        context.extend_from_slice(&(map_id.into()).to_le_bytes());
-
-        // Return the DataMap.
-        DataMap(Arc::new(InnerDataMap {
-            database,
-            context,
-            atomic_batch: Default::default(),
-            batch_in_progress: Default::default(),
-            checkpoints: Default::default(),
-        }))
-    }
-
-    struct TestStorage {
-        own_map: DataMap<usize, String>,
-        extra_maps: TestStorage2,
+    struct TestStorage<D: RawDatabase> {
+        own_map: DataMap<usize, String, D>,
+        extra_maps: TestStorage2<D>,
     }
 
-    impl TestStorage {
+    impl<D: TestDatabase> TestStorage<D> {
         fn open() -> Self {
             // Initialize a database.
-            let database = RocksDB::open_testing(temp_dir(), None).expect("Failed to open a test database");
+            let database = D::open_test_database();
 
             Self {
-                own_map: open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test)),
+                own_map: DataMap::new(database.clone(), MapID::Test(TestMap::Test)),
                 extra_maps: TestStorage2::open(database),
             }
         }
@@ -646,17 +727,17 @@ mod tests {
         }
     }
 
-    struct TestStorage2 {
-        own_map1: DataMap<usize, String>,
-        own_map2: DataMap<usize, String>,
-        extra_maps: TestStorage3,
+    struct TestStorage2<D: RawDatabase> {
+        own_map1: DataMap<usize, String, D>,
+        own_map2: DataMap<usize, String, D>,
+        extra_maps: TestStorage3<D>,
     }
 
-    impl TestStorage2 {
-        fn open(database: RocksDB) -> Self {
+    impl<D: TestDatabase> TestStorage2<D> {
+        fn open(database: D) -> Self {
             Self {
-                own_map1: open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test2)),
-                own_map2: open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test3)),
+                own_map1: DataMap::new(database.clone(), MapID::Test(TestMap::Test2)),
+                own_map2: DataMap::new(database.clone(), MapID::Test(TestMap::Test3)),
                 extra_maps: TestStorage3::open(database),
             }
         }
@@ -698,13 +779,13 @@ mod tests {
         }
     }
 
-    struct TestStorage3 {
-        own_map: DataMap<usize, String>,
+    struct TestStorage3<D: RawDatabase> {
+        own_map: DataMap<usize, String, D>,
     }
 
-    impl TestStorage3 {
-        fn open(database: RocksDB) -> Self {
-            Self { own_map: open_map_testing_from_db(database, MapID::Test(TestMap::Test4)) }
+    impl<D: TestDatabase> TestStorage3<D> {
+        fn open(database: D) -> Self {
+            Self { own_map: DataMap::new(database, MapID::Test(TestMap::Test4)) }
         }
 
         fn start_atomic(&self) {
@@ -732,95 +813,74 @@ mod tests {
         }
     }
 
-    #[test]
-    #[serial]
-    fn test_contains_key_sanity_check() {
+    fn test_contains_key_sanity_check<D: TestDatabase>() {
         // Initialize an address.
         let address =
             Address::<CurrentNetwork>::from_str("aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8")
                 .unwrap();
 
         // Initialize a map.
-        let map: DataMap<Address<CurrentNetwork>, ()> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<Address<CurrentNetwork>, (), D> =
+            DataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         map.insert(address, ()).expect("Failed to insert into data map");
         assert!(map.contains_key_confirmed(&address).unwrap());
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_insert_and_get_speculative() {
+    fn test_insert_and_get_speculative<D: TestDatabase>() {
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<usize, String, D> = DataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
 
         crate::helpers::test_helpers::map::check_insert_and_get_speculative(map);
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_remove_and_get_speculative() {
+    fn test_remove_and_get_speculative<D: TestDatabase>() {
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<usize, String, D> = DataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
 
         crate::helpers::test_helpers::map::check_remove_and_get_speculative(map);
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_contains_key() {
+    fn test_contains_key<D: TestDatabase>() {
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<usize, String, D> = DataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
 
         crate::helpers::test_helpers::map::check_contains_key(map);
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_check_iterators_match() {
+    fn test_check_iterators_match<D: TestDatabase>() {
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<usize, String, D> = DataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
 
         crate::helpers::test_helpers::map::check_iterators_match(map);
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_atomic_writes_are_batched() {
+    fn test_get_range<D: TestDatabase>() {
+        // Initialize a map.
+        let map: DataMap<usize, String, D> = DataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
+
+        crate::helpers::test_helpers::map::check_get_range(map);
+    }
+
+    fn test_atomic_writes_are_batched<D: TestDatabase>() {
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<usize, String, D> = DataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
 
         crate::helpers::test_helpers::map::check_atomic_writes_are_batched(map);
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_atomic_writes_can_be_aborted() {
+    fn test_atomic_writes_can_be_aborted<D: TestDatabase>() {
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<usize, String, D> = DataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
 
         crate::helpers::test_helpers::map::check_atomic_writes_can_be_aborted(map);
     }
 
-    #[test]
-    fn test_checkpoint_and_rewind() {
+    fn test_checkpoint_and_rewind<D: TestDatabase>() {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<usize, String, D> = DataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -887,14 +947,12 @@ mod tests {
         assert_eq!(map.checkpoints.lock().last(), None);
     }
 
-    #[test]
-    fn test_nested_atomic_batch_scope() -> Result<()> {
+    fn test_nested_atomic_batch_scope<D: TestDatabase>() -> Result<()> {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<usize, String, D> = DataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -949,14 +1007,12 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    fn test_failed_nested_atomic_batch_scope() {
+    fn test_failed_nested_atomic_batch_scope<D: TestDatabase>() {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<usize, String, D> = DataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1003,14 +1059,12 @@ mod tests {
         assert!(run_nested_atomic_batch_scope().is_err());
     }
 
-    #[test]
-    fn test_atomic_finalize() -> Result<()> {
+    fn test_atomic_finalize<D: TestDatabase>() -> Result<()> {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<usize, String, D> = DataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1082,14 +1136,12 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    fn test_atomic_finalize_failing_internal_scope() -> Result<()> {
+    fn test_atomic_finalize_failing_internal_scope<D: TestDatabase>() -> Result<()> {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<usize, String, D> = DataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1163,11 +1215,9 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    fn test_atomic_finalize_fails_to_start() {
+    fn test_atomic_finalize_fails_to_start<D: TestDatabase>() {
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<usize, String, D> = DataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1196,11 +1246,9 @@ mod tests {
         assert!(outcome().is_err());
     }
 
-    #[test]
-    fn test_atomic_checkpoint_truncation() {
+    fn test_atomic_checkpoint_truncation<D: TestDatabase>() {
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<usize, String, D> = DataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1254,11 +1302,9 @@ mod tests {
         assert_eq!(*map.iter_confirmed().next().unwrap().1, "1");
     }
 
-    #[test]
-    fn test_atomic_finalize_with_nested_batch_scope() -> Result<()> {
+    fn test_atomic_finalize_with_nested_batch_scope<D: TestDatabase>() -> Result<()> {
         // Initialize a map.
-        let map: DataMap<usize, String> =
-            RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMap::Test)).expect("Failed to open data map");
+        let map: DataMap<usize, String, D> = DataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1316,9 +1362,9 @@ mod tests {
                 // Ensure that the atomic batch length is 2.
                 assert_eq!(map.atomic_batch.lock().len(), 2);
                 // Ensure that the database atomic batch is empty.
-                assert!(map.database.atomic_batch.lock().is_empty());
+                assert!(map.database.atomic_batch().lock().is_empty());
                 // Ensure that the database atomic depth is 1.
-                assert_eq!(map.database.atomic_depth.load(Ordering::SeqCst), 1);
+                assert_eq!(map.database.atomic_depth().load(Ordering::SeqCst), 1);
 
                 // Simulates an instruction that fails.
                 let result: Result<()> = atomic_batch_scope!(map, {
@@ -1348,9 +1394,9 @@ mod tests {
             // Ensure that the atomic batch length is 1.
             assert_eq!(map.atomic_batch.lock().len(), 1);
             // Ensure that the database atomic batch is empty.
-            assert!(map.database.atomic_batch.lock().is_empty());
+            assert!(map.database.atomic_batch().lock().is_empty());
             // Ensure that the database atomic depth is 1.
-            assert_eq!(map.database.atomic_depth.load(Ordering::SeqCst), 1);
+            assert_eq!(map.database.atomic_depth().load(Ordering::SeqCst), 1);
 
             Ok(())
         });
@@ -1365,9 +1411,9 @@ mod tests {
         // Ensure that the atomic batch is empty.
         assert!(map.atomic_batch.lock().is_empty());
         // Ensure that the database atomic batch is empty.
-        assert!(map.database.atomic_batch.lock().is_empty());
+        assert!(map.database.atomic_batch().lock().is_empty());
         // Ensure that the database atomic depth is 0.
-        assert_eq!(map.database.atomic_depth.load(Ordering::SeqCst), 0);
+        assert_eq!(map.database.atomic_depth().load(Ordering::SeqCst), 0);
 
         // Ensure that the map value is correct.
         assert_eq!(*map.iter_confirmed().next().unwrap().1, "1");
@@ -1375,12 +1421,9 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_nested_atomic_write_batch_success() -> Result<()> {
+    fn test_nested_atomic_write_batch_success<D: TestDatabase>() -> Result<()> {
         // Initialize a multi-layer test storage.
-        let test_storage = TestStorage::open();
+        let test_storage = TestStorage::<D>::open();
 
         // Sanity check.
         assert!(test_storage.own_map.iter_confirmed().next().is_none());
@@ -1450,13 +1493,10 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_nested_atomic_write_batch_failure() {
+    fn test_nested_atomic_write_batch_failure<D: TestDatabase>() {
         // We'll want to execute the atomic write batch in its own function, in order to be able to
         // inspect the aftermatch after an error, as opposed to returning from the whole test.
-        fn execute_atomic_write_batch(test_storage: &TestStorage) -> Result<()> {
+        fn execute_atomic_write_batch<D: TestDatabase>(test_storage: &TestStorage<D>) -> Result<()> {
             // Start an atomic write batch.
             atomic_batch_scope!(test_storage, {
                 assert!(test_storage.is_atomic_in_progress_everywhere());
@@ -1500,7 +1540,7 @@ mod tests {
         }
 
         // Initialize a multi-layer test storage.
-        let test_storage = TestStorage::open();
+        let test_storage = TestStorage::<D>::open();
 
         // Sanity check.
         assert!(test_storage.own_map.iter_confirmed().next().is_none());
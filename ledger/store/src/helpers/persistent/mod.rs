@@ -0,0 +1,202 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod map;
+pub use map::*;
+
+mod nested_map;
+pub use nested_map::*;
+
+#[cfg(test)]
+pub(crate) mod tests;
+
+use crate::helpers::id::PREFIX_LEN;
+
+use anyhow::{Result, ensure};
+use parking_lot::Mutex;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{
+    borrow::Borrow,
+    marker::PhantomData,
+    mem,
+    ops::Deref,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+};
+
+/// The low-level operations of a persistent key-value database, which the data maps are built on.
+pub trait RawDatabase: Clone + Send + Sync {
+    /// The batch of writes that gets executed atomically.
+    type WriteBatch: RawWriteBatch;
+    /// A value read from the database.
+    type Value<'a>: Deref<Target = [u8]>
+    where
+        Self: 'a;
+    /// A cursor over the entries of the database.
+    type Cursor<'a>: RawCursor
+    where
+        Self: 'a;
+
+    /// Returns the network ID of the database.
+    fn network_id(&self) -> u16;
+
+    /// Returns the value for the given key, if it exists.
+    fn get_raw(&self, key: &[u8]) -> Result<Option<Self::Value<'_>>>;
+
+    /// Inserts the given key-value pair.
+    fn put_raw(&self, key: &[u8], value: &[u8]) -> Result<()>;
+
+    /// Removes the given key.
+    fn delete_raw(&self, key: &[u8]) -> Result<()>;
+
+    /// Executes the given batch of writes atomically.
+    fn write_batch(&self, batch: Self::WriteBatch) -> Result<()>;
+
+    /// Returns a cursor over the database, which must be positioned with `seek`.
+    fn raw_cursor(&self) -> Self::Cursor<'_>;
+
+    /// Returns a cursor positioned at the first entry that starts with the given prefix.
+    ///
+    /// The cursor is only guaranteed to stop at the end of the entries sharing the first `PREFIX_LEN`
+    /// bytes of the prefix; callers using a longer prefix must check for the end of their entries.
+    fn prefix_cursor(&self, prefix: &[u8]) -> Self::Cursor<'_>;
+
+    /// Returns the low-level database transaction that gets executed atomically at the end
+    /// of a real-run `atomic_finalize` or the outermost `atomic_batch_scope`.
+    /// Its lock is held while the transaction is executed, which makes it the lock on the atomic writes.
+    fn atomic_batch(&self) -> &Mutex<Self::WriteBatch>;
+
+    /// Returns the depth of the current atomic write batch; it gets incremented with every call
+    /// to `start_atomic` and decremented with each call to `finish_atomic`.
+    fn atomic_depth(&self) -> &AtomicUsize;
+
+    /// Returns the flag indicating whether the atomic writes are currently paused.
+    fn atomic_writes_paused(&self) -> &AtomicBool;
+
+    /// Records the given number of writes to the map with the given context.
+    fn record_writes(&self, _context: &[u8], _num_writes: usize) {}
+
+    /// Pause the execution of atomic writes for the entire database.
+    fn pause_atomic_writes(&self) -> Result<()> {
+        // This operation is only intended to be performed before or after
+        // atomic batches - never in the middle of them.
+        assert_eq!(self.atomic_depth().load(Ordering::SeqCst), 0);
+
+        // Set the flag indicating that the pause is in effect.
+        let already_paused = self.atomic_writes_paused().swap(true, Ordering::SeqCst);
+        // Make sure that we haven't already paused atomic writes (which would
+        // indicate a logic bug).
+        assert!(!already_paused);
+
+        Ok(())
+    }
+
+    /// Unpause the execution of atomic writes for the entire database; this
+    /// executes all the writes that have been queued since they were paused.
+    fn unpause_atomic_writes<const DISCARD_BATCH: bool>(&self) -> Result<()> {
+        // Ensure the call to unpause is only performed before or after an atomic batch scope
+        // - and never in the middle of one (otherwise there is a fundamental logic bug).
+        // Note: In production, this `ensure` is a safety-critical invariant that never fails.
+        ensure!(self.atomic_depth().load(Ordering::SeqCst) == 0, "Atomic depth must be 0 to unpause atomic writes");
+
+        // https://github.com/rust-lang/rust/issues/98485
+        let currently_paused = self.atomic_writes_paused().load(Ordering::SeqCst);
+        // Ensure the database is paused (otherwise there is a fundamental logic bug).
+        // Note: In production, this `ensure` is a safety-critical invariant that never fails.
+        ensure!(currently_paused, "Atomic writes must be paused to unpause them");
+
+        // In order to ensure that all the operations that are intended
+        // to be atomic via the usual macro approach are still performed
+        // atomically (just as a part of a larger batch), every atomic
+        // storage operation that has accumulated from the moment the
+        // writes have been paused becomes executed as a single atomic batch.
+        // Note: The lock on the atomic batch is held until the batch is executed,
+        // so that a concurrent checkpoint never observes a partially-committed batch.
+        let mut atomic_batch = self.atomic_batch().lock();
+        let batch = mem::take(&mut *atomic_batch);
+        if !DISCARD_BATCH {
+            self.write_batch(batch)?;
+        }
+        drop(atomic_batch);
+
+        // Unset the flag indicating that the pause is in effect.
+        self.atomic_writes_paused().store(false, Ordering::SeqCst);
+
+        Ok(())
+    }
+
+    /// Checks whether the atomic writes are currently paused.
+    fn are_atomic_writes_paused(&self) -> bool {
+        self.atomic_writes_paused().load(Ordering::SeqCst)
+    }
+}
+
+/// A batch of writes, which gets executed atomically.
+pub trait RawWriteBatch: Default + Send {
+    /// Queues the insertion of the given key-value pair.
+    fn put(&mut self, key: &[u8], value: &[u8]);
+
+    /// Queues the removal of the given key.
+    fn delete(&mut self, key: &[u8]);
+
+    /// Returns the number of queued writes.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no queued writes.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all the queued writes.
+    fn clear(&mut self);
+}
+
+/// A cursor over the entries of a database, in key order.
+pub trait RawCursor {
+    /// Positions the cursor at the first entry with a key greater than or equal to the given key.
+    fn seek(&mut self, key: &[u8]);
+
+    /// Returns `true` if the cursor is positioned at an entry.
+    fn valid(&self) -> bool;
+
+    /// Returns the key and value of the current entry.
+    fn item(&self) -> Option<(&[u8], &[u8])>;
+
+    /// Returns the key of the current entry.
+    fn key(&self) -> Option<&[u8]> {
+        self.item().map(|(key, _)| key)
+    }
+
+    /// Returns the value of the current entry.
+    fn value(&self) -> Option<&[u8]> {
+        self.item().map(|(_, value)| value)
+    }
+
+    /// Moves the cursor to the next entry.
+    fn next(&mut self);
+
+    /// Returns the error encountered while reading the entries, if any.
+    fn status(&self) -> Result<()>;
+}
+
+/// Returns the context of the map with the given ID in the given database.
+fn map_context<D: RawDatabase>(database: &D, map_id: u16) -> Vec<u8> {
+    // Combine contexts to create a new scope.
+    let mut context = database.network_id().to_le_bytes().to_vec();
+    context.extend_from_slice(&map_id.to_le_bytes());
+    context
+}
@@ -16,11 +16,11 @@
 #![allow(clippy::type_complexity)]
 
 use super::*;
-use crate::helpers::{NestedMap, NestedMapRead};
+use crate::helpers::{NestedMap, NestedMapRead, is_after_start, is_before_end, serialize_range};
 use console::prelude::{FromBytes, anyhow, cfg_into_iter};
 
 use core::{fmt, fmt::Debug, hash::Hash, mem};
-use std::{borrow::Cow, sync::atomic::Ordering};
+use std::{borrow::Cow, ops::Bound, sync::atomic::Ordering};
 use tracing::error;
 
 #[cfg(not(feature = "serial"))]
@@ -31,30 +31,43 @@ pub struct NestedDataMap<
     M: Serialize + DeserializeOwned,
     K: Serialize + DeserializeOwned,
     V: Serialize + DeserializeOwned,
+    D: RawDatabase,
 > {
-    /// The rocksDB instance.
-    pub(super) database: RocksDB,
+    /// The database instance.
+    pub(crate) database: D,
     /// The context for the map.
-    pub(super) context: Vec<u8>,
+    pub(crate) context: Vec<u8>,
     /// The tracker for whether a database transaction is in progress.
-    pub(super) batch_in_progress: Arc<AtomicBool>,
+    pub(crate) batch_in_progress: Arc<AtomicBool>,
     /// The database transaction.
-    pub(super) atomic_batch: Arc<Mutex<Vec<(M, Option<K>, Option<V>)>>>,
+    pub(crate) atomic_batch: Arc<Mutex<Vec<(M, Option<K>, Option<V>)>>>,
     /// The checkpoint stack for the batched operations within the map.
-    pub(super) checkpoints: Arc<Mutex<Vec<usize>>>,
+    pub(crate) checkpoints: Arc<Mutex<Vec<usize>>>,
 }
 
-impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> Debug
-    for NestedDataMap<M, K, V>
+impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, D: RawDatabase>
+    Debug for NestedDataMap<M, K, V, D>
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("NestedDataMap").field("context", &self.context).finish()
     }
 }
 
-impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned>
-    NestedDataMap<M, K, V>
+impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, D: RawDatabase>
+    NestedDataMap<M, K, V, D>
 {
+    /// Initializes the nested map with the given ID in the given database.
+    pub(crate) fn new<T: Into<u16>>(database: D, map_id: T) -> Self {
+        let context = map_context(&database, map_id.into());
+        Self {
+            database,
+            context,
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+            checkpoints: Default::default(),
+        }
+    }
+
     #[inline]
     fn create_prefixed_map(&self, map: &M) -> Result<Vec<u8>> {
         let mut raw_map = self.context.clone();
@@ -74,12 +87,9 @@ impl<M: Serialize + DeserializeOwned, K: Serialize + DeserializeOwned, V: Serial
     }
 
     #[inline]
-    fn get_map_key_raw(&self, map: &M, key: &K) -> Result<Option<rocksdb::DBPinnableSlice>> {
+    fn get_map_key_raw(&self, map: &M, key: &K) -> Result<Option<D::Value<'_>>> {
         let raw_map_key = self.create_prefixed_map_key(map, key)?;
-        match self.database.get_pinned_opt(&raw_map_key, &self.database.default_readopts)? {
-            Some(data) => Ok(Some(data)),
-            None => Ok(None),
-        }
+        self.database.get_raw(&raw_map_key)
     }
 }
 #[inline]
@@ -106,7 +116,8 @@ impl<
     M: 'a + Copy + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
     K: 'a + Clone + Debug + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
     V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
-> NestedMap<'a, M, K, V> for NestedDataMap<M, K, V>
+    D: 'a + RawDatabase,
+> NestedMap<'a, M, K, V> for NestedDataMap<M, K, V, D>
 {
     ///
     /// Inserts the given map-key-value pair.
@@ -121,7 +132,8 @@ impl<
                 // Prepare the prefixed map-key and serialized value.
                 let raw_key = self.create_prefixed_map_key(&map, &key)?;
                 let raw_value = bincode::serialize(&value)?;
-                self.database.put(raw_key, raw_value)?;
+                self.database.put_raw(&raw_key, &raw_value)?;
+                self.database.record_writes(&self.context, 1);
             }
         }
         Ok(())
@@ -141,17 +153,15 @@ impl<
                 let serialized_map = bincode::serialize(map)?;
 
                 // Batching the delete operations to optimize the write performance and ensure atomicity.
-                let mut batch = rocksdb::WriteBatch::default();
+                let mut batch = D::WriteBatch::default();
 
-                // Construct an iterator over the DB with the specified prefix.
-                let iterator = self.database.prefix_iterator(&self.create_prefixed_map(map)?);
+                // Construct a cursor over the DB with the specified prefix.
+                let mut iter = self.database.prefix_cursor(&self.create_prefixed_map(map)?);
 
                 // Iterate over the entries in the DB with the specified prefix.
-                for entry in iterator {
-                    let (map_key, _) = entry?;
-
+                while let Some(map_key) = iter.key() {
                     // Extract the bytes belonging to the map and the key.
-                    let Ok((entry_map, _)) = get_map_and_key(&map_key) else {
+                    let Ok((entry_map, _)) = get_map_and_key(map_key) else {
                         break;
                     };
 
@@ -163,10 +173,14 @@ impl<
                         // we've moved past the relevant keys and can break the loop.
                         break;
                     }
+                    iter.next();
                 }
+                // Ensure the iteration did not fail.
+                iter.status()?;
 
-                // Deleting the batched keys atomically from RocksDB.
-                self.database.write(batch)?;
+                // Deleting the batched keys atomically from the database.
+                self.database.record_writes(&self.context, batch.len());
+                self.database.write_batch(batch)?;
             }
         }
         Ok(())
@@ -184,7 +198,8 @@ impl<
             false => {
                 // Prepare the prefixed map-key.
                 let map_key = self.create_prefixed_map_key(map, key)?;
-                self.database.delete(map_key)?;
+                self.database.delete_raw(&map_key)?;
+                self.database.record_writes(&self.context, 1);
             }
         }
         Ok(())
@@ -198,14 +213,14 @@ impl<
         // Set the atomic batch flag to `true`.
         self.batch_in_progress.store(true, Ordering::SeqCst);
         // Increment the atomic depth index.
-        self.database.atomic_depth.fetch_add(1, Ordering::SeqCst);
+        self.database.atomic_depth().fetch_add(1, Ordering::SeqCst);
 
         // Ensure that the atomic batch is empty.
         assert!(self.atomic_batch.lock().is_empty());
         // Ensure that the database atomic batch is empty; skip this check if the atomic
         // writes are paused, as there may be pending operations.
         if !self.database.are_atomic_writes_paused() {
-            assert!(self.database.atomic_batch.lock().is_empty());
+            assert!(self.database.atomic_batch().lock().is_empty());
         }
     }
 
@@ -261,9 +276,9 @@ impl<
         // Set the atomic batch flag to `false`.
         self.batch_in_progress.store(false, Ordering::SeqCst);
         // Clear the database-wide atomic batch.
-        self.database.atomic_batch.lock().clear();
+        self.database.atomic_batch().lock().clear();
         // Reset the atomic batch depth.
-        self.database.atomic_depth.store(0, Ordering::SeqCst);
+        self.database.atomic_depth().store(0, Ordering::SeqCst);
     }
 
     ///
@@ -275,27 +290,26 @@ impl<
 
         if !operations.is_empty() {
             // Enqueue all the operations from the map in the database-wide batch.
-            let mut atomic_batch = self.database.atomic_batch.lock();
+            let mut atomic_batch = self.database.atomic_batch().lock();
+            let initial_len = atomic_batch.len();
 
             for (map, key, value) in operations {
                 match (key, value) {
                     (Some(key), Some(value)) => {
-                        atomic_batch.put(self.create_prefixed_map_key(&map, &key)?, bincode::serialize(&value)?)
+                        atomic_batch.put(&self.create_prefixed_map_key(&map, &key)?, &bincode::serialize(&value)?)
                     }
-                    (Some(key), None) => atomic_batch.delete(self.create_prefixed_map_key(&map, &key)?),
+                    (Some(key), None) => atomic_batch.delete(&self.create_prefixed_map_key(&map, &key)?),
                     (None, None) => {
                         // Serialize the map.
                         let serialized_map = bincode::serialize(&map)?;
 
-                        // Construct an iterator over the DB with the specified prefix.
-                        let iterator = self.database.prefix_iterator(&self.create_prefixed_map(&map)?);
+                        // Construct a cursor over the DB with the specified prefix.
+                        let mut iter = self.database.prefix_cursor(&self.create_prefixed_map(&map)?);
 
                         // Iterate over the entries in the DB with the specified prefix.
-                        for entry in iterator {
-                            let (map_key, _) = entry?;
-
+                        while let Some(map_key) = iter.key() {
                             // Extract the bytes belonging to the map and the key.
-                            let Ok((entry_map, _)) = get_map_and_key(&map_key) else {
+                            let Ok((entry_map, _)) = get_map_and_key(map_key) else {
                                 break;
                             };
 
@@ -307,11 +321,17 @@ impl<
                                 // we've moved past the relevant keys and can break the loop.
                                 break;
                             }
+                            iter.next();
                         }
+                        // Ensure the iteration did not fail.
+                        iter.status()?;
                     }
                     (None, Some(_)) => unreachable!("Cannot insert a value without a key"),
                 }
             }
+
+            // Record the writes to the map.
+            self.database.record_writes(&self.context, atomic_batch.len() - initial_len);
         }
 
         // Clear the checkpoint stack.
@@ -320,7 +340,7 @@ impl<
         self.batch_in_progress.store(false, Ordering::SeqCst);
 
         // Subtract the atomic depth index.
-        let previous_atomic_depth = self.database.atomic_depth.fetch_sub(1, Ordering::SeqCst);
+        let previous_atomic_depth = self.database.atomic_depth().fetch_sub(1, Ordering::SeqCst);
 
         // Ensure that the value of `atomic_depth` doesn't overflow, meaning that all the
         // calls to `start_atomic` have corresponding calls to `finish_atomic`.
@@ -331,11 +351,14 @@ impl<
         // atomic writes are paused.
         if previous_atomic_depth == 1 && !self.database.are_atomic_writes_paused() {
             // Empty the collection of pending operations.
-            let batch = mem::take(&mut *self.database.atomic_batch.lock());
+            // Note: The lock on the atomic batch is held until the operations are executed,
+            // so that a concurrent checkpoint never observes a partially-committed batch.
+            let mut atomic_batch = self.database.atomic_batch().lock();
+            let batch = mem::take(&mut *atomic_batch);
             // Execute all the operations atomically.
-            self.database.rocksdb.write(batch)?;
+            self.database.write_batch(batch)?;
             // Ensure that the database atomic batch is empty.
-            assert!(self.database.atomic_batch.lock().is_empty());
+            assert!(atomic_batch.is_empty());
         }
 
         Ok(())
@@ -347,15 +370,16 @@ impl<
     M: 'a + Copy + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned + Send + Sync,
     K: 'a + Clone + Debug + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
     V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned + Send + Sync,
-> NestedMapRead<'a, M, K, V> for NestedDataMap<M, K, V>
+    D: 'a + RawDatabase,
+> NestedMapRead<'a, M, K, V> for NestedDataMap<M, K, V, D>
 {
-    type Iterator = NestedIter<'a, M, K, V>;
-    type Keys = NestedKeys<'a, M, K>;
+    type Iterator = NestedIter<'a, M, K, V, D>;
+    type Keys = NestedKeys<'a, M, K, D>;
     type PendingIterator = core::iter::Map<
         std::vec::IntoIter<(M, Option<K>, Option<V>)>,
         fn((M, Option<K>, Option<V>)) -> (Cow<'a, M>, Option<Cow<'a, K>>, Option<Cow<'a, V>>),
     >;
-    type Values = NestedValues<'a, V>;
+    type Values = NestedValues<'a, V, D>;
 
     ///
     /// Returns the number of confirmed entries in the map.
@@ -366,7 +390,7 @@ impl<
         let serialized_map = &prefix[PREFIX_LEN + 4..];
 
         // A raw iterator doesn't allocate.
-        let mut iter = self.database.raw_iterator();
+        let mut iter = self.database.raw_cursor();
         // Find the first key with the nested map prefix.
         iter.seek(&prefix);
 
@@ -439,28 +463,29 @@ impl<
         // Initialize a vector for the entries.
         let mut entries = Vec::new();
 
-        // Construct an iterator over the DB with the specified prefix.
-        let iterator = self.database.prefix_iterator(&self.create_prefixed_map(map)?);
+        // Construct a cursor over the DB with the specified prefix.
+        let mut iter = self.database.prefix_cursor(&self.create_prefixed_map(map)?);
 
         // Iterate over the entries in the DB with the specified prefix.
-        for entry in iterator {
-            let (map_key, value) = entry?;
-
+        while let Some((map_key, value)) = iter.item() {
             // Extract the bytes belonging to the map and the key.
-            let Ok((entry_map, entry_key)) = get_map_and_key(&map_key) else {
+            let Ok((entry_map, entry_key)) = get_map_and_key(map_key) else {
                 break;
             };
 
             // If the 'entry_map' matches 'serialized_map', deserialize the key and value.
             if entry_map == serialized_map {
                 // Push the key-value pair to the vector.
-                entries.push((entry_key.to_owned(), value));
+                entries.push((entry_key.to_owned(), value.to_owned()));
             } else {
                 // If the 'entry_map' no longer matches the 'serialized_map',
                 // we've moved past the relevant keys and can break the loop.
                 break;
             }
+            iter.next();
         }
+        // Ensure the iteration did not fail.
+        iter.status()?;
 
         // Possibly deserialize the entries in parallel.
         Ok(cfg_into_iter!(entries)
@@ -574,21 +599,75 @@ impl<
     /// Returns an iterator visiting each key-value pair in the map.
     ///
     fn iter_confirmed(&'a self) -> Self::Iterator {
-        NestedIter::new(self.database.prefix_iterator(&self.context))
+        NestedIter::new(self.database.prefix_cursor(&self.context))
     }
 
     ///
     /// Returns an iterator over each key.
     ///
     fn keys_confirmed(&'a self) -> Self::Keys {
-        NestedKeys::new(self.database.prefix_iterator(&self.context))
+        NestedKeys::new(self.database.prefix_cursor(&self.context))
     }
 
     ///
     /// Returns an iterator over each value.
     ///
     fn values_confirmed(&'a self) -> Self::Values {
-        NestedValues::new(self.database.prefix_iterator(&self.context))
+        NestedValues::new(self.database.prefix_cursor(&self.context))
+    }
+
+    ///
+    /// Returns up to `limit` confirmed key-value pairs for the given map with a key in the given range,
+    /// in serialized key order.
+    ///
+    fn get_map_range_confirmed(
+        &'a self,
+        map: &M,
+        start: Bound<&K>,
+        end: Bound<&K>,
+        limit: usize,
+    ) -> Result<Vec<(K, V)>> {
+        // Serialize the range, and return early if it is empty.
+        let Some((start, end)) = serialize_range(start, end)? else {
+            return Ok(Vec::new());
+        };
+        // Obtain the nested map prefix.
+        let prefix = self.create_prefixed_map(map)?;
+
+        // A raw iterator doesn't allocate.
+        let mut iter = self.database.raw_cursor();
+        // Find the first key in the range.
+        let mut raw_start = prefix.clone();
+        if let Bound::Included(key) | Bound::Excluded(key) = &start {
+            raw_start.extend_from_slice(key);
+        }
+        iter.seek(&raw_start);
+
+        // Collect the key-value pairs in the range.
+        let mut entries = Vec::new();
+        while iter.valid() && entries.len() < limit {
+            let (Some(raw_key), Some(raw_value)) = (iter.key(), iter.value()) else {
+                break;
+            };
+            // If the nested map is different, it's the end of iteration.
+            if !raw_key.starts_with(&prefix) {
+                break;
+            }
+            let key = &raw_key[prefix.len()..];
+            // If the key is past the end of the range, it's the end of iteration.
+            if !is_before_end(key, &end) {
+                break;
+            }
+            // Skip the start key, if it is excluded.
+            if is_after_start(key, &start) {
+                entries.push((bincode::deserialize(key)?, bincode::deserialize(raw_value)?));
+            }
+            iter.next();
+        }
+        // Ensure the iteration did not fail.
+        iter.status()?;
+
+        Ok(entries)
     }
 }
 
@@ -598,8 +677,9 @@ pub struct NestedIter<
     M: 'a + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
     K: 'a + Debug + PartialEq + Eq + Serialize + DeserializeOwned,
     V: 'a + PartialEq + Eq + Serialize + DeserializeOwned,
+    D: 'a + RawDatabase,
 > {
-    db_iter: rocksdb::DBRawIterator<'a>,
+    db_iter: D::Cursor<'a>,
     _phantom: PhantomData<(M, K, V)>,
 }
 
@@ -608,10 +688,11 @@ impl<
     M: 'a + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
     K: 'a + Debug + PartialEq + Eq + Serialize + DeserializeOwned,
     V: 'a + PartialEq + Eq + Serialize + DeserializeOwned,
-> NestedIter<'a, M, K, V>
+    D: 'a + RawDatabase,
+> NestedIter<'a, M, K, V, D>
 {
-    pub(super) fn new(db_iter: rocksdb::DBIterator<'a>) -> Self {
-        Self { db_iter: db_iter.into(), _phantom: PhantomData }
+    pub(super) fn new(db_iter: D::Cursor<'a>) -> Self {
+        Self { db_iter, _phantom: PhantomData }
     }
 }
 
@@ -620,7 +701,8 @@ impl<
     M: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
     K: 'a + Clone + Debug + PartialEq + Eq + Serialize + DeserializeOwned,
     V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned,
-> Iterator for NestedIter<'a, M, K, V>
+    D: 'a + RawDatabase,
+> Iterator for NestedIter<'a, M, K, V, D>
 {
     type Item = (Cow<'a, M>, Cow<'a, K>, Cow<'a, V>);
 
@@ -634,25 +716,25 @@ impl<
         // Extract the bytes belonging to the map and the key.
         let (entry_map, entry_key) = get_map_and_key(map_key)
             .map_err(|e| {
-                error!("RocksDB NestedIter get_map_and_key error: {e}");
+                error!("NestedIter get_map_and_key error: {e}");
             })
             .ok()?;
 
         // Deserialize the map, key, and value.
         let map = bincode::deserialize(entry_map)
             .map_err(|e| {
-                error!("RocksDB NestedIter deserialize(map) error: {e}");
+                error!("NestedIter deserialize(map) error: {e}");
             })
             .ok()?;
         let key = bincode::deserialize(entry_key)
             .map_err(|e| {
-                error!("RocksDB NestedIter deserialize(key) error: {e}");
+                error!("NestedIter deserialize(key) error: {e}");
             })
             .ok()?;
         // Deserialize the value.
         let value = bincode::deserialize(value)
             .map_err(|e| {
-                error!("RocksDB NestedIter deserialize(value) error: {e}");
+                error!("NestedIter deserialize(value) error: {e}");
             })
             .ok()?;
 
@@ -667,8 +749,9 @@ pub struct NestedKeys<
     'a,
     M: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
     K: 'a + Clone + Debug + PartialEq + Eq + Serialize + DeserializeOwned,
+    D: 'a + RawDatabase,
 > {
-    db_iter: rocksdb::DBRawIterator<'a>,
+    db_iter: D::Cursor<'a>,
     _phantom: PhantomData<(M, K)>,
 }
 
@@ -676,10 +759,11 @@ impl<
     'a,
     M: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
     K: 'a + Clone + Debug + PartialEq + Eq + Serialize + DeserializeOwned,
-> NestedKeys<'a, M, K>
+    D: 'a + RawDatabase,
+> NestedKeys<'a, M, K, D>
 {
-    pub(crate) fn new(db_iter: rocksdb::DBIterator<'a>) -> Self {
-        Self { db_iter: db_iter.into(), _phantom: PhantomData }
+    pub(crate) fn new(db_iter: D::Cursor<'a>) -> Self {
+        Self { db_iter, _phantom: PhantomData }
     }
 }
 
@@ -687,7 +771,8 @@ impl<
     'a,
     M: 'a + Clone + Debug + PartialEq + Eq + Hash + Serialize + DeserializeOwned,
     K: 'a + Clone + Debug + PartialEq + Eq + Serialize + DeserializeOwned,
-> Iterator for NestedKeys<'a, M, K>
+    D: 'a + RawDatabase,
+> Iterator for NestedKeys<'a, M, K, D>
 {
     type Item = (Cow<'a, M>, Cow<'a, K>);
 
@@ -701,19 +786,19 @@ impl<
         // Extract the bytes belonging to the map and the key.
         let (entry_map, entry_key) = get_map_and_key(map_key)
             .map_err(|e| {
-                error!("RocksDB NestedKeys get_map_and_key error: {e}");
+                error!("NestedKeys get_map_and_key error: {e}");
             })
             .ok()?;
 
         // Deserialize the map and key.
         let map = bincode::deserialize(entry_map)
             .map_err(|e| {
-                error!("RocksDB NestedKeys deserialize(map) error: {e}");
+                error!("NestedKeys deserialize(map) error: {e}");
             })
             .ok()?;
         let key = bincode::deserialize(entry_key)
             .map_err(|e| {
-                error!("RocksDB NestedKeys deserialize(key) error: {e}");
+                error!("NestedKeys deserialize(key) error: {e}");
             })
             .ok()?;
 
@@ -724,18 +809,20 @@ impl<
 }
 
 /// An iterator over the values of a prefix.
-pub struct NestedValues<'a, V: 'a + PartialEq + Eq + Serialize + DeserializeOwned> {
-    db_iter: rocksdb::DBRawIterator<'a>,
+pub struct NestedValues<'a, V: 'a + PartialEq + Eq + Serialize + DeserializeOwned, D: 'a + RawDatabase> {
+    db_iter: D::Cursor<'a>,
     _phantom: PhantomData<V>,
 }
 
-impl<'a, V: 'a + PartialEq + Eq + Serialize + DeserializeOwned> NestedValues<'a, V> {
-    pub(crate) fn new(db_iter: rocksdb::DBIterator<'a>) -> Self {
-        Self { db_iter: db_iter.into(), _phantom: PhantomData }
+impl<'a, V: 'a + PartialEq + Eq + Serialize + DeserializeOwned, D: 'a + RawDatabase> NestedValues<'a, V, D> {
+    pub(crate) fn new(db_iter: D::Cursor<'a>) -> Self {
+        Self { db_iter, _phantom: PhantomData }
     }
 }
 
-impl<'a, V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned> Iterator for NestedValues<'a, V> {
+impl<'a, V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned, D: 'a + RawDatabase> Iterator
+    for NestedValues<'a, V, D>
+{
     type Item = Cow<'a, V>;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -748,7 +835,7 @@ impl<'a, V: 'a + Clone + PartialEq + Eq + Serialize + DeserializeOwned> Iterator
         // Deserialize the value.
         let value = bincode::deserialize(value)
             .map_err(|e| {
-                error!("RocksDB NestedValues deserialize(value) error: {e}");
+                error!("NestedValues deserialize(value) error: {e}");
             })
             .ok()?;
 
@@ -766,7 +853,8 @@ mod tests {
         atomic_batch_scope,
         atomic_finalize,
         helpers::{
-            rocksdb::{MapID, TestMap, internal::tests::temp_dir},
+            id::{MapID, TestMap},
+            persistent::tests::{TestDatabase, database_tests},
             traits::Map,
         },
     };
@@ -775,72 +863,48 @@ mod tests {
         network::MainnetV0,
     };
 
-    use anyhow::anyhow;
-    use serial_test::serial;
-    use tracing_test::traced_test;
+    use anyhow::{anyhow, bail};
 
     type CurrentNetwork = MainnetV0;
 
+    database_tests!(
+        test_contains_key_sanity_check,
+        test_insert_and_get_value_speculative,
+        test_remove_key_and_get_value_speculative,
+        test_contains_key,
+        test_get_map,
+        test_get_map_range,
+        test_check_iterators_match,
+        test_iter_from_nested_to_non_nested,
+        test_atomic_writes_are_batched,
+        test_atomic_writes_can_be_aborted,
+        test_checkpoint_and_rewind,
+        test_nested_atomic_batch_scope -> anyhow::Result<()>,
+        test_failed_nested_atomic_batch_scope,
+        test_atomic_finalize -> anyhow::Result<()>,
+        test_atomic_finalize_failing_internal_scope -> anyhow::Result<()>,
+        test_atomic_finalize_fails_to_start,
+        test_atomic_checkpoint_truncation,
+        test_atomic_finalize_with_nested_batch_scope -> anyhow::Result<()>,
+        test_nested_atomic_write_batch_success -> anyhow::Result<()>,
+        test_nested_atomic_write_batch_failure,
+    );
+
     // Below are a few objects that mimic the way our NestedDataMaps are organized,
     // in order to provide a more accurate test setup for some scenarios.
 
-    fn open_map_testing_from_db<
-        M: Serialize + DeserializeOwned,
-        K: Serialize + DeserializeOwned,
-        V: Serialize + DeserializeOwned,
-        T: Into<u16>,
-    >(
-        database: RocksDB,
-        map_id: T,
-    ) -> NestedDataMap<M, K, V> {
-        // Combine contexts to create a new scope.
-        let mut context = database.network_id.to_le_bytes().to_vec();
-        context.extend_from_slice(&(map_id.into()).to_le_bytes());
-
-        // Return the NestedDataMap.
-        NestedDataMap {
-            database,
-            context,
-            atomic_batch: Default::default(),
-            batch_in_progress: Default::default(),
-            checkpoints: Default::default(),
-        }
+    struct TestStorage<D: RawDatabase> {
+        own_map: NestedDataMap<usize, usize, String, D>,
+        extra_maps: TestStorage2<D>,
     }
 
-    fn open_non_nested_map_testing_from_db<
-        K: Serialize + DeserializeOwned,
-        V: Serialize + DeserializeOwned,
-        T: Into<u16>,
-    >(
-        database: RocksDB,
-        map_id: T,
-    ) -> DataMap<K, V> {
-        // Combine contexts to create a new scope.
-        let mut context = database.network_id.to_le_bytes().to_vec();
-        context.extend_from_slice(&(map_id.into()).to_le_bytes());
-
-        // Return the DataMap.
-        DataMap(Arc::new(InnerDataMap {
-            database,
-            context,
-            atomic_batch: Default::default(),
-            batch_in_progress: Default::default(),
-            checkpoints: Default::default(),
-        }))
-    }
-
-    struct TestStorage {
-        own_map: NestedDataMap<usize, usize, String>,
-        extra_maps: TestStorage2,
-    }
-
-    impl TestStorage {
+    impl<D: TestDatabase> TestStorage<D> {
         fn open() -> Self {
             // Initialize a database.
-            let database = RocksDB::open_testing(temp_dir(), None).expect("Failed to open a test database");
+            let database = D::open_test_database();
 
             Self {
-                own_map: open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test)),
+                own_map: NestedDataMap::new(database.clone(), MapID::Test(TestMap::Test)),
                 extra_maps: TestStorage2::open(database),
             }
         }
@@ -883,17 +947,17 @@ mod tests {
         }
     }
 
-    struct TestStorage2 {
-        own_map1: NestedDataMap<usize, usize, String>,
-        own_map2: NestedDataMap<usize, usize, String>,
-        extra_maps: TestStorage3,
+    struct TestStorage2<D: RawDatabase> {
+        own_map1: NestedDataMap<usize, usize, String, D>,
+        own_map2: NestedDataMap<usize, usize, String, D>,
+        extra_maps: TestStorage3<D>,
     }
 
-    impl TestStorage2 {
-        fn open(database: RocksDB) -> Self {
+    impl<D: TestDatabase> TestStorage2<D> {
+        fn open(database: D) -> Self {
             Self {
-                own_map1: open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test2)),
-                own_map2: open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test3)),
+                own_map1: NestedDataMap::new(database.clone(), MapID::Test(TestMap::Test2)),
+                own_map2: NestedDataMap::new(database.clone(), MapID::Test(TestMap::Test3)),
                 extra_maps: TestStorage3::open(database),
             }
         }
@@ -935,16 +999,16 @@ mod tests {
         }
     }
 
-    struct TestStorage3 {
-        own_nested_map: NestedDataMap<usize, usize, String>,
-        own_map: DataMap<usize, String>,
+    struct TestStorage3<D: RawDatabase> {
+        own_nested_map: NestedDataMap<usize, usize, String, D>,
+        own_map: DataMap<usize, String, D>,
     }
 
-    impl TestStorage3 {
-        fn open(database: RocksDB) -> Self {
+    impl<D: TestDatabase> TestStorage3<D> {
+        fn open(database: D) -> Self {
             Self {
-                own_nested_map: open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test4)),
-                own_map: open_non_nested_map_testing_from_db(database, MapID::Test(TestMap::Test5)),
+                own_nested_map: NestedDataMap::new(database.clone(), MapID::Test(TestMap::Test4)),
+                own_map: DataMap::new(database, MapID::Test(TestMap::Test5)),
             }
         }
 
@@ -978,9 +1042,7 @@ mod tests {
         }
     }
 
-    #[test]
-    #[serial]
-    fn test_contains_key_sanity_check() {
+    fn test_contains_key_sanity_check<D: TestDatabase>() {
         // Initialize 'm'.
         let m = 0usize;
         // Initialize an address.
@@ -989,79 +1051,63 @@ mod tests {
                 .unwrap();
 
         // Initialize a map.
-        let map: NestedDataMap<usize, Address<CurrentNetwork>, ()> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
+        let map: NestedDataMap<usize, Address<CurrentNetwork>, (), D> =
+            NestedDataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         map.insert(m, address, ()).expect("Failed to insert into data map");
         assert!(map.contains_key_confirmed(&m, &address).unwrap());
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_insert_and_get_value_speculative() {
+    fn test_insert_and_get_value_speculative<D: TestDatabase>() {
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
+        let map: NestedDataMap<usize, usize, String, D> =
+            NestedDataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
 
         crate::helpers::test_helpers::nested_map::check_insert_and_get_value_speculative(map);
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_remove_key_and_get_value_speculative() {
+    fn test_remove_key_and_get_value_speculative<D: TestDatabase>() {
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
+        let map: NestedDataMap<usize, usize, String, D> =
+            NestedDataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
 
         crate::helpers::test_helpers::nested_map::check_remove_and_get_value_speculative(map);
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_contains_key() {
+    fn test_contains_key<D: TestDatabase>() {
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
+        let map: NestedDataMap<usize, usize, String, D> =
+            NestedDataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
 
         crate::helpers::test_helpers::nested_map::check_contains_key(map);
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_get_map() {
+    fn test_get_map<D: TestDatabase>() {
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
+        let map: NestedDataMap<usize, usize, String, D> =
+            NestedDataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
 
         crate::helpers::test_helpers::nested_map::check_get_map(map);
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_check_iterators_match() {
+    fn test_get_map_range<D: TestDatabase>() {
+        // Initialize a map.
+        let map: NestedDataMap<usize, usize, String, D> =
+            NestedDataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
+
+        crate::helpers::test_helpers::nested_map::check_get_map_range(map);
+    }
+
+    fn test_check_iterators_match<D: TestDatabase>() {
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
+        let map: NestedDataMap<usize, usize, String, D> =
+            NestedDataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
 
         crate::helpers::test_helpers::nested_map::check_iterators_match(map);
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_iter_from_nested_to_non_nested() {
+    fn test_iter_from_nested_to_non_nested<D: TestDatabase>() {
         // Open a storage with a DataMap right after a NestedDataMap.
-        let database = RocksDB::open_testing(temp_dir(), None).expect("Failed to open a test database");
+        let database = D::open_test_database();
         let test_storage = TestStorage3::open(database);
 
         // Insert 5 (confirmed) records into a nested map 77.
@@ -1079,39 +1125,29 @@ mod tests {
         assert_eq!(confirmed.len(), 5);
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_atomic_writes_are_batched() {
+    fn test_atomic_writes_are_batched<D: TestDatabase>() {
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
+        let map: NestedDataMap<usize, usize, String, D> =
+            NestedDataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
 
         crate::helpers::test_helpers::nested_map::check_atomic_writes_are_batched(map);
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_atomic_writes_can_be_aborted() {
+    fn test_atomic_writes_can_be_aborted<D: TestDatabase>() {
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
+        let map: NestedDataMap<usize, usize, String, D> =
+            NestedDataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
 
         crate::helpers::test_helpers::nested_map::check_atomic_writes_can_be_aborted(map);
     }
 
-    #[test]
-    fn test_checkpoint_and_rewind() {
+    fn test_checkpoint_and_rewind<D: TestDatabase>() {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
+        let map: NestedDataMap<usize, usize, String, D> =
+            NestedDataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1178,15 +1214,13 @@ mod tests {
         assert_eq!(map.checkpoints.lock().last(), None);
     }
 
-    #[test]
-    fn test_nested_atomic_batch_scope() -> Result<()> {
+    fn test_nested_atomic_batch_scope<D: TestDatabase>() -> Result<()> {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
+        let map: NestedDataMap<usize, usize, String, D> =
+            NestedDataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1241,15 +1275,13 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    fn test_failed_nested_atomic_batch_scope() {
+    fn test_failed_nested_atomic_batch_scope<D: TestDatabase>() {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
+        let map: NestedDataMap<usize, usize, String, D> =
+            NestedDataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1296,15 +1328,13 @@ mod tests {
         assert!(run_nested_atomic_batch_scope().is_err());
     }
 
-    #[test]
-    fn test_atomic_finalize() -> Result<()> {
+    fn test_atomic_finalize<D: TestDatabase>() -> Result<()> {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
+        let map: NestedDataMap<usize, usize, String, D> =
+            NestedDataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1376,15 +1406,13 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    fn test_atomic_finalize_failing_internal_scope() -> Result<()> {
+    fn test_atomic_finalize_failing_internal_scope<D: TestDatabase>() -> Result<()> {
         // The number of items that will be queued to be inserted into the map.
         const NUM_ITEMS: usize = 10;
 
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
+        let map: NestedDataMap<usize, usize, String, D> =
+            NestedDataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1458,12 +1486,10 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    fn test_atomic_finalize_fails_to_start() {
+    fn test_atomic_finalize_fails_to_start<D: TestDatabase>() {
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
+        let map: NestedDataMap<usize, usize, String, D> =
+            NestedDataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1492,12 +1518,10 @@ mod tests {
         assert!(outcome().is_err());
     }
 
-    #[test]
-    fn test_atomic_checkpoint_truncation() {
+    fn test_atomic_checkpoint_truncation<D: TestDatabase>() {
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
+        let map: NestedDataMap<usize, usize, String, D> =
+            NestedDataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1554,12 +1578,10 @@ mod tests {
         assert_eq!(map.iter_confirmed().next().unwrap(), (Cow::Owned(0), Cow::Owned(0), Cow::Owned("1".to_string())));
     }
 
-    #[test]
-    fn test_atomic_finalize_with_nested_batch_scope() -> Result<()> {
+    fn test_atomic_finalize_with_nested_batch_scope<D: TestDatabase>() -> Result<()> {
         // Initialize a map.
-        let map: NestedDataMap<usize, usize, String> =
-            RocksDB::open_nested_map_testing(temp_dir(), None, MapID::Test(TestMap::Test))
-                .expect("Failed to open data map");
+        let map: NestedDataMap<usize, usize, String, D> =
+            NestedDataMap::new(D::open_test_database(), MapID::Test(TestMap::Test));
         // Sanity check.
         assert!(map.iter_confirmed().next().is_none());
         // Make sure the checkpoint index is None.
@@ -1617,9 +1639,9 @@ mod tests {
                 // Ensure that the atomic batch length is 2.
                 assert_eq!(map.atomic_batch.lock().len(), 2);
                 // Ensure that the database atomic batch is empty.
-                assert!(map.database.atomic_batch.lock().is_empty());
+                assert!(map.database.atomic_batch().lock().is_empty());
                 // Ensure that the database atomic depth is 1.
-                assert_eq!(map.database.atomic_depth.load(Ordering::SeqCst), 1);
+                assert_eq!(map.database.atomic_depth().load(Ordering::SeqCst), 1);
 
                 // Simulates an instruction that fails.
                 let result: Result<()> = atomic_batch_scope!(map, {
@@ -1649,9 +1671,9 @@ mod tests {
             // Ensure that the atomic batch length is 1.
             assert_eq!(map.atomic_batch.lock().len(), 1);
             // Ensure that the database atomic batch is empty.
-            assert!(map.database.atomic_batch.lock().is_empty());
+            assert!(map.database.atomic_batch().lock().is_empty());
             // Ensure that the database atomic depth is 1.
-            assert_eq!(map.database.atomic_depth.load(Ordering::SeqCst), 1);
+            assert_eq!(map.database.atomic_depth().load(Ordering::SeqCst), 1);
 
             Ok(())
         });
@@ -1666,9 +1688,9 @@ mod tests {
         // Ensure that the atomic batch is empty.
         assert!(map.atomic_batch.lock().is_empty());
         // Ensure that the database atomic batch is empty.
-        assert!(map.database.atomic_batch.lock().is_empty());
+        assert!(map.database.atomic_batch().lock().is_empty());
         // Ensure that the database atomic depth is 0.
-        assert_eq!(map.database.atomic_depth.load(Ordering::SeqCst), 0);
+        assert_eq!(map.database.atomic_depth().load(Ordering::SeqCst), 0);
 
         // Ensure that the map value is correct.
         assert_eq!(map.iter_confirmed().next().unwrap(), (Cow::Owned(0), Cow::Owned(0), Cow::Owned("1".to_string())));
@@ -1676,12 +1698,9 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_nested_atomic_write_batch_success() -> Result<()> {
+    fn test_nested_atomic_write_batch_success<D: TestDatabase>() -> Result<()> {
         // Initialize a multi-layer test storage.
-        let test_storage = TestStorage::open();
+        let test_storage = TestStorage::<D>::open();
 
         // Sanity check.
         assert!(test_storage.own_map.iter_confirmed().next().is_none());
@@ -1747,13 +1766,10 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    #[serial]
-    #[traced_test]
-    fn test_nested_atomic_write_batch_failure() {
+    fn test_nested_atomic_write_batch_failure<D: TestDatabase>() {
         // We'll want to execute the atomic write batch in its own function, in order to be able to
         // inspect the aftermatch after an error, as opposed to returning from the whole test.
-        fn execute_atomic_write_batch(test_storage: &TestStorage) -> Result<()> {
+        fn execute_atomic_write_batch<D: TestDatabase>(test_storage: &TestStorage<D>) -> Result<()> {
             // Start an atomic write batch.
             atomic_batch_scope!(test_storage, {
                 assert!(test_storage.is_atomic_in_progress_everywhere());
@@ -1797,7 +1813,7 @@ mod tests {
         }
 
         // Initialize a multi-layer test storage.
-        let test_storage = TestStorage::open();
+        let test_storage = TestStorage::<D>::open();
 
         // Sanity check.
         assert!(test_storage.own_map.iter_confirmed().next().is_none());
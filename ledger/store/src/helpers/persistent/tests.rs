@@ -0,0 +1,210 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::{
+    Map,
+    MapRead,
+    id::{MapID, TestMap as TestMapID},
+    persistent::{DataMap, RawDatabase},
+};
+use console::{
+    network::{MainnetV0, Network},
+    prelude::{TestRng, Uniform},
+    types::Scalar,
+};
+
+pub(crate) fn temp_dir() -> std::path::PathBuf {
+    tempfile::tempdir().expect("Failed to open temporary directory").into_path()
+}
+
+/// A database that the shared test suites are run against.
+pub(crate) trait TestDatabase: RawDatabase + 'static {
+    /// Opens a new test database.
+    fn open_test_database() -> Self;
+}
+
+#[cfg(feature = "rocks")]
+impl TestDatabase for crate::helpers::rocksdb::RocksDB {
+    fn open_test_database() -> Self {
+        Self::open_testing(temp_dir(), None).expect("Failed to open a test database")
+    }
+}
+
+#[cfg(feature = "embedded")]
+impl TestDatabase for crate::helpers::redb::Redb {
+    fn open_test_database() -> Self {
+        Self::open_testing(temp_dir(), None).expect("Failed to open a test database")
+    }
+}
+
+/// Runs each of the given generic tests against every enabled persistent database.
+macro_rules! database_tests {
+    ($($test:ident $(-> $output:ty)?),* $(,)?) => {
+        #[cfg(feature = "rocks")]
+        mod rocksdb {
+            $(
+                #[test]
+                #[serial_test::serial]
+                fn $test() $(-> $output)? {
+                    super::$test::<crate::helpers::rocksdb::RocksDB>()
+                }
+            )*
+        }
+
+        #[cfg(feature = "embedded")]
+        mod redb {
+            $(
+                #[test]
+                #[serial_test::serial]
+                fn $test() $(-> $output)? {
+                    super::$test::<crate::helpers::redb::Redb>()
+                }
+            )*
+        }
+    };
+}
+pub(crate) use database_tests;
+
+database_tests!(
+    test_insert_and_contains_key,
+    test_insert_and_get,
+    test_insert_and_remove,
+    test_insert_and_iter,
+    test_insert_and_keys,
+    test_insert_and_values,
+    test_scalar_mul,
+    test_iterator_ordering,
+);
+
+fn test_insert_and_contains_key<D: TestDatabase>() {
+    let map = DataMap::new(D::open_test_database(), MapID::Test(TestMapID::Test));
+
+    map.insert(123456789, "123456789".to_string()).expect("Failed to insert");
+    assert!(map.contains_key_confirmed(&123456789).expect("Failed to call contains key"));
+    assert!(!map.contains_key_confirmed(&000000000).expect("Failed to call contains key"));
+}
+
+fn test_insert_and_get<D: TestDatabase>() {
+    let map = DataMap::new(D::open_test_database(), MapID::Test(TestMapID::Test));
+
+    map.insert(123456789, "123456789".to_string()).expect("Failed to insert");
+    assert_eq!(
+        Some("123456789".to_string()),
+        map.get_confirmed(&123456789).expect("Failed to get").map(|v| v.to_string())
+    );
+
+    assert_eq!(None, map.get_confirmed(&000000000).expect("Failed to get"));
+}
+
+fn test_insert_and_remove<D: TestDatabase>() {
+    let map = DataMap::new(D::open_test_database(), MapID::Test(TestMapID::Test));
+
+    map.insert(123456789, "123456789".to_string()).expect("Failed to insert");
+    assert_eq!(
+        map.get_confirmed(&123456789).expect("Failed to get").map(|v| v.to_string()),
+        Some("123456789".to_string())
+    );
+
+    map.remove(&123456789).expect("Failed to remove");
+    assert!(map.get_confirmed(&123456789).expect("Failed to get").is_none());
+}
+
+fn test_insert_and_iter<D: TestDatabase>() {
+    let map = DataMap::new(D::open_test_database(), MapID::Test(TestMapID::Test));
+
+    map.insert(123456789, "123456789".to_string()).expect("Failed to insert");
+
+    let mut iter = map.iter_confirmed();
+    assert_eq!(Some((123456789, "123456789".to_string())), iter.next().map(|(k, v)| (*k, v.to_string())));
+    assert_eq!(None, iter.next());
+}
+
+fn test_insert_and_keys<D: TestDatabase>() {
+    let map = DataMap::new(D::open_test_database(), MapID::Test(TestMapID::Test));
+
+    map.insert(123456789, "123456789".to_string()).expect("Failed to insert");
+
+    let mut keys = map.keys_confirmed();
+    assert_eq!(Some(123456789), keys.next().map(|k| *k));
+    assert_eq!(None, keys.next());
+}
+
+fn test_insert_and_values<D: TestDatabase>() {
+    let map = DataMap::new(D::open_test_database(), MapID::Test(TestMapID::Test));
+
+    map.insert(123456789, "123456789".to_string()).expect("Failed to insert");
+
+    let mut values = map.values_confirmed();
+    assert_eq!(Some("123456789".to_string()), values.next().map(|v| v.to_string()));
+    assert_eq!(None, values.next());
+}
+
+fn test_scalar_mul<D: TestDatabase>() {
+    type CurrentNetwork = MainnetV0;
+
+    let rng = &mut TestRng::default();
+
+    const ITERATIONS: u32 = 1_000_000u32;
+
+    let map = DataMap::new(D::open_test_database(), MapID::Test(TestMapID::Test));
+
+    // Sample `ITERATION` random field elements to store.
+    for i in 0..ITERATIONS {
+        let value = Scalar::<CurrentNetwork>::rand(rng);
+        map.insert(i, value).expect("Failed to insert");
+    }
+
+    let timer = std::time::Instant::now();
+
+    // Execute scalar multiplication for each stored element.
+    for value in map.values_confirmed() {
+        let _group = CurrentNetwork::g_scalar_multiply(&*value);
+    }
+
+    let elapsed = timer.elapsed().as_secs();
+    println!(" {ITERATIONS} Scalar Muls : {elapsed} s");
+}
+
+fn test_iterator_ordering<D: TestDatabase>() {
+    let map = DataMap::new(D::open_test_database(), MapID::Test(TestMapID::Test));
+
+    // Insert values into the map.
+    map.insert(5, "d".to_string()).expect("Failed to insert");
+    map.insert(6, "c".to_string()).expect("Failed to insert");
+    map.insert(7, "b".to_string()).expect("Failed to insert");
+    map.insert(8, "a".to_string()).expect("Failed to insert");
+    map.insert(1, "h".to_string()).expect("Failed to insert");
+    map.insert(2, "g".to_string()).expect("Failed to insert");
+    map.insert(3, "f".to_string()).expect("Failed to insert");
+    map.insert(4, "e".to_string()).expect("Failed to insert");
+
+    // Define the expected order of the iterator.
+    let expected_order = vec![
+        (1, "h".to_string()),
+        (2, "g".to_string()),
+        (3, "f".to_string()),
+        (4, "e".to_string()),
+        (5, "d".to_string()),
+        (6, "c".to_string()),
+        (7, "b".to_string()),
+        (8, "a".to_string()),
+    ];
+
+    // Check that the order of the iterator is lexicographical.
+    for ((k1, v1), (k2, v2)) in map.iter_confirmed().zip(expected_order.iter()) {
+        assert_eq!(&*k1, k2);
+        assert_eq!(&*v1, v2);
+    }
+}
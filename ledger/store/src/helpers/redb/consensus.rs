@@ -0,0 +1,75 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    BlockStore,
+    ConsensusStorage,
+    FinalizeStore,
+    helpers::redb::{BlockRedb, Database, FinalizeRedb, Redb, TransactionRedb, TransitionRedb},
+};
+use console::prelude::*;
+
+use aleo_std_storage::StorageMode;
+use std::path::Path;
+
+/// A redb consensus storage.
+#[derive(Clone)]
+pub struct ConsensusRedb<N: Network> {
+    /// The finalize store.
+    finalize_store: FinalizeStore<N, FinalizeRedb<N>>,
+    /// The block store.
+    block_store: BlockStore<N, BlockRedb<N>>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> ConsensusStorage<N> for ConsensusRedb<N> {
+    type FinalizeStorage = FinalizeRedb<N>;
+    type BlockStorage = BlockRedb<N>;
+    type TransactionStorage = TransactionRedb<N>;
+    type TransitionStorage = TransitionRedb<N>;
+
+    /// Initializes the consensus storage.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        // Initialize the finalize store.
+        let finalize_store = FinalizeStore::<N, FinalizeRedb<N>>::open(storage.clone())?;
+        // Initialize the block store.
+        let block_store = BlockStore::<N, BlockRedb<N>>::open(storage)?;
+        // Return the consensus storage.
+        Ok(Self {
+            finalize_store,
+            block_store,
+        })
+    }
+
+    /// Returns the finalize store.
+    fn finalize_store(&self) -> &FinalizeStore<N, Self::FinalizeStorage> {
+        &self.finalize_store
+    }
+
+    /// Returns the block store.
+    fn block_store(&self) -> &BlockStore<N, Self::BlockStorage> {
+        &self.block_store
+    }
+
+    /// Creates a point-in-time checkpoint of the storage in the given directory.
+    fn create_checkpoint(&self, path: &Path) -> Result<()> {
+        Redb::open(N::ID, self.storage_mode().clone())?.create_checkpoint(path)
+    }
+
+    /// Restores the checkpoint in the given directory into the given storage.
+    fn restore_checkpoint<S: Clone + Into<StorageMode>>(checkpoint: &Path, storage: S) -> Result<()> {
+        Redb::restore_checkpoint(checkpoint, N::ID, storage)
+    }
+}
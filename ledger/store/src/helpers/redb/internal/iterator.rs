@@ -0,0 +1,116 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::{collections::VecDeque, ops::Bound};
+
+/// A read-only snapshot of the table.
+pub(super) type Snapshot = redb::ReadOnlyTable<&'static [u8], &'static [u8]>;
+
+/// The number of entries read from the snapshot at a time.
+const CHUNK_SIZE: usize = 128;
+
+/// A cursor over the entries of a snapshot, in key order.
+///
+/// The entries are read from the snapshot in chunks, so the cursor does not borrow the database.
+pub struct RawIterator {
+    /// The snapshot of the table.
+    snapshot: Option<Snapshot>,
+    /// The prefix that every entry must start with, if any.
+    prefix: Option<Vec<u8>>,
+    /// The buffered entries, starting with the current entry.
+    entries: VecDeque<(Vec<u8>, Vec<u8>)>,
+    /// A flag indicating whether the last chunk of entries has been read.
+    is_exhausted: bool,
+    /// The error encountered while reading the entries, if any.
+    error: Option<anyhow::Error>,
+}
+
+impl RawIterator {
+    /// Initializes a new cursor over the given snapshot.
+    pub(super) fn new(snapshot: Result<Snapshot>, prefix: Option<Vec<u8>>) -> Self {
+        let (snapshot, error) = match snapshot {
+            Ok(snapshot) => (Some(snapshot), None),
+            Err(error) => (None, Some(error)),
+        };
+        Self { snapshot, prefix, entries: VecDeque::new(), is_exhausted: true, error }
+    }
+
+    /// Reads the next chunk of entries, starting at the given bound.
+    fn read_chunk(&mut self, start: Bound<&[u8]>) {
+        let Some(snapshot) = &self.snapshot else {
+            return;
+        };
+        let read = |snapshot: &Snapshot| -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+            let mut entries = Vec::with_capacity(CHUNK_SIZE);
+            for entry in snapshot.range::<&[u8]>((start, Bound::Unbounded))?.take(CHUNK_SIZE) {
+                let (key, value) = entry?;
+                entries.push((key.value().to_vec(), value.value().to_vec()));
+            }
+            Ok(entries)
+        };
+        match read(snapshot) {
+            Ok(entries) => {
+                // If the chunk is not full, or has moved past the prefix, there is nothing left to read.
+                let is_past_prefix = match (&self.prefix, entries.last()) {
+                    (Some(prefix), Some((key, _))) => !key.starts_with(prefix),
+                    _ => false,
+                };
+                self.is_exhausted = entries.len() < CHUNK_SIZE || is_past_prefix;
+                self.entries.extend(entries);
+            }
+            Err(error) => {
+                self.is_exhausted = true;
+                self.error = Some(error);
+            }
+        }
+    }
+}
+
+impl RawCursor for RawIterator {
+    fn seek(&mut self, key: &[u8]) {
+        self.entries.clear();
+        self.is_exhausted = false;
+        self.read_chunk(Bound::Included(key));
+    }
+
+    fn valid(&self) -> bool {
+        self.item().is_some()
+    }
+
+    fn item(&self) -> Option<(&[u8], &[u8])> {
+        let (key, value) = self.entries.front()?;
+        match &self.prefix {
+            Some(prefix) if !key.starts_with(prefix) => None,
+            _ => Some((key, value)),
+        }
+    }
+
+    fn next(&mut self) {
+        if let Some((key, _)) = self.entries.pop_front() {
+            if self.entries.is_empty() && !self.is_exhausted {
+                self.read_chunk(Bound::Excluded(key.as_slice()));
+            }
+        }
+    }
+
+    fn status(&self) -> Result<()> {
+        match &self.error {
+            Some(error) => bail!("Failed to iterate over the database: {error}"),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,437 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod checkpoint;
+
+pub use crate::helpers::{
+    id::*,
+    persistent::{Iter, Keys, NestedIter, NestedKeys, NestedValues, RawCursor, RawDatabase, RawWriteBatch, Values},
+};
+
+mod iterator;
+pub use iterator::*;
+
+#[cfg(test)]
+mod tests;
+
+use aleo_std_storage::StorageMode;
+use anyhow::{Result, bail, ensure};
+use once_cell::sync::OnceCell;
+use parking_lot::Mutex;
+use redb::{Durability, ReadableTable, TableDefinition};
+use serde::{Serialize, de::DeserializeOwned};
+use std::{
+    path::Path,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+};
+use tracing::error;
+
+/// The table holding the entries of every map, keyed by their prefixed keys.
+const TABLE: TableDefinition<&[u8], &[u8]> = TableDefinition::new("ledger");
+/// The name of the database file in the storage directory.
+const DATABASE_FILE: &str = "ledger.redb";
+/// The maximum number of non-atomic writes that are committed before they are synced to disk.
+const MAX_PENDING_WRITES: usize = 1024;
+
+/// A map stored in a redb database.
+pub type DataMap<K, V> = crate::helpers::persistent::DataMap<K, V, Redb>;
+/// A nested map stored in a redb database.
+pub type NestedDataMap<M, K, V> = crate::helpers::persistent::NestedDataMap<M, K, V, Redb>;
+
+pub trait Database {
+    /// Opens the database.
+    fn open<S: Clone + Into<StorageMode>>(network_id: u16, storage: S) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Opens the map with the given `network_id`, `storage mode`, and `map_id` from storage.
+    fn open_map<
+        S: Clone + Into<StorageMode>,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        network_id: u16,
+        storage: S,
+        map_id: T,
+    ) -> Result<DataMap<K, V>>;
+
+    /// Opens the nested map with the given `network_id`, `storage mode`, and `map_id` from storage.
+    fn open_nested_map<
+        S: Clone + Into<StorageMode>,
+        M: Serialize + DeserializeOwned,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        network_id: u16,
+        storage: S,
+        map_id: T,
+    ) -> Result<NestedDataMap<M, K, V>>;
+}
+
+/// A batch of writes, which gets executed atomically in a single write transaction.
+#[derive(Clone, Debug, Default)]
+pub struct WriteBatch(Vec<(Vec<u8>, Option<Vec<u8>>)>);
+
+impl RawWriteBatch for WriteBatch {
+    fn put(&mut self, key: &[u8], value: &[u8]) {
+        self.0.push((key.to_vec(), Some(value.to_vec())));
+    }
+
+    fn delete(&mut self, key: &[u8]) {
+        self.0.push((key.to_vec(), None));
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn clear(&mut self) {
+        self.0.clear()
+    }
+}
+
+/// An instance of a redb database.
+#[derive(Clone)]
+pub struct Redb {
+    /// The redb instance.
+    redb: Arc<redb::Database>,
+    /// The network ID.
+    network_id: u16,
+    /// The storage mode.
+    storage_mode: StorageMode,
+    /// The low-level database transaction that gets executed atomically at the end
+    /// of a real-run `atomic_finalize` or the outermost `atomic_batch_scope`.
+    pub(super) atomic_batch: Arc<Mutex<WriteBatch>>,
+    /// The depth of the current atomic write batch; it gets incremented with every call
+    /// to `start_atomic` and decremented with each call to `finish_atomic`.
+    pub(super) atomic_depth: Arc<AtomicUsize>,
+    /// A flag indicating whether the atomic writes are currently paused.
+    pub(super) atomic_writes_paused: Arc<AtomicBool>,
+    /// The number of non-atomic writes that were committed since the last durable commit.
+    pub(super) pending_writes: Arc<AtomicUsize>,
+}
+
+impl Database for Redb {
+    /// Opens the database.
+    ///
+    /// In production mode, the database opens file `~/.aleo/storage/ledger-{network}/ledger.redb`.
+    /// In development mode, the database opens file `/path/to/repo/.ledger-{network}-{id}/ledger.redb`.
+    fn open<S: Clone + Into<StorageMode>>(network_id: u16, storage: S) -> Result<Self> {
+        static DB: OnceCell<Redb> = OnceCell::new();
+
+        // Retrieve the database.
+        let database = DB
+            .get_or_try_init(|| {
+                let primary = aleo_std_storage::aleo_ledger_dir(network_id, storage.clone().into());
+                let redb = Arc::new(Self::open_database(&primary.join(DATABASE_FILE))?);
+
+                // Check the schema version.
+                check_schema_version(&redb, network_id)?;
+
+                Ok::<_, anyhow::Error>(Redb {
+                    redb,
+                    network_id,
+                    storage_mode: storage.clone().into(),
+                    atomic_batch: Default::default(),
+                    atomic_depth: Default::default(),
+                    atomic_writes_paused: Default::default(),
+                    pending_writes: Default::default(),
+                })
+            })?
+            .clone();
+
+        // Ensure the database network ID and storage mode match.
+        match database.network_id == network_id && database.storage_mode == storage.into() {
+            true => Ok(database),
+            false => bail!("Mismatching network ID or storage mode in the database"),
+        }
+    }
+
+    /// Opens the map with the given `network_id`, `storage mode`, and `map_id` from storage.
+    fn open_map<
+        S: Clone + Into<StorageMode>,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        network_id: u16,
+        storage: S,
+        map_id: T,
+    ) -> Result<DataMap<K, V>> {
+        // Open the redb database.
+        let database = Self::open(network_id, storage)?;
+
+        // Return the DataMap.
+        Ok(DataMap::new(database, map_id))
+    }
+
+    /// Opens the nested map with the given `network_id`, `storage mode`, and `map_id` from storage.
+    fn open_nested_map<
+        S: Clone + Into<StorageMode>,
+        M: Serialize + DeserializeOwned,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        network_id: u16,
+        storage: S,
+        map_id: T,
+    ) -> Result<NestedDataMap<M, K, V>> {
+        // Open the redb database.
+        let database = Self::open(network_id, storage)?;
+
+        // Return the NestedDataMap.
+        Ok(NestedDataMap::new(database, map_id))
+    }
+}
+
+impl RawDatabase for Redb {
+    type Cursor<'a> = RawIterator;
+    type Value<'a> = Vec<u8>;
+    type WriteBatch = WriteBatch;
+
+    fn network_id(&self) -> u16 {
+        self.network_id
+    }
+
+    fn get_raw(&self, key: &[u8]) -> Result<Option<Self::Value<'_>>> {
+        let transaction = self.redb.begin_read()?;
+        let table = transaction.open_table(TABLE)?;
+        let value = table.get(key)?.map(|value| value.value().to_vec());
+        Ok(value)
+    }
+
+    fn put_raw(&self, key: &[u8], value: &[u8]) -> Result<()> {
+        let mut batch = WriteBatch::default();
+        batch.put(key, value);
+        self.write_non_atomic(batch)
+    }
+
+    fn delete_raw(&self, key: &[u8]) -> Result<()> {
+        let mut batch = WriteBatch::default();
+        batch.delete(key);
+        self.write_non_atomic(batch)
+    }
+
+    fn write_batch(&self, batch: Self::WriteBatch) -> Result<()> {
+        self.commit(batch, true)
+    }
+    fn raw_cursor(&self) -> Self::Cursor<'_> {
+        RawIterator::new(self.open_snapshot(), None)
+    }
+
+    fn prefix_cursor(&self, prefix: &[u8]) -> Self::Cursor<'_> {
+        let mut iter = RawIterator::new(self.open_snapshot(), Some(prefix.to_vec()));
+        iter.seek(prefix);
+        iter
+    }
+
+    fn atomic_batch(&self) -> &Mutex<Self::WriteBatch> {
+        &self.atomic_batch
+    }
+
+    fn atomic_depth(&self) -> &AtomicUsize {
+        &self.atomic_depth
+    }
+
+    fn atomic_writes_paused(&self) -> &AtomicBool {
+        &self.atomic_writes_paused
+    }
+}
+
+impl Drop for Redb {
+    fn drop(&mut self) {
+        // Sync the pending non-atomic writes to disk once the last handle to the database is dropped.
+        if Arc::strong_count(&self.redb) == 1 {
+            if let Err(error) = self.flush() {
+                error!("Failed to sync the pending writes to disk: {error}");
+            }
+        }
+    }
+}
+
+/// Checks the stored schema version of the database, stamping the current schema version on a new database.
+fn check_schema_version(redb: &redb::Database, network_id: u16) -> Result<()> {
+    let mut key = network_id.to_le_bytes().to_vec();
+    key.extend_from_slice(&u16::from(MapID::Metadata(MetadataMap::SchemaVersion)).to_le_bytes());
+
+    let transaction = redb.begin_write()?;
+    {
+        let mut table = transaction.open_table(TABLE)?;
+        let schema_version = match table.get(key.as_slice())? {
+            Some(bytes) => match <[u8; 2]>::try_from(bytes.value()) {
+                Ok(bytes) => Some(u16::from_le_bytes(bytes)),
+                Err(_) => bail!("Malformed storage schema version"),
+            },
+            None => None,
+        };
+        match schema_version {
+            // Ensure the database matches the current schema version.
+            Some(schema_version) => ensure!(
+                schema_version == SCHEMA_VERSION,
+                "Mismatching storage schema version (expected {SCHEMA_VERSION}, found {schema_version})"
+            ),
+            // Stamp the current schema version.
+            None => {
+                table.insert(key.as_slice(), SCHEMA_VERSION.to_le_bytes().as_slice())?;
+            }
+        }
+    }
+    transaction.commit()?;
+    Ok(())
+}
+
+impl Redb {
+    /// Commits the given non-atomic write in its own write transaction.
+    ///
+    /// The write is visible to subsequent reads right away, but is only synced to disk by the next
+    /// durable commit, i.e. the next atomic batch, every `MAX_PENDING_WRITES` writes, or a `flush`.
+    fn write_non_atomic(&self, batch: WriteBatch) -> Result<()> {
+        let is_durable = self.pending_writes.fetch_add(1, Ordering::SeqCst) + 1 >= MAX_PENDING_WRITES;
+        self.commit(batch, is_durable)
+    }
+
+    /// Commits the given batch in a single write transaction, and syncs it to disk if `is_durable` is set.
+    fn commit(&self, batch: WriteBatch, is_durable: bool) -> Result<()> {
+        // Return early if there is nothing to write.
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        let mut transaction = self.redb.begin_write()?;
+        if !is_durable {
+            transaction.set_durability(Durability::None);
+        }
+        {
+            let mut table = transaction.open_table(TABLE)?;
+            for (key, value) in &batch.0 {
+                match value {
+                    Some(value) => {
+                        table.insert(key.as_slice(), value.as_slice())?;
+                    }
+                    None => {
+                        table.remove(key.as_slice())?;
+                    }
+                }
+            }
+        }
+        transaction.commit()?;
+
+        // Note: A durable commit also syncs the preceding non-durable commits to disk.
+        if is_durable {
+            self.pending_writes.store(0, Ordering::SeqCst);
+        }
+        Ok(())
+    }
+
+    /// Syncs the non-atomic writes that were committed since the last durable commit to disk.
+    pub fn flush(&self) -> Result<()> {
+        // Return early if there is nothing to sync.
+        if self.pending_writes.load(Ordering::SeqCst) == 0 {
+            return Ok(());
+        }
+        // Commit an empty durable transaction, which syncs the preceding non-durable commits.
+        self.redb.begin_write()?.commit()?;
+        self.pending_writes.store(0, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Opens the database file at the given path, creating it if it does not exist.
+    fn open_database(path: &Path) -> Result<redb::Database> {
+        // Ensure the storage directory exists.
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let redb = redb::Database::create(path)?;
+
+        // Ensure the table exists, so that it can be opened by read transactions.
+        let transaction = redb.begin_write()?;
+        transaction.open_table(TABLE)?;
+        transaction.commit()?;
+
+        Ok(redb)
+    }
+
+    /// Opens a read-only snapshot of the table.
+    fn open_snapshot(&self) -> Result<Snapshot> {
+        Ok(self.redb.begin_read()?.open_table(TABLE)?)
+    }
+
+    /// Opens the test database.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
+        use console::prelude::{Rng, TestRng};
+
+        // Ensure the `temp_dir` is unique.
+        let temp_dir = temp_dir.join(Rng::gen::<u64>(&mut TestRng::default()).to_string());
+
+        // Construct the directory for the test database.
+        let primary = match dev {
+            Some(dev) => temp_dir.join(dev.to_string()),
+            None => temp_dir,
+        };
+
+        // Prepare the storage mode.
+        let storage_mode = StorageMode::from(primary.clone());
+
+        Ok(Redb {
+            redb: Arc::new(Self::open_database(&primary.join(DATABASE_FILE))?),
+            network_id: u16::MAX,
+            storage_mode,
+            atomic_batch: Default::default(),
+            atomic_depth: Default::default(),
+            atomic_writes_paused: Default::default(),
+            pending_writes: Default::default(),
+        })
+    }
+
+    /// Opens the test map.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_map_testing<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
+        temp_dir: std::path::PathBuf,
+        dev: Option<u16>,
+        map_id: T,
+    ) -> Result<DataMap<K, V>> {
+        // Open the redb test database.
+        let database = Self::open_testing(temp_dir, dev)?;
+
+        // Return the DataMap.
+        Ok(DataMap::new(database, map_id))
+    }
+
+    /// Opens the test nested map.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_nested_map_testing<
+        M: Serialize + DeserializeOwned,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        temp_dir: std::path::PathBuf,
+        dev: Option<u16>,
+        map_id: T,
+    ) -> Result<NestedDataMap<M, K, V>> {
+        // Open the redb test database.
+        let database = Self::open_testing(temp_dir, dev)?;
+
+        // Return the NestedDataMap.
+        Ok(NestedDataMap::new(database, map_id))
+    }
+}
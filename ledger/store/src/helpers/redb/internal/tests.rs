@@ -0,0 +1,145 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::{
+    Map,
+    MapRead,
+    redb::{
+        MapID,
+        Redb,
+        TestMap as TestMapID,
+        internal::{DATABASE_FILE, MAX_PENDING_WRITES, TABLE},
+    },
+};
+use console::network::{MainnetV0, Network};
+
+use aleo_std_storage::StorageMode;
+use redb::ReadableTableMetadata;
+use serial_test::serial;
+use std::sync::atomic::Ordering;
+
+pub(crate) use crate::helpers::persistent::tests::temp_dir;
+
+// pub(crate) fn temp_file() -> std::path::PathBuf {
+//     tempfile::NamedTempFile::new().expect("Failed to open temporary file").path().to_owned()
+// }
+
+#[test]
+#[serial]
+fn test_open() {
+    let _storage = Redb::open_testing(temp_dir(), None).expect("Failed to open storage");
+}
+
+#[test]
+#[serial]
+fn test_open_map() {
+    let _map = Redb::open_map_testing::<u32, String, _>(temp_dir(), None, MapID::Test(TestMapID::Test))
+        .expect("Failed to open data map");
+}
+
+// #[test]
+// #[serial]
+// fn test_export_import() {
+//     let file = temp_file();
+//     {
+//         let mut map = Redb::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+//
+//         for i in 0..100 {
+//             map.insert(i, i.to_string()).expect("Failed to insert");
+//         }
+//
+//         storage.export(&file).expect("Failed to export storage");
+//     }
+//
+//     let storage = Redb::open_temporary(temp_dir(), None).expect("Failed to open storage");
+//     storage.import(&file).expect("Failed to import storage");
+//
+//     let map = storage.open_map::<u32, String>(MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+//
+//     for i in 0..100 {
+//         assert_eq!(map.get(&i).expect("Failed to get").map(|v| v.to_string()), Some(i.to_string()));
+//     }
+// }
+
+#[test]
+#[serial]
+fn test_checkpoint() {
+    let map = Redb::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+
+    // Insert values into the map, and create a checkpoint.
+    for i in 0..3u32 {
+        map.insert(i, i.to_string()).expect("Failed to insert");
+    }
+    // Ensure the checkpoint does not wait for a pending atomic batch, nor contain its writes.
+    map.start_atomic();
+    map.insert(10, "10".to_string()).expect("Failed to insert");
+    let checkpoint = temp_dir().join("checkpoint");
+    map.database.create_checkpoint(&checkpoint).expect("Failed to create a checkpoint");
+    map.finish_atomic().expect("Failed to finish the atomic batch");
+    // Ensure a checkpoint cannot overwrite an existing directory.
+    assert!(map.database.create_checkpoint(&checkpoint).is_err());
+
+    // Insert a value after the checkpoint.
+    map.insert(3, "3".to_string()).expect("Failed to insert");
+
+    // Ensure the checkpoint cannot be restored for a different network.
+    let restored = temp_dir().join("restored");
+    assert!(Redb::restore_checkpoint(&checkpoint, MainnetV0::ID, StorageMode::from(restored.clone())).is_err());
+
+    // Restore the checkpoint.
+    Redb::restore_checkpoint(&checkpoint, u16::MAX, StorageMode::from(restored.clone()))
+        .expect("Failed to restore the checkpoint");
+    // Ensure the checkpoint cannot be restored into a non-empty directory.
+    assert!(Redb::restore_checkpoint(&checkpoint, u16::MAX, StorageMode::from(restored.clone())).is_err());
+
+    // Ensure the restored database only contains the values committed before the checkpoint.
+    let database = redb::Database::open(restored.join(DATABASE_FILE)).expect("Failed to open the restored database");
+    let table = database.begin_read().unwrap().open_table(TABLE).unwrap();
+    assert_eq!(table.len().unwrap(), 3);
+}
+
+#[test]
+#[serial]
+fn test_non_atomic_writes_are_synced_in_batches() {
+    let map = Redb::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+
+    // Insert and remove values without an atomic batch, and ensure they are visible right away.
+    for i in 0..3u32 {
+        map.insert(i, i.to_string()).expect("Failed to insert");
+    }
+    map.remove(&0).expect("Failed to remove");
+    assert_eq!(map.get_confirmed(&1).unwrap().map(|value| value.into_owned()), Some("1".to_string()));
+    assert!(map.get_confirmed(&0).unwrap().is_none());
+    // Ensure the writes are pending until the next durable commit.
+    assert_eq!(map.database.pending_writes.load(Ordering::SeqCst), 4);
+
+    // Ensure an atomic batch syncs the pending writes.
+    map.start_atomic();
+    map.insert(3, "3".to_string()).expect("Failed to insert");
+    map.finish_atomic().expect("Failed to finish the atomic batch");
+    assert_eq!(map.database.pending_writes.load(Ordering::SeqCst), 0);
+
+    // Ensure the pending writes are synced once there are too many of them.
+    for i in 0..MAX_PENDING_WRITES as u32 {
+        map.insert(i, i.to_string()).expect("Failed to insert");
+    }
+    assert_eq!(map.database.pending_writes.load(Ordering::SeqCst), 0);
+
+    // Ensure a flush syncs the pending writes.
+    map.insert(0, "0".to_string()).expect("Failed to insert");
+    map.database.flush().expect("Failed to flush");
+    assert_eq!(map.database.pending_writes.load(Ordering::SeqCst), 0);
+    assert_eq!(map.iter_confirmed().count(), MAX_PENDING_WRITES);
+}
@@ -0,0 +1,212 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::type_complexity)]
+
+use crate::{
+    CommitteeStorage,
+    CommitteeStore,
+    FinalizeStorage,
+    HistoryHeight,
+    RollbackEntries,
+    helpers::redb::{self, CommitteeMap, DataMap, Database, MapID, NestedDataMap, ProgramMap},
+};
+use console::{
+    prelude::*,
+    program::{Identifier, MappingNodeEntry, MappingNodePosition, Plaintext, ProgramID, Value},
+};
+use ledger_committee::Committee;
+use synthesizer_program::FinalizeOperation;
+
+use aleo_std_storage::StorageMode;
+use indexmap::IndexSet;
+
+/// A redb finalize storage.
+#[derive(Clone)]
+pub struct FinalizeRedb<N: Network> {
+    /// The committee store.
+    committee_store: CommitteeStore<N, CommitteeRedb<N>>,
+    /// The program ID map.
+    program_id_map: DataMap<ProgramID<N>, IndexSet<Identifier<N>>>,
+    /// The key-value map.
+    key_value_map: NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The rollback map.
+    rollback_map: DataMap<u32, RollbackEntries<N>>,
+    /// The value history map.
+    value_history_map: NestedDataMap<(ProgramID<N>, Identifier<N>), (Plaintext<N>, HistoryHeight), Option<Value<N>>>,
+    /// The archive height map.
+    archive_height_map: DataMap<u8, u32>,
+    /// The ratified operations map.
+    ratified_operations_map: DataMap<u32, Vec<FinalizeOperation<N>>>,
+    /// The mapping node map.
+    mapping_node_map: DataMap<MappingNodePosition<N>, MappingNodeEntry<N>>,
+    /// The storage mode.
+    storage_mode: StorageMode,
+}
+
+#[rustfmt::skip]
+impl<N: Network> FinalizeStorage<N> for FinalizeRedb<N> {
+    type CommitteeStorage = CommitteeRedb<N>;
+    type ProgramIDMap = DataMap<ProgramID<N>, IndexSet<Identifier<N>>>;
+    type KeyValueMap = NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type RollbackMap = DataMap<u32, RollbackEntries<N>>;
+    type ValueHistoryMap = NestedDataMap<(ProgramID<N>, Identifier<N>), (Plaintext<N>, HistoryHeight), Option<Value<N>>>;
+    type ArchiveHeightMap = DataMap<u8, u32>;
+    type RatifiedOperationsMap = DataMap<u32, Vec<FinalizeOperation<N>>>;
+    type MappingNodeMap = DataMap<MappingNodePosition<N>, MappingNodeEntry<N>>;
+
+    /// Initializes the finalize storage.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        // Initialize the committee store.
+        let committee_store = CommitteeStore::<N, CommitteeRedb<N>>::open(storage.clone())?;
+        // Return the finalize storage.
+        Ok(Self {
+            committee_store,
+            program_id_map: redb::Redb::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::ProgramID))?,
+            key_value_map: redb::Redb::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::KeyValueID))?,
+            rollback_map: redb::Redb::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::Rollback))?,
+            value_history_map: redb::Redb::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::ValueHistory))?,
+            archive_height_map: redb::Redb::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::ArchiveHeight))?,
+            ratified_operations_map: redb::Redb::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::RatifiedOperations))?,
+            mapping_node_map: redb::Redb::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::MappingNode))?,
+            storage_mode: storage.into(),
+        })
+    }
+
+    /// Initializes the test-variant of the storage.
+    #[cfg(any(test, feature = "test"))]
+    fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
+        // Initialize the committee store.
+        let committee_store = CommitteeStore::<N, CommitteeRedb<N>>::open_testing(temp_dir.clone(), dev)?;
+        // Return the finalize storage.
+        Ok(Self {
+            committee_store,
+            program_id_map: redb::Redb::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ProgramID))?,
+            key_value_map: redb::Redb::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyValueID))?,
+            rollback_map: redb::Redb::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::Rollback))?,
+            value_history_map: redb::Redb::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ValueHistory))?,
+            archive_height_map: redb::Redb::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ArchiveHeight))?,
+            ratified_operations_map: redb::Redb::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::RatifiedOperations))?,
+            mapping_node_map: redb::Redb::open_map_testing(temp_dir, dev, MapID::Program(ProgramMap::MappingNode))?,
+            storage_mode: dev.into(),
+        })
+    }
+
+    /// Returns the committee store.
+    fn committee_store(&self) -> &CommitteeStore<N, Self::CommitteeStorage> {
+        &self.committee_store
+    }
+
+    /// Returns the program ID map.
+    fn program_id_map(&self) -> &Self::ProgramIDMap {
+        &self.program_id_map
+    }
+
+    /// Returns the key-value map.
+    fn key_value_map(&self) -> &Self::KeyValueMap {
+        &self.key_value_map
+    }
+
+    /// Returns the rollback map.
+    fn rollback_map(&self) -> &Self::RollbackMap {
+        &self.rollback_map
+    }
+
+    /// Returns the value history map.
+    fn value_history_map(&self) -> &Self::ValueHistoryMap {
+        &self.value_history_map
+    }
+
+    /// Returns the archive height map.
+    fn archive_height_map(&self) -> &Self::ArchiveHeightMap {
+        &self.archive_height_map
+    }
+
+    /// Returns the ratified operations map.
+    fn ratified_operations_map(&self) -> &Self::RatifiedOperationsMap {
+        &self.ratified_operations_map
+    }
+
+    /// Returns the mapping node map.
+    fn mapping_node_map(&self) -> &Self::MappingNodeMap {
+        &self.mapping_node_map
+    }
+
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        &self.storage_mode
+    }
+}
+
+/// A redb committee storage.
+#[derive(Clone)]
+pub struct CommitteeRedb<N: Network> {
+    /// The current round map.
+    current_round_map: DataMap<u8, u64>,
+    /// The round to height map.
+    round_to_height_map: DataMap<u64, u32>,
+    /// The committee map.
+    committee_map: DataMap<u32, Committee<N>>,
+    /// The storage mode.
+    storage_mode: StorageMode,
+}
+
+#[rustfmt::skip]
+impl<N: Network> CommitteeStorage<N> for CommitteeRedb<N> {
+    type CurrentRoundMap = DataMap<u8, u64>;
+    type RoundToHeightMap = DataMap<u64, u32>;
+    type CommitteeMap = DataMap<u32, Committee<N>>;
+
+    /// Initializes the committee storage.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Ok(Self {
+            current_round_map: redb::Redb::open_map(N::ID, storage.clone(), MapID::Committee(CommitteeMap::CurrentRound))?,
+            round_to_height_map: redb::Redb::open_map(N::ID, storage.clone(), MapID::Committee(CommitteeMap::RoundToHeight))?,
+            committee_map: redb::Redb::open_map(N::ID, storage.clone(), MapID::Committee(CommitteeMap::Committee))?,
+            storage_mode: storage.into(),
+        })
+    }
+
+    /// Initializes the test-variant of the storage.
+    #[cfg(any(test, feature = "test"))]
+    fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
+        Ok(Self {
+            current_round_map: redb::Redb::open_map_testing(temp_dir.clone(), dev, MapID::Committee(CommitteeMap::CurrentRound))?,
+            round_to_height_map: redb::Redb::open_map_testing(temp_dir.clone(), dev, MapID::Committee(CommitteeMap::RoundToHeight))?,
+            committee_map: redb::Redb::open_map_testing(temp_dir, dev, MapID::Committee(CommitteeMap::Committee))?,
+            storage_mode: dev.into(),
+        })
+    }
+
+    /// Returns the current round map.
+    fn current_round_map(&self) -> &Self::CurrentRoundMap {
+        &self.current_round_map
+    }
+
+    /// Returns the round to height map.
+    fn round_to_height_map(&self) -> &Self::RoundToHeightMap {
+        &self.round_to_height_map
+    }
+
+    /// Returns the committee map.
+    fn committee_map(&self) -> &Self::CommitteeMap {
+        &self.committee_map
+    }
+
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        &self.storage_mode
+    }
+}
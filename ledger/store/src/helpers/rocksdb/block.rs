@@ -17,18 +17,23 @@ use crate::{
     BlockStorage,
     ConfirmedTxType,
     TransactionStore,
+    TransitionPosition,
     TransitionStore,
     helpers::rocksdb::{
         BlockMap,
         MapID,
         TransactionDB,
         TransitionDB,
-        internal::{self, DataMap, Database},
+        internal::{self, DataMap, Database, NestedDataMap},
     },
 };
-use console::{prelude::*, types::Field};
+use console::{
+    prelude::*,
+    program::{Identifier, ProgramID},
+    types::{Address, Field},
+};
 use ledger_authority::Authority;
-use ledger_block::{Header, Ratifications, Rejected, Solutions};
+use ledger_block::{Header, Ratifications, Ratify, Rejected, Solutions};
 use ledger_puzzle::SolutionID;
 use synthesizer_program::FinalizeOperation;
 
@@ -73,6 +78,20 @@ pub struct BlockDB<N: Network> {
         DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: DataMap<Field<N>, Rejected<N>>,
+    /// The pruned height map.
+    pruned_height_map: DataMap<u8, u32>,
+    /// The pruning depth map.
+    pruning_depth_map: DataMap<u8, u32>,
+    /// The address index map.
+    address_index_map: NestedDataMap<Address<N>, TransitionPosition, N::TransitionID>,
+    /// The fee payer index map.
+    fee_payer_index_map: NestedDataMap<Address<N>, TransitionPosition, N::TransitionID>,
+    /// The function index map.
+    function_index_map: NestedDataMap<(ProgramID<N>, Identifier<N>), TransitionPosition, N::TransitionID>,
+    /// The transition index height map.
+    transition_index_height_map: DataMap<u8, u32>,
+    /// The restrictions map.
+    restrictions_map: DataMap<u32, Ratify<N>>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionDB<N>>,
 }
@@ -96,6 +115,13 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     type RejectedOrAbortedTransactionIDMap = DataMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     type RejectedDeploymentOrExecutionMap = DataMap<Field<N>, Rejected<N>>;
+    type PrunedHeightMap = DataMap<u8, u32>;
+    type PruningDepthMap = DataMap<u8, u32>;
+    type AddressIndexMap = NestedDataMap<Address<N>, TransitionPosition, N::TransitionID>;
+    type FeePayerIndexMap = NestedDataMap<Address<N>, TransitionPosition, N::TransitionID>;
+    type FunctionIndexMap = NestedDataMap<(ProgramID<N>, Identifier<N>), TransitionPosition, N::TransitionID>;
+    type TransitionIndexHeightMap = DataMap<u8, u32>;
+    type RestrictionsMap = DataMap<u32, Ratify<N>>;
     type TransactionStorage = TransactionDB<N>;
     type TransitionStorage = TransitionDB<N>;
 
@@ -123,7 +149,14 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
             aborted_transaction_ids_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::AbortedTransactionIDs))?,
             rejected_or_aborted_transaction_id_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::RejectedOrAbortedTransactionID))?,
             confirmed_transactions_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::ConfirmedTransactions))?,
-            rejected_deployment_or_execution_map: internal::RocksDB::open_map(N::ID, storage, MapID::Block(BlockMap::RejectedDeploymentOrExecution))?,
+            rejected_deployment_or_execution_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::RejectedDeploymentOrExecution))?,
+            pruned_height_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::PrunedHeight))?,
+            pruning_depth_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::PruningDepth))?,
+            address_index_map: internal::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Block(BlockMap::AddressIndex))?,
+            fee_payer_index_map: internal::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Block(BlockMap::FeePayerIndex))?,
+            function_index_map: internal::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Block(BlockMap::FunctionIndex))?,
+            transition_index_height_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::TransitionIndexHeight))?,
+            restrictions_map: internal::RocksDB::open_map(N::ID, storage, MapID::Block(BlockMap::Restrictions))?,
             transaction_store,
         })
     }
@@ -213,6 +246,41 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the pruned height map.
+    fn pruned_height_map(&self) -> &Self::PrunedHeightMap {
+        &self.pruned_height_map
+    }
+
+    /// Returns the pruning depth map.
+    fn pruning_depth_map(&self) -> &Self::PruningDepthMap {
+        &self.pruning_depth_map
+    }
+
+    /// Returns the address index map.
+    fn address_index_map(&self) -> &Self::AddressIndexMap {
+        &self.address_index_map
+    }
+
+    /// Returns the fee payer index map.
+    fn fee_payer_index_map(&self) -> &Self::FeePayerIndexMap {
+        &self.fee_payer_index_map
+    }
+
+    /// Returns the function index map.
+    fn function_index_map(&self) -> &Self::FunctionIndexMap {
+        &self.function_index_map
+    }
+
+    /// Returns the transition index height map.
+    fn transition_index_height_map(&self) -> &Self::TransitionIndexHeightMap {
+        &self.transition_index_height_map
+    }
+
+    /// Returns the restrictions map.
+    fn restrictions_map(&self) -> &Self::RestrictionsMap {
+        &self.restrictions_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
@@ -17,11 +17,13 @@ use crate::{
     BlockStore,
     ConsensusStorage,
     FinalizeStore,
-    helpers::rocksdb::{BlockDB, FinalizeDB, TransactionDB, TransitionDB},
+    MapStats,
+    helpers::rocksdb::{BlockDB, Database, FinalizeDB, RocksDB, TransactionDB, TransitionDB},
 };
 use console::prelude::*;
 
 use aleo_std_storage::StorageMode;
+use std::path::Path;
 
 /// An RocksDB consensus storage.
 #[derive(Clone)]
@@ -61,4 +63,19 @@ impl<N: Network> ConsensusStorage<N> for ConsensusDB<N> {
     fn block_store(&self) -> &BlockStore<N, Self::BlockStorage> {
         &self.block_store
     }
+
+    /// Creates a point-in-time checkpoint of the storage in the given directory.
+    fn create_checkpoint(&self, path: &Path) -> Result<()> {
+        RocksDB::open(N::ID, self.storage_mode().clone())?.create_checkpoint(path)
+    }
+
+    /// Restores the checkpoint in the given directory into the given storage.
+    fn restore_checkpoint<S: Clone + Into<StorageMode>>(checkpoint: &Path, storage: S) -> Result<()> {
+        RocksDB::restore_checkpoint(checkpoint, N::ID, storage)
+    }
+
+    /// Returns the usage statistics of the storage maps.
+    fn map_stats(&self) -> Result<Vec<MapStats>> {
+        RocksDB::open(N::ID, self.storage_mode().clone())?.map_stats()
+    }
 }
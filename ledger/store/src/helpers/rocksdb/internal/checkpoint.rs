@@ -0,0 +1,111 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use serde::Deserialize;
+use std::{fs, path::Path};
+
+/// The name of the directory containing the database files of a checkpoint.
+const CHECKPOINT_DATABASE_DIR: &str = "rocksdb";
+/// The name of the file containing the metadata of a checkpoint.
+const CHECKPOINT_METADATA_FILE: &str = "checkpoint.json";
+
+/// The metadata of a checkpoint, which is checked before the checkpoint is restored.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct CheckpointMetadata {
+    /// The network ID of the checkpointed database.
+    network_id: u16,
+    /// The schema version of the checkpointed database.
+    schema_version: u16,
+}
+
+impl RocksDB {
+    /// Creates a point-in-time checkpoint of the database in the given (non-existent) directory.
+    ///
+    /// The checkpoint is taken while holding the lock on the atomic writes, so it contains every committed
+    /// atomic batch, and none of the pending ones (including those queued while atomic writes are paused).
+    /// The checkpoint shares the immutable files of the database
+    /// through hard links where possible, which makes it cheap to create while the node is running.
+    pub fn create_checkpoint<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        // Ensure the checkpoint directory does not exist.
+        ensure!(!path.exists(), "The checkpoint directory '{}' already exists", path.display());
+
+        // Acquire the lock on the atomic writes, which is held while an atomic batch is committed.
+        // Note: The writes of a pending atomic batch are only queued, and are not in the database yet.
+        let atomic_batch = self.atomic_batch.lock();
+
+        // Create the checkpoint of the database.
+        fs::create_dir_all(path)?;
+        rocksdb::checkpoint::Checkpoint::new(&self.rocksdb)?.create_checkpoint(path.join(CHECKPOINT_DATABASE_DIR))?;
+        drop(atomic_batch);
+
+        // Write the checkpoint metadata.
+        let metadata = CheckpointMetadata { network_id: self.network_id, schema_version: SCHEMA_VERSION };
+        fs::write(path.join(CHECKPOINT_METADATA_FILE), serde_json::to_vec_pretty(&metadata)?)?;
+        Ok(())
+    }
+
+    /// Restores the checkpoint in the given directory into the storage of the given network ID and storage mode.
+    ///
+    /// The checkpoint must match the given network ID and the current schema version, and the
+    /// storage directory must be empty. This must be called before the database is opened.
+    pub fn restore_checkpoint<P: AsRef<Path>, S: Into<StorageMode>>(
+        checkpoint: P,
+        network_id: u16,
+        storage: S,
+    ) -> Result<()> {
+        let checkpoint = checkpoint.as_ref();
+
+        // Read the checkpoint metadata.
+        let metadata: CheckpointMetadata = match fs::read(checkpoint.join(CHECKPOINT_METADATA_FILE)) {
+            Ok(bytes) => serde_json::from_slice(&bytes)?,
+            Err(error) => bail!("Failed to read the checkpoint metadata in '{}': {error}", checkpoint.display()),
+        };
+        // Ensure the checkpoint matches the network ID.
+        ensure!(
+            metadata.network_id == network_id,
+            "Mismatching network ID in the checkpoint (expected {network_id}, found {})",
+            metadata.network_id
+        );
+        // Ensure the checkpoint matches the schema version.
+        ensure!(
+            metadata.schema_version == SCHEMA_VERSION,
+            "Mismatching schema version in the checkpoint (expected {SCHEMA_VERSION}, found {})",
+            metadata.schema_version
+        );
+
+        // Ensure the storage directory is empty.
+        let primary = aleo_std_storage::aleo_ledger_dir(network_id, storage.into());
+        if primary.exists() {
+            ensure!(
+                fs::read_dir(&primary)?.next().is_none(),
+                "Cannot restore a checkpoint into the non-empty storage directory '{}'",
+                primary.display()
+            );
+        }
+
+        // Copy the database files into the storage directory.
+        fs::create_dir_all(&primary)?;
+        for entry in fs::read_dir(checkpoint.join(CHECKPOINT_DATABASE_DIR))? {
+            let entry = entry?;
+            if entry.file_type()?.is_file() {
+                fs::copy(entry.path(), primary.join(entry.file_name()))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,527 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use serde::Deserialize;
+use tracing::info;
+
+/// The schema version of a database that was populated before the schema version was stored.
+const UNVERSIONED_SCHEMA_VERSION: u16 = 1;
+/// The number of entries rewritten in each batch of a migration.
+pub const MIGRATION_BATCH_SIZE: usize = 10_000;
+
+/// A migration of the storage from one schema version to the next.
+///
+/// A migration rewrites every entry of the maps it affects, in batches. The progress of the
+/// migration is written atomically with each batch, so an interrupted migration resumes from
+/// the last written batch.
+pub trait Migration: Send + Sync {
+    /// Returns the schema version that the migration upgrades from, to `from_version() + 1`.
+    fn from_version(&self) -> u16;
+
+    /// Returns a short description of the migration.
+    fn description(&self) -> &'static str;
+
+    /// Returns the maps that are rewritten by the migration, in the order they are rewritten.
+    fn map_ids(&self) -> Vec<MapID>;
+
+    /// Rewrites the given entry of an affected map, by adding the replacement writes to the given batch.
+    ///
+    /// Note: The `key` includes the map prefix. A migration must not write new keys into the map
+    /// being rewritten that sort after the given key, as they would be rewritten again.
+    fn migrate_entry(&self, key: &[u8], value: &[u8], batch: &mut rocksdb::WriteBatch) -> Result<()>;
+}
+
+/// The progress of a migration.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MigrationProgress {
+    /// The schema version that the migration upgrades from.
+    pub from_version: u16,
+    /// The index of the map being rewritten, in `Migration::map_ids`.
+    pub map_index: usize,
+    /// The last key rewritten in the current map, if any.
+    pub last_key: Option<Vec<u8>>,
+    /// The number of entries rewritten so far.
+    pub num_migrated: u64,
+}
+
+/// A migration that only adds new maps, which start out empty, so no entries are rewritten.
+struct AddMaps {
+    /// The schema version that the migration upgrades from.
+    from_version: u16,
+    /// The description of the added maps.
+    description: &'static str,
+}
+
+impl Migration for AddMaps {
+    fn from_version(&self) -> u16 {
+        self.from_version
+    }
+
+    fn description(&self) -> &'static str {
+        self.description
+    }
+
+    fn map_ids(&self) -> Vec<MapID> {
+        vec![]
+    }
+
+    fn migrate_entry(&self, _key: &[u8], _value: &[u8], _batch: &mut rocksdb::WriteBatch) -> Result<()> {
+        bail!("The migration from schema version {} does not rewrite any entries", self.from_version)
+    }
+}
+
+/// Returns the migrations of the storage, in the order of their schema version.
+pub(super) fn migrations() -> Vec<Box<dyn Migration>> {
+    vec![
+        // Note: The mapping tree is rebuilt from the key-value pairs when the finalize store is loaded,
+        // and the transition indexes are backfilled when they are enabled.
+        Box::new(AddMaps {
+            from_version: 1,
+            description: "add the history, pruning, transition index, restrictions, and storage metadata maps",
+        }),
+    ]
+}
+
+/// Returns the prefix of the given map.
+fn map_prefix(network_id: u16, map_id: MapID) -> Vec<u8> {
+    let mut prefix = network_id.to_le_bytes().to_vec();
+    prefix.extend_from_slice(&u16::from(map_id).to_le_bytes());
+    prefix
+}
+
+/// Returns the stored schema version of the database, if any.
+pub fn read_schema_version(rocksdb: &rocksdb::DB, network_id: u16) -> Result<Option<u16>> {
+    match rocksdb.get_pinned(map_prefix(network_id, MapID::Metadata(MetadataMap::SchemaVersion)))? {
+        Some(bytes) => match <[u8; 2]>::try_from(&bytes[..]) {
+            Ok(bytes) => Ok(Some(u16::from_le_bytes(bytes))),
+            Err(_) => bail!("Malformed storage schema version"),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Returns `true` if the database holds no entries for the given network ID.
+fn is_network_empty(rocksdb: &rocksdb::DB, network_id: u16) -> Result<bool> {
+    let prefix = network_id.to_le_bytes();
+    // Note: A total order seek is required, as the seek crosses the map prefixes of the network.
+    let mut readopts = rocksdb::ReadOptions::default();
+    readopts.set_total_order_seek(true);
+    let mut iter = rocksdb.raw_iterator_opt(readopts);
+    iter.seek(prefix);
+    iter.status()?;
+    Ok(!iter.key().is_some_and(|key| key.starts_with(&prefix)))
+}
+
+/// Checks the schema version of the database, and applies the pending migrations.
+///
+/// A database without entries for the network is stamped with the current schema version, and a database
+/// with entries for the network but without a stored schema version is stamped with the schema version
+/// that predates versioning.
+pub(super) fn check_schema_version(
+    rocksdb: &rocksdb::DB,
+    network_id: u16,
+    migrations: &[Box<dyn Migration>],
+    batch_size: usize,
+    on_progress: impl FnMut(&MigrationProgress),
+) -> Result<()> {
+    // Retrieve the schema version, stamping it if the database is unversioned.
+    let schema_version = match read_schema_version(rocksdb, network_id)? {
+        Some(schema_version) => schema_version,
+        None => {
+            let schema_version = match is_network_empty(rocksdb, network_id)? {
+                true => SCHEMA_VERSION,
+                false => UNVERSIONED_SCHEMA_VERSION,
+            };
+            let key = map_prefix(network_id, MapID::Metadata(MetadataMap::SchemaVersion));
+            rocksdb.put(key, schema_version.to_le_bytes())?;
+            schema_version
+        }
+    };
+    // Ensure the database was not created by a newer version.
+    ensure!(
+        schema_version <= SCHEMA_VERSION,
+        "The storage schema version {schema_version} is newer than the supported schema version {SCHEMA_VERSION}"
+    );
+    // Apply the pending migrations.
+    migrate(rocksdb, network_id, migrations, SCHEMA_VERSION, batch_size, on_progress)?;
+    Ok(())
+}
+
+/// Applies the given migrations in order, until the database reaches the given schema version.
+/// Returns the resulting schema version.
+pub fn migrate(
+    rocksdb: &rocksdb::DB,
+    network_id: u16,
+    migrations: &[Box<dyn Migration>],
+    target_version: u16,
+    batch_size: usize,
+    mut on_progress: impl FnMut(&MigrationProgress),
+) -> Result<u16> {
+    ensure!(batch_size > 0, "The migration batch size must be greater than zero");
+
+    // Retrieve the schema version.
+    let Some(mut schema_version) = read_schema_version(rocksdb, network_id)? else {
+        bail!("Cannot migrate a database without a schema version")
+    };
+    while schema_version < target_version {
+        // Retrieve the migration from the current schema version.
+        let Some(migration) = migrations.iter().find(|migration| migration.from_version() == schema_version) else {
+            bail!("Missing the storage migration from schema version {schema_version}")
+        };
+        apply_migration(rocksdb, network_id, &**migration, batch_size, &mut on_progress)?;
+        schema_version += 1;
+    }
+    Ok(schema_version)
+}
+
+/// Applies the given migration, resuming from its stored progress, if any.
+fn apply_migration(
+    rocksdb: &rocksdb::DB,
+    network_id: u16,
+    migration: &dyn Migration,
+    batch_size: usize,
+    on_progress: &mut impl FnMut(&MigrationProgress),
+) -> Result<()> {
+    let version_key = map_prefix(network_id, MapID::Metadata(MetadataMap::SchemaVersion));
+    let progress_key = map_prefix(network_id, MapID::Metadata(MetadataMap::MigrationProgress));
+
+    // Resume from the stored progress, if it belongs to this migration.
+    let stored_progress = match rocksdb.get_pinned(&progress_key)? {
+        Some(bytes) => Some(bincode::deserialize::<MigrationProgress>(&bytes)?),
+        None => None,
+    };
+    let mut progress = match stored_progress {
+        Some(progress) if progress.from_version == migration.from_version() => progress,
+        _ => {
+            MigrationProgress { from_version: migration.from_version(), map_index: 0, last_key: None, num_migrated: 0 }
+        }
+    };
+
+    info!(
+        "Migrating the storage from schema version {} to {} ({})...",
+        migration.from_version(),
+        migration.from_version() + 1,
+        migration.description()
+    );
+
+    let map_ids = migration.map_ids();
+    loop {
+        let mut batch = rocksdb::WriteBatch::default();
+
+        // Rewrite the next batch of entries in the current map.
+        if let Some(map_id) = map_ids.get(progress.map_index) {
+            let prefix = map_prefix(network_id, *map_id);
+
+            // Position the iterator after the last rewritten key, or at the start of the map.
+            let mut iter = rocksdb.raw_iterator();
+            match &progress.last_key {
+                Some(last_key) => {
+                    iter.seek(last_key);
+                    if iter.key() == Some(&last_key[..]) {
+                        iter.next();
+                    }
+                }
+                None => iter.seek(&prefix),
+            }
+
+            let mut num_entries = 0;
+            while num_entries < batch_size {
+                let (Some(key), Some(value)) = (iter.key(), iter.value()) else { break };
+                if !key.starts_with(&prefix) {
+                    break;
+                }
+                migration.migrate_entry(key, value, &mut batch)?;
+                progress.last_key = Some(key.to_vec());
+                num_entries += 1;
+                iter.next();
+            }
+            iter.status()?;
+            progress.num_migrated += num_entries as u64;
+
+            // If the map is exhausted, move on to the next map.
+            if num_entries < batch_size {
+                progress.map_index += 1;
+                progress.last_key = None;
+            }
+        }
+
+        // Write the batch together with the progress, or with the new schema version once the migration is complete.
+        let is_complete = progress.map_index >= map_ids.len();
+        match is_complete {
+            true => {
+                batch.put(&version_key, (migration.from_version() + 1).to_le_bytes());
+                batch.delete(&progress_key);
+            }
+            false => batch.put(&progress_key, bincode::serialize(&progress)?),
+        }
+        rocksdb.write(batch)?;
+        on_progress(&progress);
+
+        if is_complete {
+            return Ok(());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{
+        MapRead,
+        rocksdb::{BlockMap, DataMap, RocksDB, TestMap, internal::tests::temp_dir},
+    };
+    use console::{
+        network::{MainnetV0, Network, TestnetV0},
+        prelude::FromBytes,
+    };
+    use ledger_block::{Block, Header};
+
+    use aleo_std_storage::StorageMode;
+    use serial_test::serial;
+    use std::sync::atomic::Ordering;
+
+    const NETWORK_ID: u16 = u16::MAX;
+
+    /// A migration that widens the `u32` values of the test map into `u64` values.
+    struct WidenValues {
+        /// The key on which the migration fails once, to simulate an interruption.
+        fail_on: Option<u32>,
+        /// A flag indicating whether the migration has failed.
+        has_failed: AtomicBool,
+    }
+
+    impl Migration for WidenValues {
+        fn from_version(&self) -> u16 {
+            SCHEMA_VERSION
+        }
+
+        fn description(&self) -> &'static str {
+            "widen the test values"
+        }
+
+        fn map_ids(&self) -> Vec<MapID> {
+            vec![MapID::Test(TestMap::Test), MapID::Test(TestMap::Test2)]
+        }
+
+        fn migrate_entry(&self, key: &[u8], value: &[u8], batch: &mut rocksdb::WriteBatch) -> Result<()> {
+            let entry = bincode::deserialize::<u32>(&key[PREFIX_LEN..])?;
+            if Some(entry) == self.fail_on && !self.has_failed.swap(true, Ordering::SeqCst) {
+                bail!("Interrupted on entry {entry}")
+            }
+            // Ensure the value is rewritten exactly once.
+            let Ok(value) = <[u8; 4]>::try_from(value) else { bail!("Entry {entry} was already rewritten") };
+            batch.put(key, u64::from(u32::from_le_bytes(value)).to_le_bytes());
+            Ok(())
+        }
+    }
+
+    /// Returns a fixture database at the current schema version, with the given number of entries in each test map.
+    fn sample_fixture(num_entries: u32) -> rocksdb::DB {
+        let mut options = rocksdb::Options::default();
+        options.create_if_missing(true);
+        let rocksdb = rocksdb::DB::open(&options, temp_dir()).expect("Failed to open the fixture database");
+
+        let mut batch = rocksdb::WriteBatch::default();
+        for map_id in [MapID::Test(TestMap::Test), MapID::Test(TestMap::Test2)] {
+            for entry in 0..num_entries {
+                let mut key = map_prefix(NETWORK_ID, map_id);
+                key.extend_from_slice(&bincode::serialize(&entry).unwrap());
+                batch.put(key, entry.to_le_bytes());
+            }
+        }
+        batch.put(map_prefix(NETWORK_ID, MapID::Metadata(MetadataMap::SchemaVersion)), SCHEMA_VERSION.to_le_bytes());
+        rocksdb.write(batch).expect("Failed to write the fixture database");
+        rocksdb
+    }
+
+    /// Returns a fixture database in the layout of the unversioned schema, which holds the genesis block
+    /// of the given network in the block maps, together with the genesis block.
+    ///
+    /// Note: The maps of the unversioned schema are keyed by the network ID and map ID, followed by the
+    /// bincode-encoded key, and the values are bincode-encoded, which is the layout of every map since.
+    fn sample_unversioned_fixture<N: Network>() -> (rocksdb::DB, Block<N>) {
+        let mut options = rocksdb::Options::default();
+        options.create_if_missing(true);
+        options.set_prefix_extractor(rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN));
+        let rocksdb = rocksdb::DB::open(&options, temp_dir()).expect("Failed to open the fixture database");
+
+        // Write the genesis block into the block maps, without a schema version.
+        let block = Block::<N>::from_bytes_le(N::genesis_bytes()).unwrap();
+        let entry = |map: BlockMap, key: Vec<u8>| [map_prefix(N::ID, MapID::Block(map)), key].concat();
+        let mut batch = rocksdb::WriteBatch::default();
+        batch.put(entry(BlockMap::ID, bincode::serialize(&0u32).unwrap()), bincode::serialize(&block.hash()).unwrap());
+        batch.put(entry(BlockMap::ReverseID, bincode::serialize(&block.hash()).unwrap()), 0u32.to_le_bytes());
+        batch.put(
+            entry(BlockMap::Header, bincode::serialize(&block.hash()).unwrap()),
+            bincode::serialize(block.header()).unwrap(),
+        );
+        rocksdb.write(batch).expect("Failed to write the fixture database");
+        (rocksdb, block)
+    }
+
+    /// Returns the given fixture database as the storage of the given network.
+    fn to_database(rocksdb: rocksdb::DB, network_id: u16) -> RocksDB {
+        RocksDB {
+            rocksdb: Arc::new(rocksdb),
+            network_id,
+            storage_mode: StorageMode::from(temp_dir()),
+            atomic_batch: Default::default(),
+            atomic_depth: Default::default(),
+            atomic_writes_paused: Default::default(),
+            write_stats: Default::default(),
+            default_readopts: Default::default(),
+        }
+    }
+
+    /// Asserts that every value of the test maps was widened.
+    fn check_widened(rocksdb: &rocksdb::DB, num_entries: u32) {
+        for map_id in [MapID::Test(TestMap::Test), MapID::Test(TestMap::Test2)] {
+            for entry in 0..num_entries {
+                let mut key = map_prefix(NETWORK_ID, map_id);
+                key.extend_from_slice(&bincode::serialize(&entry).unwrap());
+                let value = rocksdb.get(key).unwrap().unwrap();
+                assert_eq!(value, u64::from(entry).to_le_bytes());
+            }
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_check_schema_version() {
+        // Ensure an empty database is stamped with the current schema version.
+        let rocksdb = rocksdb::DB::open_default(temp_dir()).unwrap();
+        check_schema_version(&rocksdb, NETWORK_ID, &[], MIGRATION_BATCH_SIZE, |_| {}).unwrap();
+        assert_eq!(read_schema_version(&rocksdb, NETWORK_ID).unwrap(), Some(SCHEMA_VERSION));
+
+        // Ensure a populated database without a schema version is stamped with the unversioned schema version.
+        let rocksdb = rocksdb::DB::open_default(temp_dir()).unwrap();
+        rocksdb.put(map_prefix(NETWORK_ID, MapID::Test(TestMap::Test)), [0u8]).unwrap();
+        check_schema_version(&rocksdb, NETWORK_ID, &[], MIGRATION_BATCH_SIZE, |_| {}).unwrap();
+        assert_eq!(read_schema_version(&rocksdb, NETWORK_ID).unwrap(), Some(UNVERSIONED_SCHEMA_VERSION));
+
+        // Ensure a database with entries for another network only is stamped with the current schema version.
+        let rocksdb = rocksdb::DB::open_default(temp_dir()).unwrap();
+        rocksdb.put(map_prefix(NETWORK_ID - 1, MapID::Test(TestMap::Test)), [0u8]).unwrap();
+        check_schema_version(&rocksdb, NETWORK_ID, &[], MIGRATION_BATCH_SIZE, |_| {}).unwrap();
+        assert_eq!(read_schema_version(&rocksdb, NETWORK_ID).unwrap(), Some(SCHEMA_VERSION));
+
+        // Ensure a database with a newer schema version is rejected.
+        let rocksdb = sample_fixture(1);
+        let key = map_prefix(NETWORK_ID, MapID::Metadata(MetadataMap::SchemaVersion));
+        rocksdb.put(key, (SCHEMA_VERSION + 1).to_le_bytes()).unwrap();
+        assert!(check_schema_version(&rocksdb, NETWORK_ID, &[], MIGRATION_BATCH_SIZE, |_| {}).is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_migrate() {
+        const NUM_ENTRIES: u32 = 25;
+
+        let rocksdb = sample_fixture(NUM_ENTRIES);
+        let migrations: Vec<Box<dyn Migration>> =
+            vec![Box::new(WidenValues { fail_on: None, has_failed: Default::default() })];
+
+        // Ensure a missing migration is reported.
+        assert!(migrate(&rocksdb, NETWORK_ID, &[], SCHEMA_VERSION + 1, 10, |_| {}).is_err());
+
+        // Apply the migration, in batches of 10 entries.
+        let mut reports = Vec::new();
+        let version = migrate(&rocksdb, NETWORK_ID, &migrations, SCHEMA_VERSION + 1, 10, |progress| {
+            reports.push(progress.num_migrated)
+        })
+        .unwrap();
+        assert_eq!(version, SCHEMA_VERSION + 1);
+        assert_eq!(reports, vec![10, 20, 25, 35, 45, 50]);
+
+        // Ensure the values were rewritten, and the schema version was updated.
+        check_widened(&rocksdb, NUM_ENTRIES);
+        assert_eq!(read_schema_version(&rocksdb, NETWORK_ID).unwrap(), Some(SCHEMA_VERSION + 1));
+        let progress_key = map_prefix(NETWORK_ID, MapID::Metadata(MetadataMap::MigrationProgress));
+        assert!(rocksdb.get(progress_key).unwrap().is_none());
+
+        // Ensure migrating again is a no-op.
+        assert_eq!(migrate(&rocksdb, NETWORK_ID, &migrations, SCHEMA_VERSION + 1, 10, |_| {}).unwrap(), version);
+    }
+
+    #[test]
+    #[serial]
+    fn test_migrate_resumes() {
+        const NUM_ENTRIES: u32 = 25;
+
+        let rocksdb = sample_fixture(NUM_ENTRIES);
+        let migrations: Vec<Box<dyn Migration>> =
+            vec![Box::new(WidenValues { fail_on: Some(15), has_failed: Default::default() })];
+
+        // Interrupt the migration in the second batch.
+        assert!(migrate(&rocksdb, NETWORK_ID, &migrations, SCHEMA_VERSION + 1, 10, |_| {}).is_err());
+        assert_eq!(read_schema_version(&rocksdb, NETWORK_ID).unwrap(), Some(SCHEMA_VERSION));
+
+        // Ensure the progress of the first batch was stored.
+        let progress_key = map_prefix(NETWORK_ID, MapID::Metadata(MetadataMap::MigrationProgress));
+        let progress = bincode::deserialize::<MigrationProgress>(&rocksdb.get(progress_key).unwrap().unwrap()).unwrap();
+        assert_eq!(progress.map_index, 0);
+        assert_eq!(progress.num_migrated, 10);
+
+        // Resume the migration, and ensure every value was rewritten exactly once.
+        let version = migrate(&rocksdb, NETWORK_ID, &migrations, SCHEMA_VERSION + 1, 10, |_| {}).unwrap();
+        assert_eq!(version, SCHEMA_VERSION + 1);
+        check_widened(&rocksdb, NUM_ENTRIES);
+    }
+
+    #[test]
+    fn test_migrations() {
+        // Ensure there is exactly one migration from every schema version prior to the current one.
+        let migrations = migrations();
+        assert_eq!(migrations.len(), usize::from(SCHEMA_VERSION - UNVERSIONED_SCHEMA_VERSION));
+        for (migration, from_version) in migrations.iter().zip(UNVERSIONED_SCHEMA_VERSION..SCHEMA_VERSION) {
+            assert_eq!(migration.from_version(), from_version);
+        }
+        // Ensure the versioned schema starts after the unversioned schema.
+        assert!(SCHEMA_VERSION > UNVERSIONED_SCHEMA_VERSION);
+    }
+
+    #[test]
+    #[serial]
+    fn test_migrate_unversioned_fixture() {
+        let (rocksdb, block) = sample_unversioned_fixture::<MainnetV0>();
+
+        // Ensure the unversioned database is migrated to the current schema version.
+        let mut from_versions = Vec::new();
+        check_schema_version(&rocksdb, MainnetV0::ID, &migrations(), MIGRATION_BATCH_SIZE, |progress| {
+            from_versions.push(progress.from_version)
+        })
+        .unwrap();
+        assert_eq!(from_versions, (UNVERSIONED_SCHEMA_VERSION..SCHEMA_VERSION).collect::<Vec<_>>());
+        assert_eq!(read_schema_version(&rocksdb, MainnetV0::ID).unwrap(), Some(SCHEMA_VERSION));
+        // Ensure the schema version of another network is unaffected.
+        assert_eq!(read_schema_version(&rocksdb, TestnetV0::ID).unwrap(), None);
+
+        // Ensure the genesis block is read back by the current block maps.
+        let database = to_database(rocksdb, MainnetV0::ID);
+        let id_map =
+            DataMap::<u32, <MainnetV0 as Network>::BlockHash>::new(database.clone(), MapID::Block(BlockMap::ID));
+        let reverse_id_map =
+            DataMap::<<MainnetV0 as Network>::BlockHash, u32>::new(database.clone(), MapID::Block(BlockMap::ReverseID));
+        let header_map = DataMap::<<MainnetV0 as Network>::BlockHash, Header<MainnetV0>>::new(
+            database,
+            MapID::Block(BlockMap::Header),
+        );
+        assert_eq!(id_map.get_confirmed(&0).unwrap().as_deref(), Some(&block.hash()));
+        assert_eq!(reverse_id_map.get_confirmed(&block.hash()).unwrap().as_deref(), Some(&0));
+        assert_eq!(header_map.get_confirmed(&block.hash()).unwrap().as_deref(), Some(block.header()));
+    }
+}
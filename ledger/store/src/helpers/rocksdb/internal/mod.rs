@@ -13,14 +13,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-mod id;
-pub use id::*;
+mod checkpoint;
 
-mod map;
-pub use map::*;
+pub use crate::helpers::{
+    id::*,
+    persistent::{Iter, Keys, NestedIter, NestedKeys, NestedValues, RawCursor, RawDatabase, RawWriteBatch, Values},
+};
+
+mod migration;
+pub use migration::*;
 
-mod nested_map;
-pub use nested_map::*;
+mod stats;
+use stats::WriteStats;
 
 #[cfg(test)]
 mod tests;
@@ -31,17 +35,17 @@ use once_cell::sync::OnceCell;
 use parking_lot::Mutex;
 use serde::{Serialize, de::DeserializeOwned};
 use std::{
-    borrow::Borrow,
-    marker::PhantomData,
-    mem,
     ops::Deref,
     sync::{
         Arc,
-        atomic::{AtomicBool, AtomicUsize, Ordering},
+        atomic::{AtomicBool, AtomicUsize},
     },
 };
 
-pub const PREFIX_LEN: usize = 4; // N::ID (u16) + DataID (u16)
+/// A map stored in a RocksDB database.
+pub type DataMap<K, V> = crate::helpers::persistent::DataMap<K, V, RocksDB>;
+/// A nested map stored in a RocksDB database.
+pub type NestedDataMap<M, K, V> = crate::helpers::persistent::NestedDataMap<M, K, V, RocksDB>;
 
 pub trait Database {
     /// Opens the database.
@@ -91,6 +95,8 @@ pub struct RocksDB {
     pub(super) atomic_depth: Arc<AtomicUsize>,
     /// A flag indicating whether the atomic writes are currently paused.
     pub(super) atomic_writes_paused: Arc<AtomicBool>,
+    /// The write counters of the storage maps.
+    pub(super) write_stats: Arc<WriteStats>,
     /// This is an optimization that avoids some allocations when querying the database.
     pub(super) default_readopts: rocksdb::ReadOptions,
 }
@@ -104,6 +110,7 @@ impl Clone for RocksDB {
             atomic_batch: self.atomic_batch.clone(),
             atomic_depth: self.atomic_depth.clone(),
             atomic_writes_paused: self.atomic_writes_paused.clone(),
+            write_stats: self.write_stats.clone(),
             default_readopts: Default::default(),
         }
     }
@@ -146,6 +153,15 @@ impl Database for RocksDB {
                     Arc::new(rocksdb::DB::open(&options, primary)?)
                 };
 
+                // Check the schema version, and apply the pending migrations.
+                check_schema_version(&rocksdb, network_id, &migrations(), MIGRATION_BATCH_SIZE, |progress| {
+                    tracing::info!(
+                        "Migrated {} entries of the storage from schema version {}",
+                        progress.num_migrated,
+                        progress.from_version
+                    )
+                })?;
+
                 Ok::<_, anyhow::Error>(RocksDB {
                     rocksdb,
                     network_id,
@@ -153,6 +169,7 @@ impl Database for RocksDB {
                     atomic_batch: Default::default(),
                     atomic_depth: Default::default(),
                     atomic_writes_paused: Default::default(),
+                    write_stats: Default::default(),
                     default_readopts: Default::default(),
                 })
             })?
@@ -179,18 +196,8 @@ impl Database for RocksDB {
         // Open the RocksDB database.
         let database = Self::open(network_id, storage)?;
 
-        // Combine contexts to create a new scope.
-        let mut context = database.network_id.to_le_bytes().to_vec();
-        context.extend_from_slice(&(map_id.into()).to_le_bytes());
-
         // Return the DataMap.
-        Ok(DataMap(Arc::new(InnerDataMap {
-            database,
-            context,
-            batch_in_progress: Default::default(),
-            atomic_batch: Default::default(),
-            checkpoints: Default::default(),
-        })))
+        Ok(DataMap::new(database, map_id))
     }
 
     /// Opens the nested map with the given `network_id`, `storage mode`, and `map_id` from storage.
@@ -208,72 +215,102 @@ impl Database for RocksDB {
         // Open the RocksDB database.
         let database = Self::open(network_id, storage)?;
 
-        // Combine contexts to create a new scope.
-        let mut context = database.network_id.to_le_bytes().to_vec();
-        context.extend_from_slice(&(map_id.into()).to_le_bytes());
+        // Return the NestedDataMap.
+        Ok(NestedDataMap::new(database, map_id))
+    }
+}
+
+impl RawDatabase for RocksDB {
+    type Cursor<'a> = rocksdb::DBRawIterator<'a>;
+    type Value<'a> = rocksdb::DBPinnableSlice<'a>;
+    type WriteBatch = rocksdb::WriteBatch;
 
-        // Return the DataMap.
-        Ok(NestedDataMap {
-            database,
-            context,
-            batch_in_progress: Default::default(),
-            atomic_batch: Default::default(),
-            checkpoints: Default::default(),
-        })
+    fn network_id(&self) -> u16 {
+        self.network_id
+    }
+
+    fn get_raw(&self, key: &[u8]) -> Result<Option<Self::Value<'_>>> {
+        Ok(self.rocksdb.get_pinned_opt(key, &self.default_readopts)?)
+    }
+
+    fn put_raw(&self, key: &[u8], value: &[u8]) -> Result<()> {
+        Ok(self.rocksdb.put(key, value)?)
+    }
+
+    fn delete_raw(&self, key: &[u8]) -> Result<()> {
+        Ok(self.rocksdb.delete(key)?)
+    }
+
+    fn write_batch(&self, batch: Self::WriteBatch) -> Result<()> {
+        Ok(self.rocksdb.write(batch)?)
+    }
+
+    fn raw_cursor(&self) -> Self::Cursor<'_> {
+        self.rocksdb.raw_iterator()
+    }
+
+    fn prefix_cursor(&self, prefix: &[u8]) -> Self::Cursor<'_> {
+        self.rocksdb.prefix_iterator(prefix).into()
+    }
+
+    fn atomic_batch(&self) -> &Mutex<Self::WriteBatch> {
+        &self.atomic_batch
+    }
+
+    fn atomic_depth(&self) -> &AtomicUsize {
+        &self.atomic_depth
+    }
+
+    fn atomic_writes_paused(&self) -> &AtomicBool {
+        &self.atomic_writes_paused
+    }
+
+    fn record_writes(&self, context: &[u8], num_writes: usize) {
+        self.write_stats.record(context, num_writes);
     }
 }
 
-impl RocksDB {
-    /// Pause the execution of atomic writes for the entire database.
-    fn pause_atomic_writes(&self) -> Result<()> {
-        // This operation is only intended to be performed before or after
-        // atomic batches - never in the middle of them.
-        assert_eq!(self.atomic_depth.load(Ordering::SeqCst), 0);
-
-        // Set the flag indicating that the pause is in effect.
-        let already_paused = self.atomic_writes_paused.swap(true, Ordering::SeqCst);
-        // Make sure that we haven't already paused atomic writes (which would
-        // indicate a logic bug).
-        assert!(!already_paused);
-
-        Ok(())
+impl RawWriteBatch for rocksdb::WriteBatch {
+    fn put(&mut self, key: &[u8], value: &[u8]) {
+        rocksdb::WriteBatch::put(self, key, value)
     }
 
-    /// Unpause the execution of atomic writes for the entire database; this
-    /// executes all the writes that have been queued since they were paused.
-    fn unpause_atomic_writes<const DISCARD_BATCH: bool>(&self) -> Result<()> {
-        // Ensure the call to unpause is only performed before or after an atomic batch scope
-        // - and never in the middle of one (otherwise there is a fundamental logic bug).
-        // Note: In production, this `ensure` is a safety-critical invariant that never fails.
-        ensure!(self.atomic_depth.load(Ordering::SeqCst) == 0, "Atomic depth must be 0 to unpause atomic writes");
-
-        // https://github.com/rust-lang/rust/issues/98485
-        let currently_paused = self.atomic_writes_paused.load(Ordering::SeqCst);
-        // Ensure the database is paused (otherwise there is a fundamental logic bug).
-        // Note: In production, this `ensure` is a safety-critical invariant that never fails.
-        ensure!(currently_paused, "Atomic writes must be paused to unpause them");
-
-        // In order to ensure that all the operations that are intended
-        // to be atomic via the usual macro approach are still performed
-        // atomically (just as a part of a larger batch), every atomic
-        // storage operation that has accumulated from the moment the
-        // writes have been paused becomes executed as a single atomic batch.
-        let batch = mem::take(&mut *self.atomic_batch.lock());
-        if !DISCARD_BATCH {
-            self.rocksdb.write(batch)?;
-        }
+    fn delete(&mut self, key: &[u8]) {
+        rocksdb::WriteBatch::delete(self, key)
+    }
+
+    fn len(&self) -> usize {
+        rocksdb::WriteBatch::len(self)
+    }
 
-        // Unset the flag indicating that the pause is in effect.
-        self.atomic_writes_paused.store(false, Ordering::SeqCst);
+    fn clear(&mut self) {
+        rocksdb::WriteBatch::clear(self)
+    }
+}
 
-        Ok(())
+impl RawCursor for rocksdb::DBRawIterator<'_> {
+    fn seek(&mut self, key: &[u8]) {
+        rocksdb::DBRawIterator::seek(self, key)
     }
 
-    /// Checks whether the atomic writes are currently paused.
-    fn are_atomic_writes_paused(&self) -> bool {
-        self.atomic_writes_paused.load(Ordering::SeqCst)
+    fn valid(&self) -> bool {
+        rocksdb::DBRawIterator::valid(self)
     }
 
+    fn item(&self) -> Option<(&[u8], &[u8])> {
+        rocksdb::DBRawIterator::item(self)
+    }
+
+    fn next(&mut self) {
+        rocksdb::DBRawIterator::next(self)
+    }
+
+    fn status(&self) -> Result<()> {
+        Ok(rocksdb::DBRawIterator::status(self)?)
+    }
+}
+
+impl RocksDB {
     /// Opens the test database.
     #[cfg(any(test, feature = "test"))]
     pub fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
@@ -327,6 +364,7 @@ impl RocksDB {
                 atomic_batch: Default::default(),
                 atomic_depth: Default::default(),
                 atomic_writes_paused: Default::default(),
+                write_stats: Default::default(),
                 default_readopts: Default::default(),
             })
         }?;
@@ -348,18 +386,8 @@ impl RocksDB {
         // Open the RocksDB test database.
         let database = Self::open_testing(temp_dir, dev)?;
 
-        // Combine contexts to create a new scope.
-        let mut context = database.network_id.to_le_bytes().to_vec();
-        context.extend_from_slice(&(map_id.into()).to_le_bytes());
-
         // Return the DataMap.
-        Ok(DataMap(Arc::new(InnerDataMap {
-            database,
-            context,
-            batch_in_progress: Default::default(),
-            atomic_batch: Default::default(),
-            checkpoints: Default::default(),
-        })))
+        Ok(DataMap::new(database, map_id))
     }
 
     /// Opens the test nested map.
@@ -377,18 +405,8 @@ impl RocksDB {
         // Open the RocksDB test database.
         let database = Self::open_testing(temp_dir, dev)?;
 
-        // Combine contexts to create a new scope.
-        let mut context = database.network_id.to_le_bytes().to_vec();
-        context.extend_from_slice(&(map_id.into()).to_le_bytes());
-
-        // Return the DataMap.
-        Ok(NestedDataMap {
-            database,
-            context,
-            batch_in_progress: Default::default(),
-            atomic_batch: Default::default(),
-            checkpoints: Default::default(),
-        })
+        // Return the NestedDataMap.
+        Ok(NestedDataMap::new(database, map_id))
     }
 }
 
@@ -0,0 +1,126 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::MapStats;
+
+use std::{collections::HashMap, time::Instant};
+
+/// The write counters of the storage maps, which are used to compute their write rates.
+#[derive(Default)]
+pub(super) struct WriteStats {
+    /// The number of writes to each storage map since the database was opened, indexed by map ID.
+    num_writes: Mutex<HashMap<u16, u64>>,
+    /// The time and the number of writes to each storage map when the statistics were last collected.
+    previous: Mutex<Option<(Instant, HashMap<u16, u64>)>>,
+}
+
+impl WriteStats {
+    /// Records the given number of writes to the storage map with the given context.
+    pub(super) fn record(&self, context: &[u8], num_writes: usize) {
+        // Retrieve the map ID from the context.
+        let map_id = u16::from_le_bytes([context[2], context[3]]);
+        *self.num_writes.lock().entry(map_id).or_default() += num_writes as u64;
+    }
+}
+
+impl RocksDB {
+    /// Returns the usage statistics of every storage map.
+    ///
+    /// The key count and size of each map are estimated from the metadata of the SST files, without
+    /// reading any entries: the entries and bytes of each file are split evenly across the maps its
+    /// key range covers, and the key counts are scaled to the `rocksdb.estimate-num-keys` property,
+    /// which also covers the entries still in the memtables. The write rate of each map is computed
+    /// over the period since the previous call to this method.
+    pub fn map_stats(&self) -> Result<Vec<MapStats>> {
+        // Snapshot the write counters, and replace the previous snapshot.
+        let now = Instant::now();
+        let num_writes = self.write_stats.num_writes.lock().clone();
+        let previous = self.write_stats.previous.lock().replace((now, num_writes.clone()));
+
+        // Construct the map prefixes.
+        let prefixes = MapID::ALL
+            .iter()
+            .map(|map_id| {
+                let mut prefix = self.network_id.to_le_bytes().to_vec();
+                prefix.extend_from_slice(&u16::from(*map_id).to_le_bytes());
+                prefix
+            })
+            .collect::<Vec<_>>();
+
+        // Split the live entries and the size of each SST file across the maps its key range covers.
+        let mut file_keys = vec![0u64; MapID::ALL.len()];
+        let mut sizes_in_bytes = vec![0u64; MapID::ALL.len()];
+        for file in self.live_files()? {
+            let (Some(start_key), Some(end_key)) = (&file.start_key, &file.end_key) else {
+                continue;
+            };
+            let start = &start_key[..start_key.len().min(PREFIX_LEN)];
+            let end = &end_key[..end_key.len().min(PREFIX_LEN)];
+            let covered = prefixes
+                .iter()
+                .enumerate()
+                .filter(|(_, prefix)| start <= prefix.as_slice() && prefix.as_slice() <= end)
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>();
+            if covered.is_empty() {
+                continue;
+            }
+            let num_maps = covered.len() as u64;
+            for index in covered {
+                file_keys[index] += file.num_entries.saturating_sub(file.num_deletions) / num_maps;
+                sizes_in_bytes[index] += file.size as u64 / num_maps;
+            }
+        }
+
+        // Scale the key counts to the estimated number of keys in the database. If nothing has been
+        // flushed to the SST files yet, the estimate is split by the number of writes to each map.
+        let estimated_keys = self.property_int_value(rocksdb::properties::ESTIMATE_NUM_KEYS)?.unwrap_or_default();
+        let weights = match file_keys.iter().sum::<u64>() {
+            0 => MapID::ALL
+                .iter()
+                .map(|map_id| num_writes.get(&u16::from(*map_id)).copied().unwrap_or_default())
+                .collect(),
+            _ => file_keys,
+        };
+        let total_weight = weights.iter().sum::<u64>().max(1) as u128;
+
+        let mut stats = Vec::with_capacity(MapID::ALL.len());
+        for (index, map_id) in MapID::ALL.iter().enumerate() {
+            let id = u16::from(*map_id);
+
+            // Compute the write rate of the map.
+            let map_writes = num_writes.get(&id).copied().unwrap_or_default();
+            let writes_per_second = match &previous {
+                Some((instant, previous)) => {
+                    let elapsed = now.duration_since(*instant).as_secs_f64();
+                    let new_writes = map_writes.saturating_sub(previous.get(&id).copied().unwrap_or_default());
+                    if elapsed > 0.0 { new_writes as f64 / elapsed } else { 0.0 }
+                }
+                None => 0.0,
+            };
+
+            stats.push(MapStats {
+                category: map_id.category(),
+                name: map_id.name(),
+                num_keys: (estimated_keys as u128 * weights[index] as u128 / total_weight) as u64,
+                size_in_bytes: sizes_in_bytes[index],
+                num_writes: map_writes,
+                writes_per_second,
+            });
+        }
+        Ok(stats)
+    }
+}
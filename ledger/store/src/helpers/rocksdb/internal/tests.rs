@@ -15,20 +15,14 @@
 
 use crate::helpers::{
     Map,
-    MapRead,
-    rocksdb::{MapID, RocksDB, TestMap as TestMapID},
-};
-use console::{
-    network::{MainnetV0, Network},
-    prelude::{TestRng, Uniform},
-    types::Scalar,
+    rocksdb::{BlockMap, MapID, RocksDB, TestMap as TestMapID},
 };
+use console::network::{MainnetV0, Network};
 
+use aleo_std_storage::StorageMode;
 use serial_test::serial;
 
-pub(crate) fn temp_dir() -> std::path::PathBuf {
-    tempfile::tempdir().expect("Failed to open temporary directory").into_path()
-}
+pub(crate) use crate::helpers::persistent::tests::temp_dir;
 
 // pub(crate) fn temp_file() -> std::path::PathBuf {
 //     tempfile::NamedTempFile::new().expect("Failed to open temporary file").path().to_owned()
@@ -47,87 +41,6 @@ fn test_open_map() {
         .expect("Failed to open data map");
 }
 
-#[test]
-#[serial]
-fn test_insert_and_contains_key() {
-    let map =
-        RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
-
-    map.insert(123456789, "123456789".to_string()).expect("Failed to insert");
-    assert!(map.contains_key_confirmed(&123456789).expect("Failed to call contains key"));
-    assert!(!map.contains_key_confirmed(&000000000).expect("Failed to call contains key"));
-}
-
-#[test]
-#[serial]
-fn test_insert_and_get() {
-    let map =
-        RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
-
-    map.insert(123456789, "123456789".to_string()).expect("Failed to insert");
-    assert_eq!(
-        Some("123456789".to_string()),
-        map.get_confirmed(&123456789).expect("Failed to get").map(|v| v.to_string())
-    );
-
-    assert_eq!(None, map.get_confirmed(&000000000).expect("Failed to get"));
-}
-
-#[test]
-#[serial]
-fn test_insert_and_remove() {
-    let map =
-        RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
-
-    map.insert(123456789, "123456789".to_string()).expect("Failed to insert");
-    assert_eq!(
-        map.get_confirmed(&123456789).expect("Failed to get").map(|v| v.to_string()),
-        Some("123456789".to_string())
-    );
-
-    map.remove(&123456789).expect("Failed to remove");
-    assert!(map.get_confirmed(&123456789).expect("Failed to get").is_none());
-}
-
-#[test]
-#[serial]
-fn test_insert_and_iter() {
-    let map =
-        RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
-
-    map.insert(123456789, "123456789".to_string()).expect("Failed to insert");
-
-    let mut iter = map.iter_confirmed();
-    assert_eq!(Some((123456789, "123456789".to_string())), iter.next().map(|(k, v)| (*k, v.to_string())));
-    assert_eq!(None, iter.next());
-}
-
-#[test]
-#[serial]
-fn test_insert_and_keys() {
-    let map =
-        RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
-
-    map.insert(123456789, "123456789".to_string()).expect("Failed to insert");
-
-    let mut keys = map.keys_confirmed();
-    assert_eq!(Some(123456789), keys.next().map(|k| *k));
-    assert_eq!(None, keys.next());
-}
-
-#[test]
-#[serial]
-fn test_insert_and_values() {
-    let map =
-        RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
-
-    map.insert(123456789, "123456789".to_string()).expect("Failed to insert");
-
-    let mut values = map.values_confirmed();
-    assert_eq!(Some("123456789".to_string()), values.next().map(|v| v.to_string()));
-    assert_eq!(None, values.next());
-}
-
 // #[test]
 // #[serial]
 // fn test_export_import() {
@@ -154,64 +67,76 @@ fn test_insert_and_values() {
 
 #[test]
 #[serial]
-fn test_scalar_mul() {
-    type CurrentNetwork = MainnetV0;
-
-    let rng = &mut TestRng::default();
-
-    const ITERATIONS: u32 = 1_000_000u32;
-
+fn test_checkpoint() {
     let map =
         RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
 
-    // Sample `ITERATION` random field elements to store.
-    for i in 0..ITERATIONS {
-        let value = Scalar::<CurrentNetwork>::rand(rng);
-        map.insert(i, value).expect("Failed to insert");
+    // Insert values into the map, and create a checkpoint.
+    for i in 0..3u32 {
+        map.insert(i, i.to_string()).expect("Failed to insert");
     }
-
-    let timer = std::time::Instant::now();
-
-    // Execute scalar multiplication for each stored element.
-    for value in map.values_confirmed() {
-        let _group = CurrentNetwork::g_scalar_multiply(&*value);
-    }
-
-    let elapsed = timer.elapsed().as_secs();
-    println!(" {ITERATIONS} Scalar Muls : {elapsed} s");
+    // Ensure the checkpoint does not wait for a pending atomic batch, nor contain its writes.
+    map.start_atomic();
+    map.insert(10, "10".to_string()).expect("Failed to insert");
+    let checkpoint = temp_dir().join("checkpoint");
+    map.database.create_checkpoint(&checkpoint).expect("Failed to create a checkpoint");
+    map.finish_atomic().expect("Failed to finish the atomic batch");
+    // Ensure a checkpoint cannot overwrite an existing directory.
+    assert!(map.database.create_checkpoint(&checkpoint).is_err());
+
+    // Insert a value after the checkpoint.
+    map.insert(3, "3".to_string()).expect("Failed to insert");
+
+    // Ensure the checkpoint cannot be restored for a different network.
+    let restored = temp_dir().join("restored");
+    assert!(RocksDB::restore_checkpoint(&checkpoint, MainnetV0::ID, StorageMode::from(restored.clone())).is_err());
+
+    // Restore the checkpoint.
+    RocksDB::restore_checkpoint(&checkpoint, u16::MAX, StorageMode::from(restored.clone()))
+        .expect("Failed to restore the checkpoint");
+    // Ensure the checkpoint cannot be restored into a non-empty directory.
+    assert!(RocksDB::restore_checkpoint(&checkpoint, u16::MAX, StorageMode::from(restored.clone())).is_err());
+
+    // Ensure the restored database only contains the values committed before the checkpoint.
+    let database = rocksdb::DB::open_default(&restored).expect("Failed to open the restored database");
+    assert_eq!(database.iterator(rocksdb::IteratorMode::Start).count(), 3);
 }
 
 #[test]
 #[serial]
-fn test_iterator_ordering() {
-    let map =
-        RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+fn test_map_stats() {
+    let map = RocksDB::open_map_testing(temp_dir(), None, MapID::Block(BlockMap::PrunedHeight))
+        .expect("Failed to open data map");
 
-    // Insert values into the map.
-    map.insert(5, "d".to_string()).expect("Failed to insert");
-    map.insert(6, "c".to_string()).expect("Failed to insert");
-    map.insert(7, "b".to_string()).expect("Failed to insert");
-    map.insert(8, "a".to_string()).expect("Failed to insert");
-    map.insert(1, "h".to_string()).expect("Failed to insert");
-    map.insert(2, "g".to_string()).expect("Failed to insert");
-    map.insert(3, "f".to_string()).expect("Failed to insert");
-    map.insert(4, "e".to_string()).expect("Failed to insert");
-
-    // Define the expected order of the iterator.
-    let expected_order = vec![
-        (1, "h".to_string()),
-        (2, "g".to_string()),
-        (3, "f".to_string()),
-        (4, "e".to_string()),
-        (5, "d".to_string()),
-        (6, "c".to_string()),
-        (7, "b".to_string()),
-        (8, "a".to_string()),
-    ];
-
-    // Check that the order of the iterator is lexicographical.
-    for ((k1, v1), (k2, v2)) in map.iter_confirmed().zip(expected_order.iter()) {
-        assert_eq!(&*k1, k2);
-        assert_eq!(&*v1, v2);
+    // Insert values into the map, both directly and atomically, and remove one of them.
+    for i in 0..3u32 {
+        map.insert(i, i.to_string()).expect("Failed to insert");
     }
+    map.start_atomic();
+    map.insert(3, "3".to_string()).expect("Failed to insert");
+    map.insert(4, "4".to_string()).expect("Failed to insert");
+    map.finish_atomic().expect("Failed to finish the atomic batch");
+    map.remove(&0).expect("Failed to remove");
+
+    // Ensure the statistics cover every map, and that the unflushed entries are attributed to the populated map.
+    let stats = map.database.map_stats().expect("Failed to collect the map stats");
+    assert_eq!(stats.len(), MapID::ALL.len());
+    let (populated, empty): (Vec<_>, Vec<_>) =
+        stats.into_iter().partition(|stats| stats.category == "block" && stats.name == "PrunedHeight");
+    assert!(empty.iter().all(|stats| stats.num_keys == 0 && stats.size_in_bytes == 0 && stats.num_writes == 0));
+    assert!(populated[0].num_keys > 0);
+    assert_eq!(populated[0].num_writes, 6);
+    // Ensure the write rate is only computed once there are previous statistics.
+    assert_eq!(populated[0].writes_per_second, 0.0);
+
+    // Insert another value and flush the memtables, and ensure the estimates come from the SST files.
+    map.insert(5, "5".to_string()).expect("Failed to insert");
+    map.database.flush().expect("Failed to flush the database");
+    let stats = map.database.map_stats().expect("Failed to collect the map stats");
+    assert!(stats.iter().filter(|stats| stats.name != "PrunedHeight").all(|stats| stats.num_keys == 0));
+    let stats = stats.iter().find(|stats| stats.name == "PrunedHeight").unwrap();
+    assert!(stats.num_keys > 0);
+    assert!(stats.size_in_bytes > 0);
+    assert_eq!(stats.num_writes, 7);
+    assert!(stats.writes_per_second > 0.0);
 }
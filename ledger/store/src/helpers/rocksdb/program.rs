@@ -19,13 +19,16 @@ use crate::{
     CommitteeStorage,
     CommitteeStore,
     FinalizeStorage,
+    HistoryHeight,
+    RollbackEntries,
     helpers::rocksdb::{self, CommitteeMap, DataMap, Database, MapID, NestedDataMap, ProgramMap},
 };
 use console::{
     prelude::*,
-    program::{Identifier, Plaintext, ProgramID, Value},
+    program::{Identifier, MappingNodeEntry, MappingNodePosition, Plaintext, ProgramID, Value},
 };
 use ledger_committee::Committee;
+use synthesizer_program::FinalizeOperation;
 
 use aleo_std_storage::StorageMode;
 use indexmap::IndexSet;
@@ -39,6 +42,16 @@ pub struct FinalizeDB<N: Network> {
     program_id_map: DataMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The rollback map.
+    rollback_map: DataMap<u32, RollbackEntries<N>>,
+    /// The value history map.
+    value_history_map: NestedDataMap<(ProgramID<N>, Identifier<N>), (Plaintext<N>, HistoryHeight), Option<Value<N>>>,
+    /// The archive height map.
+    archive_height_map: DataMap<u8, u32>,
+    /// The ratified operations map.
+    ratified_operations_map: DataMap<u32, Vec<FinalizeOperation<N>>>,
+    /// The mapping node map.
+    mapping_node_map: DataMap<MappingNodePosition<N>, MappingNodeEntry<N>>,
     /// The storage mode.
     storage_mode: StorageMode,
 }
@@ -48,6 +61,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
     type CommitteeStorage = CommitteeDB<N>;
     type ProgramIDMap = DataMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type RollbackMap = DataMap<u32, RollbackEntries<N>>;
+    type ValueHistoryMap = NestedDataMap<(ProgramID<N>, Identifier<N>), (Plaintext<N>, HistoryHeight), Option<Value<N>>>;
+    type ArchiveHeightMap = DataMap<u8, u32>;
+    type RatifiedOperationsMap = DataMap<u32, Vec<FinalizeOperation<N>>>;
+    type MappingNodeMap = DataMap<MappingNodePosition<N>, MappingNodeEntry<N>>;
 
     /// Initializes the finalize storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
@@ -58,6 +76,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::ProgramID))?,
             key_value_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::KeyValueID))?,
+            rollback_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::Rollback))?,
+            value_history_map: rocksdb::RocksDB::open_nested_map(N::ID, storage.clone(), MapID::Program(ProgramMap::ValueHistory))?,
+            archive_height_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::ArchiveHeight))?,
+            ratified_operations_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::RatifiedOperations))?,
+            mapping_node_map: rocksdb::RocksDB::open_map(N::ID, storage.clone(), MapID::Program(ProgramMap::MappingNode))?,
             storage_mode: storage.into(),
         })
     }
@@ -71,7 +94,12 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         Ok(Self {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ProgramID))?,
-            key_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir, dev, MapID::Program(ProgramMap::KeyValueID))?,
+            key_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyValueID))?,
+            rollback_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::Rollback))?,
+            value_history_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ValueHistory))?,
+            archive_height_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ArchiveHeight))?,
+            ratified_operations_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::RatifiedOperations))?,
+            mapping_node_map: rocksdb::RocksDB::open_map_testing(temp_dir, dev, MapID::Program(ProgramMap::MappingNode))?,
             storage_mode: dev.into(),
         })
     }
@@ -91,6 +119,31 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         &self.key_value_map
     }
 
+    /// Returns the rollback map.
+    fn rollback_map(&self) -> &Self::RollbackMap {
+        &self.rollback_map
+    }
+
+    /// Returns the value history map.
+    fn value_history_map(&self) -> &Self::ValueHistoryMap {
+        &self.value_history_map
+    }
+
+    /// Returns the archive height map.
+    fn archive_height_map(&self) -> &Self::ArchiveHeightMap {
+        &self.archive_height_map
+    }
+
+    /// Returns the ratified operations map.
+    fn ratified_operations_map(&self) -> &Self::RatifiedOperationsMap {
+        &self.ratified_operations_map
+    }
+
+    /// Returns the mapping node map.
+    fn mapping_node_map(&self) -> &Self::MappingNodeMap {
+        &self.mapping_node_map
+    }
+
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
         &self.storage_mode
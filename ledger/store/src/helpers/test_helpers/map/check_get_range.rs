@@ -0,0 +1,138 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ensure_map_is_empty;
+use crate::helpers::Map;
+
+use core::ops::Bound::{Excluded, Included, Unbounded};
+
+pub fn check_get_range(map: impl for<'a> Map<'a, usize, String>) {
+    ensure_map_is_empty(&map);
+
+    // Note: The keys are below 256, so their serialized order matches their numeric order, until the keys
+    // that are not in serialized order are tested.
+    const NUM_ITEMS: usize = 20;
+
+    // Returns the expected key-value pairs for the given keys.
+    let entries = |keys: core::ops::Range<usize>| keys.map(|i| (i, i.to_string())).collect::<Vec<_>>();
+
+    for i in 0..NUM_ITEMS {
+        // Insert an item into the map.
+        map.insert(i, i.to_string()).unwrap();
+    }
+
+    // Check the range scans.
+    assert_eq!(map.get_range_confirmed(Unbounded, Unbounded, usize::MAX).unwrap(), entries(0..NUM_ITEMS));
+    assert_eq!(map.get_range_confirmed(Included(&5), Excluded(&10), usize::MAX).unwrap(), entries(5..10));
+    assert_eq!(map.get_range_confirmed(Excluded(&5), Included(&10), usize::MAX).unwrap(), entries(6..11));
+    assert_eq!(map.get_range_confirmed(Included(&5), Unbounded, 3).unwrap(), entries(5..8));
+    assert_eq!(map.get_range_confirmed(Unbounded, Included(&2), usize::MAX).unwrap(), entries(0..3));
+
+    // Check the empty ranges.
+    assert!(map.get_range_confirmed(Included(&10), Excluded(&5), usize::MAX).unwrap().is_empty());
+    assert!(map.get_range_confirmed(Excluded(&5), Excluded(&5), usize::MAX).unwrap().is_empty());
+    assert!(map.get_range_confirmed(Included(&NUM_ITEMS), Unbounded, usize::MAX).unwrap().is_empty());
+    assert!(map.get_range_confirmed(Unbounded, Unbounded, 0).unwrap().is_empty());
+
+    // Check the pagination.
+    let mut cursor = None;
+    let mut pages = Vec::new();
+    loop {
+        let page = map.get_page_confirmed(cursor.as_ref(), 7).unwrap();
+        if page.is_empty() {
+            break;
+        }
+        cursor = page.last().map(|(key, _)| *key);
+        pages.push(page);
+    }
+    assert_eq!(pages.len(), 3);
+    assert_eq!(pages.concat(), entries(0..NUM_ITEMS));
+
+    /* test atomic insertions */
+
+    {
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Insert an item into the map.
+        map.insert(NUM_ITEMS, NUM_ITEMS.to_string()).unwrap();
+
+        // Ensure the pending item is not returned.
+        assert_eq!(map.get_page_confirmed(None, usize::MAX).unwrap(), entries(0..NUM_ITEMS));
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // Ensure the confirmed item is returned.
+        assert_eq!(
+            map.get_page_confirmed(Some(&(NUM_ITEMS - 1)), usize::MAX).unwrap(),
+            entries(NUM_ITEMS..NUM_ITEMS + 1)
+        );
+    }
+
+    /* test keys that are not in serialized order */
+
+    {
+        // Insert keys from 255, whose little-endian serialized order differs from their numeric order.
+        let large_keys = [255usize, 256, 257, 511, 65_536];
+        for key in large_keys {
+            map.insert(key, key.to_string()).unwrap();
+        }
+
+        // Returns the expected key-value pairs for the given keys, in serialized key order.
+        let sorted = |keys: &mut dyn Iterator<Item = usize>| {
+            let mut entries = keys.map(|i| (i, i.to_string())).collect::<Vec<_>>();
+            entries.sort_by_key(|(key, _)| bincode::serialize(key).unwrap());
+            entries
+        };
+        let keys = (0..=NUM_ITEMS).chain(large_keys).collect::<Vec<_>>();
+
+        // Ensure the range scans and the pages return every entry, in serialized key order.
+        let expected = sorted(&mut keys.iter().copied());
+        assert_ne!(expected.iter().map(|(key, _)| *key).collect::<Vec<_>>(), keys);
+        assert_eq!(map.get_range_confirmed(Unbounded, Unbounded, usize::MAX).unwrap(), expected);
+        let mut cursor = None;
+        let mut pages = Vec::new();
+        loop {
+            let page = map.get_page_confirmed(cursor.as_ref(), 4).unwrap();
+            if page.is_empty() {
+                break;
+            }
+            cursor = page.last().map(|(key, _)| *key);
+            pages.push(page);
+        }
+        assert_eq!(pages.concat(), expected);
+
+        // Ensure the range bounds are compared by their serialized representation.
+        let start = bincode::serialize(&256usize).unwrap();
+        assert_eq!(
+            map.get_range_confirmed(Included(&256), Unbounded, usize::MAX).unwrap(),
+            sorted(&mut keys.iter().copied().filter(|key| bincode::serialize(key).unwrap() >= start))
+        );
+        assert!(map.get_range_confirmed(Included(&255), Excluded(&256), usize::MAX).unwrap().is_empty());
+
+        for key in large_keys {
+            // Remove the item from the map.
+            map.remove(&key).unwrap();
+        }
+    }
+
+    for i in 0..=NUM_ITEMS {
+        // Remove the item from the map.
+        map.remove(&i).unwrap();
+    }
+
+    ensure_map_is_empty(&map);
+}
@@ -22,6 +22,9 @@ pub use check_atomic_writes_can_be_aborted::*;
 mod check_contains_key;
 pub use check_contains_key::*;
 
+mod check_get_range;
+pub use check_get_range::*;
+
 mod check_insert_and_get_speculative;
 pub use check_insert_and_get_speculative::*;
 
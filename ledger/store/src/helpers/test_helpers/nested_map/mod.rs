@@ -25,6 +25,9 @@ pub use check_contains_key::*;
 mod check_get_map;
 pub use check_get_map::*;
 
+mod check_get_map_range;
+pub use check_get_map_range::*;
+
 mod check_insert_and_get_value_speculative;
 pub use check_insert_and_get_value_speculative::*;
 
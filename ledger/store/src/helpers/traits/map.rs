@@ -15,7 +15,7 @@
 
 use console::network::prelude::{Deserialize, Result, Serialize};
 
-use core::{borrow::Borrow, hash::Hash};
+use core::{borrow::Borrow, hash::Hash, ops::Bound};
 use std::borrow::Cow;
 
 /// A trait representing map-like storage operations with read-write capabilities.
@@ -187,4 +187,73 @@ pub trait MapRead<
     /// Returns an iterator over each value in the map.
     ///
     fn values_confirmed(&'a self) -> Self::Values;
+
+    ///
+    /// Returns up to `limit` confirmed key-value pairs with a key in the given range, in serialized key order.
+    ///
+    /// Note: The keys and the range bounds are compared by their serialized representation, which is the same for
+    /// every storage backend, but is not the natural order of every key type. For example, integers are serialized
+    /// in little-endian, so `256` is ordered before `1`; keys that are scanned by value need a big-endian encoding.
+    ///
+    fn get_range_confirmed(&'a self, start: Bound<&K>, end: Bound<&K>, limit: usize) -> Result<Vec<(K, V)>>;
+
+    ///
+    /// Returns up to `limit` confirmed key-value pairs in serialized key order, starting after the given cursor,
+    /// or from the first key if no cursor is given. The last key of a page is the cursor of the next page.
+    ///
+    fn get_page_confirmed(&'a self, cursor: Option<&K>, limit: usize) -> Result<Vec<(K, V)>> {
+        let start = match cursor {
+            Some(cursor) => Bound::Excluded(cursor),
+            None => Bound::Unbounded,
+        };
+        self.get_range_confirmed(start, Bound::Unbounded, limit)
+    }
+}
+
+/// The serialized `(start, end)` bounds of a key range.
+pub(crate) type SerializedRange = (Bound<Vec<u8>>, Bound<Vec<u8>>);
+
+/// Returns the serialized bounds of the given key range, or `None` if the range is empty.
+pub(crate) fn serialize_range<K: Serialize>(start: Bound<&K>, end: Bound<&K>) -> Result<Option<SerializedRange>> {
+    // Serialize the bounds.
+    let serialize = |bound: Bound<&K>| -> Result<Bound<Vec<u8>>> {
+        Ok(match bound {
+            Bound::Included(key) => Bound::Included(bincode::serialize(key)?),
+            Bound::Excluded(key) => Bound::Excluded(bincode::serialize(key)?),
+            Bound::Unbounded => Bound::Unbounded,
+        })
+    };
+    let (start, end) = (serialize(start)?, serialize(end)?);
+    // Determine if the range is empty.
+    let is_empty = match (&start, &end) {
+        (Bound::Included(start), Bound::Included(end)) => start > end,
+        (Bound::Included(start), Bound::Excluded(end))
+        | (Bound::Excluded(start), Bound::Included(end))
+        | (Bound::Excluded(start), Bound::Excluded(end)) => start >= end,
+        _ => false,
+    };
+    match is_empty {
+        true => Ok(None),
+        false => Ok(Some((start, end))),
+    }
+}
+
+/// Returns `true` if the given serialized key is at or after the given serialized start bound.
+#[cfg(any(feature = "rocks", feature = "embedded"))]
+pub(crate) fn is_after_start(key: &[u8], start: &Bound<Vec<u8>>) -> bool {
+    match start {
+        Bound::Included(start) => key >= start.as_slice(),
+        Bound::Excluded(start) => key > start.as_slice(),
+        Bound::Unbounded => true,
+    }
+}
+
+/// Returns `true` if the given serialized key is at or before the given serialized end bound.
+#[cfg(any(feature = "rocks", feature = "embedded"))]
+pub(crate) fn is_before_end(key: &[u8], end: &Bound<Vec<u8>>) -> bool {
+    match end {
+        Bound::Included(end) => key <= end.as_slice(),
+        Bound::Excluded(end) => key < end.as_slice(),
+        Bound::Unbounded => true,
+    }
 }
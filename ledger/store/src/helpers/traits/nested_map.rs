@@ -15,7 +15,7 @@
 
 use console::network::prelude::{Deserialize, Result, Serialize};
 
-use core::hash::Hash;
+use core::{hash::Hash, ops::Bound};
 use std::borrow::Cow;
 
 /// A trait representing 'nested map'-like storage operations with read-write capabilities.
@@ -175,4 +175,32 @@ pub trait NestedMapRead<
     /// Returns an iterator over each confirmed value.
     ///
     fn values_confirmed(&'a self) -> Self::Values;
+
+    ///
+    /// Returns up to `limit` confirmed key-value pairs for the given map with a key in the given range,
+    /// in serialized key order.
+    ///
+    /// Note: The keys and the range bounds are compared by their serialized representation, which is the same for
+    /// every storage backend, but is not the natural order of every key type (see `MapRead::get_range_confirmed`).
+    ///
+    fn get_map_range_confirmed(
+        &'a self,
+        map: &M,
+        start: Bound<&K>,
+        end: Bound<&K>,
+        limit: usize,
+    ) -> Result<Vec<(K, V)>>;
+
+    ///
+    /// Returns up to `limit` confirmed key-value pairs for the given map in serialized key order, starting after
+    /// the given cursor, or from the first key if no cursor is given. The last key of a page is the cursor of the
+    /// next page.
+    ///
+    fn get_map_page_confirmed(&'a self, map: &M, cursor: Option<&K>, limit: usize) -> Result<Vec<(K, V)>> {
+        let start = match cursor {
+            Some(cursor) => Bound::Excluded(cursor),
+            None => Bound::Unbounded,
+        };
+        self.get_map_range_confirmed(map, start, Bound::Unbounded, limit)
+    }
 }
@@ -164,6 +164,28 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Stores the given `(start height, committee)` pair into empty storage, without the committees
+    /// prior to the start height. This is used to bootstrap the committee storage from a snapshot.
+    fn insert_starting_committee(&self, start_height: u32, committee: Committee<N>) -> Result<()> {
+        // Retrieve the starting round.
+        let start_round = committee.starting_round();
+        // Ensure the starting round is at least the start height.
+        ensure!(start_round >= start_height as u64, "Starting round must be at least the start height");
+        // Ensure the committee storage is empty.
+        ensure!(self.current_round().is_err(), "Cannot insert a starting committee into non-empty committee storage");
+
+        // Start an atomic batch.
+        atomic_batch_scope!(self, {
+            // Store the starting round.
+            self.current_round_map().insert(ROUND_KEY, start_round)?;
+            // Store the starting round's height.
+            self.round_to_height_map().insert(start_round, start_height)?;
+            // Store the committee.
+            self.committee_map().insert(start_height, committee)?;
+            Ok(())
+        })
+    }
+
     /// Removes the committee for the given `height`, in the process
     /// removing all round to height entries back to the previous committee.
     fn remove(&self, height: u32) -> Result<()> {
@@ -236,6 +258,42 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Removes the committees for all heights above the given `height`, in the process
+    /// restoring the current round to the starting round of the committee at the given `height`.
+    fn revert_to_height(&self, height: u32) -> Result<()> {
+        // Retrieve the current round.
+        let current_round = self.current_round()?;
+        // Retrieve the current height.
+        let current_height = self.current_height()?;
+        // Ensure the given height is not in the future.
+        ensure!(
+            height <= current_height,
+            "Cannot revert committee storage to height {height} (current is {current_height})"
+        );
+        // Retrieve the committee for the given height.
+        let Some(committee) = self.get_committee(height)? else {
+            bail!("Committee not found for height {height} in committee storage");
+        };
+        // Retrieve the round for the given height.
+        let committee_round = committee.starting_round();
+
+        // Start an atomic batch.
+        atomic_batch_scope!(self, {
+            // Restore the current round.
+            self.current_round_map().insert(ROUND_KEY, committee_round)?;
+            // Remove the round to height mappings after the committee round.
+            for round in committee_round.saturating_add(1)..=current_round {
+                self.round_to_height_map().remove(&round)?;
+            }
+            // Remove the committees after the given height.
+            for next_height in height.saturating_add(1)..=current_height {
+                self.committee_map().remove(&next_height)?;
+            }
+
+            Ok(())
+        })
+    }
+
     /// Returns the current round.
     fn current_round(&self) -> Result<u64> {
         match self.current_round_map().get_confirmed(&ROUND_KEY)? {
@@ -370,11 +428,23 @@ impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
         self.storage.insert(next_height, committee)
     }
 
+    /// Stores the given `(start height, committee)` pair into empty storage, without the committees
+    /// prior to the start height. This is used to bootstrap the committee storage from a snapshot.
+    pub fn insert_starting_committee(&self, start_height: u32, committee: Committee<N>) -> Result<()> {
+        self.storage.insert_starting_committee(start_height, committee)
+    }
+
     /// Removes the committee for the given `height`, in the process
     /// removing all round to height entries back to the previous committee.
     pub fn remove(&self, height: u32) -> Result<()> {
         self.storage.remove(height)
     }
+
+    /// Removes the committees for all heights above the given `height`, in the process
+    /// restoring the current round to the starting round of the committee at the given `height`.
+    pub fn revert_to_height(&self, height: u32) -> Result<()> {
+        self.storage.revert_to_height(height)
+    }
 }
 
 impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
@@ -615,4 +685,91 @@ mod tests {
         assert_eq!(store.get_committee_for_round(4).unwrap(), None);
         assert_eq!(store.get_committee_for_round(5).unwrap(), None);
     }
+
+    #[test]
+    fn test_revert_to_height() {
+        let rng = &mut TestRng::default();
+
+        // Sample the committees.
+        let committee_0 = ledger_committee::test_helpers::sample_committee_for_round(0, rng);
+        let committee_1 = ledger_committee::test_helpers::sample_committee_for_round(5, rng);
+        let committee_2 = ledger_committee::test_helpers::sample_committee_for_round(8, rng);
+
+        // Initialize a new committee store.
+        let store = CommitteeStore::<CurrentNetwork, CommitteeMemory<_>>::open(None).unwrap();
+        // Insert the committees.
+        store.insert(0, committee_0.clone()).unwrap();
+        store.insert(1, committee_1.clone()).unwrap();
+        store.insert(2, committee_2.clone()).unwrap();
+        assert_eq!(store.current_round().unwrap(), 8);
+        assert_eq!(store.current_height().unwrap(), 2);
+
+        // Ensure reverting to a future height fails.
+        assert!(store.revert_to_height(3).is_err());
+
+        // Revert to height 1.
+        store.revert_to_height(1).unwrap();
+        assert_eq!(store.current_round().unwrap(), 5);
+        assert_eq!(store.current_height().unwrap(), 1);
+        assert_eq!(store.current_committee().unwrap(), committee_1);
+        assert_eq!(store.get_height_for_round(5).unwrap(), Some(1));
+        assert_eq!(store.get_height_for_round(6).unwrap(), None);
+        assert_eq!(store.get_height_for_round(8).unwrap(), None);
+        assert_eq!(store.get_committee(2).unwrap(), None);
+
+        // Ensure the committee can be re-inserted.
+        store.insert(2, committee_2.clone()).unwrap();
+        assert_eq!(store.current_round().unwrap(), 8);
+        assert_eq!(store.get_height_for_round(7).unwrap(), Some(1));
+        assert_eq!(store.get_committee_for_round(8).unwrap().unwrap(), committee_2);
+
+        // Revert to height 0.
+        store.revert_to_height(0).unwrap();
+        assert_eq!(store.current_round().unwrap(), 0);
+        assert_eq!(store.current_height().unwrap(), 0);
+        assert_eq!(store.current_committee().unwrap(), committee_0);
+        for round in 1..=8 {
+            assert_eq!(store.get_height_for_round(round).unwrap(), None);
+        }
+        assert_eq!(store.get_committee(1).unwrap(), None);
+        assert_eq!(store.get_committee(2).unwrap(), None);
+    }
+
+    #[test]
+    fn test_insert_starting_committee() {
+        let rng = &mut TestRng::default();
+
+        // Sample the committees.
+        let committee_5 = ledger_committee::test_helpers::sample_committee_for_round(10, rng);
+        let committee_6 = ledger_committee::test_helpers::sample_committee_for_round(12, rng);
+
+        // Initialize a new committee store.
+        let store = CommitteeStore::<CurrentNetwork, CommitteeMemory<_>>::open(None).unwrap();
+
+        // Ensure the starting round must be at least the start height.
+        assert!(store.insert_starting_committee(11, committee_5.clone()).is_err());
+
+        // Insert the starting committee.
+        store.insert_starting_committee(5, committee_5.clone()).unwrap();
+        assert_eq!(store.current_round().unwrap(), 10);
+        assert_eq!(store.current_height().unwrap(), 5);
+        assert_eq!(store.current_committee().unwrap(), committee_5);
+        assert_eq!(store.get_committee(4).unwrap(), None);
+        assert_eq!(store.get_committee_for_round(9).unwrap(), None);
+
+        // Ensure a starting committee cannot be inserted into non-empty storage.
+        assert!(store.insert_starting_committee(5, committee_5.clone()).is_err());
+
+        // Ensure the subsequent committees are inserted sequentially.
+        assert!(store.insert(7, committee_6.clone()).is_err());
+        store.insert(6, committee_6.clone()).unwrap();
+        assert_eq!(store.current_height().unwrap(), 6);
+        assert_eq!(store.get_committee_for_round(11).unwrap().unwrap(), committee_5);
+        assert_eq!(store.get_committee_for_round(12).unwrap().unwrap(), committee_6);
+
+        // Ensure the committees can be reverted to the starting committee.
+        store.revert_to_height(5).unwrap();
+        assert_eq!(store.current_committee().unwrap(), committee_5);
+        assert!(store.revert_to_height(4).is_err());
+    }
 }
@@ -14,6 +14,7 @@
 // limitations under the License.
 
 use crate::{
+    MappingStats,
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
@@ -22,15 +23,109 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{Identifier, Plaintext, ProgramID, Value},
+    program::{
+        Identifier,
+        MappingNodeEntry,
+        MappingNodePosition,
+        MappingProof,
+        MappingTree,
+        Plaintext,
+        ProgramID,
+        Value,
+    },
     types::Field,
 };
 use synthesizer_program::{FinalizeOperation, FinalizeStoreTrait};
 
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
-use core::marker::PhantomData;
-use indexmap::IndexSet;
+use core::{marker::PhantomData, ops::Bound};
+use indexmap::{IndexMap, IndexSet};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// Returns the checksum of the given `((program ID, mapping name), key, value)` entries,
+/// as computed by `FinalizeStorage::get_checksum_confirmed`.
+pub fn compute_finalize_checksum<N: Network>(
+    entries: impl Iterator<Item = ((ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>)>,
+) -> Result<Field<N>> {
+    // Compute all mapping checksums.
+    let preimage: std::collections::BTreeMap<_, _> = entries
+        .map(|(m, k, v)| {
+            let mut preimage = Vec::new();
+            m.write_bits_le(&mut preimage);
+            false.write_bits_le(&mut preimage); // Separator.
+            k.write_bits_le(&mut preimage);
+            false.write_bits_le(&mut preimage); // Separator.
+
+            // Compute the mapping checksum as `Hash( m || k )`.
+            let mapping_checksum = N::hash_bhp1024(&preimage)?;
+
+            v.write_bits_le(&mut preimage);
+            false.write_bits_le(&mut preimage); // Separator.
+
+            // Compute the entry checksum as `Hash( m || k || v )`.
+            let entry_checksum = N::hash_bhp1024(&preimage)?;
+            // Return the mapping checksum and entry checksum.
+            Ok::<_, Error>((mapping_checksum, entry_checksum.to_bits_le()))
+        })
+        .try_collect()?;
+    // Compute the checksum as `Hash( all mapping checksums )`.
+    N::hash_bhp1024(&preimage.into_values().flatten().collect::<Vec<_>>())
+}
+
+/// A finalize storage entry, as `((program ID, mapping name), key, value)`.
+pub type MappingEntry<N> = ((ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>);
+
+/// Returns the mapping tree over the given entries,
+/// as maintained by the `FinalizeStore` over its confirmed key-value pairs.
+pub fn compute_mapping_tree<N: Network>(entries: Vec<MappingEntry<N>>) -> Result<MappingTree<N>> {
+    // Compute the leaves of the mapping tree.
+    let leaves = cfg_into_iter!(entries)
+        .map(|((program_id, mapping_name), key, value)| {
+            Ok((MappingTree::to_key_id(&program_id, &mapping_name, &key)?, MappingTree::to_value_hash(&value)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    // Construct the mapping tree.
+    MappingTree::new(leaves)
+}
+
+/// The prior state of the finalize storage entries that were overwritten by a block, as
+/// (`[(program ID, mapping names)]`, `[((program ID, mapping name), key, value)]`).
+/// A `None` indicates the entry did not exist prior to the block.
+pub type RollbackEntries<N> = (
+    Vec<(ProgramID<N>, Option<IndexSet<Identifier<N>>>)>,
+    Vec<((ProgramID<N>, Identifier<N>), Plaintext<N>, Option<Value<N>>)>,
+);
+
+/// The block height of an entry in the key-value history.
+///
+/// Note: The block height is serialized as the big-endian bytes of `u32::MAX - height`, so that the
+/// history of a key is ordered from the most recent block height, and a range seek from a block height
+/// returns the value of the key as of that block height first.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HistoryHeight(pub u32);
+
+impl Serialize for HistoryHeight {
+    fn serialize<S: Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        (u32::MAX - self.0).to_be_bytes().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for HistoryHeight {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        Ok(Self(u32::MAX - u32::from_be_bytes(<[u8; 4]>::deserialize(deserializer)?)))
+    }
+}
+
+const ARCHIVE_HEIGHT_KEY: u8 = 0;
+/// The number of key-value history entries to read at a time, when reconstructing a mapping at a block height.
+const HISTORY_PAGE_SIZE: usize = 1024;
+/// The number of mapping entries to read at a time, when collecting the mapping statistics.
+const MAPPING_STATS_PAGE_SIZE: usize = 1024;
 
 /// TODO (howardwu): Remove this.
 /// Returns the mapping ID for the given `program ID` and `mapping name`.
@@ -78,6 +173,22 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     type ProgramIDMap: for<'a> Map<'a, ProgramID<N>, IndexSet<Identifier<N>>>;
     /// The mapping of `(program ID, mapping name)` to `[(key, value)]`.
     type KeyValueMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    /// The mapping of `block height` to `rollback entries`.
+    type RollbackMap: for<'a> Map<'a, u32, RollbackEntries<N>>;
+    /// The mapping of `(program ID, mapping name)` to `[((key, block height), value)]`.
+    /// A `None` value indicates the key was removed at the block height.
+    type ValueHistoryMap: for<'a> NestedMap<
+        'a,
+        (ProgramID<N>, Identifier<N>),
+        (Plaintext<N>, HistoryHeight),
+        Option<Value<N>>,
+    >;
+    /// The mapping of a constant key to the block height from which the key-value history is archived.
+    type ArchiveHeightMap: for<'a> Map<'a, u8, u32>;
+    /// The mapping of `block height` to `[ratified finalize operation]`.
+    type RatifiedOperationsMap: for<'a> Map<'a, u32, Vec<FinalizeOperation<N>>>;
+    /// The mapping of `node position` to `node` in the mapping tree.
+    type MappingNodeMap: for<'a> Map<'a, MappingNodePosition<N>, MappingNodeEntry<N>>;
 
     /// Initializes the program state storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
@@ -92,6 +203,16 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     fn program_id_map(&self) -> &Self::ProgramIDMap;
     /// Returns the key-value map.
     fn key_value_map(&self) -> &Self::KeyValueMap;
+    /// Returns the rollback map.
+    fn rollback_map(&self) -> &Self::RollbackMap;
+    /// Returns the value history map.
+    fn value_history_map(&self) -> &Self::ValueHistoryMap;
+    /// Returns the archive height map.
+    fn archive_height_map(&self) -> &Self::ArchiveHeightMap;
+    /// Returns the ratified operations map.
+    fn ratified_operations_map(&self) -> &Self::RatifiedOperationsMap;
+    /// Returns the mapping node map.
+    fn mapping_node_map(&self) -> &Self::MappingNodeMap;
 
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode;
@@ -101,6 +222,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().start_atomic();
         self.program_id_map().start_atomic();
         self.key_value_map().start_atomic();
+        self.rollback_map().start_atomic();
+        self.value_history_map().start_atomic();
+        self.archive_height_map().start_atomic();
+        self.ratified_operations_map().start_atomic();
+        self.mapping_node_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -108,6 +234,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().is_atomic_in_progress()
             || self.program_id_map().is_atomic_in_progress()
             || self.key_value_map().is_atomic_in_progress()
+            || self.rollback_map().is_atomic_in_progress()
+            || self.value_history_map().is_atomic_in_progress()
+            || self.archive_height_map().is_atomic_in_progress()
+            || self.ratified_operations_map().is_atomic_in_progress()
+            || self.mapping_node_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -115,6 +246,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_checkpoint();
         self.program_id_map().atomic_checkpoint();
         self.key_value_map().atomic_checkpoint();
+        self.rollback_map().atomic_checkpoint();
+        self.value_history_map().atomic_checkpoint();
+        self.archive_height_map().atomic_checkpoint();
+        self.ratified_operations_map().atomic_checkpoint();
+        self.mapping_node_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -122,6 +258,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().clear_latest_checkpoint();
         self.program_id_map().clear_latest_checkpoint();
         self.key_value_map().clear_latest_checkpoint();
+        self.rollback_map().clear_latest_checkpoint();
+        self.value_history_map().clear_latest_checkpoint();
+        self.archive_height_map().clear_latest_checkpoint();
+        self.ratified_operations_map().clear_latest_checkpoint();
+        self.mapping_node_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -129,6 +270,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_rewind();
         self.program_id_map().atomic_rewind();
         self.key_value_map().atomic_rewind();
+        self.rollback_map().atomic_rewind();
+        self.value_history_map().atomic_rewind();
+        self.archive_height_map().atomic_rewind();
+        self.ratified_operations_map().atomic_rewind();
+        self.mapping_node_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -136,13 +282,23 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().abort_atomic();
         self.program_id_map().abort_atomic();
         self.key_value_map().abort_atomic();
+        self.rollback_map().abort_atomic();
+        self.value_history_map().abort_atomic();
+        self.archive_height_map().abort_atomic();
+        self.ratified_operations_map().abort_atomic();
+        self.mapping_node_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.committee_store().finish_atomic()?;
         self.program_id_map().finish_atomic()?;
-        self.key_value_map().finish_atomic()
+        self.key_value_map().finish_atomic()?;
+        self.rollback_map().finish_atomic()?;
+        self.value_history_map().finish_atomic()?;
+        self.archive_height_map().finish_atomic()?;
+        self.ratified_operations_map().finish_atomic()?;
+        self.mapping_node_map().finish_atomic()
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -356,6 +512,391 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Returns the prior value of every key-value pair modified in the current atomic batch,
+    /// as `[((program ID, mapping name), key, value)]`.
+    /// A `None` indicates the entry did not exist prior to the atomic batch.
+    #[allow(clippy::type_complexity)]
+    fn get_pending_key_value_entries(
+        &self,
+    ) -> Result<Vec<((ProgramID<N>, Identifier<N>), Plaintext<N>, Option<Value<N>>)>> {
+        // Retrieve the keys that are modified in the atomic batch, for each mapping.
+        // Note: A value of `None` indicates that the entire mapping was removed (or replaced).
+        let mut modified_keys = IndexMap::<_, Option<Vec<Plaintext<N>>>>::new();
+        for (m, k, _) in self.key_value_map().iter_pending() {
+            match (modified_keys.entry(cow_to_copied!(m)).or_insert_with(|| Some(Vec::new())), k) {
+                (Some(keys), Some(k)) => keys.push(cow_to_cloned!(k)),
+                (keys, None) => *keys = None,
+                (None, Some(_)) => (),
+            }
+        }
+
+        // Retrieve the prior value of each key that changed.
+        let mut key_value_entries = Vec::new();
+        for (mapping, keys) in modified_keys {
+            match keys {
+                // If the mapping was removed, compare the confirmed and speculative mapping in full.
+                None => {
+                    // Retrieve the speculative entries, indexed by the key bytes.
+                    let mut next = self
+                        .key_value_map()
+                        .get_map_speculative(&mapping)?
+                        .into_iter()
+                        .map(|(key, value)| Ok((key.to_bytes_le()?, (key, value))))
+                        .collect::<Result<IndexMap<_, _>>>()?;
+                    // Store the confirmed entries that were modified or removed.
+                    for (key, value) in self.key_value_map().get_map_confirmed(&mapping)? {
+                        match next.swap_remove(&key.to_bytes_le()?) {
+                            Some((_, next_value)) if next_value == value => (),
+                            _ => key_value_entries.push((mapping, key, Some(value))),
+                        }
+                    }
+                    // Store the entries that did not previously exist.
+                    key_value_entries.extend(next.into_values().map(|(key, _)| (mapping, key, None)));
+                }
+                // Otherwise, compare the confirmed and speculative value of each modified key.
+                Some(keys) => {
+                    let mut seen = std::collections::HashSet::new();
+                    for key in keys {
+                        // Skip the key if it was already processed.
+                        if !seen.insert(key.to_bytes_le()?) {
+                            continue;
+                        }
+                        let previous = self.get_value_confirmed(mapping.0, mapping.1, &key)?;
+                        if previous != self.get_value_speculative(mapping.0, mapping.1, &key)? {
+                            key_value_entries.push((mapping, key, previous));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(key_value_entries)
+    }
+
+    /// Stores the prior state of every entry modified in the current atomic batch as the rollback entries
+    /// for the given `block height`, so that the block can later be undone with `revert_to_height`.
+    fn insert_rollback_entries(&self, block_height: u32) -> Result<()> {
+        // Ensure the rollback entries are computed against a pending atomic batch.
+        ensure!(self.is_atomic_in_progress(), "Rollback entries must be computed within an atomic batch");
+
+        // Retrieve the program IDs that are modified in the atomic batch.
+        let program_ids = self.program_id_map().iter_pending().map(|(k, _)| cow_to_copied!(k)).collect::<IndexSet<_>>();
+        // Store the prior mapping names of each program ID that changed.
+        let mut program_entries = Vec::new();
+        for program_id in program_ids {
+            let previous = self.get_mapping_names_confirmed(&program_id)?;
+            if previous != self.get_mapping_names_speculative(&program_id)? {
+                program_entries.push((program_id, previous));
+            }
+        }
+
+        // Store the prior value of each key that changed.
+        let key_value_entries = self.get_pending_key_value_entries()?;
+
+        // If archive mode is enabled, record the next value of each modified key in its history.
+        let mut history_entries = Vec::new();
+        if self.get_archive_height()?.is_some() {
+            for (mapping, key, _) in key_value_entries.iter() {
+                let value = self.get_value_speculative(mapping.0, mapping.1, key)?;
+                history_entries.push((*mapping, (key.clone(), HistoryHeight(block_height)), value));
+            }
+        }
+
+        atomic_batch_scope!(self, {
+            // Store the rollback entries for the block height.
+            self.rollback_map().insert(block_height, (program_entries, key_value_entries))?;
+            // Store the key-value history.
+            for (mapping, key, value) in history_entries {
+                self.value_history_map().insert(mapping, key, value)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Stores the finalize operations of the ratifications for the given `block height`,
+    /// so that the finalize root of the block can later be recomputed from storage.
+    fn insert_ratified_operations(&self, block_height: u32, operations: Vec<FinalizeOperation<N>>) -> Result<()> {
+        self.ratified_operations_map().insert(block_height, operations)
+    }
+
+    /// Returns the finalize operations of the ratifications for the given `block height`.
+    fn get_ratified_operations(&self, block_height: u32) -> Result<Option<Vec<FinalizeOperation<N>>>> {
+        match self.ratified_operations_map().get_confirmed(&block_height)? {
+            Some(operations) => Ok(Some(cow_to_cloned!(operations))),
+            None => Ok(None),
+        }
+    }
+
+    /// Reverts the finalize storage to its state at the given `block height`,
+    /// by applying the rollback entries of every subsequent block in reverse order.
+    fn revert_to_height(&self, block_height: u32) -> Result<()> {
+        // Retrieve the current height.
+        let current_height = self.committee_store().current_height()?;
+        // Ensure the block height is not in the future.
+        ensure!(
+            block_height <= current_height,
+            "Cannot revert finalize storage to block {block_height} (the current height is {current_height})"
+        );
+
+        // Retrieve the rollback entries, in descending order of block height.
+        let rollbacks = (block_height + 1..=current_height)
+            .rev()
+            .map(|height| match self.rollback_map().get_confirmed(&height)? {
+                Some(entries) => Ok((height, cow_to_cloned!(entries))),
+                None => bail!("Missing the rollback entries for block {height} in finalize storage"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // If archive mode is enabled, ensure the block height is archived.
+        let is_archived = match self.get_archive_height()? {
+            Some(archive_height) => {
+                ensure!(
+                    block_height >= archive_height,
+                    "Cannot revert finalize storage to block {block_height} (the archive starts at block {archive_height})"
+                );
+                true
+            }
+            None => false,
+        };
+
+        atomic_batch_scope!(self, {
+            for (height, (program_entries, key_value_entries)) in rollbacks {
+                // Remove the key-value history of the block.
+                if is_archived {
+                    for (mapping, key, _) in key_value_entries.iter() {
+                        self.value_history_map().remove_key(mapping, &(key.clone(), HistoryHeight(height)))?;
+                    }
+                }
+                // Restore the prior mapping names.
+                for (program_id, mapping_names) in program_entries {
+                    match mapping_names {
+                        Some(mapping_names) => self.program_id_map().insert(program_id, mapping_names)?,
+                        None => self.program_id_map().remove(&program_id)?,
+                    }
+                }
+                // Restore the prior key-value pairs.
+                for (mapping, key, value) in key_value_entries {
+                    match value {
+                        Some(value) => self.key_value_map().insert(mapping, key, value)?,
+                        None => self.key_value_map().remove_key(&mapping, &key)?,
+                    }
+                }
+                // Remove the rollback entries and the ratified finalize operations.
+                self.rollback_map().remove(&height)?;
+                self.ratified_operations_map().remove(&height)?;
+            }
+            // Revert the committee storage.
+            self.committee_store().revert_to_height(block_height)
+        })
+    }
+
+    /// Removes the rollback entries of the blocks up to (and including) the given `block height`.
+    /// Note: This iterates over all of the rollback entries, so it is only used to catch up when pruning is enabled,
+    /// after which the rollback entries are pruned one block at a time with `prune_rollback_entry`.
+    fn prune_rollback_entries(&self, block_height: u32) -> Result<()> {
+        // Retrieve the heights of the rollback entries to remove.
+        let heights = self
+            .rollback_map()
+            .keys_confirmed()
+            .map(|height| cow_to_copied!(height))
+            .filter(|height| *height <= block_height)
+            .collect::<Vec<_>>();
+
+        atomic_batch_scope!(self, {
+            for height in heights {
+                self.rollback_map().remove(&height)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Removes the rollback entries of the given `block height`.
+    fn prune_rollback_entry(&self, block_height: u32) -> Result<()> {
+        atomic_batch_scope!(self, { self.rollback_map().remove(&block_height) })
+    }
+
+    /// Returns the confirmed mappings as of the given `block height`, without modifying the storage.
+    /// The state is reconstructed by applying the rollback entries of every subsequent block in memory.
+    #[allow(clippy::type_complexity)]
+    fn get_mappings_at_height(
+        &self,
+        block_height: u32,
+    ) -> Result<Vec<(ProgramID<N>, Identifier<N>, Vec<(Plaintext<N>, Value<N>)>)>> {
+        // Retrieve the current height.
+        let current_height = self.committee_store().current_height()?;
+        // Ensure the block height is not in the future.
+        ensure!(
+            block_height <= current_height,
+            "Cannot retrieve the mappings at block {block_height} (the current height is {current_height})"
+        );
+
+        // Retrieve the rollback entries, in descending order of block height.
+        let rollbacks = (block_height + 1..=current_height)
+            .rev()
+            .map(|height| match self.rollback_map().get_confirmed(&height)? {
+                Some(entries) => Ok(cow_to_cloned!(entries)),
+                None => bail!("Missing the rollback entries for block {height} in finalize storage"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Retrieve the current mapping names.
+        let mut programs = self
+            .program_id_map()
+            .iter_confirmed()
+            .map(|(program_id, mapping_names)| (cow_to_copied!(program_id), cow_to_cloned!(mapping_names)))
+            .collect::<IndexMap<_, _>>();
+        // Retrieve the current key-value pairs, indexed by the key bytes.
+        let mut entries: IndexMap<(ProgramID<N>, Identifier<N>), IndexMap<Vec<u8>, (Plaintext<N>, Value<N>)>> =
+            IndexMap::new();
+        for (mapping, key, value) in self.key_value_map().iter_confirmed() {
+            let key = cow_to_cloned!(key);
+            entries
+                .entry(cow_to_copied!(mapping))
+                .or_default()
+                .insert(key.to_bytes_le()?, (key, cow_to_cloned!(value)));
+        }
+
+        for (program_entries, key_value_entries) in rollbacks {
+            // Restore the prior mapping names.
+            for (program_id, mapping_names) in program_entries {
+                match mapping_names {
+                    Some(mapping_names) => programs.insert(program_id, mapping_names),
+                    None => programs.shift_remove(&program_id),
+                };
+            }
+            // Restore the prior key-value pairs.
+            for (mapping, key, value) in key_value_entries {
+                let mapping_entries = entries.entry(mapping).or_default();
+                match value {
+                    Some(value) => mapping_entries.insert(key.to_bytes_le()?, (key, value)),
+                    None => mapping_entries.shift_remove(&key.to_bytes_le()?),
+                };
+            }
+        }
+
+        // Return the key-value pairs of each mapping.
+        Ok(programs
+            .into_iter()
+            .flat_map(|(program_id, mapping_names)| mapping_names.into_iter().map(move |name| (program_id, name)))
+            .map(|(program_id, name)| {
+                let mapping_entries = entries.swap_remove(&(program_id, name)).unwrap_or_default();
+                (program_id, name, mapping_entries.into_values().collect())
+            })
+            .collect())
+    }
+
+    /// Enables archive mode, in which the history of every key-value pair is stored in storage,
+    /// so that the mappings can be queried at any block height from the current block height onwards.
+    fn enable_archive_mode(&self) -> Result<()> {
+        // If archive mode is already enabled, there is nothing to do.
+        if self.get_archive_height()?.is_some() {
+            return Ok(());
+        }
+        // Retrieve the current height, if any blocks have been finalized.
+        let current_height = self.committee_store().current_height().ok();
+
+        atomic_batch_scope!(self, {
+            // Store the current key-value pairs as the initial history.
+            if let Some(current_height) = current_height {
+                for (mapping, key, value) in self.key_value_map().iter_confirmed() {
+                    let key = (cow_to_cloned!(key), HistoryHeight(current_height));
+                    self.value_history_map().insert(cow_to_copied!(mapping), key, Some(cow_to_cloned!(value)))?;
+                }
+            }
+            // Store the archive height.
+            self.archive_height_map().insert(ARCHIVE_HEIGHT_KEY, current_height.unwrap_or(0))?;
+
+            Ok(())
+        })
+    }
+
+    /// Returns the block height from which the key-value history is archived, if archive mode is enabled.
+    fn get_archive_height(&self) -> Result<Option<u32>> {
+        match self.archive_height_map().get_confirmed(&ARCHIVE_HEIGHT_KEY)? {
+            Some(block_height) => Ok(Some(cow_to_copied!(block_height))),
+            None => Ok(None),
+        }
+    }
+
+    /// Ensures the key-value history is archived for the given `block height`.
+    fn ensure_archived(&self, block_height: u32) -> Result<()> {
+        // Retrieve the archive height.
+        let Some(archive_height) = self.get_archive_height()? else {
+            bail!("Archive mode is not enabled in finalize storage")
+        };
+        // Retrieve the current height.
+        let current_height = self.committee_store().current_height()?;
+        // Ensure the block height is within the archived range.
+        ensure!(
+            (archive_height..=current_height).contains(&block_height),
+            "Block {block_height} is not archived in finalize storage (the archived blocks are {archive_height} to {current_height})"
+        );
+        Ok(())
+    }
+
+    /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key` as of the given `block height`.
+    fn get_value_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        block_height: u32,
+    ) -> Result<Option<Value<N>>> {
+        // Ensure the block height is archived.
+        self.ensure_archived(block_height)?;
+        // Seek the most recent entry of the key-value history as of the block height.
+        let start = (key.clone(), HistoryHeight(block_height));
+        let end = (key.clone(), HistoryHeight(0));
+        let history = self.value_history_map().get_map_range_confirmed(
+            &(program_id, mapping_name),
+            Bound::Included(&start),
+            Bound::Included(&end),
+            1,
+        )?;
+        Ok(history.into_iter().next().and_then(|(_, value)| value))
+    }
+
+    /// Returns the confirmed mapping for the given `program ID` and `mapping name` as of the given `block height`.
+    fn get_mapping_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        block_height: u32,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        // Ensure the block height is archived.
+        self.ensure_archived(block_height)?;
+        // Page through the key-value history, in which the entries of each key are ordered from the most recent
+        // block height, and select the first entry of each key as of the block height.
+        let mapping = (program_id, mapping_name);
+        let mut entries = Vec::new();
+        let mut last_key: Option<Plaintext<N>> = None;
+        let mut is_resolved = false;
+        let mut cursor = None;
+        loop {
+            let page = self.value_history_map().get_map_page_confirmed(&mapping, cursor.as_ref(), HISTORY_PAGE_SIZE)?;
+            let is_last_page = page.len() < HISTORY_PAGE_SIZE;
+            cursor = page.last().map(|(key, _)| key.clone());
+            for ((key, height), value) in page {
+                // Start the history of the next key.
+                if last_key.as_ref() != Some(&key) {
+                    last_key = Some(key.clone());
+                    is_resolved = false;
+                }
+                if !is_resolved && height.0 <= block_height {
+                    is_resolved = true;
+                    if let Some(value) = value {
+                        entries.push((key, value));
+                    }
+                }
+            }
+            if is_last_page {
+                break;
+            }
+        }
+        Ok(entries)
+    }
+
     /// Returns `true` if the given `program ID` exist.
     fn contains_program_confirmed(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.program_id_map().contains_key_confirmed(program_id)
@@ -429,6 +970,41 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_map().get_map_speculative(&(program_id, mapping_name))
     }
 
+    /// Returns up to `limit` confirmed mapping entries for the given `program ID` and `mapping name`,
+    /// with a key in the given range, in serialized key order.
+    fn get_mapping_range_confirmed(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        start: Bound<&Plaintext<N>>,
+        end: Bound<&Plaintext<N>>,
+        limit: usize,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        // Ensure the mapping name exists.
+        if !self.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot get mapping (C).")
+        }
+        // Retrieve the key-values for the mapping.
+        self.key_value_map().get_map_range_confirmed(&(program_id, mapping_name), start, end, limit)
+    }
+
+    /// Returns up to `limit` confirmed mapping entries for the given `program ID` and `mapping name`,
+    /// in serialized key order, starting after the given cursor, or from the first key if no cursor is given.
+    fn get_mapping_page_confirmed(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        cursor: Option<&Plaintext<N>>,
+        limit: usize,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        // Ensure the mapping name exists.
+        if !self.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            bail!("Illegal operation: '{program_id}/{mapping_name}' is not initialized - cannot get mapping (C).")
+        }
+        // Retrieve the key-values for the mapping.
+        self.key_value_map().get_map_page_confirmed(&(program_id, mapping_name), cursor, limit)
+    }
+
     /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`.
     fn get_value_confirmed(
         &self,
@@ -457,35 +1033,11 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
 
     /// Returns the confirmed checksum of the finalize storage.
     fn get_checksum_confirmed(&self) -> Result<Field<N>> {
-        // Compute all mapping checksums.
-        let preimage: std::collections::BTreeMap<_, _> = self
-            .key_value_map()
-            .iter_confirmed()
-            .map(|(m, k, v)| {
-                let m = cow_to_copied!(m);
-                let k = cow_to_cloned!(k);
-                let v = cow_to_cloned!(v);
-
-                let mut preimage = Vec::new();
-                m.write_bits_le(&mut preimage);
-                false.write_bits_le(&mut preimage); // Separator.
-                k.write_bits_le(&mut preimage);
-                false.write_bits_le(&mut preimage); // Separator.
-
-                // Compute the mapping checksum as `Hash( m || k )`.
-                let mapping_checksum = N::hash_bhp1024(&preimage)?;
-
-                v.write_bits_le(&mut preimage);
-                false.write_bits_le(&mut preimage); // Separator.
-
-                // Compute the entry checksum as `Hash( m || k || v )`.
-                let entry_checksum = N::hash_bhp1024(&preimage)?;
-                // Return the mapping checksum and entry checksum.
-                Ok::<_, Error>((mapping_checksum, entry_checksum.to_bits_le()))
-            })
-            .try_collect()?;
-        // Compute the checksum as `Hash( all mapping checksums )`.
-        N::hash_bhp1024(&preimage.into_values().flatten().collect::<Vec<_>>())
+        compute_finalize_checksum(
+            self.key_value_map()
+                .iter_confirmed()
+                .map(|(m, k, v)| (cow_to_copied!(m), cow_to_cloned!(k), cow_to_cloned!(v))),
+        )
     }
 
     /// Returns the pending checksum of the finalize storage.
@@ -529,6 +1081,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
 pub struct FinalizeStore<N: Network, P: FinalizeStorage<N>> {
     /// The finalize storage.
     storage: P,
+    /// The mapping tree over the confirmed key-value pairs.
+    mapping_tree: Arc<RwLock<MappingTree<N>>>,
     /// PhantomData.
     _phantom: PhantomData<N>,
 }
@@ -547,8 +1101,38 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
 
     /// Initializes a finalize store from storage.
     pub fn from(storage: P) -> Result<Self> {
+        // Load the mapping tree from its persisted nodes.
+        let mapping_tree = match storage.mapping_node_map().keys_confirmed().next().is_some() {
+            true => MappingTree::from_nodes(
+                storage
+                    .mapping_node_map()
+                    .iter_confirmed()
+                    .map(|(position, node)| (cow_to_copied!(position), cow_to_cloned!(node))),
+            )?,
+            // If the nodes are not persisted yet, construct the mapping tree from the key-value pairs, and persist it.
+            false => {
+                let mapping_tree = Self::to_mapping_tree(&storage)?;
+                let updates = mapping_tree.to_node_updates(&MappingTree::new(vec![])?)?;
+                if !updates.is_empty() {
+                    atomic_batch_scope!(storage, { Self::insert_node_updates(&storage, updates) })?;
+                }
+                mapping_tree
+            }
+        };
         // Return the finalize store.
-        Ok(Self { storage, _phantom: PhantomData })
+        Ok(Self { storage, mapping_tree: Arc::new(RwLock::new(mapping_tree)), _phantom: PhantomData })
+    }
+
+    /// Returns the mapping tree over the confirmed key-value pairs in the given storage.
+    fn to_mapping_tree(storage: &P) -> Result<MappingTree<N>> {
+        // Retrieve the confirmed key-value pairs.
+        let entries = storage
+            .key_value_map()
+            .iter_confirmed()
+            .map(|(mapping, key, value)| (cow_to_copied!(mapping), cow_to_cloned!(key), cow_to_cloned!(value)))
+            .collect::<Vec<_>>();
+        // Construct the mapping tree.
+        compute_mapping_tree(entries)
     }
 
     /// Starts an atomic batch write operation.
@@ -583,7 +1167,84 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
 
     /// Finishes an atomic batch write operation.
     pub fn finish_atomic(&self) -> Result<()> {
-        self.storage.finish_atomic()
+        // Commit the atomic batch, and update the mapping tree.
+        if let Some(mapping_tree) = self.commit_atomic()? {
+            self.set_mapping_tree(mapping_tree);
+        }
+        Ok(())
+    }
+
+    /// Commits the atomic batch, returning the mapping tree with the key-value pairs modified in it
+    /// (or `None` if no key-value pairs were modified), without updating the mapping tree of the store.
+    fn commit_atomic(&self) -> Result<Option<MappingTree<N>>> {
+        // Prepare the mapping tree with the key-value pairs modified in the atomic batch.
+        let mapping_tree = self.prepare_mapping_tree()?;
+        // Persist the nodes of the mapping tree that changed, as part of the atomic batch.
+        if let Some(mapping_tree) = &mapping_tree {
+            Self::insert_node_updates(&self.storage, mapping_tree.to_node_updates(&self.mapping_tree.read())?)?;
+        }
+        // Commit the atomic batch.
+        self.storage.finish_atomic()?;
+        Ok(mapping_tree)
+    }
+
+    /// Sets the mapping tree of the store.
+    /// Note: This must only be used with the mapping tree of a committed atomic batch.
+    pub fn set_mapping_tree(&self, mapping_tree: MappingTree<N>) {
+        *self.mapping_tree.write() = mapping_tree;
+    }
+
+    /// Runs the given storage operation in an atomic batch (if one is not already in progress),
+    /// so that the mapping tree is updated when the atomic batch is finished.
+    fn atomic_update<T>(&self, operation: impl FnOnce() -> Result<T>) -> Result<T> {
+        // If an atomic batch is in progress, the mapping tree is updated when it is finished.
+        if self.is_atomic_in_progress() {
+            return operation();
+        }
+        // Otherwise, run the operation in a new atomic batch.
+        self.start_atomic();
+        match operation() {
+            Ok(output) => self.finish_atomic().map(|_| output),
+            Err(error) => {
+                self.abort_atomic();
+                Err(error)
+            }
+        }
+    }
+
+    /// Returns the mapping tree with the key-value pairs modified in the current atomic batch,
+    /// or `None` if no key-value pairs were modified.
+    fn prepare_mapping_tree(&self) -> Result<Option<MappingTree<N>>> {
+        // Retrieve the key-value pairs modified in the atomic batch.
+        let entries = self.storage.get_pending_key_value_entries()?;
+        if entries.is_empty() {
+            return Ok(None);
+        }
+        // Apply the next value of each modified key to the mapping tree.
+        let mut mapping_tree = self.mapping_tree.read().clone();
+        for ((program_id, mapping_name), key, _) in entries {
+            let key_id = MappingTree::to_key_id(&program_id, &mapping_name, &key)?;
+            match self.storage.get_value_speculative(program_id, mapping_name, &key)? {
+                Some(value) => mapping_tree.insert(key_id, MappingTree::to_value_hash(&value)?)?,
+                None => mapping_tree.remove(&key_id)?,
+            }
+        }
+        Ok(Some(mapping_tree))
+    }
+
+    /// Stores the given node updates of the mapping tree, where a node that no longer exists is removed.
+    #[allow(clippy::type_complexity)]
+    fn insert_node_updates(
+        storage: &P,
+        updates: Vec<(MappingNodePosition<N>, Option<MappingNodeEntry<N>>)>,
+    ) -> Result<()> {
+        for (position, node) in updates {
+            match node {
+                Some(node) => storage.mapping_node_map().insert(position, node)?,
+                None => storage.mapping_node_map().remove(&position)?,
+            }
+        }
+        Ok(())
     }
 
     /// Returns the storage mode.
@@ -635,7 +1296,7 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.insert_key_value(program_id, mapping_name, key, value)
+        self.atomic_update(|| self.storage.insert_key_value(program_id, mapping_name, key, value))
     }
 
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
@@ -649,7 +1310,7 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.update_key_value(program_id, mapping_name, key, value)
+        self.atomic_update(|| self.storage.update_key_value(program_id, mapping_name, key, value))
     }
 
     /// Removes the key-value pair for the given `program ID`, `mapping name`, and `key` from storage.
@@ -659,7 +1320,7 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         mapping_name: Identifier<N>,
         key: &Plaintext<N>,
     ) -> Result<Option<FinalizeOperation<N>>> {
-        self.storage.remove_key_value(program_id, mapping_name, key)
+        self.atomic_update(|| self.storage.remove_key_value(program_id, mapping_name, key))
     }
 }
 
@@ -682,7 +1343,7 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         mapping_name: Identifier<N>,
         entries: Vec<(Plaintext<N>, Value<N>)>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.replace_mapping(program_id, mapping_name, entries)
+        self.atomic_update(|| self.storage.replace_mapping(program_id, mapping_name, entries))
     }
 
     /// Removes the mapping for the given `program ID` and `mapping name` from storage,
@@ -692,17 +1353,169 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         program_id: ProgramID<N>,
         mapping_name: Identifier<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.remove_mapping(program_id, mapping_name)
+        self.atomic_update(|| self.storage.remove_mapping(program_id, mapping_name))
     }
 
     /// Removes the program for the given `program ID` from storage,
     /// along with all associated mappings and key-value pairs in storage.
     pub fn remove_program(&self, program_id: &ProgramID<N>) -> Result<()> {
-        self.storage.remove_program(program_id)
+        self.atomic_update(|| self.storage.remove_program(program_id))
+    }
+
+    /// Stores the prior state of every entry modified in the current atomic batch as the rollback entries
+    /// for the given `block height`, so that the block can later be undone with `revert_to_height`.
+    pub fn insert_rollback_entries(&self, block_height: u32) -> Result<()> {
+        self.storage.insert_rollback_entries(block_height)
+    }
+
+    /// Stores the finalize operations of the ratifications for the given `block height`.
+    pub fn insert_ratified_operations(&self, block_height: u32, operations: Vec<FinalizeOperation<N>>) -> Result<()> {
+        self.storage.insert_ratified_operations(block_height, operations)
+    }
+
+    /// Returns the finalize operations of the ratifications for the given `block height`.
+    pub fn get_ratified_operations(&self, block_height: u32) -> Result<Option<Vec<FinalizeOperation<N>>>> {
+        self.storage.get_ratified_operations(block_height)
+    }
+
+    /// Reverts the finalize store to its state at the given `block height`,
+    /// by applying the rollback entries of every subsequent block in reverse order.
+    pub fn revert_to_height(&self, block_height: u32) -> Result<()> {
+        if let Some(mapping_tree) = self.revert_storage_to_height(block_height)? {
+            self.set_mapping_tree(mapping_tree);
+        }
+        Ok(())
+    }
+
+    /// Reverts the finalize storage to its state at the given `block height`, returning the reverted mapping tree
+    /// (or `None` if no key-value pairs were reverted), without updating the mapping tree of the store.
+    ///
+    /// This is used when the revert is queued behind paused atomic writes, as the mapping tree must
+    /// only be updated (with `set_mapping_tree`) once the queued writes are committed to storage.
+    pub fn revert_storage_to_height(&self, block_height: u32) -> Result<Option<MappingTree<N>>> {
+        // Ensure an atomic batch is not already in progress, as the mapping tree is returned on commit.
+        ensure!(!self.is_atomic_in_progress(), "Cannot revert finalize storage within an atomic batch");
+        // Revert the storage in a new atomic batch.
+        self.start_atomic();
+        match self.storage.revert_to_height(block_height) {
+            Ok(()) => self.commit_atomic(),
+            Err(error) => {
+                self.abort_atomic();
+                Err(error)
+            }
+        }
+    }
+
+    /// Removes the rollback entries of the blocks up to (and including) the given `block height`,
+    /// as the blocks are pruned, and can no longer be reverted.
+    pub fn prune_rollback_entries(&self, block_height: u32) -> Result<()> {
+        self.storage.prune_rollback_entries(block_height)
+    }
+
+    /// Removes the rollback entries of the given `block height`, as the block is pruned, and can no longer be reverted.
+    pub fn prune_rollback_entry(&self, block_height: u32) -> Result<()> {
+        self.storage.prune_rollback_entry(block_height)
+    }
+
+    /// Enables archive mode, so that the mappings can be queried at any block height from the current height onwards.
+    pub fn enable_archive_mode(&self) -> Result<()> {
+        self.storage.enable_archive_mode()
     }
 }
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Returns the root of the mapping tree over the confirmed key-value pairs.
+    pub fn current_mapping_root(&self) -> Field<N> {
+        self.mapping_tree.read().root()
+    }
+
+    /// Recomputes the root of the mapping tree from the confirmed key-value pairs in storage.
+    pub fn compute_mapping_root(&self) -> Result<Field<N>> {
+        Ok(Self::to_mapping_tree(&self.storage)?.root())
+    }
+
+    /// Returns the mapping tree over the confirmed key-value pairs.
+    pub fn current_mapping_tree(&self) -> MappingTree<N> {
+        self.mapping_tree.read().clone()
+    }
+
+    /// Applies the rollback entries of the block at the given `block height` to the given mapping tree,
+    /// so that the tree commits to the key-value pairs prior to the block.
+    /// Returns `false` if the rollback entries of the block are not in storage.
+    pub fn rollback_mapping_tree(&self, mapping_tree: &mut MappingTree<N>, block_height: u32) -> Result<bool> {
+        let (_, key_value_entries) = match self.storage.rollback_map().get_confirmed(&block_height)? {
+            Some(entries) => cow_to_cloned!(entries),
+            None => return Ok(false),
+        };
+        // Restore the prior value of each key that the block modified.
+        for ((program_id, mapping_name), key, value) in key_value_entries {
+            let key_id = MappingTree::to_key_id(&program_id, &mapping_name, &key)?;
+            match value {
+                Some(value) => mapping_tree.insert(key_id, MappingTree::to_value_hash(&value)?)?,
+                None => mapping_tree.remove(&key_id)?,
+            }
+        }
+        Ok(true)
+    }
+
+    /// Ensures the given finalize `operations` of the block at the given `block height` commit to
+    /// the confirmed value of every key that the block modified.
+    /// Note: This only holds for the latest block, as a later block may modify the same keys.
+    pub fn verify_finalize_operations(&self, block_height: u32, operations: &[FinalizeOperation<N>]) -> Result<()> {
+        let (_, key_value_entries) = match self.storage.rollback_map().get_confirmed(&block_height)? {
+            Some(entries) => cow_to_cloned!(entries),
+            None => bail!("Missing the rollback entries for block {block_height} in finalize storage"),
+        };
+        for ((program_id, mapping_name), key, _) in key_value_entries {
+            // Compute the mapping ID and key ID.
+            let mapping_id = to_mapping_id(&program_id, &mapping_name)?;
+            let key_id = to_key_id(&program_id, &mapping_name, &key)?;
+            // Ensure an operation sets the key to its confirmed value.
+            let is_committed = match self.storage.get_value_confirmed(program_id, mapping_name, &key)? {
+                Some(value) => {
+                    let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
+                    operations.iter().any(|operation| match operation {
+                        FinalizeOperation::InsertKeyValue(m, k, v) | FinalizeOperation::UpdateKeyValue(m, k, v) => {
+                            *m == mapping_id && *k == key_id && *v == value_id
+                        }
+                        FinalizeOperation::ReplaceMapping(m) => *m == mapping_id,
+                        _ => false,
+                    })
+                }
+                None => operations.iter().any(|operation| match operation {
+                    FinalizeOperation::RemoveKeyValue(m, k) => *m == mapping_id && *k == key_id,
+                    FinalizeOperation::ReplaceMapping(m) | FinalizeOperation::RemoveMapping(m) => *m == mapping_id,
+                    _ => false,
+                }),
+            };
+            ensure!(
+                is_committed,
+                "The value of '{program_id}/{mapping_name}' key '{key}' is not committed to by block {block_height}"
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the inclusion proof for the given `program ID`, `mapping name`, and `key` against the current mapping root,
+    /// or the exclusion proof if the key does not exist.
+    /// Note: Proofs are only served against the current mapping root, as the nodes of prior mapping trees are not retained.
+    pub fn get_mapping_proof(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<MappingProof<N>> {
+        // Ensure the mapping exists.
+        ensure!(
+            self.storage.contains_mapping_confirmed(program_id, mapping_name)?,
+            "Mapping '{program_id}/{mapping_name}' does not exist in finalize storage"
+        );
+        // Compute the key ID.
+        let key_id = MappingTree::to_key_id(program_id, mapping_name, key)?;
+        // Return the proof.
+        Ok(self.mapping_tree.read().prove(&key_id))
+    }
+
     /// Returns `true` if the given `program ID` exist.
     pub fn contains_program_confirmed(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.storage.contains_program_confirmed(program_id)
@@ -743,6 +1556,33 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.get_mapping_speculative(program_id, mapping_name)
     }
 
+    /// Returns up to `limit` confirmed mapping entries for the given `program ID` and `mapping name`,
+    /// with a key in the given range, in serialized key order.
+    /// Note: The keys are ordered by their serialized representation, not by their value.
+    pub fn get_mapping_range_confirmed(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        start: Bound<&Plaintext<N>>,
+        end: Bound<&Plaintext<N>>,
+        limit: usize,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        self.storage.get_mapping_range_confirmed(program_id, mapping_name, start, end, limit)
+    }
+
+    /// Returns up to `limit` confirmed mapping entries for the given `program ID` and `mapping name`,
+    /// in serialized key order, starting after the given cursor, or from the first key if no cursor is given.
+    /// The key of the last entry of a page is the cursor of the next page.
+    pub fn get_mapping_page_confirmed(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        cursor: Option<&Plaintext<N>>,
+        limit: usize,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        self.storage.get_mapping_page_confirmed(program_id, mapping_name, cursor, limit)
+    }
+
     /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`.
     pub fn get_value_confirmed(
         &self,
@@ -767,6 +1607,83 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     pub fn get_checksum_confirmed(&self) -> Result<Field<N>> {
         self.storage.get_checksum_confirmed()
     }
+
+    /// Returns the number of entries and the approximate size of each confirmed mapping.
+    ///
+    /// The mappings are read page by page, so only a single page of entries is held in memory at a time.
+    pub fn mapping_stats(&self) -> Result<Vec<MappingStats<N>>> {
+        let mut stats = Vec::new();
+        for (program_id, mapping_names) in self.storage.program_id_map().iter_confirmed() {
+            let program_id = cow_to_copied!(program_id);
+            for mapping_name in mapping_names.iter() {
+                // Page through the key-value pairs of the mapping, and sum the size of the serialized keys and values.
+                let (mut num_entries, mut size_in_bytes) = (0u64, 0u64);
+                let mut cursor = None;
+                loop {
+                    let page = self.storage.get_mapping_page_confirmed(
+                        program_id,
+                        *mapping_name,
+                        cursor.as_ref(),
+                        MAPPING_STATS_PAGE_SIZE,
+                    )?;
+                    let is_last_page = page.len() < MAPPING_STATS_PAGE_SIZE;
+                    num_entries += page.len() as u64;
+                    for (key, value) in &page {
+                        size_in_bytes += (key.to_bytes_le()?.len() + value.to_bytes_le()?.len()) as u64;
+                    }
+                    cursor = page.into_iter().last().map(|(key, _)| key);
+                    if is_last_page {
+                        break;
+                    }
+                }
+                stats.push(MappingStats { program_id, mapping_name: *mapping_name, num_entries, size_in_bytes });
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Returns the confirmed mappings as of the given `block height`.
+    #[allow(clippy::type_complexity)]
+    pub fn get_mappings_at_height(
+        &self,
+        block_height: u32,
+    ) -> Result<Vec<(ProgramID<N>, Identifier<N>, Vec<(Plaintext<N>, Value<N>)>)>> {
+        self.storage.get_mappings_at_height(block_height)
+    }
+
+    /// Returns the rollback entries of the block at the given `block height`, if they are in storage.
+    pub fn get_rollback_entries(&self, block_height: u32) -> Result<Option<RollbackEntries<N>>> {
+        match self.storage.rollback_map().get_confirmed(&block_height)? {
+            Some(entries) => Ok(Some(cow_to_cloned!(entries))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the block height from which the key-value history is archived, if archive mode is enabled.
+    pub fn get_archive_height(&self) -> Result<Option<u32>> {
+        self.storage.get_archive_height()
+    }
+
+    /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key` as of the given `block height`.
+    pub fn get_value_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        block_height: u32,
+    ) -> Result<Option<Value<N>>> {
+        self.storage.get_value_at_height(program_id, mapping_name, key, block_height)
+    }
+
+    /// Returns the confirmed mapping for the given `program ID` and `mapping name` as of the given `block height`.
+    pub fn get_mapping_at_height(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        block_height: u32,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        self.storage.get_mapping_at_height(program_id, mapping_name, block_height)
+    }
 }
 
 #[cfg(test)]
@@ -1392,4 +2309,367 @@ mod tests {
         finalize_store.remove_program(&program_id).unwrap();
         println!("FinalizeStore::remove_program - {} μs", timer.elapsed().as_micros());
     }
+
+    #[test]
+    fn test_revert_to_height() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Prepare the keys and values.
+        let (key_1, key_2) = (Plaintext::from_str("1field").unwrap(), Plaintext::from_str("2field").unwrap());
+        let (value_1, value_2) = (Value::from_str("1u64").unwrap(), Value::from_str("2u64").unwrap());
+
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::<CurrentNetwork, FinalizeMemory<_>>::open(None).unwrap();
+
+        // Finalizes a block at the given height, in the same manner as `VM::atomic_finalize`.
+        let finalize = |height: u32, rng: &mut TestRng, operations: &dyn Fn()| {
+            finalize_store.start_atomic();
+            operations();
+            let committee = ledger_committee::test_helpers::sample_committee_for_round(height as u64, rng);
+            finalize_store.committee_store().insert(height, committee).unwrap();
+            finalize_store.insert_rollback_entries(height).unwrap();
+            finalize_store.finish_atomic().unwrap();
+            finalize_store.get_checksum_confirmed().unwrap()
+        };
+
+        // Block 0: initialize the mapping and insert a key-value pair.
+        let checksum_0 = finalize(0, rng, &|| {
+            finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key_1.clone(), value_1.clone()).unwrap();
+        });
+        // Block 1: update the existing key and insert a new key.
+        let checksum_1 = finalize(1, rng, &|| {
+            finalize_store.update_key_value(program_id, mapping_name, key_1.clone(), value_2.clone()).unwrap();
+            finalize_store.update_key_value(program_id, mapping_name, key_1.clone(), value_1.clone()).unwrap();
+            finalize_store.update_key_value(program_id, mapping_name, key_1.clone(), value_2.clone()).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key_2.clone(), value_1.clone()).unwrap();
+        });
+        // Block 2: replace the mapping, and initialize a new program.
+        let checksum_2 = finalize(2, rng, &|| {
+            finalize_store.replace_mapping(program_id, mapping_name, vec![(key_2.clone(), value_2.clone())]).unwrap();
+            finalize_store.initialize_mapping(ProgramID::from_str("world.aleo").unwrap(), mapping_name).unwrap();
+        });
+        assert_ne!(checksum_1, checksum_2);
+        assert_eq!(finalize_store.get_mapping_confirmed(program_id, mapping_name).unwrap(), vec![(
+            key_2.clone(),
+            value_2.clone()
+        )]);
+
+        // Ensure the mappings at a prior height can be retrieved without reverting.
+        let mappings_at_height = |height: u32| {
+            let mut mappings = finalize_store.get_mappings_at_height(height).unwrap();
+            mappings.iter_mut().for_each(|(_, _, entries)| entries.sort_by_key(|(key, _)| key.to_string()));
+            mappings
+        };
+        assert_eq!(mappings_at_height(0), vec![(program_id, mapping_name, vec![(key_1.clone(), value_1.clone())])]);
+        assert_eq!(mappings_at_height(1), vec![(program_id, mapping_name, vec![
+            (key_1.clone(), value_2.clone()),
+            (key_2.clone(), value_1.clone())
+        ])]);
+        assert_eq!(mappings_at_height(2).len(), 2);
+        assert!(finalize_store.get_mappings_at_height(3).is_err());
+
+        // Ensure reverting to a future height fails.
+        assert!(finalize_store.revert_to_height(3).is_err());
+        // Ensure reverting to the current height is a no-op.
+        finalize_store.revert_to_height(2).unwrap();
+        assert_eq!(finalize_store.get_checksum_confirmed().unwrap(), checksum_2);
+
+        // Revert to block 1.
+        finalize_store.revert_to_height(1).unwrap();
+        assert_eq!(finalize_store.get_checksum_confirmed().unwrap(), checksum_1);
+        assert_eq!(finalize_store.get_value_confirmed(program_id, mapping_name, &key_1).unwrap(), Some(value_2));
+        assert_eq!(
+            finalize_store.get_value_confirmed(program_id, mapping_name, &key_2).unwrap(),
+            Some(value_1.clone())
+        );
+        assert!(!finalize_store.contains_program_confirmed(&ProgramID::from_str("world.aleo").unwrap()).unwrap());
+        assert_eq!(finalize_store.committee_store().current_height().unwrap(), 1);
+
+        // Revert to block 0.
+        finalize_store.revert_to_height(0).unwrap();
+        assert_eq!(finalize_store.get_checksum_confirmed().unwrap(), checksum_0);
+        assert_eq!(finalize_store.get_value_confirmed(program_id, mapping_name, &key_1).unwrap(), Some(value_1));
+        assert_eq!(finalize_store.get_value_confirmed(program_id, mapping_name, &key_2).unwrap(), None);
+        assert_eq!(finalize_store.committee_store().current_height().unwrap(), 0);
+        // Ensure the reverted block can no longer be reverted.
+        assert!(finalize_store.revert_to_height(1).is_err());
+    }
+
+    #[test]
+    fn test_archive_mode() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Prepare the keys and values.
+        let (key_1, key_2) = (Plaintext::from_str("1field").unwrap(), Plaintext::from_str("2field").unwrap());
+        let (value_1, value_2) = (Value::from_str("1u64").unwrap(), Value::from_str("2u64").unwrap());
+
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::<CurrentNetwork, FinalizeMemory<_>>::open(None).unwrap();
+
+        // Finalizes a block at the given height, in the same manner as `VM::atomic_finalize`.
+        let finalize = |height: u32, rng: &mut TestRng, operations: &dyn Fn()| {
+            finalize_store.start_atomic();
+            operations();
+            let committee = ledger_committee::test_helpers::sample_committee_for_round(height as u64, rng);
+            finalize_store.committee_store().insert(height, committee).unwrap();
+            finalize_store.insert_rollback_entries(height).unwrap();
+            finalize_store.finish_atomic().unwrap();
+        };
+
+        // Block 0: initialize the mapping and insert a key-value pair.
+        finalize(0, rng, &|| {
+            finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key_1.clone(), value_1.clone()).unwrap();
+        });
+
+        // Ensure the history cannot be queried before archive mode is enabled.
+        assert_eq!(finalize_store.get_archive_height().unwrap(), None);
+        assert!(finalize_store.get_value_at_height(program_id, mapping_name, &key_1, 0).is_err());
+
+        // Enable archive mode.
+        finalize_store.enable_archive_mode().unwrap();
+        assert_eq!(finalize_store.get_archive_height().unwrap(), Some(0));
+
+        // Block 1: update the existing key and insert a new key.
+        finalize(1, rng, &|| {
+            finalize_store.update_key_value(program_id, mapping_name, key_1.clone(), value_2.clone()).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key_2.clone(), value_1.clone()).unwrap();
+        });
+        // Block 2: remove the existing key.
+        finalize(2, rng, &|| {
+            finalize_store.remove_key_value(program_id, mapping_name, &key_1).unwrap();
+        });
+        // Block 3: replace the mapping.
+        finalize(3, rng, &|| {
+            finalize_store.replace_mapping(program_id, mapping_name, vec![(key_1.clone(), value_1.clone())]).unwrap();
+        });
+
+        // Ensure the values can be retrieved at every archived height.
+        let value_at_height = |key: &Plaintext<CurrentNetwork>, height: u32| {
+            finalize_store.get_value_at_height(program_id, mapping_name, key, height).unwrap()
+        };
+        assert_eq!(value_at_height(&key_1, 0), Some(value_1.clone()));
+        assert_eq!(value_at_height(&key_1, 1), Some(value_2.clone()));
+        assert_eq!(value_at_height(&key_1, 2), None);
+        assert_eq!(value_at_height(&key_1, 3), Some(value_1.clone()));
+        assert_eq!(value_at_height(&key_2, 0), None);
+        assert_eq!(value_at_height(&key_2, 1), Some(value_1.clone()));
+        assert_eq!(value_at_height(&key_2, 2), Some(value_1.clone()));
+        assert_eq!(value_at_height(&key_2, 3), None);
+        assert!(finalize_store.get_value_at_height(program_id, mapping_name, &key_1, 4).is_err());
+
+        // Ensure the mappings can be retrieved at every archived height.
+        let mapping_at_height = |height: u32| {
+            let mut mapping = finalize_store.get_mapping_at_height(program_id, mapping_name, height).unwrap();
+            mapping.sort_by_key(|(key, _)| key.to_string());
+            mapping
+        };
+        for height in 0..=3 {
+            let mut expected = finalize_store.get_mappings_at_height(height).unwrap().swap_remove(0).2;
+            expected.sort_by_key(|(key, _)| key.to_string());
+            assert_eq!(mapping_at_height(height), expected);
+        }
+
+        // Revert to block 1, and ensure the history after block 1 is removed.
+        finalize_store.revert_to_height(1).unwrap();
+        assert_eq!(value_at_height(&key_1, 1), Some(value_2.clone()));
+        assert_eq!(value_at_height(&key_2, 1), Some(value_1.clone()));
+        assert!(finalize_store.get_value_at_height(program_id, mapping_name, &key_1, 2).is_err());
+
+        // Block 2: update the existing key.
+        finalize(2, rng, &|| {
+            finalize_store.update_key_value(program_id, mapping_name, key_2.clone(), value_2.clone()).unwrap();
+        });
+        assert_eq!(value_at_height(&key_1, 2), Some(value_2.clone()));
+        assert_eq!(value_at_height(&key_2, 2), Some(value_2));
+        assert_eq!(mapping_at_height(0), vec![(key_1, value_1)]);
+    }
+
+    #[test]
+    fn test_history_height_order() {
+        // Ensure the serialized block heights are ordered from the most recent block height.
+        let heights = [0, 1, 255, 256, 65_535, 65_536, u32::MAX];
+        let serialized =
+            heights.iter().map(|height| bincode::serialize(&HistoryHeight(*height)).unwrap()).collect::<Vec<_>>();
+        assert!(serialized.windows(2).all(|pair| pair[0] > pair[1]));
+        // Ensure the block heights are deserialized back.
+        for (height, bytes) in heights.iter().zip(&serialized) {
+            assert_eq!(bincode::deserialize::<HistoryHeight>(bytes).unwrap(), HistoryHeight(*height));
+        }
+    }
+
+    #[test]
+    fn test_mapping_proof() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Prepare the keys and values.
+        let (key_1, key_2) = (Plaintext::from_str("1field").unwrap(), Plaintext::from_str("2field").unwrap());
+        let (value_1, value_2) = (Value::from_str("1u64").unwrap(), Value::from_str("2u64").unwrap());
+
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::<CurrentNetwork, FinalizeMemory<_>>::open(None).unwrap();
+        assert_eq!(finalize_store.current_mapping_root(), Field::zero());
+        // Ensure a proof cannot be retrieved for a mapping that does not exist.
+        assert!(finalize_store.get_mapping_proof(&program_id, &mapping_name, &key_1).is_err());
+
+        // Finalizes a block at the given height, in the same manner as `VM::atomic_finalize`.
+        let finalize = |height: u32, rng: &mut TestRng, operations: &dyn Fn()| {
+            finalize_store.start_atomic();
+            operations();
+            let committee = ledger_committee::test_helpers::sample_committee_for_round(height as u64, rng);
+            finalize_store.committee_store().insert(height, committee).unwrap();
+            finalize_store.insert_rollback_entries(height).unwrap();
+            finalize_store.finish_atomic().unwrap();
+        };
+        // Checks the mapping root against a new mapping tree, and the proof for each key.
+        let check = |expected: &[(&Plaintext<CurrentNetwork>, Option<&Value<CurrentNetwork>>)]| {
+            let root = finalize_store.current_mapping_root();
+            let leaves = expected
+                .iter()
+                .filter_map(|(key, value)| value.map(|value| (key, value)))
+                .map(|(key, value)| {
+                    let key_id = MappingTree::to_key_id(&program_id, &mapping_name, key).unwrap();
+                    (key_id, MappingTree::to_value_hash(value).unwrap())
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(root, MappingTree::new(leaves).unwrap().root());
+            for (key, value) in expected {
+                let proof = finalize_store.get_mapping_proof(&program_id, &mapping_name, key).unwrap();
+                assert!(proof.verify(&root, &program_id, &mapping_name, key, *value));
+            }
+            root
+        };
+
+        // Block 0: initialize the mapping and insert a key-value pair.
+        finalize(0, rng, &|| {
+            finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key_1.clone(), value_1.clone()).unwrap();
+        });
+        let root_0 = check(&[(&key_1, Some(&value_1)), (&key_2, None)]);
+
+        // Block 1: update the existing key and insert a new key.
+        finalize(1, rng, &|| {
+            finalize_store.update_key_value(program_id, mapping_name, key_1.clone(), value_2.clone()).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key_2.clone(), value_1.clone()).unwrap();
+        });
+        check(&[(&key_1, Some(&value_2)), (&key_2, Some(&value_1))]);
+
+        // Ensure a dry run does not modify the mapping root.
+        let root_1 = finalize_store.current_mapping_root();
+        finalize_store.start_atomic();
+        finalize_store.remove_key_value(program_id, mapping_name, &key_1).unwrap();
+        finalize_store.abort_atomic();
+        assert_eq!(finalize_store.current_mapping_root(), root_1);
+
+        // Block 2: replace the mapping.
+        finalize(2, rng, &|| {
+            finalize_store.replace_mapping(program_id, mapping_name, vec![(key_2.clone(), value_2.clone())]).unwrap();
+        });
+        check(&[(&key_1, None), (&key_2, Some(&value_2))]);
+
+        // Ensure the mapping tree is persisted, and is restored from its nodes when the store is reopened.
+        assert!(!finalize_store.storage.mapping_node_map().is_empty_confirmed());
+        let reopened = FinalizeStore::<CurrentNetwork, _>::from(finalize_store.storage.clone()).unwrap();
+        assert_eq!(reopened.current_mapping_root(), finalize_store.current_mapping_root());
+
+        // Revert to block 0, and ensure the mapping root is restored.
+        finalize_store.revert_to_height(0).unwrap();
+        assert_eq!(check(&[(&key_1, Some(&value_1)), (&key_2, None)]), root_0);
+        let reopened = FinalizeStore::<CurrentNetwork, _>::from(finalize_store.storage.clone()).unwrap();
+        assert_eq!(reopened.current_mapping_root(), root_0);
+
+        // Ensure the mapping tree is rebuilt and persisted, if its nodes are missing from storage.
+        let positions = finalize_store.storage.mapping_node_map().keys_confirmed().map(|p| *p).collect::<Vec<_>>();
+        for position in positions {
+            finalize_store.storage.mapping_node_map().remove(&position).unwrap();
+        }
+        let reopened = FinalizeStore::<CurrentNetwork, _>::from(finalize_store.storage.clone()).unwrap();
+        assert_eq!(reopened.current_mapping_root(), root_0);
+        assert!(!finalize_store.storage.mapping_node_map().is_empty_confirmed());
+    }
+
+    #[test]
+    fn test_mapping_pagination() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::<CurrentNetwork, FinalizeMemory<_>>::open(None).unwrap();
+        // Ensure a page cannot be retrieved for a mapping that does not exist.
+        assert!(finalize_store.get_mapping_page_confirmed(program_id, mapping_name, None, 10).is_err());
+
+        // Initialize the mapping, and insert the key-value pairs.
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        for i in 0..25 {
+            let key = Plaintext::from_str(&format!("{i}field")).unwrap();
+            let value = Value::from_str(&format!("{i}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key, value).unwrap();
+        }
+        let mut expected = finalize_store.get_mapping_confirmed(program_id, mapping_name).unwrap();
+        assert_eq!(expected.len(), 25);
+
+        // Retrieve the mapping page by page.
+        let mut cursor = None;
+        let mut entries = Vec::new();
+        loop {
+            let page =
+                finalize_store.get_mapping_page_confirmed(program_id, mapping_name, cursor.as_ref(), 10).unwrap();
+            if page.is_empty() {
+                break;
+            }
+            assert!(page.len() <= 10);
+            cursor = page.last().map(|(key, _)| key.clone());
+            entries.extend(page);
+        }
+        // Ensure the pages cover the mapping, in serialized key order.
+        expected.sort_by_key(|(key, _)| bincode::serialize(key).unwrap());
+        assert_eq!(entries, expected);
+
+        // Ensure a range scan returns the entries between the given keys.
+        let (start, end) = (&expected[5].0, &expected[15].0);
+        let range = finalize_store
+            .get_mapping_range_confirmed(program_id, mapping_name, Bound::Included(start), Bound::Excluded(end), 100)
+            .unwrap();
+        assert_eq!(range, expected[5..15]);
+    }
+
+    #[test]
+    fn test_mapping_stats() {
+        // Initialize a program ID and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let account = Identifier::from_str("account").unwrap();
+        let empty = Identifier::from_str("empty").unwrap();
+
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::<CurrentNetwork, FinalizeMemory<_>>::open(None).unwrap();
+        assert!(finalize_store.mapping_stats().unwrap().is_empty());
+
+        // Initialize the mappings, and insert the key-value pairs into one of them.
+        finalize_store.initialize_mapping(program_id, account).unwrap();
+        finalize_store.initialize_mapping(program_id, empty).unwrap();
+        for i in 0..5 {
+            let key = Plaintext::from_str(&format!("{i}field")).unwrap();
+            let value = Value::from_str(&format!("{i}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, account, key, value).unwrap();
+        }
+
+        // Ensure the statistics cover both mappings.
+        let stats = finalize_store.mapping_stats().unwrap();
+        assert_eq!(stats.len(), 2);
+        assert_eq!((stats[0].program_id, stats[0].mapping_name, stats[0].num_entries), (program_id, account, 5));
+        assert!(stats[0].size_in_bytes > 0);
+        assert_eq!((stats[1].program_id, stats[1].mapping_name, stats[1].num_entries), (program_id, empty, 0));
+        assert_eq!(stats[1].size_in_bytes, 0);
+    }
 }
@@ -195,6 +195,28 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the transaction for the given `transaction ID`, by removing the execution and fee proofs,
+    /// and the transition values. Note: The deployment itself is kept, as it is required to load the program.
+    fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the transaction type.
+        let transaction_type = match self.id_map().get_confirmed(transaction_id)? {
+            Some(transaction_type) => cow_to_copied!(transaction_type),
+            None => bail!("Failed to get the type for transaction '{transaction_id}'"),
+        };
+
+        atomic_batch_scope!(self, {
+            match transaction_type {
+                // Prune the fee of the deployment transaction.
+                TransactionType::Deploy => self.fee_store().prune(transaction_id)?,
+                // Prune the execution transaction.
+                TransactionType::Execute => self.execution_store().prune(transaction_id)?,
+                // Prune the fee transaction.
+                TransactionType::Fee => self.fee_store().prune(transaction_id)?,
+            }
+            Ok(())
+        })
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -263,6 +285,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.remove(transaction_id)
     }
 
+    /// Prunes the proofs and the transition values for the given `transaction ID`.
+    pub fn prune(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.prune(transaction_id)
+    }
+
     /// Returns the deployment store.
     pub fn deployment_store(&self) -> &DeploymentStore<N, T::DeploymentStorage> {
         self.storage.deployment_store()
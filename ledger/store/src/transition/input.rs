@@ -218,6 +218,28 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the inputs for the given `transition ID`, by removing the input values.
+    /// Note: The input IDs, serial numbers, and tags are kept in storage.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the input IDs.
+        let input_ids = self.get_ids(transition_id)?;
+
+        atomic_batch_scope!(self, {
+            // Remove the input values.
+            for input_id in input_ids {
+                if self.constant_map().contains_key_confirmed(&input_id)? {
+                    self.constant_map().insert(input_id, None)?;
+                } else if self.public_map().contains_key_confirmed(&input_id)? {
+                    self.public_map().insert(input_id, None)?;
+                } else if self.private_map().contains_key_confirmed(&input_id)? {
+                    self.private_map().insert(input_id, None)?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
     /// Returns the transition ID that contains the given `input ID`.
     fn find_transition_id(&self, input_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         match self.reverse_id_map().get_confirmed(input_id)? {
@@ -345,6 +367,11 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the input values for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
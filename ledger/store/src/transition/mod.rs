@@ -54,6 +54,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     type ReverseTCMMap: for<'a> Map<'a, Field<N>, N::TransitionID>;
     /// The signer commitments.
     type SCMMap: for<'a> Map<'a, N::TransitionID, Field<N>>;
+    /// The pruned transition IDs.
+    type PrunedMap: for<'a> Map<'a, N::TransitionID, ()>;
 
     /// Initializes the transition storage.
     fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self>;
@@ -74,6 +76,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap;
     /// Returns the signer commitments map.
     fn scm_map(&self) -> &Self::SCMMap;
+    /// Returns the pruned transition IDs.
+    fn pruned_map(&self) -> &Self::PrunedMap;
 
     /// Returns the storage mode.
     fn storage_mode(&self) -> &StorageMode {
@@ -91,6 +95,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().start_atomic();
         self.reverse_tcm_map().start_atomic();
         self.scm_map().start_atomic();
+        self.pruned_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -103,6 +108,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             || self.tcm_map().is_atomic_in_progress()
             || self.reverse_tcm_map().is_atomic_in_progress()
             || self.scm_map().is_atomic_in_progress()
+            || self.pruned_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -115,6 +121,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().atomic_checkpoint();
         self.reverse_tcm_map().atomic_checkpoint();
         self.scm_map().atomic_checkpoint();
+        self.pruned_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -127,6 +134,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().clear_latest_checkpoint();
         self.reverse_tcm_map().clear_latest_checkpoint();
         self.scm_map().clear_latest_checkpoint();
+        self.pruned_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -139,6 +147,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().atomic_rewind();
         self.reverse_tcm_map().atomic_rewind();
         self.scm_map().atomic_rewind();
+        self.pruned_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -151,6 +160,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tcm_map().abort_atomic();
         self.reverse_tcm_map().abort_atomic();
         self.scm_map().abort_atomic();
+        self.pruned_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -162,7 +172,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().finish_atomic()?;
         self.tcm_map().finish_atomic()?;
         self.reverse_tcm_map().finish_atomic()?;
-        self.scm_map().finish_atomic()
+        self.scm_map().finish_atomic()?;
+        self.pruned_map().finish_atomic()
     }
 
     /// Stores the given `transition` into storage.
@@ -221,6 +232,23 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.reverse_tcm_map().remove(&tcm)?;
             // Remove `scm`.
             self.scm_map().remove(transition_id)?;
+            // Remove the pruned marker, if it exists.
+            self.pruned_map().remove(transition_id)?;
+
+            Ok(())
+        })
+    }
+
+    /// Prunes the transition for the given `transition ID`, by removing its input and output values.
+    /// Note: The pruned transition retains its ID, as well as the IDs of its inputs and outputs.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        atomic_batch_scope!(self, {
+            // Prune the inputs.
+            self.input_store().prune(transition_id)?;
+            // Prune the outputs.
+            self.output_store().prune(transition_id)?;
+            // Mark the transition as pruned.
+            self.pruned_map().insert(*transition_id, ())?;
 
             Ok(())
         })
@@ -286,6 +314,8 @@ pub struct TransitionStore<N: Network, T: TransitionStorage<N>> {
     reverse_tcm: T::ReverseTCMMap,
     /// The map of signer commitments.
     scm: T::SCMMap,
+    /// The map of pruned transition IDs.
+    pruned: T::PrunedMap,
     /// The transition storage.
     storage: T,
 }
@@ -305,6 +335,7 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
             tcm: storage.tcm_map().clone(),
             reverse_tcm: storage.reverse_tcm_map().clone(),
             scm: storage.scm_map().clone(),
+            pruned: storage.pruned_map().clone(),
             storage,
         })
     }
@@ -320,6 +351,7 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
             tcm: storage.tcm_map().clone(),
             reverse_tcm: storage.reverse_tcm_map().clone(),
             scm: storage.scm_map().clone(),
+            pruned: storage.pruned_map().clone(),
             storage,
         }
     }
@@ -334,6 +366,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the input and output values of the transition for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -389,11 +426,29 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         // Throw an error.
         bail!("Failed to find the transition ID for the given input or output ID '{id}'")
     }
+
+    /// Returns the transition ID that contains the given `input ID`.
+    pub fn find_transition_id_from_input_id(&self, input_id: &Field<N>) -> Result<Option<N::TransitionID>> {
+        self.inputs.find_transition_id(input_id)
+    }
+
+    /// Returns the transition ID that contains the given `output ID`.
+    pub fn find_transition_id_from_output_id(&self, output_id: &Field<N>) -> Result<Option<N::TransitionID>> {
+        self.outputs.find_transition_id(output_id)
+    }
 }
 
 impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
     /// Returns the transition for the given `transition ID`.
+    /// Note: This method fails if the transition has been pruned, as its input and output values are no longer in storage.
     pub fn get_transition(&self, transition_id: &N::TransitionID) -> Result<Option<Transition<N>>> {
+        self.ensure_not_pruned(transition_id)?;
+        self.storage.get(transition_id)
+    }
+
+    /// Returns the transition for the given `transition ID`, where a pruned transition is returned without its input and output values.
+    /// Note: The IDs of a pruned transition are retained, so it can still be used to construct state paths.
+    pub(crate) fn get_transition_allow_pruned(&self, transition_id: &N::TransitionID) -> Result<Option<Transition<N>>> {
         self.storage.get(transition_id)
     }
 
@@ -420,6 +475,7 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
 
     /// Returns the inputs for the given `transition ID`.
     pub fn get_inputs(&self, transition_id: &N::TransitionID) -> Result<Vec<Input<N>>> {
+        self.ensure_not_pruned(transition_id)?;
         self.inputs.get_inputs(transition_id)
     }
 
@@ -430,6 +486,7 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
 
     /// Returns the outputs for the given `transition ID`.
     pub fn get_outputs(&self, transition_id: &N::TransitionID) -> Result<Vec<Output<N>>> {
+        self.ensure_not_pruned(transition_id)?;
         self.outputs.get_outputs(transition_id)
     }
 
@@ -437,9 +494,24 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
     ///
     /// If the record exists, `Ok(Some(record))` is returned.
     /// If the record was purged, `Ok(None)` is returned.
-    /// If the record does not exist, `Err(error)` is returned.
+    /// If the record does not exist, or its transition was pruned, `Err(error)` is returned.
     pub fn get_record(&self, commitment: &Field<N>) -> Result<Option<Record<N, Ciphertext<N>>>> {
-        self.outputs.get_record(commitment)
+        match self.outputs.get_record(commitment)? {
+            Some(record) => Ok(Some(record)),
+            // Ensure the missing record was not pruned.
+            None => match self.outputs.find_transition_id(commitment)? {
+                Some(transition_id) => self.ensure_not_pruned(&transition_id).map(|_| None),
+                None => Ok(None),
+            },
+        }
+    }
+
+    /// Ensures the transition for the given `transition ID` has not been pruned.
+    fn ensure_not_pruned(&self, transition_id: &N::TransitionID) -> Result<()> {
+        match self.is_pruned(transition_id)? {
+            true => bail!("Transition '{transition_id}' has been pruned from storage"),
+            false => Ok(()),
+        }
     }
 }
 
@@ -449,6 +521,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.locator.contains_key_confirmed(transition_id)
     }
 
+    /// Returns `true` if the input and output values of the given transition ID have been pruned.
+    pub fn is_pruned(&self, transition_id: &N::TransitionID) -> Result<bool> {
+        self.pruned.contains_key_confirmed(transition_id)
+    }
+
     /* Input */
 
     /// Returns `true` if the given input ID exists.
@@ -623,6 +700,7 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
     }
 
     /// Returns an iterator over the `(commitment, record)` pairs, for all transition outputs that are records.
+    /// Note: The records of pruned transitions are not included.
     pub fn records(&self) -> impl '_ + Iterator<Item = (Cow<'_, Field<N>>, Cow<'_, Record<N, Ciphertext<N>>>)> {
         self.outputs.records()
     }
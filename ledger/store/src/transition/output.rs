@@ -15,6 +15,7 @@
 
 use crate::{
     atomic_batch_scope,
+    cow_to_cloned,
     helpers::{Map, MapRead},
 };
 use console::{
@@ -235,6 +236,33 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
         })
     }
 
+    /// Prunes the outputs for the given `transition ID`, by removing the output values.
+    /// Note: The output IDs, commitments, checksums, and record nonces are kept in storage.
+    fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        // Retrieve the output IDs.
+        let output_ids = self.get_ids(transition_id)?;
+
+        atomic_batch_scope!(self, {
+            // Remove the output values.
+            for output_id in output_ids {
+                if self.constant_map().contains_key_confirmed(&output_id)? {
+                    self.constant_map().insert(output_id, None)?;
+                } else if self.public_map().contains_key_confirmed(&output_id)? {
+                    self.public_map().insert(output_id, None)?;
+                } else if self.private_map().contains_key_confirmed(&output_id)? {
+                    self.private_map().insert(output_id, None)?;
+                } else if let Some(record) = self.record_map().get_confirmed(&output_id)? {
+                    let (checksum, _) = cow_to_cloned!(record);
+                    self.record_map().insert(output_id, (checksum, None))?;
+                } else if self.future_map().contains_key_confirmed(&output_id)? {
+                    self.future_map().insert(output_id, None)?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
     /// Returns the transition ID that contains the given `output ID`.
     fn find_transition_id(&self, output_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         match self.reverse_id_map().get_confirmed(output_id)? {
@@ -368,6 +396,11 @@ impl<N: Network, O: OutputStorage<N>> OutputStore<N, O> {
         self.storage.remove(transition_id)
     }
 
+    /// Prunes the output values for the given `transition ID`.
+    pub fn prune(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.prune(transition_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -15,17 +15,75 @@
 
 #![forbid(unsafe_code)]
 
-const GAUGE_NAMES: [&str; 1] = [committee::TOTAL_STAKE];
+const COUNTER_NAMES: [&str; 4] =
+    [vm::ACCEPTED_TRANSACTIONS, vm::REJECTED_TRANSACTIONS, vm::ABORTED_TRANSACTIONS, vm::TOTAL_FEES];
+
+const GAUGE_NAMES: [&str; 7] = [
+    committee::TOTAL_STAKE,
+    storage::MAP_KEYS,
+    storage::MAP_SIZE_IN_BYTES,
+    storage::MAP_WRITES_PER_SECOND,
+    storage::MAPPING_ENTRIES,
+    storage::MAPPING_SIZE_IN_BYTES,
+    vm::BLOCK_FEES,
+];
+
+const HISTOGRAM_NAMES: [&str; 8] = [
+    vm::ADD_NEXT_BLOCK_LATENCY,
+    vm::SPECULATE_LATENCY,
+    vm::CHECK_TRANSACTION_LATENCY,
+    process::EXECUTE_LATENCY,
+    process::VERIFY_DEPLOYMENT_LATENCY,
+    process::VERIFY_EXECUTION_LATENCY,
+    process::VERIFY_PROOF_LATENCY,
+    puzzle::CHECK_SOLUTIONS_LATENCY,
+];
 
 pub mod committee {
     pub const TOTAL_STAKE: &str = "snarkvm_ledger_committee_total_stake";
 }
 
+pub mod process {
+    pub const EXECUTE_LATENCY: &str = "snarkvm_process_execute_latency_secs";
+    pub const VERIFY_DEPLOYMENT_LATENCY: &str = "snarkvm_process_verify_deployment_latency_secs";
+    pub const VERIFY_EXECUTION_LATENCY: &str = "snarkvm_process_verify_execution_latency_secs";
+    pub const VERIFY_PROOF_LATENCY: &str = "snarkvm_process_verify_proof_latency_secs";
+}
+
+pub mod puzzle {
+    pub const CHECK_SOLUTIONS_LATENCY: &str = "snarkvm_puzzle_check_solutions_latency_secs";
+}
+
+pub mod storage {
+    pub const MAP_KEYS: &str = "snarkvm_ledger_storage_map_keys";
+    pub const MAP_SIZE_IN_BYTES: &str = "snarkvm_ledger_storage_map_size_in_bytes";
+    pub const MAP_WRITES_PER_SECOND: &str = "snarkvm_ledger_storage_map_writes_per_second";
+    pub const MAPPING_ENTRIES: &str = "snarkvm_ledger_storage_mapping_entries";
+    pub const MAPPING_SIZE_IN_BYTES: &str = "snarkvm_ledger_storage_mapping_size_in_bytes";
+}
+
+pub mod vm {
+    pub const ADD_NEXT_BLOCK_LATENCY: &str = "snarkvm_vm_add_next_block_latency_secs";
+    pub const SPECULATE_LATENCY: &str = "snarkvm_vm_speculate_latency_secs";
+    pub const CHECK_TRANSACTION_LATENCY: &str = "snarkvm_vm_check_transaction_latency_secs";
+    pub const ACCEPTED_TRANSACTIONS: &str = "snarkvm_vm_accepted_transactions_total";
+    pub const REJECTED_TRANSACTIONS: &str = "snarkvm_vm_rejected_transactions_total";
+    pub const ABORTED_TRANSACTIONS: &str = "snarkvm_vm_aborted_transactions_total";
+    pub const BLOCK_FEES: &str = "snarkvm_vm_block_fees";
+    pub const TOTAL_FEES: &str = "snarkvm_vm_fees_total";
+}
+
 /// Registers all snarkVM metrics.
 pub fn register_metrics() {
+    for name in COUNTER_NAMES {
+        register_counter(name);
+    }
     for name in GAUGE_NAMES {
         register_gauge(name);
     }
+    for name in HISTOGRAM_NAMES {
+        register_histogram(name);
+    }
 }
 
 /******** Counter ********/
@@ -53,6 +111,15 @@ pub fn increment_counter(name: &'static str) {
     counter.increment(1);
 }
 
+/// Increments a counter with the given name by the given value.
+///
+/// Counters represent a single monotonic value, which means the value can only be incremented,
+/// not decremented, and always starts out with an initial value of zero.
+pub fn increment_counter_by<V: Into<u64>>(name: &'static str, value: V) {
+    let counter = ::metrics::counter!(name);
+    counter.increment(value.into());
+}
+
 /******** Gauge ********/
 
 /// Registers a gauge with the given name.
@@ -69,6 +136,14 @@ pub fn gauge<V: Into<f64>>(name: &'static str, value: V) {
     gauge.set(value.into());
 }
 
+/// Updates a gauge with the given name and label to the given value.
+///
+/// Gauges represent a single value that can go up or down over time,
+/// and always starts out with an initial value of zero.
+pub fn gauge_label<V: Into<f64>>(name: &'static str, label_key: &'static str, label_value: String, value: V) {
+    ::metrics::gauge!(name, label_key => label_value).set(value.into());
+}
+
 /// Increments a gauge with the given name by the given value.
 ///
 /// Gauges represent a single value that can go up or down over time,
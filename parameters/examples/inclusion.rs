@@ -17,7 +17,7 @@ use snarkvm_algorithms::crypto_hash::sha256::sha256;
 use snarkvm_circuit::{Aleo, Assignment};
 use snarkvm_console::{
     account::PrivateKey,
-    network::{CanaryV0, MainnetV0, Network, TestnetV0},
+    network::{CanaryV0, DevnetV0, MainnetV0, Network, TestnetV0},
     prelude::{One, ToBytes, Zero},
     program::{Plaintext, Record, StatePath},
     types::Field,
@@ -180,6 +180,9 @@ pub fn main() -> Result<()> {
         "canary" => {
             inclusion::<CanaryV0, snarkvm_circuit::AleoCanaryV0>()?;
         }
+        "devnet" => {
+            inclusion::<DevnetV0, snarkvm_circuit::AleoDevnetV0>()?;
+        }
         _ => panic!("Invalid network"),
     };
 
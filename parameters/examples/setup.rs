@@ -15,7 +15,7 @@
 
 use snarkvm_algorithms::crypto_hash::sha256::sha256;
 use snarkvm_circuit::Aleo;
-use snarkvm_console::network::{CanaryV0, MainnetV0, Network, TestnetV0, prelude::ToBytes};
+use snarkvm_console::network::{CanaryV0, DevnetV0, MainnetV0, Network, TestnetV0, prelude::ToBytes};
 use snarkvm_synthesizer::{Process, Program};
 
 use anyhow::Result;
@@ -154,6 +154,7 @@ pub fn main() -> Result<()> {
             "mainnet" => credits_program::<MainnetV0, snarkvm_circuit::AleoV0>(),
             "testnet" => credits_program::<TestnetV0, snarkvm_circuit::AleoTestnetV0>(),
             "canary" => credits_program::<CanaryV0, snarkvm_circuit::AleoCanaryV0>(),
+            "devnet" => credits_program::<DevnetV0, snarkvm_circuit::AleoDevnetV0>(),
             _ => panic!("Invalid network"),
         }?,
         _ => panic!("Invalid parameter"),
@@ -0,0 +1,300 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The parameters of a devnet, which are loaded from a configuration file at startup instead of being embedded.
+
+mod protocol;
+pub use protocol::DevnetProtocol;
+
+use crate::errors::ParameterError;
+use snarkvm_utilities::FromBytes;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+/// The environment variable with the path of the devnet configuration file,
+/// which is loaded if the devnet was not configured with [`configure`].
+pub const DEVNET_CONFIG_ENV: &str = "SNARKVM_DEVNET_CONFIG";
+
+/// The devnet parameters, once configured.
+static PARAMETERS: OnceLock<DevnetParameters> = OnceLock::new();
+
+/// The parameters of a devnet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DevnetParameters {
+    /// The bytes of the genesis block.
+    genesis_bytes: Vec<u8>,
+    /// The restrictions list, as a JSON-compatible string.
+    restrictions_list: String,
+    /// The address of the governor that signs restrictions updates, if updates are enabled.
+    restrictions_governor: Option<String>,
+    /// The directory containing the circuit keys.
+    keys_dir: PathBuf,
+    /// The protocol parameters.
+    protocol: DevnetProtocol,
+}
+
+impl DevnetParameters {
+    /// Loads the devnet parameters from the given configuration file, which is a JSON object of the form:
+    /// ```json
+    /// {
+    ///     "genesis": "block.genesis",
+    ///     "restrictions": "restrictions.json",
+    ///     "restrictions_governor": null,
+    ///     "keys": "keys/",
+    ///     "protocol": {
+    ///         "max_certificates": 25,
+    ///         "max_solutions_as_power_of_two": 2,
+    ///         "block_time": 10,
+    ///         "anchor_time": 25,
+    ///         "num_blocks_per_epoch": 60,
+    ///         "genesis_coinbase_target": 31,
+    ///         "genesis_proof_target": 8,
+    ///         "starting_supply": 1500000000000000,
+    ///         "deployment_fee_multiplier": 10,
+    ///         "synthesis_fee_multiplier": 1,
+    ///         "execution_storage_fee_scaling_factor": 5000,
+    ///         "execution_storage_penalty_threshold": 5000
+    ///     }
+    /// }
+    /// ```
+    /// Relative paths are resolved against the directory of the configuration file. The `keys` directory
+    /// contains the circuit keys of `credits.aleo` and of the inclusion circuit with their metadata, as written
+    /// by `cargo run --example setup credits devnet` and `cargo run --example inclusion devnet`.
+    ///
+    /// The `protocol` parameters are optional, and every missing parameter takes its default value.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ParameterError> {
+        let path = path.as_ref();
+        let config: serde_json::Value = serde_json::from_slice(&fs::read(path)?)
+            .map_err(|error| ParameterError::Message(format!("Invalid devnet configuration: {error}")))?;
+
+        // Load the protocol parameters.
+        let protocol = DevnetProtocol::from_config(&config).map_err(ParameterError::Message)?;
+
+        // Resolves the path in the given field against the directory of the configuration file.
+        let base_dir = path.parent().unwrap_or_else(|| Path::new(""));
+        let resolve = |field: &str| -> Result<PathBuf, ParameterError> {
+            match config[field].as_str() {
+                Some(value) => Ok(base_dir.join(value)),
+                None => Err(ParameterError::Message(format!("Missing '{field}' in the devnet configuration"))),
+            }
+        };
+
+        let genesis_bytes = fs::read(resolve("genesis")?)?;
+        let restrictions_list = fs::read_to_string(resolve("restrictions")?)?;
+        let restrictions_governor = config["restrictions_governor"].as_str().map(str::to_string);
+        let keys_dir = resolve("keys")?;
+        // Ensure the keys directory exists.
+        if !keys_dir.is_dir() {
+            return Err(ParameterError::Message(format!("Missing the devnet keys directory '{}'", keys_dir.display())));
+        }
+
+        Ok(Self { genesis_bytes, restrictions_list, restrictions_governor, keys_dir, protocol })
+    }
+
+    /// Returns the bytes of the genesis block.
+    pub fn genesis_bytes(&self) -> &[u8] {
+        &self.genesis_bytes
+    }
+
+    /// Returns the restrictions list, as a JSON-compatible string.
+    pub fn restrictions_list(&self) -> &str {
+        &self.restrictions_list
+    }
+
+    /// Returns the address of the governor that signs restrictions updates, if updates are enabled.
+    pub fn restrictions_governor(&self) -> Option<&str> {
+        self.restrictions_governor.as_deref()
+    }
+
+    /// Returns the protocol parameters.
+    pub fn protocol(&self) -> &DevnetProtocol {
+        &self.protocol
+    }
+
+    /// Returns the names of the circuit keys in the keys directory, in alphabetical order.
+    pub fn key_names(&self) -> Result<Vec<String>, ParameterError> {
+        let mut names = Vec::new();
+        for entry in fs::read_dir(&self.keys_dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|extension| extension.to_str()) == Some("metadata") {
+                if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+
+    /// Returns the bytes of the circuit key with the given name and type (`prover` or `verifier`),
+    /// after checking their size and checksum against the metadata of the key.
+    pub fn load_key_bytes(&self, name: &str, key_type: &str) -> Result<Vec<u8>, ParameterError> {
+        let metadata: serde_json::Value =
+            serde_json::from_slice(&fs::read(self.keys_dir.join(format!("{name}.metadata")))?)
+                .map_err(|error| ParameterError::Message(format!("Invalid metadata for the '{name}' key: {error}")))?;
+        let expected_checksum = metadata[format!("{key_type}_checksum")]
+            .as_str()
+            .ok_or_else(|| ParameterError::Message(format!("Missing the {key_type} checksum of the '{name}' key")))?;
+        let expected_size = metadata[format!("{key_type}_size")]
+            .as_u64()
+            .ok_or_else(|| ParameterError::Message(format!("Missing the {key_type} size of the '{name}' key")))?
+            as usize;
+
+        // The prover is stored under a versioned filename, as it is too large to be committed.
+        let filename = match (key_type, expected_checksum.get(0..7)) {
+            ("prover", Some(sum)) => format!("{name}.{key_type}.{sum}"),
+            _ => format!("{name}.{key_type}"),
+        };
+        let buffer = fs::read(self.keys_dir.join(filename))?;
+
+        // Ensure the size matches.
+        if expected_size != buffer.len() {
+            return Err(ParameterError::SizeMismatch(expected_size, buffer.len()));
+        }
+        // Ensure the checksum matches.
+        let candidate_checksum = checksum!(&buffer);
+        if expected_checksum != candidate_checksum {
+            return checksum_error!(expected_checksum.to_string(), candidate_checksum);
+        }
+        Ok(buffer)
+    }
+
+    /// Returns the circuit key with the given name and type (`prover` or `verifier`), loaded as in `load_key_bytes`.
+    pub fn load_key<T: FromBytes>(&self, name: &str, key_type: &str) -> Result<T, ParameterError> {
+        let key_bytes = self.load_key_bytes(name, key_type)?;
+        // Recover the circuit key, skipping the first byte, which is the encoded version.
+        T::from_bytes_le(key_bytes.get(1..).unwrap_or_default())
+            .map_err(|error| ParameterError::Message(format!("Failed to recover the {key_type} '{name}': {error}")))
+    }
+}
+
+/// Configures the devnet with the parameters in the given configuration file.
+///
+/// This must be called at startup, before the devnet parameters are used.
+pub fn configure<P: AsRef<Path>>(path: P) -> Result<(), ParameterError> {
+    let parameters = DevnetParameters::load(path)?;
+    PARAMETERS.set(parameters).map_err(|_| ParameterError::Message("The devnet is already configured".to_string()))
+}
+
+/// Returns the devnet parameters, which are loaded from the file in `SNARKVM_DEVNET_CONFIG`
+/// if the devnet was not configured with [`configure`].
+pub fn parameters() -> Result<&'static DevnetParameters, ParameterError> {
+    if let Some(parameters) = PARAMETERS.get() {
+        return Ok(parameters);
+    }
+    let path = std::env::var(DEVNET_CONFIG_ENV).map_err(|_| {
+        ParameterError::Message(format!("The devnet is not configured - call 'configure' or set '{DEVNET_CONFIG_ENV}'"))
+    })?;
+    let parameters = DevnetParameters::load(path)?;
+    Ok(PARAMETERS.get_or_init(|| parameters))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_key_bytes() {
+        let dir = std::env::temp_dir().join(format!("snarkvm_devnet_parameters_{}", std::process::id()));
+        let keys_dir = dir.join("keys");
+        fs::create_dir_all(&keys_dir).unwrap();
+
+        // Write the configuration.
+        fs::write(dir.join("block.genesis"), [1u8, 2, 3]).unwrap();
+        fs::write(dir.join("restrictions.json"), "{}").unwrap();
+        fs::write(
+            dir.join("devnet.json"),
+            r#"{ "genesis": "block.genesis", "restrictions": "restrictions.json", "keys": "keys" }"#,
+        )
+        .unwrap();
+
+        // Write a key and its metadata.
+        let verifier = vec![0u8, 4, 5, 6];
+        let metadata =
+            serde_json::json!({ "verifier_checksum": checksum!(&verifier), "verifier_size": verifier.len() });
+        fs::write(keys_dir.join("join.verifier"), &verifier).unwrap();
+        fs::write(keys_dir.join("join.metadata"), metadata.to_string()).unwrap();
+
+        // Load the parameters.
+        let parameters = DevnetParameters::load(dir.join("devnet.json")).unwrap();
+        assert_eq!(parameters.genesis_bytes(), &[1u8, 2, 3]);
+        assert_eq!(parameters.restrictions_list(), "{}");
+        assert_eq!(parameters.restrictions_governor(), None);
+        assert_eq!(parameters.protocol(), &DevnetProtocol::DEFAULT);
+        assert_eq!(parameters.key_names().unwrap(), vec!["join".to_string()]);
+        assert_eq!(parameters.load_key_bytes("join", "verifier").unwrap(), verifier);
+        // Ensure a corrupted key is rejected.
+        fs::write(keys_dir.join("join.verifier"), [0u8, 4, 5, 7]).unwrap();
+        assert!(parameters.load_key_bytes("join", "verifier").is_err());
+        // Ensure a missing key is rejected.
+        assert!(parameters.load_key_bytes("join", "prover").is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_protocol() {
+        // Ensure the missing protocol parameters take their default values.
+        assert_eq!(DevnetProtocol::from_config(&serde_json::json!({})).unwrap(), DevnetProtocol::DEFAULT);
+        let config = serde_json::json!({
+            "protocol": { "max_certificates": 10, "synthesis_fee_multiplier": 4, "num_blocks_per_epoch": 10 }
+        });
+        let protocol = DevnetProtocol::from_config(&config).unwrap();
+        assert_eq!(protocol.max_certificates, 10);
+        assert_eq!(protocol.synthesis_fee_multiplier, 4);
+        assert_eq!(protocol.num_blocks_per_epoch, 10);
+        assert_eq!(protocol.block_time, DevnetProtocol::DEFAULT.block_time);
+
+        // Ensure invalid protocol parameters are rejected.
+        for protocol in [
+            serde_json::json!({ "max_certificates": 2 }),
+            serde_json::json!({ "max_certificates": 101 }),
+            serde_json::json!({ "max_solutions_as_power_of_two": 9 }),
+            serde_json::json!({ "block_time": 65536 }),
+            serde_json::json!({ "block_time": 30, "anchor_time": 25 }),
+            serde_json::json!({ "num_blocks_per_epoch": -1 }),
+            serde_json::json!({ "genesis_proof_target": 64, "genesis_coinbase_target": 63 }),
+        ] {
+            assert!(DevnetProtocol::from_config(&serde_json::json!({ "protocol": protocol })).is_err());
+        }
+
+        // Ensure the protocol parameters are loaded with the configuration, and invalid ones are rejected.
+        let dir = std::env::temp_dir().join(format!("snarkvm_devnet_protocol_{}", std::process::id()));
+        fs::create_dir_all(dir.join("keys")).unwrap();
+        fs::write(dir.join("block.genesis"), [1u8, 2, 3]).unwrap();
+        fs::write(dir.join("restrictions.json"), "{}").unwrap();
+        let write_config = |protocol: serde_json::Value| {
+            let config = serde_json::json!({
+                "genesis": "block.genesis",
+                "restrictions": "restrictions.json",
+                "keys": "keys",
+                "protocol": protocol,
+            });
+            fs::write(dir.join("devnet.json"), config.to_string()).unwrap();
+        };
+        write_config(serde_json::json!({ "block_time": 2, "anchor_time": 4 }));
+        let parameters = DevnetParameters::load(dir.join("devnet.json")).unwrap();
+        assert_eq!(parameters.protocol().block_time, 2);
+        assert_eq!(parameters.protocol().anchor_time, 4);
+        write_config(serde_json::json!({ "block_time": 0 }));
+        assert!(DevnetParameters::load(dir.join("devnet.json")).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
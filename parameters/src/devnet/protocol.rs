@@ -0,0 +1,158 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The protocol parameters of a devnet, which are returned by the protocol parameter functions of `DevnetV0`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DevnetProtocol {
+    /// The maximum number of certificates in a batch, which is also the maximum committee size.
+    pub max_certificates: u16,
+    /// The maximum number of solutions that can be included per block as a power of 2.
+    pub max_solutions_as_power_of_two: u8,
+    /// The expected time per block in seconds.
+    pub block_time: u16,
+    /// The anchor time in seconds.
+    pub anchor_time: u16,
+    /// The number of blocks per epoch.
+    pub num_blocks_per_epoch: u32,
+    /// The genesis block coinbase target.
+    pub genesis_coinbase_target: u64,
+    /// The genesis block proof target.
+    pub genesis_proof_target: u64,
+    /// The starting supply of Aleo credits.
+    pub starting_supply: u64,
+    /// The cost in microcredits per byte for the deployment transaction.
+    pub deployment_fee_multiplier: u64,
+    /// The cost in microcredits per constraint for the deployment transaction.
+    pub synthesis_fee_multiplier: u64,
+    /// The constant that divides the storage polynomial.
+    pub execution_storage_fee_scaling_factor: u64,
+    /// The maximum size execution transactions can be before a quadratic storage penalty applies.
+    pub execution_storage_penalty_threshold: u64,
+}
+
+impl DevnetProtocol {
+    /// The upper bound on the number of certificates in a batch, which bounds the batch and committee encodings.
+    pub const MAX_CERTIFICATES_LIMIT: u16 = 100;
+
+    /// The default protocol parameters, which are tuned for small committees, short epochs and cheap fees.
+    pub const DEFAULT: Self = Self {
+        max_certificates: 25,                   // 25 certificates
+        max_solutions_as_power_of_two: 2,       // 4 solutions
+        block_time: 10,                         // 10 seconds
+        anchor_time: 25,                        // 25 seconds
+        num_blocks_per_epoch: 60,               // 60 blocks == ~10 minutes
+        genesis_coinbase_target: (1 << 5) - 1,  // 31
+        genesis_proof_target: 1 << 3,           // 8
+        starting_supply: 1_500_000_000_000_000, // 1.5B credits
+        deployment_fee_multiplier: 10,          // 10 microcredits per byte
+        synthesis_fee_multiplier: 1,            // 1 microcredit per constraint
+        execution_storage_fee_scaling_factor: 5000,
+        execution_storage_penalty_threshold: 5000,
+    };
+
+    /// Returns the protocol parameters in the `protocol` object of the given devnet configuration,
+    /// where every missing parameter takes its default value.
+    pub fn from_config(config: &serde_json::Value) -> Result<Self, String> {
+        let protocol = match &config["protocol"] {
+            serde_json::Value::Null => return Ok(Self::DEFAULT),
+            protocol @ serde_json::Value::Object(_) => protocol,
+            _ => return Err("The devnet 'protocol' parameters must be an object".to_string()),
+        };
+
+        // Returns the parameter with the given name, or the given default value if it is missing.
+        fn parameter<T: TryFrom<u64>>(protocol: &serde_json::Value, name: &str, default: T) -> Result<T, String> {
+            match &protocol[name] {
+                serde_json::Value::Null => Ok(default),
+                value => value
+                    .as_u64()
+                    .and_then(|value| T::try_from(value).ok())
+                    .ok_or_else(|| format!("Invalid devnet protocol parameter '{name}' (found '{value}')")),
+            }
+        }
+
+        let default = Self::DEFAULT;
+        let parameters = Self {
+            max_certificates: parameter(protocol, "max_certificates", default.max_certificates)?,
+            max_solutions_as_power_of_two: parameter(
+                protocol,
+                "max_solutions_as_power_of_two",
+                default.max_solutions_as_power_of_two,
+            )?,
+            block_time: parameter(protocol, "block_time", default.block_time)?,
+            anchor_time: parameter(protocol, "anchor_time", default.anchor_time)?,
+            num_blocks_per_epoch: parameter(protocol, "num_blocks_per_epoch", default.num_blocks_per_epoch)?,
+            genesis_coinbase_target: parameter(protocol, "genesis_coinbase_target", default.genesis_coinbase_target)?,
+            genesis_proof_target: parameter(protocol, "genesis_proof_target", default.genesis_proof_target)?,
+            starting_supply: parameter(protocol, "starting_supply", default.starting_supply)?,
+            deployment_fee_multiplier: parameter(
+                protocol,
+                "deployment_fee_multiplier",
+                default.deployment_fee_multiplier,
+            )?,
+            synthesis_fee_multiplier: parameter(
+                protocol,
+                "synthesis_fee_multiplier",
+                default.synthesis_fee_multiplier,
+            )?,
+            execution_storage_fee_scaling_factor: parameter(
+                protocol,
+                "execution_storage_fee_scaling_factor",
+                default.execution_storage_fee_scaling_factor,
+            )?,
+            execution_storage_penalty_threshold: parameter(
+                protocol,
+                "execution_storage_penalty_threshold",
+                default.execution_storage_penalty_threshold,
+            )?,
+        };
+        parameters.check()?;
+        Ok(parameters)
+    }
+
+    /// Ensures the protocol parameters are within the bounds assumed by the network.
+    fn check(&self) -> Result<(), String> {
+        // Ensure a committee can have its minimum of 3 members, and the encodings remain bounded.
+        if self.max_certificates < 3 || self.max_certificates > Self::MAX_CERTIFICATES_LIMIT {
+            return Err(format!(
+                "The devnet 'max_certificates' must be between 3 and {}",
+                Self::MAX_CERTIFICATES_LIMIT
+            ));
+        }
+        // Ensure the block has room for at least one solution, and the solutions root has a bounded depth.
+        if self.max_solutions_as_power_of_two > 8 {
+            return Err("The devnet 'max_solutions_as_power_of_two' must be at most 8".to_string());
+        }
+        // Ensure the anchor height (`anchor_time / block_time`) is non-zero.
+        if self.block_time == 0 || self.anchor_time < self.block_time {
+            return Err("The devnet 'block_time' must be non-zero, and at most the 'anchor_time'".to_string());
+        }
+        // Ensure the epochs are non-empty.
+        if self.num_blocks_per_epoch == 0 {
+            return Err("The devnet 'num_blocks_per_epoch' must be non-zero".to_string());
+        }
+        // Ensure the targets are non-zero, and a proof target never exceeds the coinbase target.
+        if self.genesis_proof_target == 0 || self.genesis_coinbase_target < self.genesis_proof_target {
+            return Err(
+                "The devnet 'genesis_proof_target' must be non-zero, and at most the 'genesis_coinbase_target'"
+                    .to_string(),
+            );
+        }
+        // Ensure the storage polynomial is well-defined.
+        if self.execution_storage_fee_scaling_factor == 0 {
+            return Err("The devnet 'execution_storage_fee_scaling_factor' must be non-zero".to_string());
+        }
+        Ok(())
+    }
+}
@@ -33,6 +33,8 @@ pub use errors::*;
 
 pub mod canary;
 
+pub mod devnet;
+
 pub mod mainnet;
 
 pub mod testnet;
@@ -14,6 +14,11 @@
 // limitations under the License.
 
 use super::*;
+use console::program::{InputID, Register, ValueType};
+use ledger_query::{QueryTrait, StaticQuery};
+use synthesizer_program::Operand;
+
+use indexmap::IndexSet;
 
 impl<N: Network> Process<N> {
     /// Authorizes a call to the program function for the given inputs.
@@ -108,6 +113,53 @@ impl<N: Network> Process<N> {
     }
 }
 
+impl<N: Network> Process<N> {
+    /// Returns the commitments of the input records that must be proven to exist in the global state,
+    /// i.e. the input records that are not produced by an earlier request in the authorization.
+    ///
+    /// Note: A record produced by a request is identified by its nonce, which is derived from
+    /// the transition view key of the request and the register of the record output.
+    pub fn to_global_commitments(&self, authorization: &Authorization<N>) -> Result<Vec<Field<N>>> {
+        // Initialize the nonces of the records produced by the requests so far.
+        let mut local_nonces = IndexSet::new();
+        // Initialize the commitments of the global input records.
+        let mut commitments = IndexSet::new();
+        for request in authorization.to_vec_deque() {
+            // Retrieve the commitments of the input records that are not produced by an earlier request.
+            for (input_id, input) in request.input_ids().iter().zip_eq(request.inputs()) {
+                if let (InputID::Record(commitment, ..), Value::Record(record)) = (input_id, input) {
+                    if !local_nonces.contains(record.nonce()) {
+                        commitments.insert(*commitment);
+                    }
+                }
+            }
+            // Compute the nonces of the records produced by the request.
+            let function = self.get_stack(request.program_id())?.get_function(request.function_name())?;
+            for output in function.outputs() {
+                if let (ValueType::Record(..), Operand::Register(Register::Locator(index))) =
+                    (output.value_type(), output.operand())
+                {
+                    // Compute the encryption randomizer as `HashToScalar(tvk || index)`.
+                    let randomizer = N::hash_to_scalar_psd2(&[*request.tvk(), Field::from_u64(*index)])?;
+                    // Compute the record nonce.
+                    local_nonces.insert(N::g_scalar_multiply(&randomizer));
+                }
+            }
+        }
+        Ok(commitments.into_iter().collect())
+    }
+
+    /// Exports the state root, block height, and state paths required to prove the given authorization
+    /// from the given query (e.g. a full node), so that it can be proven without network access.
+    pub fn to_static_query(
+        &self,
+        authorization: &Authorization<N>,
+        query: &impl QueryTrait<N>,
+    ) -> Result<StaticQuery<N>> {
+        StaticQuery::fetch(query, &self.to_global_commitments(authorization)?)
+    }
+}
+
 /// Ensures the record contains a sufficient balance to pay the fee.
 fn ensure_record_microcredits_is_sufficient<N: Network>(
     record: &Record<N, Plaintext<N>>,
@@ -37,11 +37,12 @@ pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (
 
     // Compute the storage cost in microcredits.
     let storage_cost = size_in_bytes
-        .checked_mul(N::DEPLOYMENT_FEE_MULTIPLIER)
+        .checked_mul(N::deployment_fee_multiplier())
         .ok_or(anyhow!("The storage cost computation overflowed for a deployment"))?;
 
     // Compute the synthesis cost in microcredits.
-    let synthesis_cost = num_combined_variables.saturating_add(num_combined_constraints) * N::SYNTHESIS_FEE_MULTIPLIER;
+    let synthesis_cost =
+        num_combined_variables.saturating_add(num_combined_constraints) * N::synthesis_fee_multiplier();
 
     // Compute the namespace cost in credits: 10^(10 - num_characters).
     let namespace_cost = 10u64
@@ -99,8 +100,8 @@ pub fn execution_cost_v1<N: Network>(process: &Process<N>, execution: &Execution
 
 /// Returns the storage cost in microcredits for a program execution.
 fn execution_storage_cost<N: Network>(size_in_bytes: u64) -> u64 {
-    if size_in_bytes > N::EXECUTION_STORAGE_PENALTY_THRESHOLD {
-        size_in_bytes.saturating_mul(size_in_bytes).saturating_div(N::EXECUTION_STORAGE_FEE_SCALING_FACTOR)
+    if size_in_bytes > N::execution_storage_penalty_threshold() {
+        size_in_bytes.saturating_mul(size_in_bytes).saturating_div(N::execution_storage_fee_scaling_factor())
     } else {
         size_in_bytes
     }
@@ -465,7 +466,7 @@ mod tests {
     use super::*;
     use crate::test_helpers::get_execution;
 
-    use console::network::{CanaryV0, MainnetV0, TestnetV0};
+    use console::network::{CanaryV0, DevnetV0, MainnetV0, TestnetV0};
     use synthesizer_program::Program;
 
     // Test program with two functions just below and above the size threshold.
@@ -496,7 +497,7 @@ function over_five_thousand:
 
     fn test_storage_cost_bounds<N: Network>() {
         // Calculate the bounds directly above and below the size threshold.
-        let threshold = N::EXECUTION_STORAGE_PENALTY_THRESHOLD;
+        let threshold = N::execution_storage_penalty_threshold();
         let threshold_lower_offset = threshold.saturating_sub(1);
         let threshold_upper_offset = threshold.saturating_add(1);
 
@@ -512,6 +513,7 @@ function over_five_thousand:
     #[test]
     fn test_storage_cost_bounds_for_all_networks() {
         test_storage_cost_bounds::<CanaryV0>();
+        test_storage_cost_bounds::<DevnetV0>();
         test_storage_cost_bounds::<MainnetV0>();
         test_storage_cost_bounds::<TestnetV0>();
     }
@@ -519,7 +521,7 @@ function over_five_thousand:
     #[test]
     fn test_storage_costs_compute_correctly() {
         // Test the storage cost of an execution.
-        let threshold = MainnetV0::EXECUTION_STORAGE_PENALTY_THRESHOLD;
+        let threshold = MainnetV0::execution_storage_penalty_threshold();
 
         // Test the cost of an execution.
         let mut process = Process::load().unwrap();
@@ -24,6 +24,8 @@ impl<N: Network> Process<N> {
         rng: &mut R,
     ) -> Result<(Response<N>, Trace<N>)> {
         let timer = timer!("Process::execute");
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
 
         // Retrieve the main request (without popping it).
         let request = authorization.peek_next()?;
@@ -55,6 +57,8 @@ impl<N: Network> Process<N> {
         ensure!(!trace.transitions().is_empty(), "Execution of '{locator}' is empty");
 
         finish!(timer);
+        #[cfg(feature = "metrics")]
+        metrics::histogram(metrics::process::EXECUTE_LATENCY, start.elapsed().as_secs_f64());
         Ok((response, trace))
     }
 }
@@ -135,6 +135,22 @@ impl<N: Network> Process<N> {
         // Add the stack to the process.
         self.stacks.insert(*stack.program_id(), Arc::new(stack));
     }
+
+    /// Removes the program with the given program ID from the process.
+    /// Note: This is intended for reverting deployments, and does not check for dependent programs.
+    #[inline]
+    pub fn remove_program(&mut self, program_id: &ProgramID<N>) -> Result<()> {
+        // Ensure the program is not 'credits.aleo'.
+        ensure!(
+            program_id != &ProgramID::<N>::from_str("credits.aleo")?,
+            "Cannot remove 'credits.aleo' from the process"
+        );
+        // Remove the stack from the process.
+        match self.stacks.shift_remove(program_id) {
+            Some(_) => Ok(()),
+            None => bail!("Program '{program_id}' does not exist"),
+        }
+    }
 }
 
 impl<N: Network> Process<N> {
@@ -1193,6 +1193,87 @@ function transfer:
     // assert_eq!(215810, CurrentAleo::num_gates());
 }
 
+#[test]
+fn test_process_global_commitments_with_chained_record() {
+    // Initialize a new program.
+    let program0 = Program::<CurrentNetwork>::from_str(
+        r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;
+
+function join:
+    input r0 as token.record;
+    input r1 as token.record;
+    add r0.amount r1.amount into r2;
+    cast r0.owner r2 into r3 as token.record;
+    output r3 as token.record;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program0);
+    // Initialize another program, which joins a given record with a newly minted record.
+    let program1 = Program::<CurrentNetwork>::from_str(
+        r"
+import token.aleo;
+
+program wallet.aleo;
+
+function mint_and_join:
+    input r0 as token.aleo/token.record;
+    input r1 as u64.private;
+    call token.aleo/mint r0.owner r1 into r2;
+    call token.aleo/join r0 r2 into r3;
+    output r3 as token.aleo/token.record;",
+    )
+    .unwrap();
+    process.add_program(&program1).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Declare the input values.
+    let r0 = Value::<CurrentNetwork>::from_str(&format!(
+        "{{ owner: {caller}.private, amount: 100u64.private, _nonce: 0group.public }}"
+    ))
+    .unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("mint_and_join").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 3);
+
+    // Retrieve the commitment of the given record.
+    let requests = authorization.to_vec_deque();
+    let commitment = match &requests[0].input_ids()[0] {
+        console::program::InputID::Record(commitment, ..) => *commitment,
+        _ => panic!("Expected a record input"),
+    };
+    // Ensure the `join` request consumes the given record, and the minted record.
+    assert_eq!(requests[2].input_ids().len(), 2);
+
+    // Ensure the minted record is not a global commitment, even though the `join` request consumes it.
+    assert_eq!(process.to_global_commitments(&authorization).unwrap(), vec![commitment]);
+}
+
 #[test]
 fn test_process_execute_and_finalize_get_add_set() {
     // Initialize a new program.
@@ -318,7 +318,13 @@ impl<N: Network> Trace<N> {
             // Insert the inclusion verifier inputs.
             verifier_inputs.push((VerifyingKey::<N>::new(verifying_key, num_variables), batch_inclusion_inputs));
         }
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
         // Verify the proof.
-        VerifyingKey::verify_batch(locator, verifier_inputs, proof).map_err(|e| anyhow!("Failed to verify proof - {e}"))
+        let verification = VerifyingKey::verify_batch(locator, verifier_inputs, proof)
+            .map_err(|e| anyhow!("Failed to verify proof - {e}"));
+        #[cfg(feature = "metrics")]
+        metrics::histogram(metrics::process::VERIFY_PROOF_LATENCY, start.elapsed().as_secs_f64());
+        verification
     }
 }
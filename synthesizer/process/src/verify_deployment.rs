@@ -24,6 +24,8 @@ impl<N: Network> Process<N> {
         rng: &mut R,
     ) -> Result<()> {
         let timer = timer!("Process::verify_deployment");
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
 
         // Retrieve the program ID.
         let program_id = deployment.program().id();
@@ -39,6 +41,8 @@ impl<N: Network> Process<N> {
         lap!(timer, "Verify the deployment");
 
         finish!(timer);
+        #[cfg(feature = "metrics")]
+        metrics::histogram(metrics::process::VERIFY_DEPLOYMENT_LATENCY, start.elapsed().as_secs_f64());
         verification
     }
 }
@@ -21,6 +21,8 @@ impl<N: Network> Process<N> {
     #[inline]
     pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
         let timer = timer!("Process::verify_execution");
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
 
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
@@ -156,6 +158,8 @@ impl<N: Network> Process<N> {
         lap!(timer, "Verify the proof");
 
         finish!(timer);
+        #[cfg(feature = "metrics")]
+        metrics::histogram(metrics::process::VERIFY_EXECUTION_LATENCY, start.elapsed().as_secs_f64());
         Ok(())
     }
 }
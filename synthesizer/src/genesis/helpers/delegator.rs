@@ -0,0 +1,84 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use console::{network::prelude::*, types::Address};
+
+use utilities::DeserializeExt;
+
+/// A genesis delegator, bonded to a genesis validator in `credits.aleo`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GenesisDelegator<N: Network> {
+    /// The address of the delegator.
+    address: Address<N>,
+    /// The address of the validator the delegator is bonded to.
+    validator: Address<N>,
+    /// The withdrawal address of the delegator.
+    withdrawal_address: Address<N>,
+    /// The amount of microcredits bonded by the delegator.
+    amount: u64,
+}
+
+impl<N: Network> GenesisDelegator<N> {
+    /// Initializes a new genesis delegator.
+    pub const fn new(address: Address<N>, validator: Address<N>, withdrawal_address: Address<N>, amount: u64) -> Self {
+        Self { address, validator, withdrawal_address, amount }
+    }
+
+    /// Returns the address of the delegator.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns the address of the validator the delegator is bonded to.
+    pub const fn validator(&self) -> Address<N> {
+        self.validator
+    }
+
+    /// Returns the withdrawal address of the delegator.
+    pub const fn withdrawal_address(&self) -> Address<N> {
+        self.withdrawal_address
+    }
+
+    /// Returns the amount of microcredits bonded by the delegator.
+    pub const fn amount(&self) -> u64 {
+        self.amount
+    }
+}
+
+impl<N: Network> Serialize for GenesisDelegator<N> {
+    /// Serializes the genesis delegator into JSON.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("GenesisDelegator", 4)?;
+        state.serialize_field("address", &self.address)?;
+        state.serialize_field("validator", &self.validator)?;
+        state.serialize_field("withdrawal_address", &self.withdrawal_address)?;
+        state.serialize_field("amount", &self.amount)?;
+        state.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for GenesisDelegator<N> {
+    /// Deserializes the genesis delegator from JSON.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut delegator = serde_json::Value::deserialize(deserializer)?;
+
+        // Recover the genesis delegator.
+        Ok(Self {
+            address: DeserializeExt::take_from_value::<D>(&mut delegator, "address")?,
+            validator: DeserializeExt::take_from_value::<D>(&mut delegator, "validator")?,
+            withdrawal_address: DeserializeExt::take_from_value::<D>(&mut delegator, "withdrawal_address")?,
+            amount: DeserializeExt::take_from_value::<D>(&mut delegator, "amount")?,
+        })
+    }
+}
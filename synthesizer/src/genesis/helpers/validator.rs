@@ -0,0 +1,106 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use console::{network::prelude::*, types::Address};
+
+use utilities::DeserializeExt;
+
+/// A genesis validator, bonded to itself in `credits.aleo`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GenesisValidator<N: Network> {
+    /// The address of the validator.
+    address: Address<N>,
+    /// The withdrawal address of the validator.
+    withdrawal_address: Address<N>,
+    /// The amount of microcredits self-bonded by the validator.
+    stake: u64,
+    /// The `is_open` flag is `true` if the validator accepts delegators.
+    is_open: bool,
+    /// The commission percentage of the validator.
+    commission: u8,
+}
+
+impl<N: Network> GenesisValidator<N> {
+    /// Initializes a new genesis validator.
+    pub const fn new(
+        address: Address<N>,
+        withdrawal_address: Address<N>,
+        stake: u64,
+        is_open: bool,
+        commission: u8,
+    ) -> Self {
+        Self { address, withdrawal_address, stake, is_open, commission }
+    }
+
+    /// Returns the address of the validator.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns the withdrawal address of the validator.
+    pub const fn withdrawal_address(&self) -> Address<N> {
+        self.withdrawal_address
+    }
+
+    /// Returns the amount of microcredits self-bonded by the validator.
+    pub const fn stake(&self) -> u64 {
+        self.stake
+    }
+
+    /// Returns the `is_open` flag.
+    pub const fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Returns the commission percentage of the validator.
+    pub const fn commission(&self) -> u8 {
+        self.commission
+    }
+}
+
+impl<N: Network> Serialize for GenesisValidator<N> {
+    /// Serializes the genesis validator into JSON.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("GenesisValidator", 5)?;
+        state.serialize_field("address", &self.address)?;
+        state.serialize_field("withdrawal_address", &self.withdrawal_address)?;
+        state.serialize_field("stake", &self.stake)?;
+        state.serialize_field("is_open", &self.is_open)?;
+        state.serialize_field("commission", &self.commission)?;
+        state.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for GenesisValidator<N> {
+    /// Deserializes the genesis validator from JSON.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut validator = serde_json::Value::deserialize(deserializer)?;
+
+        // Recover the genesis validator.
+        Ok(Self {
+            address: DeserializeExt::take_from_value::<D>(&mut validator, "address")?,
+            withdrawal_address: DeserializeExt::take_from_value::<D>(&mut validator, "withdrawal_address")?,
+            stake: DeserializeExt::take_from_value::<D>(&mut validator, "stake")?,
+            // Note: Following `credits.aleo`, a validator is open to delegators by default.
+            is_open: match validator.get("is_open") {
+                Some(_) => DeserializeExt::take_from_value::<D>(&mut validator, "is_open")?,
+                None => true,
+            },
+            commission: match validator.get("commission") {
+                Some(_) => DeserializeExt::take_from_value::<D>(&mut validator, "commission")?,
+                None => 0,
+            },
+        })
+    }
+}
@@ -0,0 +1,354 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod helpers;
+pub use helpers::*;
+
+mod serialize;
+mod string;
+mod summary;
+pub use summary::*;
+
+use console::{network::prelude::*, program::ProgramID, types::Address};
+use ledger_committee::{Committee, MAX_DELEGATORS, MIN_DELEGATOR_STAKE, MIN_VALIDATOR_SELF_STAKE, MIN_VALIDATOR_STAKE};
+use synthesizer_program::Program;
+
+use indexmap::{IndexMap, IndexSet};
+
+/// A declarative specification of the genesis state of a quorum chain.
+///
+/// The spec lists the validators (with their self-bonded stake, commission and `is_open` flag),
+/// the delegators bonded to them, the public balances, and the programs to deploy once the chain starts.
+/// Upon construction, the spec is checked against the bonding rules of `credits.aleo`.
+///
+/// The spec is read from and written to JSON (see `FromStr` and `Display`), which is the only supported format.
+///
+/// Note: The programs are **not** deployed in the genesis block, which only contains the `credits.aleo`
+/// transactions. Instead, `VM::genesis_deployments` returns their deployments, which must be included
+/// in the block at height 1. As such, each program may only import `credits.aleo`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct GenesisSpec<N: Network> {
+    /// The genesis validators.
+    validators: Vec<GenesisValidator<N>>,
+    /// The genesis delegators.
+    delegators: Vec<GenesisDelegator<N>>,
+    /// The public balances, in microcredits.
+    public_balances: IndexMap<Address<N>, u64>,
+    /// The programs to deploy in the block at height 1, which may only import `credits.aleo`.
+    programs: Vec<Program<N>>,
+}
+
+impl<N: Network> GenesisSpec<N> {
+    /// Initializes a new genesis spec, ensuring it is well-formed.
+    pub fn new(
+        validators: Vec<GenesisValidator<N>>,
+        delegators: Vec<GenesisDelegator<N>>,
+        public_balances: IndexMap<Address<N>, u64>,
+        programs: Vec<Program<N>>,
+    ) -> Result<Self> {
+        // Construct the genesis spec.
+        let spec = Self { validators, delegators, public_balances, programs };
+        // Ensure the genesis spec is well-formed.
+        spec.check()?;
+        // Return the genesis spec.
+        Ok(spec)
+    }
+
+    /// Returns the genesis validators.
+    pub fn validators(&self) -> &[GenesisValidator<N>] {
+        &self.validators
+    }
+
+    /// Returns the genesis delegators.
+    pub fn delegators(&self) -> &[GenesisDelegator<N>] {
+        &self.delegators
+    }
+
+    /// Returns the public balances, in microcredits.
+    pub const fn public_balances(&self) -> &IndexMap<Address<N>, u64> {
+        &self.public_balances
+    }
+
+    /// Returns the programs to deploy in the block at height 1 (see `VM::genesis_deployments`).
+    pub fn programs(&self) -> &[Program<N>] {
+        &self.programs
+    }
+}
+
+impl<N: Network> GenesisSpec<N> {
+    /// Returns the total amount of microcredits delegated to each validator, including its self-bonded stake.
+    pub fn stake_per_validator(&self) -> Result<IndexMap<Address<N>, u64>> {
+        // Initialize the stake per validator with the self-bonded stake.
+        let mut stake_per_validator: IndexMap<_, _> =
+            self.validators.iter().map(|validator| (validator.address(), validator.stake())).collect();
+        // Accumulate the delegated stake.
+        for delegator in &self.delegators {
+            let Some(stake) = stake_per_validator.get_mut(&delegator.validator()) else {
+                bail!("Delegator {} is bonded to an unknown validator {}", delegator.address(), delegator.validator())
+            };
+            *stake = stake.checked_add(delegator.amount()).ok_or_else(|| anyhow!("Invalid validator stake"))?;
+        }
+        Ok(stake_per_validator)
+    }
+
+    /// Returns the genesis committee.
+    pub fn committee(&self) -> Result<Committee<N>> {
+        // Retrieve the stake per validator.
+        let stake_per_validator = self.stake_per_validator()?;
+        // Construct the committee members.
+        let members = self
+            .validators
+            .iter()
+            .map(|validator| {
+                let stake = stake_per_validator.get(&validator.address()).copied().unwrap_or_default();
+                (validator.address(), (stake, validator.is_open(), validator.commission()))
+            })
+            .collect();
+        // Construct the committee.
+        Committee::new_genesis(members)
+    }
+
+    /// Returns the bonded balances, as a map of `staker => (validator, withdrawal address, amount)`.
+    pub fn bonded_balances(&self) -> IndexMap<Address<N>, (Address<N>, Address<N>, u64)> {
+        let validators = self.validators.iter().map(|validator| {
+            (validator.address(), (validator.address(), validator.withdrawal_address(), validator.stake()))
+        });
+        let delegators = self.delegators.iter().map(|delegator| {
+            (delegator.address(), (delegator.validator(), delegator.withdrawal_address(), delegator.amount()))
+        });
+        validators.chain(delegators).collect()
+    }
+
+    /// Returns the total amount of bonded microcredits.
+    pub fn total_bonded(&self) -> Result<u64> {
+        let validators = self.validators.iter().map(|validator| validator.stake());
+        let delegators = self.delegators.iter().map(|delegator| delegator.amount());
+        validators
+            .chain(delegators)
+            .try_fold(0u64, |acc, amount| acc.checked_add(amount).ok_or_else(|| anyhow!("Invalid bonded amount")))
+    }
+
+    /// Returns the total amount of public microcredits.
+    pub fn total_public(&self) -> Result<u64> {
+        self.public_balances
+            .values()
+            .try_fold(0u64, |acc, amount| acc.checked_add(*amount).ok_or_else(|| anyhow!("Invalid account supply")))
+    }
+
+    /// Returns the total supply of microcredits.
+    pub fn total_supply(&self) -> Result<u64> {
+        self.total_bonded()?.checked_add(self.total_public()?).ok_or_else(|| anyhow!("Invalid total supply"))
+    }
+}
+
+impl<N: Network> GenesisSpec<N> {
+    /// Ensures the genesis spec is well-formed, following the rules enforced by `credits.aleo` and `Ratify::Genesis`.
+    pub fn check(&self) -> Result<()> {
+        // Ensure the validators are well-formed.
+        let mut validators = IndexMap::with_capacity(self.validators.len());
+        for validator in &self.validators {
+            let address = validator.address();
+            // Ensure the validator is unique.
+            ensure!(!validators.contains_key(&address), "Validator {address} is specified more than once");
+            // Ensure the withdrawal address is not the validator address.
+            ensure!(
+                validator.withdrawal_address() != address,
+                "Validator {address} must use a withdrawal address that differs from its own address"
+            );
+            // Ensure the validator self-bonds at least the minimum amount.
+            ensure!(
+                validator.stake() >= MIN_VALIDATOR_SELF_STAKE,
+                "Validator {address} must self-bond at least {MIN_VALIDATOR_SELF_STAKE} microcredits"
+            );
+            // Ensure the commission percentage does not exceed 100%.
+            ensure!(validator.commission() <= 100, "Validator {address} must have a commission of at most 100%");
+            validators.insert(address, validator);
+        }
+
+        // Ensure the number of delegators does not exceed the maximum.
+        ensure!(
+            self.delegators.len() <= MAX_DELEGATORS as usize,
+            "The genesis spec exceeds the maximum number of delegators ({MAX_DELEGATORS})"
+        );
+        // Ensure the delegators are well-formed.
+        let mut delegators = IndexSet::with_capacity(self.delegators.len());
+        for delegator in &self.delegators {
+            let address = delegator.address();
+            // Ensure the delegator is unique.
+            ensure!(delegators.insert(address), "Delegator {address} is specified more than once");
+            // Ensure the delegator is not a validator.
+            ensure!(!validators.contains_key(&address), "Delegator {address} is also a validator");
+            // Ensure the delegator bonds at least the minimum amount.
+            ensure!(
+                delegator.amount() >= MIN_DELEGATOR_STAKE,
+                "Delegator {address} must bond at least {MIN_DELEGATOR_STAKE} microcredits"
+            );
+            // Ensure the delegator is bonded to an open validator.
+            match validators.get(&delegator.validator()) {
+                Some(validator) => ensure!(
+                    validator.is_open(),
+                    "Delegator {address} is bonded to a closed validator {}",
+                    delegator.validator()
+                ),
+                None => bail!("Delegator {address} is bonded to an unknown validator {}", delegator.validator()),
+            }
+        }
+
+        // Ensure each validator meets the minimum stake, including its delegated stake.
+        for (address, stake) in self.stake_per_validator()? {
+            ensure!(
+                stake >= MIN_VALIDATOR_STAKE,
+                "Validator {address} must have at least {MIN_VALIDATOR_STAKE} microcredits in stake, found {stake}"
+            );
+        }
+        // Ensure the committee is well-formed.
+        self.committee()?;
+
+        // Ensure the total supply matches the starting supply.
+        let total_supply = self.total_supply()?;
+        ensure!(
+            total_supply == N::starting_supply(),
+            "Invalid total supply. Found {total_supply}, expected {}",
+            N::starting_supply()
+        );
+
+        // Ensure the programs are well-formed.
+        let credits_id = ProgramID::<N>::from_str("credits.aleo")?;
+        let mut program_ids = IndexSet::with_capacity(self.programs.len());
+        for program in &self.programs {
+            let program_id = program.id();
+            // Ensure the program is not `credits.aleo`.
+            ensure!(*program_id != credits_id, "The genesis spec may not redeploy 'credits.aleo'");
+            // Ensure the program is unique.
+            ensure!(program_ids.insert(*program_id), "Program '{program_id}' is specified more than once");
+            // Ensure the program contains at least one function.
+            ensure!(!program.functions().is_empty(), "Program '{program_id}' must contain at least one function");
+            // Ensure the program only imports 'credits.aleo'.
+            // Note: Each deployment is created against the genesis state, which only contains 'credits.aleo'.
+            for import_id in program.imports().keys() {
+                ensure!(*import_id == credits_id, "Program '{program_id}' may only import 'credits.aleo'");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::MainnetV0};
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Samples a genesis spec with 4 validators and 1 delegator.
+    fn sample_genesis_spec(rng: &mut TestRng) -> GenesisSpec<CurrentNetwork> {
+        // Sample the addresses.
+        let mut sample_address = || Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let validators: Vec<_> = (0..4)
+            .map(|i| GenesisValidator::new(sample_address(), sample_address(), MIN_VALIDATOR_STAKE, true, i * 5))
+            .collect();
+        let delegator =
+            GenesisDelegator::new(sample_address(), validators[0].address(), sample_address(), 1_000_000_000_000);
+        // Assign the remaining supply to the first validator.
+        let remaining_supply = CurrentNetwork::starting_supply() - 4 * MIN_VALIDATOR_STAKE - delegator.amount();
+        let public_balances = indexmap::indexmap! { validators[0].address() => remaining_supply };
+        GenesisSpec::new(validators, vec![delegator], public_balances, vec![]).unwrap()
+    }
+
+    #[test]
+    fn test_genesis_spec() {
+        let rng = &mut TestRng::default();
+
+        let spec = sample_genesis_spec(rng);
+        assert_eq!(spec.total_supply().unwrap(), CurrentNetwork::starting_supply());
+        assert_eq!(spec.bonded_balances().len(), 5);
+
+        // Check the committee includes the delegated stake.
+        let committee = spec.committee().unwrap();
+        let first = spec.validators()[0].address();
+        assert_eq!(committee.get_stake(first), MIN_VALIDATOR_STAKE + 1_000_000_000_000);
+        assert_eq!(committee.total_stake(), spec.total_bonded().unwrap());
+        assert_eq!(committee.members().get(&spec.validators()[3].address()).unwrap().2, 15);
+    }
+
+    #[test]
+    fn test_genesis_spec_invalid() {
+        let rng = &mut TestRng::default();
+
+        let spec = sample_genesis_spec(rng);
+        let new = |spec: &GenesisSpec<CurrentNetwork>| {
+            GenesisSpec::new(
+                spec.validators.clone(),
+                spec.delegators.clone(),
+                spec.public_balances.clone(),
+                spec.programs.clone(),
+            )
+        };
+        let v = spec.validators[0];
+        let d = spec.delegators[0];
+
+        // Check a delegator bonded to a closed validator is rejected.
+        let mut candidate = spec.clone();
+        candidate.validators[0] = GenesisValidator::new(v.address(), v.withdrawal_address(), v.stake(), false, 0);
+        assert!(new(&candidate).is_err());
+
+        // Check a validator using its own address as the withdrawal address is rejected.
+        let mut candidate = spec.clone();
+        candidate.validators[0] = GenesisValidator::new(v.address(), v.address(), v.stake(), true, 0);
+        assert!(new(&candidate).is_err());
+
+        // Check a commission above 100% is rejected.
+        let mut candidate = spec.clone();
+        candidate.validators[0] = GenesisValidator::new(v.address(), v.withdrawal_address(), v.stake(), true, 101);
+        assert!(new(&candidate).is_err());
+
+        // Check a delegator below the minimum stake is rejected.
+        let mut candidate = spec.clone();
+        let amount = MIN_DELEGATOR_STAKE - 1;
+        candidate.delegators[0] = GenesisDelegator::new(d.address(), d.validator(), d.withdrawal_address(), amount);
+        *candidate.public_balances.get_index_mut(0).unwrap().1 += d.amount() - amount;
+        assert!(new(&candidate).is_err());
+
+        // Check a delegator that is also a validator is rejected.
+        let mut candidate = spec.clone();
+        let address = spec.validators[1].address();
+        candidate.delegators[0] = GenesisDelegator::new(address, d.validator(), d.withdrawal_address(), d.amount());
+        assert!(new(&candidate).is_err());
+
+        // Check a duplicate validator is rejected.
+        let mut candidate = spec.clone();
+        candidate.validators[1] = v;
+        assert!(new(&candidate).is_err());
+
+        // Check an incorrect total supply is rejected.
+        let mut candidate = spec.clone();
+        *candidate.public_balances.get_index_mut(0).unwrap().1 -= 1;
+        assert!(new(&candidate).is_err());
+
+        // Check a committee below the minimum size is rejected.
+        let mut candidate = spec.clone();
+        let removed = candidate.validators.pop().unwrap();
+        *candidate.public_balances.get_index_mut(0).unwrap().1 += removed.stake();
+        assert!(new(&candidate).is_err());
+
+        // Check a redeployment of 'credits.aleo' is rejected.
+        let mut candidate = spec.clone();
+        candidate.programs.push(Program::credits().unwrap());
+        assert!(new(&candidate).is_err());
+
+        // Check the unmodified spec is accepted.
+        assert!(new(&spec).is_ok());
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+use utilities::DeserializeExt;
+
+impl<N: Network> Serialize for GenesisSpec<N> {
+    /// Serializes the genesis spec into JSON.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("GenesisSpec", 4)?;
+        state.serialize_field("validators", &self.validators)?;
+        state.serialize_field("delegators", &self.delegators)?;
+        state.serialize_field("public_balances", &self.public_balances)?;
+        state.serialize_field("programs", &self.programs)?;
+        state.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for GenesisSpec<N> {
+    /// Deserializes the genesis spec from JSON.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut spec = serde_json::Value::deserialize(deserializer)?;
+
+        // Recover the genesis spec.
+        Self::new(
+            DeserializeExt::take_from_value::<D>(&mut spec, "validators")?,
+            take_from_value_or_default::<D, _>(&mut spec, "delegators")?,
+            take_from_value_or_default::<D, _>(&mut spec, "public_balances")?,
+            take_from_value_or_default::<D, _>(&mut spec, "programs")?,
+        )
+        .map_err(de::Error::custom)
+    }
+}
+
+/// Returns the value of the given field, or the default value if the field is missing.
+fn take_from_value_or_default<'de, D: Deserializer<'de>, T: DeserializeOwned + Default>(
+    value: &mut serde_json::Value,
+    field: &str,
+) -> Result<T, D::Error> {
+    match value.get(field) {
+        Some(_) => DeserializeExt::take_from_value::<D>(value, field),
+        None => Ok(T::default()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::MainnetV0};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_serde_json() {
+        let rng = &mut TestRng::default();
+
+        // Sample the addresses.
+        let mut sample_address = || Address::<CurrentNetwork>::try_from(PrivateKey::new(rng).unwrap()).unwrap();
+        let validators: Vec<_> = (0..4).map(|_| (sample_address(), sample_address())).collect();
+        let delegator = sample_address();
+
+        // Construct the genesis spec.
+        let remaining_supply = CurrentNetwork::starting_supply() - 4 * MIN_VALIDATOR_STAKE - MIN_DELEGATOR_STAKE;
+        let program = "program hello_genesis.aleo;\n\nfunction hello:\n    input r0 as u32.public;\n    output r0 as u32.public;\n";
+        let mut json = serde_json::json!({
+            "validators": validators.iter().map(|(address, withdrawal_address)| serde_json::json!({
+                "address": address.to_string(),
+                "withdrawal_address": withdrawal_address.to_string(),
+                "stake": MIN_VALIDATOR_STAKE,
+                "commission": 10,
+            })).collect::<Vec<_>>(),
+            "delegators": [{
+                "address": delegator.to_string(),
+                "validator": validators[0].0.to_string(),
+                "withdrawal_address": delegator.to_string(),
+                "amount": MIN_DELEGATOR_STAKE,
+            }],
+            "public_balances": { delegator.to_string(): remaining_supply },
+            "programs": [program],
+        });
+
+        // Deserialize the genesis spec.
+        let spec = GenesisSpec::<CurrentNetwork>::from_str(&json.to_string()).unwrap();
+        assert_eq!(spec.validators().len(), 4);
+        assert!(spec.validators().iter().all(|validator| validator.is_open() && validator.commission() == 10));
+        assert_eq!(spec.delegators()[0].validator(), validators[0].0);
+        assert_eq!(spec.programs()[0].id().to_string(), "hello_genesis.aleo");
+
+        // Check the serialization round-trips.
+        let candidate = serde_json::to_string(&spec).unwrap();
+        assert_eq!(spec, GenesisSpec::from_str(&candidate).unwrap());
+
+        // Check an invalid genesis spec fails to deserialize.
+        json["public_balances"][delegator.to_string()] = serde_json::json!(1);
+        assert!(GenesisSpec::<CurrentNetwork>::from_str(&json.to_string()).is_err());
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+/// A human-readable summary of a genesis block built from a `GenesisSpec`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct GenesisSummary<N: Network> {
+    /// The genesis block hash.
+    block_hash: N::BlockHash,
+    /// The genesis committee.
+    committee: Committee<N>,
+    /// The self-bonded stake of each validator.
+    self_stakes: IndexMap<Address<N>, u64>,
+    /// The number of delegators bonded to each validator.
+    num_delegators: IndexMap<Address<N>, usize>,
+    /// The number of accounts with a public balance.
+    num_accounts: usize,
+    /// The total amount of bonded microcredits.
+    total_bonded: u64,
+    /// The total amount of public microcredits.
+    total_public: u64,
+    /// The programs to deploy in the block at height 1.
+    programs: Vec<ProgramID<N>>,
+}
+
+impl<N: Network> GenesisSummary<N> {
+    /// Initializes a new genesis summary, for the given genesis block hash and genesis spec.
+    pub fn new(block_hash: N::BlockHash, spec: &GenesisSpec<N>) -> Result<Self> {
+        // Retrieve the self-bonded stake of each validator.
+        let self_stakes = spec.validators().iter().map(|validator| (validator.address(), validator.stake())).collect();
+        // Count the delegators bonded to each validator.
+        let mut num_delegators: IndexMap<_, _> =
+            spec.validators().iter().map(|validator| (validator.address(), 0usize)).collect();
+        for delegator in spec.delegators() {
+            *num_delegators.entry(delegator.validator()).or_default() += 1;
+        }
+        Ok(Self {
+            block_hash,
+            committee: spec.committee()?,
+            self_stakes,
+            num_delegators,
+            num_accounts: spec.public_balances().len(),
+            total_bonded: spec.total_bonded()?,
+            total_public: spec.total_public()?,
+            programs: spec.programs().iter().map(|program| *program.id()).collect(),
+        })
+    }
+
+    /// Returns the genesis block hash.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the genesis committee.
+    pub const fn committee(&self) -> &Committee<N> {
+        &self.committee
+    }
+
+    /// Returns the total amount of bonded microcredits.
+    pub const fn total_bonded(&self) -> u64 {
+        self.total_bonded
+    }
+
+    /// Returns the total amount of public microcredits.
+    pub const fn total_public(&self) -> u64 {
+        self.total_public
+    }
+
+    /// Returns the programs to deploy in the block at height 1.
+    pub fn programs(&self) -> &[ProgramID<N>] {
+        &self.programs
+    }
+}
+
+impl<N: Network> Debug for GenesisSummary<N> {
+    /// Prints the genesis summary.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for GenesisSummary<N> {
+    /// Displays the genesis summary as a human-readable report.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Note: Amounts are displayed in credits, with 6 decimal places.
+        let credits = |microcredits: u64| format!("{}.{:06}", microcredits / 1_000_000, microcredits % 1_000_000);
+
+        writeln!(f, "Genesis block for {} (network ID {})", N::NAME, N::ID)?;
+        writeln!(f, "  Block hash:     {}", self.block_hash)?;
+        writeln!(f, "  Committee ID:   {}", self.committee.id())?;
+        writeln!(f, "  Total supply:   {} credits", credits(self.total_bonded.saturating_add(self.total_public)))?;
+        writeln!(f, "  Total bonded:   {} credits", credits(self.total_bonded))?;
+        writeln!(f, "  Total public:   {} credits ({} accounts)", credits(self.total_public), self.num_accounts)?;
+        writeln!(f)?;
+        writeln!(f, "Committee ({} validators)", self.committee.num_members())?;
+        for (address, (stake, is_open, commission)) in self.committee.members() {
+            let self_stake = self.self_stakes.get(address).copied().unwrap_or_default();
+            let num_delegators = self.num_delegators.get(address).copied().unwrap_or_default();
+            // Compute the share of the total stake, in basis points.
+            let share = (*stake as u128 * 10_000).checked_div(self.committee.total_stake() as u128).unwrap_or_default();
+            writeln!(
+                f,
+                "  {address}  stake {} credits ({}.{:02}%), self-bonded {} credits, {num_delegators} delegators, commission {commission}%, {}",
+                credits(*stake),
+                share / 100,
+                share % 100,
+                credits(self_stake),
+                if *is_open { "open" } else { "closed" },
+            )?;
+        }
+        if !self.programs.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "Programs to deploy at height 1 ({})", self.programs.len())?;
+            for program_id in &self.programs {
+                writeln!(f, "  {program_id}")?;
+            }
+        }
+        Ok(())
+    }
+}
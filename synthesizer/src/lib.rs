@@ -33,6 +33,11 @@ pub use crate::process::{Authorization, CallMetrics, Process, Stack, Trace};
 #[cfg(feature = "program")]
 pub use crate::program::{Closure, Command, Finalize, Function, Instruction, Program};
 
+#[cfg(all(feature = "process", feature = "program", feature = "snark"))]
+mod genesis;
+#[cfg(all(feature = "process", feature = "program", feature = "snark"))]
+pub use genesis::*;
+
 #[cfg(all(feature = "process", feature = "program", feature = "snark"))]
 mod restrictions;
 #[cfg(all(feature = "process", feature = "program", feature = "snark"))]
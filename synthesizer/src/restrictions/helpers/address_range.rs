@@ -0,0 +1,156 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, types::Address};
+
+/// An inclusive range of addresses of the form `{start}..={end}`, or `{address}` for a single address.
+/// The addresses are ordered by their x-coordinate.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct AddressRange<N: Network> {
+    /// The first address in the range.
+    start: Address<N>,
+    /// The last address in the range.
+    end: Address<N>,
+}
+
+impl<N: Network> AddressRange<N> {
+    /// Initializes a new address range, from `start` to `end` (inclusive).
+    pub fn new(start: Address<N>, end: Address<N>) -> Result<Self> {
+        // Ensure the range is not empty.
+        ensure!(
+            start.to_x_coordinate() <= end.to_x_coordinate(),
+            "The address range '{start}..={end}' is empty, as the start is after the end"
+        );
+        Ok(Self { start, end })
+    }
+
+    /// Initializes a new address range, containing only the given address.
+    pub const fn single(address: Address<N>) -> Self {
+        Self { start: address, end: address }
+    }
+
+    /// Returns the first address in the range.
+    pub const fn start(&self) -> Address<N> {
+        self.start
+    }
+
+    /// Returns the last address in the range.
+    pub const fn end(&self) -> Address<N> {
+        self.end
+    }
+
+    /// Returns `true` if the address range contains the given address.
+    pub fn contains(&self, address: &Address<N>) -> bool {
+        let x_coordinate = address.to_x_coordinate();
+        self.start.to_x_coordinate() <= x_coordinate && x_coordinate <= self.end.to_x_coordinate()
+    }
+}
+
+impl<N: Network> FromStr for AddressRange<N> {
+    type Err = Error;
+
+    /// Parses a string into an address range.
+    fn from_str(string: &str) -> Result<Self> {
+        match string.split_once("..=") {
+            Some((start, end)) => Self::new(Address::from_str(start)?, Address::from_str(end)?),
+            None => Ok(Self::single(Address::from_str(string)?)),
+        }
+    }
+}
+
+impl<N: Network> Debug for AddressRange<N> {
+    /// Prints the address range as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for AddressRange<N> {
+    /// Prints the address range as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.start == self.end {
+            true => write!(f, "{}", self.start),
+            false => write!(f, "{}..={}", self.start, self.end),
+        }
+    }
+}
+
+impl<N: Network> Serialize for AddressRange<N> {
+    /// Serializes the address range into a string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for AddressRange<N> {
+    /// Deserializes the address range from a string.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    /// Returns two sampled addresses, ordered by their x-coordinate.
+    fn sample_ordered_addresses(rng: &mut TestRng) -> (Address<CurrentNetwork>, Address<CurrentNetwork>) {
+        let (a, b) = (Address::rand(rng), Address::rand(rng));
+        match a.to_x_coordinate() <= b.to_x_coordinate() {
+            true => (a, b),
+            false => (b, a),
+        }
+    }
+
+    #[test]
+    fn test_contains() {
+        let rng = &mut TestRng::default();
+
+        let (start, end) = sample_ordered_addresses(rng);
+        let range = AddressRange::new(start, end).unwrap();
+        assert!(range.contains(&start));
+        assert!(range.contains(&end));
+        // Ensure the range matches exactly the addresses between its bounds.
+        for _ in 0..100 {
+            let address = Address::rand(rng);
+            let x_coordinate = address.to_x_coordinate();
+            let expected = start.to_x_coordinate() <= x_coordinate && x_coordinate <= end.to_x_coordinate();
+            assert_eq!(range.contains(&address), expected);
+        }
+        // Ensure a single address range only contains the address.
+        let range = AddressRange::single(start);
+        assert!(range.contains(&start));
+        assert!(!range.contains(&end));
+        // Ensure an empty range is rejected.
+        assert!(AddressRange::new(end, start).is_err());
+    }
+
+    #[test]
+    fn test_serde_json() {
+        let rng = &mut TestRng::default();
+
+        let (start, end) = sample_ordered_addresses(rng);
+        for expected in [AddressRange::new(start, end).unwrap(), AddressRange::single(start)] {
+            let candidate_string = serde_json::to_string(&expected).unwrap();
+            assert_eq!(candidate_string, format!("\"{expected}\""));
+            assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+            assert_eq!(expected, AddressRange::from_str(&expected.to_string()).unwrap());
+        }
+        // Ensure a single address is parsed as a single address range.
+        assert_eq!(AddressRange::from_str(&start.to_string()).unwrap(), AddressRange::single(start));
+    }
+}
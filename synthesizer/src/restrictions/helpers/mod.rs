@@ -13,8 +13,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod address_range;
+pub use address_range::*;
+
 mod argument_locator;
 pub use argument_locator::*;
 
 mod block_range;
 pub use block_range::*;
+
+mod record_owner;
+pub use record_owner::*;
+
+mod restriction_match;
+pub use restriction_match::*;
@@ -0,0 +1,102 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    account::ViewKey,
+    network::prelude::*,
+    program::{Ciphertext, Owner, Record},
+    types::{Address, Field},
+};
+
+/// The owner of the records restricted by a record owner restriction.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RecordOwner<N: Network> {
+    /// The records publicly owned by the address.
+    /// Note: A record with a private owner cannot be attributed to an address without its view key.
+    Address(Address<N>),
+    /// The records owned by the address of the view key, whether the owner is public or private.
+    /// Note: The view key is public in the restrictions list, which reveals every record of the address.
+    ViewKey(ViewKey<N>),
+}
+
+impl<N: Network> RecordOwner<N> {
+    /// Returns the address of the record owner.
+    pub fn address(&self) -> Address<N> {
+        match self {
+            Self::Address(address) => *address,
+            Self::ViewKey(view_key) => view_key.to_address(),
+        }
+    }
+
+    /// Returns `true` if the given record is owned by the record owner.
+    pub fn is_owner(&self, record: &Record<N, Ciphertext<N>>) -> bool {
+        match (self, record.owner()) {
+            (Self::Address(address), Owner::Public(owner)) => address == owner,
+            (Self::Address(_), Owner::Private(_)) => false,
+            (Self::ViewKey(view_key), _) => record.is_owner(view_key),
+        }
+    }
+
+    /// Returns a unique field element encoding of the record owner.
+    pub fn to_fields(&self) -> Result<Vec<Field<N>>> {
+        match self {
+            Self::Address(address) => Ok(vec![Field::from_u8(0), address.to_field()?]),
+            Self::ViewKey(view_key) => Ok(vec![Field::from_u8(1), Field::from_bits_le(&view_key.to_bits_le())?]),
+        }
+    }
+}
+
+impl<N: Network> FromStr for RecordOwner<N> {
+    type Err = Error;
+
+    /// Parses a string into a record owner, as either an address or a view key.
+    fn from_str(string: &str) -> Result<Self> {
+        match string.starts_with("AViewKey1") {
+            true => Ok(Self::ViewKey(ViewKey::from_str(string)?)),
+            false => Ok(Self::Address(Address::from_str(string)?)),
+        }
+    }
+}
+
+impl<N: Network> Debug for RecordOwner<N> {
+    /// Prints the record owner as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for RecordOwner<N> {
+    /// Prints the record owner as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Address(address) => write!(f, "{address}"),
+            Self::ViewKey(view_key) => write!(f, "{view_key}"),
+        }
+    }
+}
+
+impl<N: Network> Serialize for RecordOwner<N> {
+    /// Serializes the record owner into a string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for RecordOwner<N> {
+    /// Deserializes the record owner from a string.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::ArgumentLocator;
+use console::{
+    network::prelude::*,
+    program::{Literal, Locator, ProgramID},
+    types::Address,
+};
+
+/// The restriction rule that matched a transaction.
+#[derive(Clone, PartialEq, Eq)]
+pub enum RestrictionMatch<N: Network> {
+    /// The program of a transition is restricted.
+    Program(ProgramID<N>),
+    /// The `(program ID, function name)` pair of a transition is restricted.
+    Function(Locator<N>),
+    /// The argument at the argument locator of a transition is restricted.
+    Argument(Locator<N>, ArgumentLocator, Literal<N>),
+    /// The deployed program imports a restricted program, directly or transitively, as `(program ID, import ID)`.
+    Import(ProgramID<N>, ProgramID<N>),
+    /// The argument at the argument locator of a transition contains an address in a restricted address range.
+    Address(Locator<N>, ArgumentLocator, Address<N>),
+    /// The record output at the index of a transition is owned by a restricted address.
+    RecordOwner(Locator<N>, u16, Address<N>),
+}
+
+impl<N: Network> Debug for RestrictionMatch<N> {
+    /// Prints the restriction match as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for RestrictionMatch<N> {
+    /// Prints the restriction match as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Program(program_id) => write!(f, "program '{program_id}' is restricted"),
+            Self::Function(locator) => write!(f, "function '{locator}' is restricted"),
+            Self::Argument(locator, argument_locator, literal) => {
+                write!(f, "argument '{argument_locator}' of '{locator}' is restricted ('{literal}')")
+            }
+            Self::Import(program_id, import_id) => {
+                write!(f, "program '{program_id}' imports the restricted program '{import_id}'")
+            }
+            Self::Address(locator, argument_locator, address) => {
+                write!(f, "argument '{argument_locator}' of '{locator}' contains the restricted address '{address}'")
+            }
+            Self::RecordOwner(locator, index, address) => {
+                write!(f, "output {index} of '{locator}' is a record owned by the restricted address '{address}'")
+            }
+        }
+    }
+}
@@ -0,0 +1,338 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::account::{Address, PrivateKey, Signature};
+use ledger_block::Ratify;
+
+/// A version of the restrictions list, ratified in a block and activated from a block height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RestrictionsVersion<N: Network> {
+    /// The height of the block that ratified the restrictions list.
+    ratified_height: u32,
+    /// The block height from which the restrictions list is active.
+    activation_height: u32,
+    /// The restrictions list.
+    restrictions: Restrictions<N>,
+}
+
+impl<N: Network> RestrictionsVersion<N> {
+    /// Returns the height of the block that ratified the restrictions list.
+    pub const fn ratified_height(&self) -> u32 {
+        self.ratified_height
+    }
+
+    /// Returns the block height from which the restrictions list is active.
+    pub const fn activation_height(&self) -> u32 {
+        self.activation_height
+    }
+
+    /// Returns the restrictions list.
+    pub const fn restrictions(&self) -> &Restrictions<N> {
+        &self.restrictions
+    }
+}
+
+/// The history of the restrictions lists of the network.
+///
+/// The first version is the restrictions list of the network, and is active from genesis.
+/// Every subsequent version is ratified on-chain by a `Ratify::Restrictions` ratification,
+/// which is signed by the restrictions governor over the transition from the latest restrictions ID
+/// to the new restrictions ID, and the activation height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RestrictionsHistory<N: Network> {
+    /// The address of the restrictions governor, if restrictions updates are enabled.
+    governor: Option<Address<N>>,
+    /// The versions of the restrictions list, in increasing order of activation height.
+    versions: Vec<RestrictionsVersion<N>>,
+}
+
+impl<N: Network> RestrictionsHistory<N> {
+    /// Initializes the `RestrictionsHistory` instance for the current network.
+    pub fn load() -> Result<Self> {
+        // Load the restrictions governor from the network.
+        let governor = N::restrictions_governor_as_str().map(Address::from_str).transpose()?;
+        // Initialize the restrictions history.
+        Ok(Self::new(Restrictions::load()?, governor))
+    }
+
+    /// Initializes a new `RestrictionsHistory` instance, with the given restrictions list active from genesis.
+    pub fn new(restrictions: Restrictions<N>, governor: Option<Address<N>>) -> Self {
+        Self {
+            governor,
+            versions: vec![RestrictionsVersion { ratified_height: 0, activation_height: 0, restrictions }],
+        }
+    }
+
+    /// Returns the address of the restrictions governor, if restrictions updates are enabled.
+    pub const fn governor(&self) -> Option<Address<N>> {
+        self.governor
+    }
+
+    /// Returns the versions of the restrictions list, in increasing order of activation height.
+    pub fn versions(&self) -> &[RestrictionsVersion<N>] {
+        &self.versions
+    }
+
+    /// Returns the latest ratified restrictions list, which may not be active yet.
+    pub fn latest(&self) -> &Restrictions<N> {
+        // Note: The history always contains the initial restrictions list.
+        &self.versions[self.versions.len() - 1].restrictions
+    }
+
+    /// Returns the restrictions list that is active at the given block height.
+    pub fn get(&self, block_height: u32) -> &Restrictions<N> {
+        // Note: The initial restrictions list is active from genesis, so a version is always found.
+        let index = self.versions.partition_point(|version| version.activation_height <= block_height);
+        &self.versions[index.saturating_sub(1)].restrictions
+    }
+
+    /// Returns `true` if the given execution contains any restricted transitions,
+    /// for the restrictions list that is active at the given block height.
+    pub fn contains_restricted_transitions(&self, execution: &Execution<N>, block_height: u32) -> bool {
+        self.get(block_height).contains_restricted_transitions(execution, block_height)
+    }
+
+    /// Returns `true` if the given deployment is restricted,
+    /// for the restrictions list that is active at the given block height.
+    pub fn is_deployment_restricted(
+        &self,
+        deployment: &Deployment<N>,
+        block_height: u32,
+        get_imports: impl Fn(&ProgramID<N>) -> Vec<ProgramID<N>>,
+    ) -> bool {
+        self.get(block_height).is_deployment_restricted(deployment, block_height, get_imports)
+    }
+
+    /// Returns the restriction rule that matches the given transaction,
+    /// for the restrictions list that is active at the given block height, if any.
+    pub fn explain(
+        &self,
+        transaction: &Transaction<N>,
+        block_height: u32,
+        get_imports: impl Fn(&ProgramID<N>) -> Vec<ProgramID<N>>,
+    ) -> Option<RestrictionMatch<N>> {
+        self.get(block_height).explain(transaction, block_height, get_imports)
+    }
+}
+
+impl<N: Network> RestrictionsHistory<N> {
+    /// Returns the message signed by the restrictions governor, for a restrictions update.
+    pub fn to_update_message(
+        previous_restrictions_id: Field<N>,
+        restrictions_id: Field<N>,
+        activation_height: u32,
+    ) -> Vec<Field<N>> {
+        vec![previous_restrictions_id, restrictions_id, Field::from_u32(activation_height)]
+    }
+
+    /// Returns a restrictions update from the latest restrictions list to the given restrictions list,
+    /// signed by the given restrictions governor.
+    pub fn sign_update<R: Rng + CryptoRng>(
+        &self,
+        governor_private_key: &PrivateKey<N>,
+        restrictions: &Restrictions<N>,
+        activation_height: u32,
+        rng: &mut R,
+    ) -> Result<Ratify<N>> {
+        // Construct the message.
+        let message =
+            Self::to_update_message(self.latest().restrictions_id(), restrictions.restrictions_id(), activation_height);
+        // Sign the message.
+        let signature = Signature::sign(governor_private_key, &message, rng)?;
+        // Return the restrictions update.
+        Ok(Ratify::Restrictions(Box::new(restrictions.to_string()), activation_height, Box::new(signature)))
+    }
+
+    /// Checks the given restrictions update, ratified in the block at the given height,
+    /// and returns the new restrictions list.
+    pub fn check_update(&self, block_height: u32, ratify: &Ratify<N>) -> Result<Restrictions<N>> {
+        // Retrieve the restrictions update.
+        let Ratify::Restrictions(restrictions, activation_height, signature) = ratify else {
+            bail!("Expected a restrictions update, found '{ratify}'")
+        };
+        // Retrieve the restrictions governor.
+        let Some(governor) = self.governor else { bail!("Restrictions updates are not enabled for {}", N::NAME) };
+        // Ensure restrictions updates are only ratified from `N::CONSENSUS_V3_HEIGHT` onwards.
+        ensure!(
+            block_height >= N::CONSENSUS_V3_HEIGHT,
+            "Restrictions updates are only supported from block {} (found {block_height})",
+            N::CONSENSUS_V3_HEIGHT
+        );
+
+        // Ensure the restrictions list activates after the block that ratifies it.
+        ensure!(
+            *activation_height > block_height,
+            "The restrictions update must activate after block {block_height} (found {activation_height})"
+        );
+        // Ensure the restrictions list activates after the latest restrictions list.
+        // Note: The history always contains the initial restrictions list.
+        let latest_activation_height = self.versions[self.versions.len() - 1].activation_height;
+        ensure!(
+            *activation_height > latest_activation_height,
+            "The restrictions update must activate after block {latest_activation_height} (found {activation_height})"
+        );
+
+        // Parse the restrictions list.
+        let restrictions = Restrictions::<N>::from_str(restrictions)?;
+        // Ensure the restrictions ID matches the computed value.
+        restrictions.check_restrictions_id()?;
+        // Ensure the restrictions list differs from the latest restrictions list.
+        let previous_restrictions_id = self.latest().restrictions_id();
+        ensure!(
+            restrictions.restrictions_id() != previous_restrictions_id,
+            "The restrictions update does not change the restrictions list ({previous_restrictions_id})"
+        );
+
+        // Ensure the signature is from the restrictions governor.
+        let message =
+            Self::to_update_message(previous_restrictions_id, restrictions.restrictions_id(), *activation_height);
+        ensure!(
+            signature.verify(&governor, &message),
+            "The restrictions update is not signed by the restrictions governor ({governor})"
+        );
+        // Return the restrictions list.
+        Ok(restrictions)
+    }
+
+    /// Adds the given restrictions update, ratified in the block at the given height, to the history.
+    pub fn add_update(&mut self, block_height: u32, ratify: &Ratify<N>) -> Result<()> {
+        // Check the restrictions update.
+        let restrictions = self.check_update(block_height, ratify)?;
+        // Retrieve the activation height.
+        let Ratify::Restrictions(_, activation_height, _) = ratify else {
+            bail!("Expected a restrictions update, found '{ratify}'")
+        };
+        // Add the restrictions list to the history.
+        self.versions.push(RestrictionsVersion {
+            ratified_height: block_height,
+            activation_height: *activation_height,
+            restrictions,
+        });
+        Ok(())
+    }
+
+    /// Removes the restrictions lists that were ratified after the given block height.
+    pub fn revert_to_height(&mut self, block_height: u32) {
+        // Note: The initial restrictions list is never removed.
+        let num_versions = self.versions.iter().skip(1).take_while(|v| v.ratified_height <= block_height).count();
+        self.versions.truncate(num_versions + 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    /// Returns a new restrictions history, with a sampled restrictions governor.
+    fn sample_history(rng: &mut TestRng) -> (RestrictionsHistory<CurrentNetwork>, PrivateKey<CurrentNetwork>) {
+        let private_key = PrivateKey::new(rng).unwrap();
+        let governor = Address::try_from(&private_key).unwrap();
+        let history = RestrictionsHistory::new(Restrictions::new_blank().unwrap(), Some(governor));
+        (history, private_key)
+    }
+
+    /// Returns a restrictions list that restricts the given program.
+    fn sample_restrictions(program_id: &str) -> Restrictions<CurrentNetwork> {
+        let mut restrictions = Restrictions::new_blank().unwrap();
+        restrictions.programs.insert(ProgramID::from_str(program_id).unwrap(), BlockRange::RangeFrom(0..));
+        restrictions.restrictions_id = Restrictions::compute_restrictions_id(
+            &restrictions.programs,
+            &restrictions.functions,
+            &restrictions.arguments,
+            &restrictions.imports,
+            &restrictions.addresses,
+            &restrictions.record_owners,
+        )
+        .unwrap();
+        restrictions
+    }
+
+    #[test]
+    fn test_restrictions_history_update() {
+        let rng = &mut TestRng::default();
+
+        let (mut history, private_key) = sample_history(rng);
+        let program_id = ProgramID::from_str("restricted.aleo").unwrap();
+        // Note: Restrictions updates are only supported from the consensus V3 height onwards.
+        let height = CurrentNetwork::CONSENSUS_V3_HEIGHT;
+
+        // Ratify a restrictions update in block `height + 10`, that activates at block `height + 20`.
+        let restrictions = sample_restrictions("restricted.aleo");
+        let ratify = history.sign_update(&private_key, &restrictions, height + 20, rng).unwrap();
+        history.add_update(height + 10, &ratify).unwrap();
+        assert_eq!(history.versions().len(), 2);
+        assert_eq!(history.latest(), &restrictions);
+
+        // Ensure the restrictions list is only active from the activation height.
+        assert!(!history.get(height + 19).is_program_restricted(&program_id, height + 19));
+        assert!(history.get(height + 20).is_program_restricted(&program_id, height + 20));
+        assert!(history.get(u32::MAX).is_program_restricted(&program_id, u32::MAX));
+
+        // Ensure the restrictions update cannot be ratified twice.
+        assert!(history.check_update(height + 11, &ratify).is_err());
+
+        // Ensure reverting the block that ratified the update removes it.
+        history.revert_to_height(height + 10);
+        assert_eq!(history.versions().len(), 2);
+        history.revert_to_height(height + 9);
+        assert_eq!(history.versions().len(), 1);
+        assert!(!history.get(height + 20).is_program_restricted(&program_id, height + 20));
+    }
+
+    #[test]
+    fn test_restrictions_history_invalid_update() {
+        let rng = &mut TestRng::default();
+
+        let (history, private_key) = sample_history(rng);
+        let restrictions = sample_restrictions("restricted.aleo");
+        let height = CurrentNetwork::CONSENSUS_V3_HEIGHT;
+
+        // Ensure the update must activate after the block that ratifies it.
+        let ratify = history.sign_update(&private_key, &restrictions, height + 10, rng).unwrap();
+        assert!(history.check_update(height + 10, &ratify).is_err());
+        assert!(history.check_update(height + 9, &ratify).is_ok());
+
+        // Ensure the update cannot be ratified before the consensus V3 height.
+        let ratify = history.sign_update(&private_key, &restrictions, height, rng).unwrap();
+        assert!(history.check_update(height - 1, &ratify).is_err());
+
+        // Ensure the update must be signed by the restrictions governor.
+        let other_private_key = PrivateKey::new(rng).unwrap();
+        let ratify = history.sign_update(&other_private_key, &restrictions, height + 10, rng).unwrap();
+        assert!(history.check_update(height + 9, &ratify).is_err());
+
+        // Ensure the signature covers the activation height.
+        let Ratify::Restrictions(restrictions_string, _, signature) =
+            history.sign_update(&private_key, &restrictions, height + 10, rng).unwrap()
+        else {
+            unreachable!()
+        };
+        let ratify = Ratify::Restrictions(restrictions_string, height + 11, signature);
+        assert!(history.check_update(height + 9, &ratify).is_err());
+
+        // Ensure the update must change the restrictions list.
+        let ratify = history.sign_update(&private_key, history.latest(), height + 10, rng).unwrap();
+        assert!(history.check_update(height + 9, &ratify).is_err());
+
+        // Ensure updates are rejected if there is no restrictions governor.
+        let history = RestrictionsHistory::new(Restrictions::new_blank().unwrap(), None);
+        let ratify = history.sign_update(&private_key, &restrictions, height + 10, rng).unwrap();
+        assert!(history.check_update(height + 9, &ratify).is_err());
+    }
+}
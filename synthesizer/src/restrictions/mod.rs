@@ -16,17 +16,22 @@
 mod helpers;
 pub use helpers::*;
 
+mod history;
+pub use history::*;
+
 mod serialize;
 mod string;
 
 use console::{
     network::prelude::*,
-    program::{Identifier, Literal, Locator, Plaintext, ProgramID},
-    types::Field,
+    program::{Argument, Future, Identifier, Literal, Locator, Plaintext, ProgramID},
+    types::{Address, Field},
 };
-use ledger_block::{Execution, Input, Output, Transition};
+use ledger_block::{Deployment, Execution, Input, Output, Transaction, Transition};
+use synthesizer_program::Program;
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
+use std::collections::VecDeque;
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Restrictions<N: Network> {
@@ -57,6 +62,23 @@ pub struct Restrictions<N: Network> {
     ///    - If an input or output index does not match, the additional lookup cost is `0`.
     ///    - If an input or output index matches, the additional lookup cost is `O(n)` for `n` restricted arguments with the same index.
     arguments: IndexMap<Locator<N>, IndexMap<ArgumentLocator, IndexMap<Literal<N>, BlockRange>>>,
+    /// The set of program IDs that are restricted from being imported by a deployed program.
+    /// e.g. `restricted.aleo` => `..` (all blocks)
+    /// e.g. `restricted.aleo` => `10..20` (from block 10 to block 20)
+    ///
+    /// Note: The imports are matched transitively, i.e. including the imports of the imported programs.
+    imports: IndexMap<ProgramID<N>, BlockRange>,
+    /// The set of address ranges that are restricted from appearing in a public argument of any transition.
+    /// e.g. `aleo1zkpxxxxx` => `..` (all blocks)
+    /// e.g. `aleo1aaaxxxxx..=aleo1zzzxxxxx` => `10..20` (from block 10 to block 20)
+    ///
+    /// Note: The public and constant inputs and outputs are matched, including the members of structs,
+    /// the elements of arrays, and the arguments of futures. Private arguments are encrypted, and cannot be matched.
+    addresses: IndexMap<AddressRange<N>, BlockRange>,
+    /// The set of owners that are restricted from owning a record output of any transition.
+    /// e.g. `aleo1zkpxxxxx` => `..` (all blocks, for the records with a public owner)
+    /// e.g. `AViewKey1xxxxx` => `10..20` (from block 10 to block 20, for the records with a public or private owner)
+    record_owners: IndexMap<RecordOwner<N>, BlockRange>,
 }
 
 impl<N: Network> Restrictions<N> {
@@ -65,13 +87,7 @@ impl<N: Network> Restrictions<N> {
         // Load the restrictions list from the network.
         let restrictions = Self::from_str(N::restrictions_list_as_str())?;
         // Ensure the restrictions ID matches the computed value.
-        let expected_restrictions_id =
-            Self::compute_restrictions_id(&restrictions.programs, &restrictions.functions, &restrictions.arguments)?;
-        if restrictions.restrictions_id != expected_restrictions_id {
-            bail!(
-                "The restrictions ID does not match the computed value upon initialization (expected - {expected_restrictions_id})"
-            );
-        }
+        restrictions.check_restrictions_id()?;
         // Return the restrictions.
         Ok(restrictions)
     }
@@ -79,10 +95,20 @@ impl<N: Network> Restrictions<N> {
     /// Initializes a new `Restrictions` instance.
     pub fn new_blank() -> Result<Self> {
         Ok(Self {
-            restrictions_id: Self::compute_restrictions_id(&IndexMap::new(), &IndexMap::new(), &IndexMap::new())?,
+            restrictions_id: Self::compute_restrictions_id(
+                &IndexMap::new(),
+                &IndexMap::new(),
+                &IndexMap::new(),
+                &IndexMap::new(),
+                &IndexMap::new(),
+                &IndexMap::new(),
+            )?,
             programs: IndexMap::new(),
             functions: IndexMap::new(),
             arguments: IndexMap::new(),
+            imports: IndexMap::new(),
+            addresses: IndexMap::new(),
+            record_owners: IndexMap::new(),
         })
     }
 }
@@ -109,6 +135,21 @@ impl<N: Network> Restrictions<N> {
     ) -> &IndexMap<Locator<N>, IndexMap<ArgumentLocator, IndexMap<Literal<N>, BlockRange>>> {
         &self.arguments
     }
+
+    /// Returns the set of program IDs that are restricted from being imported by a deployed program.
+    pub const fn imports(&self) -> &IndexMap<ProgramID<N>, BlockRange> {
+        &self.imports
+    }
+
+    /// Returns the set of address ranges that are restricted from appearing in a public argument of any transition.
+    pub const fn addresses(&self) -> &IndexMap<AddressRange<N>, BlockRange> {
+        &self.addresses
+    }
+
+    /// Returns the set of owners that are restricted from owning a record output of any transition.
+    pub const fn record_owners(&self) -> &IndexMap<RecordOwner<N>, BlockRange> {
+        &self.record_owners
+    }
 }
 
 impl<N: Network> Restrictions<N> {
@@ -131,93 +172,297 @@ impl<N: Network> Restrictions<N> {
 
     /// Returns `true` if the given `(program ID, function ID, argument)` triple is restricted from being executed.
     pub fn is_argument_restricted(&self, transition: &Transition<N>, block_height: u32) -> bool {
-        self.arguments.get(&Locator::new(*transition.program_id(), *transition.function_name())).map_or(
-            false,
-            |entries| {
-                // Check if any argument is restricted and return `true` if one is found.
-                for (argument_locator, arguments) in entries {
-                    match argument_locator.is_input() {
-                        true => {
-                            if let Some(argument) = transition.inputs().get(argument_locator.index() as usize) {
-                                match argument {
-                                    Input::Constant(_, Some(plaintext)) | Input::Public(_, Some(plaintext)) => {
-                                        match plaintext {
-                                            Plaintext::Literal(literal, _) => {
-                                                if let Some(range) = arguments.get(literal) {
-                                                    if range.contains(block_height) {
-                                                        return true;
-                                                    }
+        self.find_restricted_argument(transition, block_height).is_some()
+    }
+
+    /// Returns the restricted argument of the given transition, as `(argument locator, literal)`, if one is found.
+    pub fn find_restricted_argument(
+        &self,
+        transition: &Transition<N>,
+        block_height: u32,
+    ) -> Option<(ArgumentLocator, Literal<N>)> {
+        self.arguments.get(&Locator::new(*transition.program_id(), *transition.function_name())).and_then(|entries| {
+            // Check if any argument is restricted and return it if one is found.
+            for (argument_locator, arguments) in entries {
+                match argument_locator.is_input() {
+                    true => {
+                        if let Some(argument) = transition.inputs().get(argument_locator.index() as usize) {
+                            match argument {
+                                Input::Constant(_, Some(plaintext)) | Input::Public(_, Some(plaintext)) => {
+                                    match plaintext {
+                                        Plaintext::Literal(literal, _) => {
+                                            if let Some(range) = arguments.get(literal) {
+                                                if range.contains(block_height) {
+                                                    return Some((*argument_locator, literal.clone()));
                                                 }
                                             }
-                                            Plaintext::Struct(..) | Plaintext::Array(..) => continue,
                                         }
+                                        Plaintext::Struct(..) | Plaintext::Array(..) => continue,
                                     }
-                                    _ => continue,
                                 }
+                                _ => continue,
                             }
                         }
-                        false => {
-                            if let Some(argument) = transition.outputs().get(argument_locator.index() as usize) {
-                                match argument {
-                                    Output::Constant(_, Some(plaintext)) | Output::Public(_, Some(plaintext)) => {
-                                        match plaintext {
-                                            Plaintext::Literal(literal, _) => {
-                                                if let Some(range) = arguments.get(literal) {
-                                                    if range.contains(block_height) {
-                                                        return true;
-                                                    }
+                    }
+                    false => {
+                        if let Some(argument) = transition.outputs().get(argument_locator.index() as usize) {
+                            match argument {
+                                Output::Constant(_, Some(plaintext)) | Output::Public(_, Some(plaintext)) => {
+                                    match plaintext {
+                                        Plaintext::Literal(literal, _) => {
+                                            if let Some(range) = arguments.get(literal) {
+                                                if range.contains(block_height) {
+                                                    return Some((*argument_locator, literal.clone()));
                                                 }
                                             }
-                                            Plaintext::Struct(..) | Plaintext::Array(..) => continue,
                                         }
+                                        Plaintext::Struct(..) | Plaintext::Array(..) => continue,
                                     }
-                                    _ => continue,
                                 }
+                                _ => continue,
                             }
                         }
                     }
                 }
-                // Otherwise, return `false`.
-                false
-            },
-        )
+            }
+            // Otherwise, return `None`.
+            None
+        })
     }
 }
 
 impl<N: Network> Restrictions<N> {
-    /// Returns `true` if the given execution contains any restricted transitions for the given block height.
-    pub fn contains_restricted_transitions(&self, execution: &Execution<N>, block_height: u32) -> bool {
-        // Check if any transition is restricted.
-        execution.transitions().any(|transition| {
-            // Retrieve the program ID.
-            let program_id = transition.program_id();
-            // Retrieve the function name.
-            let function_name = transition.function_name();
-
-            // If the program is restricted, then the transition is restricted.
-            if self.is_program_restricted(program_id, block_height) {
-                return true;
+    /// Returns `true` if the given address is in a restricted address range at the given block height.
+    pub fn is_address_restricted(&self, address: &Address<N>, block_height: u32) -> bool {
+        self.addresses.iter().any(|(addresses, range)| range.contains(block_height) && addresses.contains(address))
+    }
+
+    /// Returns the restricted address among the public and constant arguments of the given transition,
+    /// as `(argument locator, address)`, if one is found.
+    pub fn find_restricted_address(
+        &self,
+        transition: &Transition<N>,
+        block_height: u32,
+    ) -> Option<(ArgumentLocator, Address<N>)> {
+        // If there are no restricted addresses, return early.
+        if self.addresses.is_empty() {
+            return None;
+        }
+        // Check the inputs of the transition.
+        let input = transition.inputs().iter().enumerate().find_map(|(index, input)| {
+            let address = match input {
+                Input::Constant(_, Some(plaintext)) | Input::Public(_, Some(plaintext)) => {
+                    self.find_restricted_address_in_plaintext(plaintext, block_height)
+                }
+                _ => None,
+            }?;
+            Some((ArgumentLocator::new(true, u16::try_from(index).ok()?), address))
+        });
+        // Check the outputs of the transition.
+        input.or_else(|| {
+            transition.outputs().iter().enumerate().find_map(|(index, output)| {
+                let address = match output {
+                    Output::Constant(_, Some(plaintext)) | Output::Public(_, Some(plaintext)) => {
+                        self.find_restricted_address_in_plaintext(plaintext, block_height)
+                    }
+                    Output::Future(_, Some(future)) => self.find_restricted_address_in_future(future, block_height),
+                    _ => None,
+                }?;
+                Some((ArgumentLocator::new(false, u16::try_from(index).ok()?), address))
+            })
+        })
+    }
+
+    /// Returns the restricted address in the given plaintext, including the members of structs
+    /// and the elements of arrays, if one is found.
+    fn find_restricted_address_in_plaintext(&self, plaintext: &Plaintext<N>, block_height: u32) -> Option<Address<N>> {
+        match plaintext {
+            Plaintext::Literal(Literal::Address(address), _) => {
+                self.is_address_restricted(address, block_height).then_some(*address)
             }
-            // If the function is restricted, then the transition is restricted.
-            if self.is_function_restricted(program_id, function_name, block_height) {
-                return true;
+            Plaintext::Literal(..) => None,
+            Plaintext::Struct(members, _) => {
+                members.values().find_map(|member| self.find_restricted_address_in_plaintext(member, block_height))
             }
-            // If any argument is restricted, then the transition is restricted.
-            if self.is_argument_restricted(transition, block_height) {
-                return true;
+            Plaintext::Array(elements, _) => {
+                elements.iter().find_map(|element| self.find_restricted_address_in_plaintext(element, block_height))
+            }
+        }
+    }
+
+    /// Returns the restricted address in the arguments of the given future, including its nested futures,
+    /// if one is found.
+    fn find_restricted_address_in_future(&self, future: &Future<N>, block_height: u32) -> Option<Address<N>> {
+        future.arguments().iter().find_map(|argument| match argument {
+            Argument::Plaintext(plaintext) => self.find_restricted_address_in_plaintext(plaintext, block_height),
+            Argument::Future(future) => self.find_restricted_address_in_future(future, block_height),
+        })
+    }
+
+    /// Returns the restricted owner among the record outputs of the given transition,
+    /// as `(output index, address)`, if one is found.
+    pub fn find_restricted_record_owner(
+        &self,
+        transition: &Transition<N>,
+        block_height: u32,
+    ) -> Option<(u16, Address<N>)> {
+        // If there are no restricted record owners, return early.
+        if self.record_owners.is_empty() {
+            return None;
+        }
+        transition.outputs().iter().enumerate().find_map(|(index, output)| match output {
+            Output::Record(_, _, Some(record)) => {
+                let (owner, _) = self
+                    .record_owners
+                    .iter()
+                    .find(|(owner, range)| range.contains(block_height) && owner.is_owner(record))?;
+                Some((u16::try_from(index).ok()?, owner.address()))
             }
-            // Otherwise, the transition is not restricted.
-            false
+            _ => None,
         })
     }
+
+    /// Returns the restricted import of the given program, if one is found.
+    /// The imports are matched transitively, where `get_imports` returns the imports of a deployed program
+    /// (or no imports if the program is not deployed, as the deployment is then rejected for its missing import).
+    pub fn find_restricted_import(
+        &self,
+        program: &Program<N>,
+        block_height: u32,
+        get_imports: impl Fn(&ProgramID<N>) -> Vec<ProgramID<N>>,
+    ) -> Option<ProgramID<N>> {
+        // If there are no restricted imports, return early.
+        if self.imports.is_empty() {
+            return None;
+        }
+        // Traverse the imports in breadth-first order, starting with the direct imports of the program.
+        let mut visited = IndexSet::new();
+        let mut queue = program.imports().keys().copied().collect::<VecDeque<_>>();
+        while let Some(import_id) = queue.pop_front() {
+            // Skip the imports that were already checked.
+            if !visited.insert(import_id) {
+                continue;
+            }
+            if self.imports.get(&import_id).map_or(false, |range| range.contains(block_height)) {
+                return Some(import_id);
+            }
+            queue.extend(get_imports(&import_id));
+        }
+        None
+    }
 }
 
 impl<N: Network> Restrictions<N> {
+    /// Returns `true` if the given execution contains any restricted transitions for the given block height.
+    pub fn contains_restricted_transitions(&self, execution: &Execution<N>, block_height: u32) -> bool {
+        self.explain_execution(execution, block_height).is_some()
+    }
+
+    /// Returns `true` if the given deployment is restricted for the given block height,
+    /// where `get_imports` returns the imports of a deployed program.
+    pub fn is_deployment_restricted(
+        &self,
+        deployment: &Deployment<N>,
+        block_height: u32,
+        get_imports: impl Fn(&ProgramID<N>) -> Vec<ProgramID<N>>,
+    ) -> bool {
+        self.explain_deployment(deployment, block_height, get_imports).is_some()
+    }
+
+    /// Returns the restriction rule that matches the given transaction for the given block height, if any,
+    /// where `get_imports` returns the imports of a deployed program.
+    pub fn explain(
+        &self,
+        transaction: &Transaction<N>,
+        block_height: u32,
+        get_imports: impl Fn(&ProgramID<N>) -> Vec<ProgramID<N>>,
+    ) -> Option<RestrictionMatch<N>> {
+        match transaction {
+            Transaction::Deploy(_, _, deployment, _) => self.explain_deployment(deployment, block_height, get_imports),
+            Transaction::Execute(_, execution, _) => self.explain_execution(execution, block_height),
+            Transaction::Fee(..) => None,
+        }
+    }
+
+    /// Returns the restriction rule that matches the given deployment for the given block height, if any,
+    /// where `get_imports` returns the imports of a deployed program.
+    pub fn explain_deployment(
+        &self,
+        deployment: &Deployment<N>,
+        block_height: u32,
+        get_imports: impl Fn(&ProgramID<N>) -> Vec<ProgramID<N>>,
+    ) -> Option<RestrictionMatch<N>> {
+        self.find_restricted_import(deployment.program(), block_height, get_imports)
+            .map(|import_id| RestrictionMatch::Import(*deployment.program_id(), import_id))
+    }
+
+    /// Returns the restriction rule that matches the given execution for the given block height, if any.
+    pub fn explain_execution(&self, execution: &Execution<N>, block_height: u32) -> Option<RestrictionMatch<N>> {
+        execution.transitions().find_map(|transition| self.explain_transition(transition, block_height))
+    }
+
+    /// Returns the restriction rule that matches the given transition for the given block height, if any.
+    pub fn explain_transition(&self, transition: &Transition<N>, block_height: u32) -> Option<RestrictionMatch<N>> {
+        // Retrieve the program ID.
+        let program_id = transition.program_id();
+        // Retrieve the function name.
+        let function_name = transition.function_name();
+
+        // If the program is restricted, then the transition is restricted.
+        if self.is_program_restricted(program_id, block_height) {
+            return Some(RestrictionMatch::Program(*program_id));
+        }
+        // If the function is restricted, then the transition is restricted.
+        if self.is_function_restricted(program_id, function_name, block_height) {
+            return Some(RestrictionMatch::Function(Locator::new(*program_id, *function_name)));
+        }
+        // If any argument is restricted, then the transition is restricted.
+        if let Some((argument_locator, literal)) = self.find_restricted_argument(transition, block_height) {
+            return Some(RestrictionMatch::Argument(
+                Locator::new(*program_id, *function_name),
+                argument_locator,
+                literal,
+            ));
+        }
+        // If any public argument contains a restricted address, then the transition is restricted.
+        if let Some((argument_locator, address)) = self.find_restricted_address(transition, block_height) {
+            let locator = Locator::new(*program_id, *function_name);
+            return Some(RestrictionMatch::Address(locator, argument_locator, address));
+        }
+        // If any record output is owned by a restricted address, then the transition is restricted.
+        if let Some((index, address)) = self.find_restricted_record_owner(transition, block_height) {
+            return Some(RestrictionMatch::RecordOwner(Locator::new(*program_id, *function_name), index, address));
+        }
+        // Otherwise, the transition is not restricted.
+        None
+    }
+}
+
+impl<N: Network> Restrictions<N> {
+    /// Ensures the restrictions ID matches the computed value.
+    fn check_restrictions_id(&self) -> Result<()> {
+        let expected_restrictions_id = Self::compute_restrictions_id(
+            &self.programs,
+            &self.functions,
+            &self.arguments,
+            &self.imports,
+            &self.addresses,
+            &self.record_owners,
+        )?;
+        if self.restrictions_id != expected_restrictions_id {
+            bail!("The restrictions ID does not match the computed value (expected - {expected_restrictions_id})");
+        }
+        Ok(())
+    }
+
     /// Returns the restrictions ID.
     pub fn compute_restrictions_id(
         programs: &IndexMap<ProgramID<N>, BlockRange>,
         functions: &IndexMap<Locator<N>, BlockRange>,
         arguments: &IndexMap<Locator<N>, IndexMap<ArgumentLocator, IndexMap<Literal<N>, BlockRange>>>,
+        imports: &IndexMap<ProgramID<N>, BlockRange>,
+        addresses: &IndexMap<AddressRange<N>, BlockRange>,
+        record_owners: &IndexMap<RecordOwner<N>, BlockRange>,
     ) -> Result<Field<N>> {
         // Prepare the preimage data.
         let mut preimage = Vec::new();
@@ -263,6 +508,35 @@ impl<N: Network> Restrictions<N> {
             }
         }
 
+        // Encode the pattern restrictions, if there are any.
+        // Note: This ensures the restrictions ID of a list without pattern restrictions is unchanged.
+        if !imports.is_empty() || !addresses.is_empty() || !record_owners.is_empty() {
+            // Append the number of imports.
+            preimage.push(Field::from_u64(imports.len() as u64));
+            // Encode the imports.
+            for (program_id, range) in imports {
+                preimage.extend_from_slice(&program_id.to_fields()?);
+                preimage.extend_from_slice(&range.to_fields()?);
+            }
+
+            // Append the number of address ranges.
+            preimage.push(Field::from_u64(addresses.len() as u64));
+            // Encode the address ranges.
+            for (addresses, range) in addresses {
+                preimage.push(addresses.start().to_field()?);
+                preimage.push(addresses.end().to_field()?);
+                preimage.extend_from_slice(&range.to_fields()?);
+            }
+
+            // Append the number of record owners.
+            preimage.push(Field::from_u64(record_owners.len() as u64));
+            // Encode the record owners.
+            for (owner, range) in record_owners {
+                preimage.extend_from_slice(&owner.to_fields()?);
+                preimage.extend_from_slice(&range.to_fields()?);
+            }
+        }
+
         // Hash the preimage data.
         // Note: This call must be collision-resistant, and so we use BHP-1024.
         N::hash_bhp1024(&preimage.to_bits_le())
@@ -272,7 +546,11 @@ impl<N: Network> Restrictions<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::types::I8;
+    use console::{
+        account::{PrivateKey, ViewKey},
+        program::Record,
+        types::{I8, Scalar},
+    };
 
     use indexmap::indexmap;
     use ledger_block::Input;
@@ -332,6 +610,184 @@ mod tests {
         assert!(!restrictions.is_argument_restricted(&transition, 25));
     }
 
+    #[test]
+    fn test_restrictions_import_restricted() {
+        let mut restrictions = Restrictions::<CurrentNetwork>::new_blank().unwrap();
+        let program_id = ProgramID::from_str("restricted.aleo").unwrap();
+        restrictions.imports.insert(program_id, BlockRange::Range(10..20));
+
+        let program = Program::from_str(
+            r"
+import restricted.aleo;
+program importer.aleo;
+function foo:
+    input r0 as u8.public;",
+        )
+        .unwrap();
+        assert_eq!(restrictions.find_restricted_import(&program, 5, |_| vec![]), None);
+        assert_eq!(restrictions.find_restricted_import(&program, 10, |_| vec![]), Some(program_id));
+        assert_eq!(restrictions.find_restricted_import(&program, 20, |_| vec![]), None);
+
+        // Ensure a transitive import is matched, through the imports of the imported program.
+        let importer_id = *program.id();
+        let program = Program::from_str(
+            r"
+import importer.aleo;
+program other.aleo;
+function foo:
+    input r0 as u8.public;",
+        )
+        .unwrap();
+        let get_imports = |import_id: &ProgramID<CurrentNetwork>| match *import_id == importer_id {
+            true => vec![program_id],
+            false => vec![],
+        };
+        assert_eq!(restrictions.find_restricted_import(&program, 10, get_imports), Some(program_id));
+        assert_eq!(restrictions.find_restricted_import(&program, 10, |_| vec![]), None);
+
+        let program = Program::from_str(
+            r"
+program other.aleo;
+function foo:
+    input r0 as u8.public;",
+        )
+        .unwrap();
+        assert_eq!(restrictions.find_restricted_import(&program, 10, get_imports), None);
+    }
+
+    #[test]
+    fn test_restrictions_address_restricted() {
+        let rng = &mut TestRng::default();
+
+        let mut restrictions = Restrictions::<CurrentNetwork>::new_blank().unwrap();
+        let program_id = ProgramID::from_str("restricted.aleo").unwrap();
+        let function_id = Identifier::from_str("bar").unwrap();
+        let address = Address::rand(rng);
+        restrictions.addresses.insert(AddressRange::single(address), BlockRange::Range(10..20));
+
+        // Ensure the address is matched in any public or constant input position.
+        let inputs = vec![
+            Input::Public(rng.gen(), Some(Literal::I8(I8::new(42)).into())),
+            Input::Constant(rng.gen(), Some(Literal::Address(address).into())),
+        ];
+        let transition =
+            Transition::new(program_id, function_id, inputs, vec![], rng.gen(), rng.gen(), rng.gen()).unwrap();
+        let argument_locator = ArgumentLocator::new(true, 1);
+        assert_eq!(restrictions.find_restricted_address(&transition, 5), None);
+        assert_eq!(restrictions.find_restricted_address(&transition, 10), Some((argument_locator, address)));
+        assert_eq!(
+            restrictions.explain_transition(&transition, 15),
+            Some(RestrictionMatch::Address(Locator::new(program_id, function_id), argument_locator, address))
+        );
+        assert_eq!(restrictions.explain_transition(&transition, 20), None);
+
+        // Ensure the address is matched in a struct member, an array element, and a future argument.
+        let struct_ = Plaintext::from_str(&format!("{{ amount: 1u64, owners: [{address}] }}")).unwrap();
+        let future = Future::new(program_id, function_id, vec![Argument::Plaintext(struct_.clone())]);
+        let input = Input::Public(rng.gen(), Some(struct_));
+        let output = Output::Future(rng.gen(), Some(future));
+        let transition =
+            Transition::new(program_id, function_id, vec![input], vec![], rng.gen(), rng.gen(), rng.gen()).unwrap();
+        assert_eq!(
+            restrictions.find_restricted_address(&transition, 10),
+            Some((ArgumentLocator::new(true, 0), address))
+        );
+        let transition =
+            Transition::new(program_id, function_id, vec![], vec![output], rng.gen(), rng.gen(), rng.gen()).unwrap();
+        assert_eq!(
+            restrictions.find_restricted_address(&transition, 10),
+            Some((ArgumentLocator::new(false, 0), address))
+        );
+
+        // Ensure a different address is not matched.
+        let input = Input::Public(rng.gen(), Some(Literal::Address(Address::rand(rng)).into()));
+        let transition =
+            Transition::new(program_id, function_id, vec![input], vec![], rng.gen(), rng.gen(), rng.gen()).unwrap();
+        assert_eq!(restrictions.explain_transition(&transition, 15), None);
+    }
+
+    #[test]
+    fn test_restrictions_address_range_restricted() {
+        let rng = &mut TestRng::default();
+
+        let mut restrictions = Restrictions::<CurrentNetwork>::new_blank().unwrap();
+        let program_id = ProgramID::from_str("restricted.aleo").unwrap();
+        let function_id = Identifier::from_str("bar").unwrap();
+
+        // Sample addresses, ordered by their x-coordinate.
+        let mut addresses = (0..5).map(|_| Address::<CurrentNetwork>::rand(rng)).collect::<Vec<_>>();
+        addresses.sort_by_key(|address| address.to_x_coordinate());
+        restrictions.addresses.insert(AddressRange::new(addresses[1], addresses[3]).unwrap(), BlockRange::FullRange);
+
+        // Ensure exactly the addresses within the range are matched.
+        for (index, address) in addresses.iter().enumerate() {
+            let input = Input::Public(rng.gen(), Some(Literal::Address(*address).into()));
+            let transition =
+                Transition::new(program_id, function_id, vec![input], vec![], rng.gen(), rng.gen(), rng.gen()).unwrap();
+            let expected = (1..=3).contains(&index).then_some((ArgumentLocator::new(true, 0), *address));
+            assert_eq!(restrictions.find_restricted_address(&transition, 0), expected);
+        }
+    }
+
+    #[test]
+    fn test_restrictions_record_owner_restricted() {
+        let rng = &mut TestRng::default();
+
+        let mut restrictions = Restrictions::<CurrentNetwork>::new_blank().unwrap();
+        let program_id = ProgramID::from_str("restricted.aleo").unwrap();
+        let function_id = Identifier::from_str("bar").unwrap();
+        let address = Address::rand(rng);
+        restrictions.record_owners.insert(RecordOwner::Address(address), BlockRange::FullRange);
+
+        // Construct a record output with a public owner.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {address}.public, amount: 1u64.private, _nonce: 0group.public }}"
+        ))
+        .unwrap();
+        let output = Output::Record(rng.gen(), rng.gen(), Some(record.encrypt(Scalar::rand(rng)).unwrap()));
+        let transition =
+            Transition::new(program_id, function_id, vec![], vec![output], rng.gen(), rng.gen(), rng.gen()).unwrap();
+        assert_eq!(restrictions.find_restricted_record_owner(&transition, 0), Some((0, address)));
+        assert_eq!(
+            restrictions.explain_transition(&transition, 0),
+            Some(RestrictionMatch::RecordOwner(Locator::new(program_id, function_id), 0, address))
+        );
+    }
+
+    #[test]
+    fn test_restrictions_private_record_owner_restricted() {
+        let rng = &mut TestRng::default();
+
+        let mut restrictions = Restrictions::<CurrentNetwork>::new_blank().unwrap();
+        let program_id = ProgramID::from_str("restricted.aleo").unwrap();
+        let function_id = Identifier::from_str("bar").unwrap();
+        let private_key = PrivateKey::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let address = view_key.to_address();
+
+        // Construct a record output with a private owner.
+        let randomizer = Scalar::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {address}.private, amount: 1u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let output = Output::Record(rng.gen(), rng.gen(), Some(record.encrypt(randomizer).unwrap()));
+        let transition =
+            Transition::new(program_id, function_id, vec![], vec![output], rng.gen(), rng.gen(), rng.gen()).unwrap();
+
+        // Ensure the private owner is not matched by its address.
+        restrictions.record_owners.insert(RecordOwner::Address(address), BlockRange::FullRange);
+        assert_eq!(restrictions.find_restricted_record_owner(&transition, 0), None);
+        // Ensure the private owner is matched by its view key.
+        restrictions.record_owners.insert(RecordOwner::ViewKey(view_key), BlockRange::FullRange);
+        assert_eq!(restrictions.find_restricted_record_owner(&transition, 0), Some((0, address)));
+        // Ensure the view key of another owner is not matched.
+        let other_view_key = ViewKey::try_from(&PrivateKey::new(rng).unwrap()).unwrap();
+        restrictions.record_owners = indexmap!(RecordOwner::ViewKey(other_view_key) => BlockRange::FullRange);
+        assert_eq!(restrictions.find_restricted_record_owner(&transition, 0), None);
+    }
+
     /// **Attention**: This method is used to auto-generate the restrictions lists for each network
     /// to be used by the `snarkvm_parameters` crate.
     #[test]
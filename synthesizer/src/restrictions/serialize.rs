@@ -22,11 +22,21 @@ impl<N: Network + Serialize> Serialize for Restrictions<N> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("Restrictions", 4)?;
+        let mut state = serializer.serialize_struct("Restrictions", 7)?;
         state.serialize_field("restrictions_id", &self.restrictions_id)?;
         state.serialize_field("programs", &self.programs)?;
         state.serialize_field("functions", &self.functions)?;
         state.serialize_field("arguments", &self.arguments)?;
+        // Note: The pattern restrictions are omitted when empty, to preserve the format of existing lists.
+        if !self.imports.is_empty() {
+            state.serialize_field("imports", &self.imports)?;
+        }
+        if !self.addresses.is_empty() {
+            state.serialize_field("addresses", &self.addresses)?;
+        }
+        if !self.record_owners.is_empty() {
+            state.serialize_field("record_owners", &self.record_owners)?;
+        }
         state.end()
     }
 }
@@ -42,14 +52,31 @@ impl<'de, N: Network> Deserialize<'de> for Restrictions<N> {
             programs: DeserializeExt::take_from_value::<D>(&mut restrictions, "programs")?,
             functions: DeserializeExt::take_from_value::<D>(&mut restrictions, "functions")?,
             arguments: DeserializeExt::take_from_value::<D>(&mut restrictions, "arguments")?,
+            imports: take_from_value_or_default::<D, _>(&mut restrictions, "imports")?,
+            addresses: take_from_value_or_default::<D, _>(&mut restrictions, "addresses")?,
+            record_owners: take_from_value_or_default::<D, _>(&mut restrictions, "record_owners")?,
         })
     }
 }
 
+/// Returns the value of the given field, or the default value if the field is missing.
+fn take_from_value_or_default<'de, D: Deserializer<'de>, T: DeserializeOwned + Default>(
+    value: &mut serde_json::Value,
+    field: &str,
+) -> Result<T, D::Error> {
+    match value.get(field) {
+        Some(_) => DeserializeExt::take_from_value::<D>(value, field),
+        None => Ok(T::default()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::types::Address;
+    use console::{
+        account::{PrivateKey, ViewKey},
+        types::Address,
+    };
 
     use rand::seq::SliceRandom;
 
@@ -130,11 +157,39 @@ mod tests {
             }
             restrictions.arguments.insert(locator, arguments);
         }
+        // Add the import restrictions.
+        for _ in 0..NUM_RESTRICTIONS {
+            let program_id = sample_program_id(rng);
+            let range = sample_block_range(rng);
+            restrictions.imports.insert(program_id, range);
+        }
+        // Add the address restrictions.
+        for _ in 0..NUM_RESTRICTIONS {
+            let (start, end) = (Address::rand(rng), Address::rand(rng));
+            let addresses = match start.to_x_coordinate() <= end.to_x_coordinate() {
+                true => AddressRange::new(start, end).unwrap(),
+                false => AddressRange::single(start),
+            };
+            let range = sample_block_range(rng);
+            restrictions.addresses.insert(addresses, range);
+        }
+        // Add the record owner restrictions.
+        for _ in 0..NUM_RESTRICTIONS {
+            let owner = match rng.gen::<bool>() {
+                true => RecordOwner::Address(Address::rand(rng)),
+                false => RecordOwner::ViewKey(ViewKey::try_from(&PrivateKey::new(rng).unwrap()).unwrap()),
+            };
+            let range = sample_block_range(rng);
+            restrictions.record_owners.insert(owner, range);
+        }
         // Set the restrictions ID.
         restrictions.restrictions_id = Restrictions::compute_restrictions_id(
             &restrictions.programs,
             &restrictions.functions,
             &restrictions.arguments,
+            &restrictions.imports,
+            &restrictions.addresses,
+            &restrictions.record_owners,
         )
         .unwrap();
         // Return the restrictions.
@@ -44,6 +44,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         rng: &mut R,
     ) -> Result<(Ratifications<N>, Transactions<N>, Vec<N::TransactionID>, Vec<FinalizeOperation<N>>)> {
         let timer = timer!("VM::speculate");
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
 
         // Collect the candidate transactions into a vector.
         let candidate_transactions: Vec<_> = candidate_transactions.collect::<Vec<_>>();
@@ -89,6 +91,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             .collect();
 
         finish!(timer, "Finished dry-run of the transactions");
+        #[cfg(feature = "metrics")]
+        metrics::histogram(metrics::vm::SPECULATE_LATENCY, start.elapsed().as_secs_f64());
 
         // Return the ratifications, confirmed transactions, aborted transaction IDs, and ratified finalize operations.
         Ok((
@@ -201,6 +205,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ///   - If `coinbase_reward = Some(coinbase_reward)`, then the method will append a
     ///     `Ratify::BlockReward(block_reward)` and `Ratify::PuzzleReward(puzzle_reward)`
     ///     to the front of the `ratifications` list.
+    #[cfg_attr(
+        feature = "spans",
+        tracing::instrument(
+            name = "VM::atomic_speculate",
+            skip_all,
+            fields(height = state.block_height(), num_transactions = transactions.len())
+        )
+    )]
     fn atomic_speculate<'a>(
         &self,
         state: FinalizeGlobalState,
@@ -250,12 +262,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // Initialize an iterator for ratifications before finalize.
             let pre_ratifications = ratifications.iter().filter(|r| match r {
                 Ratify::Genesis(_, _, _) => true,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => false,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::Restrictions(..) => false,
             });
             // Initialize an iterator for ratifications after finalize.
             let post_ratifications = ratifications.iter().filter(|r| match r {
                 Ratify::Genesis(_, _, _) => false,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => true,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::Restrictions(..) => true,
             });
 
             // Initialize a list of finalize operations.
@@ -301,6 +313,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             // Finalize the transactions.
             'outer: for transaction in transactions {
+                #[cfg(feature = "spans")]
+                let _span = transaction_span(transaction).entered();
+
                 // Ensure the number of confirmed transactions does not exceed the maximum.
                 // Upon reaching the maximum number of confirmed transactions, all remaining transactions are aborted.
                 if confirmed.len() >= Self::MAXIMUM_CONFIRMED_TRANSACTIONS {
@@ -495,8 +510,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // Compute the block reward.
                     let block_reward = ledger_block::block_reward::<N>(
                         state.block_height(),
-                        N::STARTING_SUPPLY,
-                        N::BLOCK_TIME,
+                        N::starting_supply(),
+                        N::block_time(),
                         time_since_last_block,
                         coinbase_reward,
                         transaction_fees,
@@ -513,7 +528,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let post_ratifications = reward_ratifications.iter().chain(post_ratifications);
 
             // Process the post-ratifications.
-            match Self::atomic_post_ratify::<false>(&self.puzzle, store, state, post_ratifications, solutions) {
+            match Self::atomic_post_ratify::<false>(
+                &self.puzzle,
+                &self.restrictions.read(),
+                store,
+                state,
+                post_ratifications,
+                solutions,
+            ) {
                 // Store the finalize operations from the post-ratify.
                 Ok(operations) => ratified_finalize_operations.extend(operations),
                 // Note: This will abort the entire atomic batch.
@@ -541,6 +563,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ///
     /// Returns the finalize operations from pre-ratify and post-ratify.
     #[inline]
+    #[cfg_attr(
+        feature = "spans",
+        tracing::instrument(
+            name = "VM::atomic_finalize",
+            skip_all,
+            fields(height = state.block_height(), num_transactions = transactions.len())
+        )
+    )]
     fn atomic_finalize(
         &self,
         state: FinalizeGlobalState,
@@ -560,12 +590,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // Initialize an iterator for ratifications before finalize.
             let pre_ratifications = ratifications.iter().filter(|r| match r {
                 Ratify::Genesis(_, _, _) => true,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => false,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::Restrictions(..) => false,
             });
             // Initialize an iterator for ratifications after finalize.
             let post_ratifications = ratifications.iter().filter(|r| match r {
                 Ratify::Genesis(_, _, _) => false,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => true,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::Restrictions(..) => true,
             });
 
             // Initialize a list of finalize operations.
@@ -595,6 +625,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             // Finalize the transactions.
             for (index, transaction) in transactions.iter().enumerate() {
+                #[cfg(feature = "spans")]
+                let _span = transaction_span(transaction.transaction()).entered();
+
                 // Convert the transaction index to a u32.
                 // Note: On failure, this will abort the entire atomic batch.
                 let index = u32::try_from(index).map_err(|_| "Failed to convert transaction index".to_string())?;
@@ -757,7 +790,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             /* Perform the ratifications after finalize. */
 
-            match Self::atomic_post_ratify::<true>(&self.puzzle, store, state, post_ratifications, solutions) {
+            match Self::atomic_post_ratify::<true>(
+                &self.puzzle,
+                &self.restrictions.read(),
+                store,
+                state,
+                post_ratifications,
+                solutions,
+            ) {
                 // Store the finalize operations from the post-ratify.
                 Ok(operations) => ratified_finalize_operations.extend(operations),
                 // Note: This will abort the entire atomic batch.
@@ -766,6 +806,18 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             /* Start the commit process. */
 
+            // Store the rollback entries, so that this block can be reverted.
+            // Note: This will abort the entire atomic batch.
+            if let Err(e) = store.insert_rollback_entries(state.block_height()) {
+                return Err(format!("Failed to store the rollback entries - {e}"));
+            }
+            // Store the ratified finalize operations, so that the finalize root of this block can be recomputed.
+            // Note: This will abort the entire atomic batch.
+            if let Err(e) = store.insert_ratified_operations(state.block_height(), ratified_finalize_operations.clone())
+            {
+                return Err(format!("Failed to store the ratified finalize operations - {e}"));
+            }
+
             // Commit all of the stacks to the process.
             if !stacks.is_empty() {
                 stacks.into_iter().for_each(|stack| process.add_stack(stack))
@@ -996,7 +1048,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // Compute the next committee size.
                 let next_committee_size = committee_members.len().saturating_add(num_new_validators);
                 // Check that the number of new validators being bonded does not exceed the maximum number of validators.
-                match next_committee_size > Committee::<N>::MAX_COMMITTEE_SIZE as usize {
+                match next_committee_size > Committee::<N>::max_committee_size() as usize {
                     true => Err(anyhow!("Call to 'credits.aleo/bond_public' exceeds the committee size")),
                     false => Ok(()),
                 }
@@ -1046,7 +1098,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     );
                     // Ensure that the number of members in the committee does not exceed the maximum.
                     ensure!(
-                        committee.members().len() <= Committee::<N>::MAX_COMMITTEE_SIZE as usize,
+                        committee.members().len() <= Committee::<N>::max_committee_size() as usize,
                         "Ratify::Genesis(..) exceeds the maximum number of committee members"
                     );
                     // Ensure that the number of delegators does not exceed the maximum.
@@ -1206,7 +1258,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // Set the genesis ratification flag.
                     is_genesis_ratified = true;
                 }
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => continue,
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) | Ratify::Restrictions(..) => continue,
             }
         }
 
@@ -1218,6 +1270,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     #[inline]
     fn atomic_post_ratify<'a, const IS_FINALIZE: bool>(
         puzzle: &Puzzle<N>,
+        restrictions: &RestrictionsHistory<N>,
         store: &FinalizeStore<N, C::FinalizeStorage>,
         state: FinalizeGlobalState,
         post_ratifications: impl Iterator<Item = &'a Ratify<N>>,
@@ -1241,6 +1294,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let mut is_block_reward_ratified = false;
         // Initialize a flag for the puzzle reward ratification.
         let mut is_puzzle_reward_ratified = false;
+        // Initialize a flag for the restrictions ratification.
+        let mut is_restrictions_ratified = false;
 
         // Iterate over the ratifications.
         for ratify in post_ratifications {
@@ -1368,6 +1423,18 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // Set the puzzle reward ratification flag.
                     is_puzzle_reward_ratified = true;
                 }
+                Ratify::Restrictions(..) => {
+                    // Ensure the restrictions update has not been ratified yet.
+                    ensure!(!is_restrictions_ratified, "Ratify::Restrictions(..) has already been ratified");
+
+                    // Ensure the restrictions update is valid.
+                    // Note: The restrictions update is added to the restrictions history once the block is added,
+                    // and does not change the finalize storage.
+                    restrictions.check_update(state.block_height(), ratify)?;
+
+                    // Set the restrictions ratification flag.
+                    is_restrictions_ratified = true;
+                }
             }
         }
 
@@ -1475,7 +1542,7 @@ finalize transfer_public:
         rng: &mut R,
     ) -> Result<Block<CurrentNetwork>> {
         // Speculate on the candidate ratifications, solutions, and transactions.
-        let time_since_last_block = CurrentNetwork::BLOCK_TIME as i64;
+        let time_since_last_block = CurrentNetwork::block_time() as i64;
         let (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations) = vm.speculate(
             sample_finalize_state(previous_block.height() + 1),
             time_since_last_block,
@@ -1493,8 +1560,8 @@ finalize transfer_public:
             previous_block.height() + 1,
             0,
             0,
-            CurrentNetwork::GENESIS_COINBASE_TARGET,
-            CurrentNetwork::GENESIS_PROOF_TARGET,
+            CurrentNetwork::genesis_coinbase_target(),
+            CurrentNetwork::genesis_proof_target(),
             previous_block.last_coinbase_target(),
             previous_block.last_coinbase_timestamp(),
             previous_block.timestamp().saturating_add(time_since_last_block),
@@ -1505,6 +1572,7 @@ finalize transfer_public:
             vm.block_store().current_state_root(),
             transactions.to_transactions_root().unwrap(),
             transactions.to_finalize_root(ratified_finalize_operations).unwrap(),
+            Field::zero(),
             ratifications.to_ratifications_root().unwrap(),
             Field::zero(),
             Field::zero(),
@@ -1755,7 +1823,7 @@ finalize transfer_public:
         let (ratifications, confirmed_transactions, aborted_transaction_ids, _) = vm
             .speculate(
                 sample_finalize_state(1),
-                CurrentNetwork::BLOCK_TIME as i64,
+                CurrentNetwork::block_time() as i64,
                 None,
                 vec![],
                 &None.into(),
@@ -1785,7 +1853,7 @@ finalize transfer_public:
         let (_, candidate_transactions, aborted_transaction_ids, _) = vm
             .atomic_speculate(
                 sample_finalize_state(1),
-                CurrentNetwork::BLOCK_TIME as i64,
+                CurrentNetwork::block_time() as i64,
                 None,
                 vec![],
                 &None.into(),
@@ -1829,7 +1897,7 @@ finalize transfer_public:
         let new_validator_balance = MIN_VALIDATOR_STAKE + 100_000_000;
         let mut public_balances = sample_public_balances(
             &validator_addresses,
-            <CurrentNetwork as Network>::STARTING_SUPPLY - allocated_amount - new_validator_balance,
+            <CurrentNetwork as Network>::starting_supply() - allocated_amount - new_validator_balance,
         );
         // Set the public balance of the new validator to the minimum validator stake.
         public_balances.insert(new_validator_address, new_validator_balance);
@@ -1879,7 +1947,7 @@ finalize transfer_public:
         let (_, confirmed_transactions, _, _) = vm
             .atomic_speculate(
                 sample_finalize_state(1),
-                CurrentNetwork::BLOCK_TIME as i64,
+                CurrentNetwork::block_time() as i64,
                 None,
                 vec![],
                 &None.into(),
@@ -1990,7 +2058,7 @@ finalize transfer_public:
             let (_, confirmed_transactions, aborted_transaction_ids, _) = vm
                 .atomic_speculate(
                     sample_finalize_state(1),
-                    CurrentNetwork::BLOCK_TIME as i64,
+                    CurrentNetwork::block_time() as i64,
                     None,
                     vec![],
                     &None.into(),
@@ -2018,7 +2086,7 @@ finalize transfer_public:
             let (_, confirmed_transactions, aborted_transaction_ids, _) = vm
                 .atomic_speculate(
                     sample_finalize_state(1),
-                    CurrentNetwork::BLOCK_TIME as i64,
+                    CurrentNetwork::block_time() as i64,
                     None,
                     vec![],
                     &None.into(),
@@ -2046,7 +2114,7 @@ finalize transfer_public:
             let (_, confirmed_transactions, aborted_transaction_ids, _) = vm
                 .atomic_speculate(
                     sample_finalize_state(1),
-                    CurrentNetwork::BLOCK_TIME as i64,
+                    CurrentNetwork::block_time() as i64,
                     None,
                     vec![],
                     &None.into(),
@@ -2078,7 +2146,7 @@ finalize transfer_public:
             let (_, confirmed_transactions, aborted_transaction_ids, _) = vm
                 .atomic_speculate(
                     sample_finalize_state(1),
-                    CurrentNetwork::BLOCK_TIME as i64,
+                    CurrentNetwork::block_time() as i64,
                     None,
                     vec![],
                     &None.into(),
@@ -2185,7 +2253,7 @@ function ped_hash:
             let (_, confirmed_transactions, aborted_transaction_ids, _) = vm
                 .speculate(
                     sample_finalize_state(1),
-                    CurrentNetwork::BLOCK_TIME as i64,
+                    CurrentNetwork::block_time() as i64,
                     None,
                     vec![],
                     &None.into(),
@@ -2448,7 +2516,7 @@ finalize compute:
         // Construct the public balances, allocating the remaining supply.
         let public_balances = sample_public_balances(
             &validators.keys().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>(),
-            <CurrentNetwork as Network>::STARTING_SUPPLY - allocated_amount,
+            <CurrentNetwork as Network>::starting_supply() - allocated_amount,
         );
 
         // Construct the bonded balances.
@@ -2500,7 +2568,7 @@ finalize compute:
         // Construct the public balances, allocating the remaining supply to the validators and zero to the delegators.
         let mut public_balances = sample_public_balances(
             &validators.keys().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>(),
-            <CurrentNetwork as Network>::STARTING_SUPPLY - allocated_amount,
+            <CurrentNetwork as Network>::starting_supply() - allocated_amount,
         );
         public_balances.extend(sample_public_balances(
             &delegators.keys().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>(),
@@ -2566,7 +2634,7 @@ finalize compute:
         // Construct the public balances, allocating the remaining supply to the validators and zero to the delegators.
         let mut public_balances = sample_public_balances(
             &validators.keys().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>(),
-            <CurrentNetwork as Network>::STARTING_SUPPLY - allocated_amount,
+            <CurrentNetwork as Network>::starting_supply() - allocated_amount,
         );
         public_balances.extend(sample_public_balances(
             &delegators.keys().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>(),
@@ -2767,7 +2835,7 @@ finalize compute:
         }
         public_balances.extend(sample_public_balances(
             &validators.keys().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>(),
-            <CurrentNetwork as Network>::STARTING_SUPPLY - allocated_amount,
+            <CurrentNetwork as Network>::starting_supply() - allocated_amount,
         ));
 
         // Construct the bonded balances.
@@ -2834,7 +2902,7 @@ finalize compute:
         // Construct the public balances, allocating the remaining supply to the validators and zero to the delegators.
         let mut public_balances = sample_public_balances(
             &validators.keys().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>(),
-            <CurrentNetwork as Network>::STARTING_SUPPLY - allocated_amount,
+            <CurrentNetwork as Network>::starting_supply() - allocated_amount,
         );
         public_balances.extend(sample_public_balances(
             &delegators.keys().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>(),
@@ -2955,7 +3023,7 @@ finalize compute:
         // Construct the public balances, allocating the remaining supply to rest of the validators.
         let public_balances = sample_public_balances(
             &validators.keys().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>(),
-            <CurrentNetwork as Network>::STARTING_SUPPLY - allocated_amount,
+            <CurrentNetwork as Network>::starting_supply() - allocated_amount,
         );
 
         // Construct the bonded balances.
@@ -3008,7 +3076,7 @@ finalize compute:
         // Construct the public balances, allocating the remaining supply to rest of the validators.
         let mut public_balances = sample_public_balances(
             &validators.keys().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>(),
-            <CurrentNetwork as Network>::STARTING_SUPPLY - allocated_amount,
+            <CurrentNetwork as Network>::starting_supply() - allocated_amount,
         );
         public_balances
             .extend(sample_public_balances(&[Address::try_from(delegators.keys().next().unwrap()).unwrap()], 0));
@@ -3048,7 +3116,7 @@ finalize compute:
         // Construct the public balances, allocating the remaining supply to rest of the validators.
         let mut public_balances = sample_public_balances(
             &validators.keys().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>(),
-            <CurrentNetwork as Network>::STARTING_SUPPLY - allocated_amount,
+            <CurrentNetwork as Network>::starting_supply() - allocated_amount,
         );
         public_balances
             .extend(sample_public_balances(&[Address::try_from(delegators.keys().next().unwrap()).unwrap()], 0));
@@ -3096,7 +3164,7 @@ finalize compute:
         // Construct the public balances, allocating the remaining supply to rest of the validators.
         let mut public_balances = sample_public_balances(
             &validators.keys().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>(),
-            <CurrentNetwork as Network>::STARTING_SUPPLY - allocated_amount,
+            <CurrentNetwork as Network>::starting_supply() - allocated_amount,
         );
         public_balances.extend(sample_public_balances(
             &delegators.keys().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>(),
@@ -3124,7 +3192,7 @@ finalize compute:
         // Construct the public balances, allocating the remaining supply to rest of the validators.
         let mut public_balances = sample_public_balances(
             &validators.keys().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>(),
-            <CurrentNetwork as Network>::STARTING_SUPPLY - allocated_amount,
+            <CurrentNetwork as Network>::starting_supply() - allocated_amount,
         );
         public_balances.extend(sample_public_balances(
             &delegators.keys().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>(),
@@ -3171,7 +3239,7 @@ finalize compute:
         // Construct the public balances, allocating half to the first validator and the remaining to the delegator.
         let public_balances = sample_public_balances(
             &[Address::try_from(validators.keys().next().unwrap()).unwrap(), delegator_address],
-            <CurrentNetwork as Network>::STARTING_SUPPLY - allocated_amount,
+            <CurrentNetwork as Network>::starting_supply() - allocated_amount,
         );
 
         // Construct the bonded balances.
@@ -3308,7 +3376,7 @@ finalize compute:
         // Construct the public balances, allocating the remaining supply to the validators and zero to the delegators.
         let mut public_balances = sample_public_balances(
             &validators.keys().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>(),
-            <CurrentNetwork as Network>::STARTING_SUPPLY - allocated_amount,
+            <CurrentNetwork as Network>::starting_supply() - allocated_amount,
         );
         public_balances.extend(sample_public_balances(
             &delegators.keys().map(|private_key| Address::try_from(private_key).unwrap()).collect::<Vec<_>>(),
@@ -0,0 +1,432 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::{
+    bonded_map_into_stakers,
+    committee_and_delegated_maps_into_committee,
+    ensure_stakers_matches,
+    staking_reward,
+    to_next_committee,
+    to_next_delegated,
+};
+use console::{
+    account::Address,
+    network::prelude::*,
+    program::{Plaintext, Value},
+};
+use ledger_committee::Committee;
+
+use indexmap::IndexMap;
+
+/// The number of seconds in a year with 365 days. Leap years are ignored for simplicity.
+const SECONDS_IN_A_YEAR: u64 = 60 * 60 * 24 * 365;
+
+/// The assumptions used to forecast the staking rewards.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RewardAssumptions {
+    /// The height of the first forecasted block.
+    starting_height: u32,
+    /// The expected number of seconds between blocks.
+    block_interval_in_secs: i64,
+    /// The expected coinbase reward per block, in microcredits.
+    coinbase_reward: u64,
+    /// The expected transaction fees per block, in microcredits.
+    transaction_fees: u64,
+}
+
+impl RewardAssumptions {
+    /// Initializes a new set of reward assumptions.
+    pub const fn new(
+        starting_height: u32,
+        block_interval_in_secs: i64,
+        coinbase_reward: u64,
+        transaction_fees: u64,
+    ) -> Self {
+        Self { starting_height, block_interval_in_secs, coinbase_reward, transaction_fees }
+    }
+
+    /// Returns the height of the first forecasted block.
+    pub const fn starting_height(&self) -> u32 {
+        self.starting_height
+    }
+
+    /// Returns the expected number of seconds between blocks.
+    pub const fn block_interval_in_secs(&self) -> i64 {
+        self.block_interval_in_secs
+    }
+
+    /// Returns the expected coinbase reward per block, in microcredits.
+    pub const fn coinbase_reward(&self) -> u64 {
+        self.coinbase_reward
+    }
+
+    /// Returns the expected transaction fees per block, in microcredits.
+    pub const fn transaction_fees(&self) -> u64 {
+        self.transaction_fees
+    }
+
+    /// Returns the block reward for the given block height, following `Ratify::BlockReward`.
+    pub fn block_reward<N: Network>(&self, block_height: u32) -> u64 {
+        ledger_block::block_reward::<N>(
+            block_height,
+            N::starting_supply(),
+            N::block_time(),
+            self.block_interval_in_secs,
+            self.coinbase_reward,
+            self.transaction_fees,
+        )
+    }
+}
+
+/// The forecasted staking rewards of a single staker.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct StakerForecast<N: Network> {
+    /// The validator the staker is bonded to.
+    validator: Address<N>,
+    /// The amount of microcredits bonded at the start of the forecast.
+    initial_stake: u64,
+    /// The amount of microcredits bonded at the end of the forecast.
+    final_stake: u64,
+}
+
+impl<N: Network> StakerForecast<N> {
+    /// Returns the validator the staker is bonded to.
+    pub const fn validator(&self) -> Address<N> {
+        self.validator
+    }
+
+    /// Returns the amount of microcredits bonded at the start of the forecast.
+    pub const fn initial_stake(&self) -> u64 {
+        self.initial_stake
+    }
+
+    /// Returns the amount of microcredits bonded at the end of the forecast.
+    pub const fn final_stake(&self) -> u64 {
+        self.final_stake
+    }
+
+    /// Returns the forecasted reward, in microcredits.
+    pub const fn reward(&self) -> u64 {
+        self.final_stake.saturating_sub(self.initial_stake)
+    }
+}
+
+/// The forecasted staking rewards of a committee and its stakers over a number of blocks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RewardsForecast<N: Network> {
+    /// The assumptions used for the forecast.
+    assumptions: RewardAssumptions,
+    /// The number of forecasted blocks.
+    num_blocks: u32,
+    /// The sum of the block rewards over the forecast.
+    total_block_rewards: u64,
+    /// The forecast for each staker.
+    stakers: IndexMap<Address<N>, StakerForecast<N>>,
+    /// The committee at the end of the forecast.
+    committee: Committee<N>,
+}
+
+impl<N: Network> RewardsForecast<N> {
+    /// Returns the assumptions used for the forecast.
+    pub const fn assumptions(&self) -> &RewardAssumptions {
+        &self.assumptions
+    }
+
+    /// Returns the number of forecasted blocks.
+    pub const fn num_blocks(&self) -> u32 {
+        self.num_blocks
+    }
+
+    /// Returns the sum of the block rewards over the forecast.
+    pub const fn total_block_rewards(&self) -> u64 {
+        self.total_block_rewards
+    }
+
+    /// Returns the forecast for each staker.
+    pub const fn stakers(&self) -> &IndexMap<Address<N>, StakerForecast<N>> {
+        &self.stakers
+    }
+
+    /// Returns the committee at the end of the forecast.
+    pub const fn committee(&self) -> &Committee<N> {
+        &self.committee
+    }
+
+    /// Returns the forecasted reward of the given staker, in microcredits.
+    pub fn staker_reward(&self, staker: &Address<N>) -> Option<u64> {
+        self.stakers.get(staker).map(StakerForecast::reward)
+    }
+
+    /// Returns the forecasted reward of each validator, as a map of `validator => (self reward, delegator rewards)`.
+    ///
+    /// Note: The self reward of a validator includes the commission it receives from its delegators,
+    /// and the delegator rewards are net of the commission.
+    pub fn validator_rewards(&self) -> IndexMap<Address<N>, (u64, u64)> {
+        let mut rewards = IndexMap::<_, (u64, u64)>::new();
+        for (staker, forecast) in &self.stakers {
+            let (self_reward, delegator_rewards) = rewards.entry(forecast.validator).or_default();
+            match *staker == forecast.validator {
+                true => *self_reward = self_reward.saturating_add(forecast.reward()),
+                false => *delegator_rewards = delegator_rewards.saturating_add(forecast.reward()),
+            }
+        }
+        rewards
+    }
+
+    /// Returns the annualized rate of return of the given staker, extrapolated from the forecast.
+    ///
+    /// Note: The rate is a fraction (e.g. `0.1` for 10%) and includes the effect of compounding over the forecast.
+    pub fn annualized_rate(&self, staker: &Address<N>) -> Option<f64> {
+        let forecast = self.stakers.get(staker)?;
+        // Compute the duration of the forecast, in seconds.
+        let duration = (self.num_blocks as u64).saturating_mul(self.assumptions.block_interval_in_secs.max(0) as u64);
+        if forecast.initial_stake == 0 || duration == 0 {
+            return None;
+        }
+        let rate = forecast.reward() as f64 / forecast.initial_stake as f64;
+        Some(rate * SECONDS_IN_A_YEAR as f64 / duration as f64)
+    }
+}
+
+/// Returns the forecasted staking rewards for the given committee and stakers over `num_blocks` blocks.
+///
+/// Each block rewards the stakers as in `Ratify::BlockReward`, using the stakes and committee after the previous
+/// block, so the forecast accounts for compounding, commissions, the minimum delegator stake, and the exclusion
+/// of validators with more than 25% of the total stake. Bonding and unbonding during the forecast are not modeled.
+///
+/// Note: Each block only updates the stakes in place, so the cost is linear in `num_blocks * stakers.len()`,
+/// and the committee is only constructed once, at the end of the forecast.
+pub fn forecast_staking_rewards<N: Network>(
+    committee: &Committee<N>,
+    stakers: &IndexMap<Address<N>, (Address<N>, u64)>,
+    assumptions: RewardAssumptions,
+    num_blocks: u32,
+) -> Result<RewardsForecast<N>> {
+    // Ensure the committee matches the stakers.
+    ensure_stakers_matches(committee, stakers)?;
+
+    // Initialize the stake and commission rate of each validator, and the total stake.
+    let mut validators: IndexMap<Address<N>, (u64, u8)> = committee
+        .members()
+        .iter()
+        .map(|(validator, (stake, _, commission_rate))| (*validator, (*stake, *commission_rate)))
+        .collect();
+    let mut total_stake = committee.total_stake();
+    // Initialize the stakes, along with the index of their validator in the committee.
+    let mut next_stakers: Vec<(Address<N>, Address<N>, Option<usize>, u64)> = stakers
+        .iter()
+        .map(|(staker, (validator, stake))| (*staker, *validator, validators.get_index_of(validator), *stake))
+        .collect();
+    let mut total_block_rewards = 0u64;
+
+    for offset in 0..num_blocks {
+        // Compute the block reward.
+        let block_reward = assumptions.block_reward::<N>(assumptions.starting_height.saturating_add(offset));
+        total_block_rewards = total_block_rewards.saturating_add(block_reward);
+
+        // Compute the staking reward of each staker, using the validator stakes and total stake before the block.
+        let mut deltas = vec![0u64; validators.len()];
+        for (staker, validator, index, stake) in next_stakers.iter_mut() {
+            // If the validator is not in the committee, skip the staker.
+            let Some(index) = *index else { continue };
+            let (validator_stake, commission_rate) = validators[index];
+            let reward =
+                staking_reward(staker, validator, *stake, validator_stake, commission_rate, total_stake, block_reward);
+            *stake = stake.saturating_add(reward);
+            deltas[index] = deltas[index].saturating_add(reward);
+        }
+        // Update the validator stakes and the total stake, as in the next committee.
+        for ((validator_stake, _), delta) in validators.values_mut().zip(deltas) {
+            *validator_stake = validator_stake.saturating_add(delta);
+            total_stake = total_stake.saturating_add(delta);
+        }
+    }
+
+    // Construct the stakers and the committee at the end of the forecast.
+    let next_stakers: IndexMap<_, _> =
+        next_stakers.into_iter().map(|(staker, validator, _, stake)| (staker, (validator, stake))).collect();
+    let next_delegated = to_next_delegated(&next_stakers);
+    let next_committee = to_next_committee(committee, committee.starting_round(), &next_delegated)?;
+
+    // Construct the forecast for each staker.
+    let stakers = stakers
+        .iter()
+        .map(|(staker, (validator, initial_stake))| {
+            let final_stake = next_stakers.get(staker).map(|(_, stake)| *stake).unwrap_or(*initial_stake);
+            (*staker, StakerForecast { validator: *validator, initial_stake: *initial_stake, final_stake })
+        })
+        .collect();
+
+    Ok(RewardsForecast { assumptions, num_blocks, total_block_rewards, stakers, committee: next_committee })
+}
+
+/// Returns the forecasted staking rewards over `num_blocks` blocks, given the `committee`, `delegated`,
+/// and `bonded` mappings of `credits.aleo` at the given round.
+pub fn forecast_staking_rewards_from_mappings<N: Network>(
+    round: u64,
+    committee_map: Vec<(Plaintext<N>, Value<N>)>,
+    delegated_map: Vec<(Plaintext<N>, Value<N>)>,
+    bonded_map: Vec<(Plaintext<N>, Value<N>)>,
+    assumptions: RewardAssumptions,
+    num_blocks: u32,
+) -> Result<RewardsForecast<N>> {
+    // Convert the committee and delegated mappings into a committee.
+    let committee = committee_and_delegated_maps_into_committee(round, committee_map, delegated_map)?;
+    // Convert the bonded mapping into stakers.
+    let stakers = bonded_map_into_stakers(bonded_map)?;
+    // Forecast the staking rewards.
+    forecast_staking_rewards(&committee, &stakers, assumptions, num_blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::staking_rewards;
+    use console::prelude::TestRng;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    /// The assumptions used in the tests.
+    const ASSUMPTIONS: RewardAssumptions = RewardAssumptions::new(1_000_000, 3, 100_000_000, 0);
+
+    #[test]
+    fn test_forecast_matches_staking_rewards() {
+        let rng = &mut TestRng::default();
+        // Sample a random committee.
+        let committee = ledger_committee::test_helpers::sample_committee_with_commissions(rng);
+        // Convert the committee into stakers.
+        let stakers = crate::committee::test_helpers::to_stakers(committee.members(), rng);
+
+        // Forecast a single block, and ensure it matches `staking_rewards`.
+        let forecast = forecast_staking_rewards(&committee, &stakers, ASSUMPTIONS, 1).unwrap();
+        let block_reward = ASSUMPTIONS.block_reward::<CurrentNetwork>(ASSUMPTIONS.starting_height());
+        assert_eq!(forecast.total_block_rewards(), block_reward);
+        let expected = staking_rewards(&stakers, &committee, block_reward);
+        for (staker, (_, stake)) in expected {
+            assert_eq!(forecast.stakers().get(&staker).unwrap().final_stake(), stake);
+        }
+
+        // Forecast a few epochs, and ensure it matches applying `staking_rewards` and updating the committee
+        // block by block, as in `Ratify::BlockReward`.
+        let num_blocks = 3 * CurrentNetwork::num_blocks_per_epoch();
+        let forecast = forecast_staking_rewards(&committee, &stakers, ASSUMPTIONS, num_blocks).unwrap();
+        let mut expected_committee = committee.clone();
+        let mut expected_stakers = stakers.clone();
+        let mut expected_block_rewards = 0;
+        for height in ASSUMPTIONS.starting_height()..ASSUMPTIONS.starting_height() + num_blocks {
+            let block_reward = ASSUMPTIONS.block_reward::<CurrentNetwork>(height);
+            expected_block_rewards += block_reward;
+            expected_stakers = staking_rewards(&expected_stakers, &expected_committee, block_reward);
+            let next_delegated = to_next_delegated(&expected_stakers);
+            expected_committee =
+                to_next_committee(&expected_committee, committee.starting_round(), &next_delegated).unwrap();
+        }
+        assert_eq!(forecast.total_block_rewards(), expected_block_rewards);
+        assert_eq!(forecast.committee(), &expected_committee);
+        for (staker, (_, stake)) in &expected_stakers {
+            assert_eq!(forecast.stakers().get(staker).unwrap().final_stake(), *stake);
+        }
+
+        // Ensure the rewards do not exceed the block rewards.
+        let total_rewards = forecast.stakers().values().map(|forecast| forecast.reward()).sum::<u64>();
+        assert!(total_rewards <= forecast.total_block_rewards());
+        // Ensure the validator rewards add up to the staker rewards.
+        let validator_rewards = forecast.validator_rewards();
+        assert_eq!(validator_rewards.values().map(|(a, b)| a + b).sum::<u64>(), total_rewards);
+    }
+
+    #[test]
+    fn test_forecast_across_epochs() {
+        let rng = &mut TestRng::default();
+        // Sample a random committee.
+        let committee = ledger_committee::test_helpers::sample_committee_with_commissions(rng);
+        // Convert the committee into stakers.
+        let stakers = crate::committee::test_helpers::to_stakers(committee.members(), rng);
+
+        // Forecast until the end of the first epoch.
+        let num_blocks_per_epoch = CurrentNetwork::num_blocks_per_epoch();
+        let first_blocks = num_blocks_per_epoch - ASSUMPTIONS.starting_height() % num_blocks_per_epoch;
+        let first = forecast_staking_rewards(&committee, &stakers, ASSUMPTIONS, first_blocks).unwrap();
+        // Forecast the next epoch, from the end of the first epoch.
+        let next_stakers: IndexMap<_, _> = first
+            .stakers()
+            .iter()
+            .map(|(staker, forecast)| (*staker, (forecast.validator(), forecast.final_stake())))
+            .collect();
+        let assumptions = RewardAssumptions::new(
+            ASSUMPTIONS.starting_height() + first_blocks,
+            ASSUMPTIONS.block_interval_in_secs(),
+            ASSUMPTIONS.coinbase_reward(),
+            ASSUMPTIONS.transaction_fees(),
+        );
+        let second =
+            forecast_staking_rewards(first.committee(), &next_stakers, assumptions, num_blocks_per_epoch).unwrap();
+
+        // Ensure forecasting across the epochs matches forecasting each epoch separately.
+        let forecast =
+            forecast_staking_rewards(&committee, &stakers, ASSUMPTIONS, first_blocks + num_blocks_per_epoch).unwrap();
+        assert_eq!(forecast.total_block_rewards(), first.total_block_rewards() + second.total_block_rewards());
+        assert_eq!(forecast.committee(), second.committee());
+        for (staker, staker_forecast) in forecast.stakers() {
+            assert_eq!(staker_forecast.initial_stake(), first.stakers()[staker].initial_stake());
+            assert_eq!(staker_forecast.final_stake(), second.stakers()[staker].final_stake());
+        }
+        // Ensure the stakers are rewarded.
+        assert!(forecast.stakers().values().any(|forecast| forecast.reward() > 0));
+    }
+
+    #[test]
+    fn test_forecast_commission_and_cap() {
+        let rng = &mut TestRng::default();
+
+        // Sample the validators and a delegator.
+        let mut sample_address = || Address::<CurrentNetwork>::new(rng.gen());
+        let validators: Vec<_> = (0..5).map(|_| sample_address()).collect();
+        let delegator = sample_address();
+
+        // Construct a committee, where the last validator holds more than 25% of the total stake.
+        let stake = ledger_committee::MIN_VALIDATOR_STAKE;
+        let members = indexmap::indexmap! {
+            validators[0] => (stake * 2, true, 20),
+            validators[1] => (stake, true, 0),
+            validators[2] => (stake, true, 0),
+            validators[3] => (stake, true, 0),
+            validators[4] => (stake * 3, true, 0),
+        };
+        let committee = Committee::<CurrentNetwork>::new_genesis(members).unwrap();
+        // Construct the stakers, with the delegator bonded to the first validator.
+        let mut stakers: IndexMap<_, _> =
+            committee.members().keys().map(|validator| (*validator, (*validator, stake))).collect();
+        stakers.insert(validators[4], (validators[4], stake * 3));
+        stakers.insert(delegator, (validators[0], stake));
+
+        // Forecast the staking rewards.
+        let forecast = forecast_staking_rewards(&committee, &stakers, ASSUMPTIONS, 100).unwrap();
+
+        // Ensure the validator with more than 25% of the total stake is not rewarded.
+        assert_eq!(forecast.staker_reward(&validators[4]), Some(0));
+        assert_eq!(forecast.annualized_rate(&validators[4]), Some(0.0));
+        // Ensure the validator receives the commission of its delegator.
+        let validator_reward = forecast.staker_reward(&validators[0]).unwrap();
+        let delegator_reward = forecast.staker_reward(&delegator).unwrap();
+        assert!(validator_reward > delegator_reward);
+        // Ensure the validators without commission receive the same reward as the delegator, before commission.
+        let reward = forecast.staker_reward(&validators[1]).unwrap();
+        assert!(delegator_reward < reward && reward < validator_reward);
+        assert_eq!(forecast.staker_reward(&validators[2]), Some(reward));
+        // Ensure the annualized rates are consistent.
+        assert!(forecast.annualized_rate(&delegator).unwrap() < forecast.annualized_rate(&validators[1]).unwrap());
+        assert!(forecast.annualized_rate(&sample_address()).is_none());
+    }
+}
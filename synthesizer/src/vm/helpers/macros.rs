@@ -71,6 +71,10 @@ macro_rules! convert {
                 // Process the logic.
                 $logic!(console::network::CanaryV0, circuit::AleoCanaryV0)
             }
+            console::network::DevnetV0::ID => {
+                // Process the logic.
+                $logic!(console::network::DevnetV0, circuit::AleoDevnetV0)
+            }
             _ => bail!("Unsupported VM configuration for network: {}", N::ID),
         }
     }};
@@ -106,6 +110,14 @@ macro_rules! process {
                 // Process the logic.
                 $logic!(process.read(), console::network::CanaryV0, circuit::AleoCanaryV0)
             }
+            console::network::DevnetV0::ID => {
+                // Cast the process.
+                let process = (&$self.process as &dyn std::any::Any)
+                    .downcast_ref::<Arc<RwLock<Process<console::network::DevnetV0>>>>()
+                    .ok_or_else(|| anyhow!("Failed to downcast {}", stringify!($self.process)))?;
+                // Process the logic.
+                $logic!(process.read(), console::network::DevnetV0, circuit::AleoDevnetV0)
+            }
             _ => bail!("Unsupported VM configuration for network: {}", N::ID),
         }
     }};
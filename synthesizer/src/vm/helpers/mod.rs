@@ -16,6 +16,9 @@
 pub(crate) mod committee;
 pub use committee::*;
 
+mod forecast;
+pub use forecast::*;
+
 #[cfg(feature = "history")]
 mod history;
 #[cfg(feature = "history")]
@@ -25,3 +28,8 @@ mod macros;
 
 mod rewards;
 pub use rewards::*;
+
+#[cfg(feature = "spans")]
+mod spans;
+#[cfg(feature = "spans")]
+pub(crate) use spans::*;
@@ -0,0 +1,38 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+use ledger_block::Transaction;
+
+use indexmap::IndexSet;
+use tracing::{Span, field};
+
+/// Returns the span for processing the given transaction, which carries the transaction ID
+/// and the IDs of the programs deployed or executed by the transaction.
+pub(crate) fn transaction_span<N: Network>(transaction: &Transaction<N>) -> Span {
+    let span = debug_span!("transaction", id = %transaction.id(), program_ids = field::Empty);
+    // Only collect the program IDs if the span is enabled.
+    if !span.is_disabled() {
+        let program_ids = transaction
+            .deployment()
+            .map(|deployment| deployment.program_id())
+            .into_iter()
+            .chain(transaction.transitions().map(|transition| transition.program_id()))
+            .map(|program_id| program_id.to_string())
+            .collect::<IndexSet<_>>();
+        span.record("program_ids", program_ids.into_iter().collect::<Vec<_>>().join(",").as_str());
+    }
+    span
+}
@@ -22,7 +22,16 @@ mod execute;
 mod finalize;
 mod verify;
 
-use crate::{Restrictions, cast_mut_ref, cast_ref, convert, process};
+use crate::{
+    GenesisSpec,
+    GenesisSummary,
+    RestrictionMatch,
+    RestrictionsHistory,
+    cast_mut_ref,
+    cast_ref,
+    convert,
+    process,
+};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
@@ -54,6 +63,7 @@ use ledger_store::{
     ConsensusStore,
     FinalizeMode,
     FinalizeStore,
+    StorageReport,
     TransactionStorage,
     TransactionStore,
     TransitionStore,
@@ -69,7 +79,7 @@ use itertools::Either;
 use lru::LruCache;
 use parking_lot::{Mutex, RwLock};
 use rand::{SeedableRng, rngs::StdRng};
-use std::{collections::HashSet, num::NonZeroUsize, sync::Arc};
+use std::{collections::HashSet, num::NonZeroUsize, path::Path, sync::Arc};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -84,8 +94,8 @@ pub struct VM<N: Network, C: ConsensusStorage<N>> {
     store: ConsensusStore<N, C>,
     /// A cache containing the list of recent partially-verified transactions.
     partially_verified_transactions: Arc<RwLock<LruCache<N::TransactionID, N::TransmissionChecksum>>>,
-    /// The restrictions list.
-    restrictions: Restrictions<N>,
+    /// The history of the restrictions list.
+    restrictions: Arc<RwLock<RestrictionsHistory<N>>>,
     /// The lock to guarantee atomicity over calls to speculate and finalize.
     atomic_lock: Arc<Mutex<()>>,
     /// The lock for ensuring there is no concurrency when advancing blocks.
@@ -186,6 +196,19 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
         }
 
+        // Initialize the restrictions history.
+        let mut restrictions = RestrictionsHistory::load()?;
+        // Add the restrictions updates from storage, in increasing order of block height.
+        let mut restrictions_updates = store
+            .block_store()
+            .restrictions_updates()
+            .map(|(height, ratify)| (*height, ratify.into_owned()))
+            .collect::<Vec<_>>();
+        restrictions_updates.sort_unstable_by_key(|(height, _)| *height);
+        for (height, ratify) in restrictions_updates {
+            restrictions.add_update(height, &ratify)?;
+        }
+
         // Return the new VM.
         Ok(Self {
             process: Arc::new(RwLock::new(process)),
@@ -194,7 +217,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             partially_verified_transactions: Arc::new(RwLock::new(LruCache::new(
                 NonZeroUsize::new(Transactions::<N>::MAX_TRANSACTIONS).unwrap(),
             ))),
-            restrictions: Restrictions::load()?,
+            restrictions: Arc::new(RwLock::new(restrictions)),
             atomic_lock: Arc::new(Mutex::new(())),
             block_lock: Arc::new(Mutex::new(())),
         })
@@ -224,10 +247,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         self.partially_verified_transactions.clone()
     }
 
-    /// Returns the restrictions.
+    /// Returns the restrictions history.
     #[inline]
-    pub const fn restrictions(&self) -> &Restrictions<N> {
-        &self.restrictions
+    pub fn restrictions(&self) -> Arc<RwLock<RestrictionsHistory<N>>> {
+        self.restrictions.clone()
     }
 }
 
@@ -255,6 +278,18 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     pub fn transition_store(&self) -> &TransitionStore<N, C::TransitionStorage> {
         self.store.transition_store()
     }
+
+    /// Creates a point-in-time checkpoint of the VM storage in the given directory.
+    #[inline]
+    pub fn create_checkpoint(&self, path: &Path) -> Result<()> {
+        self.store.create_checkpoint(path)
+    }
+
+    /// Returns the usage report of the VM storage.
+    #[inline]
+    pub fn storage_report(&self) -> Result<StorageReport<N>> {
+        self.store.storage_report()
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -288,7 +323,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let committee = Committee::<N>::new_genesis(members)?;
 
         // Compute the remaining supply.
-        let remaining_supply = N::STARTING_SUPPLY - (ledger_committee::MIN_VALIDATOR_STAKE * 4);
+        let remaining_supply = N::starting_supply() - (ledger_committee::MIN_VALIDATOR_STAKE * 4);
         // Construct the public balances.
         let public_balances = indexmap::indexmap! {
             Address::try_from(private_keys[0])? => remaining_supply / 4,
@@ -328,9 +363,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             total_bonded_amount.checked_add(account_supply).ok_or_else(|| anyhow!("Invalid total supply"))?;
         // Ensure the total supply matches.
         ensure!(
-            total_supply == N::STARTING_SUPPLY,
+            total_supply == N::starting_supply(),
             "Invalid total supply. Found {total_supply}, expected {}",
-            N::STARTING_SUPPLY
+            N::starting_supply()
         );
 
         // Prepare the caller.
@@ -391,13 +426,76 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         }
     }
 
+    /// Returns a new genesis block for a quorum chain, as specified by the given genesis spec,
+    /// alongside a human-readable summary of the genesis state.
+    ///
+    /// Note: The programs in the genesis spec are not part of the genesis block. They must be deployed
+    /// in the block at height 1, using the transactions returned by `VM::genesis_deployments`.
+    pub fn genesis_from_spec<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        spec: &GenesisSpec<N>,
+        rng: &mut R,
+    ) -> Result<(Block<N>, GenesisSummary<N>)> {
+        // Ensure the genesis spec is well-formed.
+        spec.check()?;
+        // Construct the genesis block.
+        let block = self.genesis_quorum(
+            private_key,
+            spec.committee()?,
+            spec.public_balances().clone(),
+            spec.bonded_balances(),
+            rng,
+        )?;
+        // Construct the genesis summary.
+        let summary = GenesisSummary::new(block.hash(), spec)?;
+        Ok((block, summary))
+    }
+
+    /// Returns the deployment transactions for the programs in the given genesis spec.
+    ///
+    /// The genesis block only contains the `credits.aleo` transactions, so the programs are deployed in the
+    /// block at height 1, against a state that only contains `credits.aleo` (which is thus their only allowed import).
+    /// This method must be called once the genesis block has been added to the VM, and the deployment fees
+    /// are paid from the public balance of the given private key.
+    pub fn genesis_deployments<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        spec: &GenesisSpec<N>,
+        rng: &mut R,
+    ) -> Result<Vec<Transaction<N>>> {
+        // Ensure the VM contains the genesis block.
+        ensure!(self.block_store().max_height() == Some(0), "The VM must only contain the genesis block");
+        // Construct the deployments.
+        spec.programs().iter().map(|program| self.deploy(private_key, program, None, 0, None, rng)).collect()
+    }
+
     /// Adds the given block into the VM.
     #[inline]
     pub fn add_next_block(&self, block: &Block<N>) -> Result<()> {
+        self.add_block(block, || self.block_store().insert(block))
+    }
+
+    /// Adds the given block into the VM, along with the given ancestor block hashes, without the ancestor blocks.
+    /// This is used to bootstrap the VM from a snapshot of the finalize state prior to the given block.
+    #[inline]
+    pub fn add_next_block_with_ancestor_hashes(
+        &self,
+        ancestor_hashes: &[N::BlockHash],
+        block: &Block<N>,
+    ) -> Result<()> {
+        self.add_block(block, || self.block_store().insert_with_ancestor_hashes(ancestor_hashes, block))
+    }
+
+    /// Adds the given block into the VM, using the given function to insert the block into the block store.
+    fn add_block(&self, block: &Block<N>, insert: impl FnOnce() -> Result<()>) -> Result<()> {
         // Acquire the block lock, which is needed to ensure this function is not called concurrently.
         // Note: This lock must be held for the entire scope of this function.
         let _block_lock = self.block_lock.lock();
 
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
         // Construct the finalize state.
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
@@ -407,13 +505,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             block.previous_hash(),
         )?;
 
+        // Prepare the restrictions history with the restrictions updates of the block.
+        // Note: This is done before the block is committed, so the restrictions history cannot diverge from storage.
+        let mut next_restrictions = None;
+        for ratify in block.ratifications().iter().filter(|ratify| matches!(ratify, Ratify::Restrictions(..))) {
+            next_restrictions
+                .get_or_insert_with(|| self.restrictions.read().clone())
+                .add_update(block.height(), ratify)?;
+        }
+
         // Pause the atomic writes, so that both the insertion and finalization belong to a single batch.
-        #[cfg(feature = "rocks")]
+        #[cfg(any(feature = "rocks", feature = "embedded"))]
         self.block_store().pause_atomic_writes()?;
 
         // First, insert the block.
-        if let Err(insert_error) = self.block_store().insert(block) {
-            if cfg!(feature = "rocks") {
+        if let Err(insert_error) = insert() {
+            if cfg!(any(feature = "rocks", feature = "embedded")) {
                 // Clear all pending atomic operations so that unpausing the atomic writes
                 // doesn't execute any of the queued storage operations.
                 self.block_store().abort_atomic();
@@ -425,16 +532,53 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             return Err(insert_error);
         };
 
-        // Next, finalize the transactions.
-        match self.finalize(state, block.ratifications(), block.solutions(), block.transactions()) {
+        // Determine the block height that is pruned by the insertion, if pruning is enabled.
+        let prune_height = self.block_store().pruning_depth().and_then(|depth| block.height().checked_sub(depth));
+
+        // Next, finalize the transactions, and prune the rollback entries of the pruned block.
+        // Note: The rollback entries of a pruned block are no longer needed, as it can no longer be reverted.
+        match self.finalize(state, block.ratifications(), block.solutions(), block.transactions()).and_then(
+            |ratified_finalize_operations| {
+                if let Some(prune_height) = prune_height {
+                    self.finalize_store().prune_rollback_entry(prune_height)?;
+                }
+                Ok(ratified_finalize_operations)
+            },
+        ) {
             Ok(_ratified_finalize_operations) => {
                 // Unpause the atomic writes, executing the ones queued from block insertion and finalization.
-                #[cfg(feature = "rocks")]
+                #[cfg(any(feature = "rocks", feature = "embedded"))]
                 self.block_store().unpause_atomic_writes::<false>()?;
+                // Update the restrictions history, if the block contains a restrictions update.
+                if let Some(next_restrictions) = next_restrictions {
+                    *self.restrictions.write() = next_restrictions;
+                }
+                #[cfg(feature = "metrics")]
+                {
+                    let transactions = block.transactions();
+                    metrics::increment_counter_by(
+                        metrics::vm::ACCEPTED_TRANSACTIONS,
+                        transactions.num_accepted() as u64,
+                    );
+                    metrics::increment_counter_by(
+                        metrics::vm::REJECTED_TRANSACTIONS,
+                        transactions.num_rejected() as u64,
+                    );
+                    metrics::increment_counter_by(
+                        metrics::vm::ABORTED_TRANSACTIONS,
+                        block.aborted_transaction_ids().len() as u64,
+                    );
+                    // Note: The fee amounts of a block in storage are well-formed.
+                    let fees: u64 =
+                        transactions.transaction_fee_amounts().filter_map(|fee| fee.ok()).map(|fee| *fee).sum();
+                    metrics::gauge(metrics::vm::BLOCK_FEES, fees as f64);
+                    metrics::increment_counter_by(metrics::vm::TOTAL_FEES, fees);
+                    metrics::histogram(metrics::vm::ADD_NEXT_BLOCK_LATENCY, start.elapsed().as_secs_f64());
+                }
                 Ok(())
             }
             Err(finalize_error) => {
-                if cfg!(feature = "rocks") {
+                if cfg!(any(feature = "rocks", feature = "embedded")) {
                     // Clear all pending atomic operations so that unpausing the atomic writes
                     // doesn't execute any of the queued storage operations.
                     self.block_store().abort_atomic();
@@ -459,6 +603,95 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
         }
     }
+
+    /// Reverts the VM to the given block height, by removing all subsequent blocks from storage,
+    /// undoing their finalize operations, and removing the programs they deployed from the process.
+    #[inline]
+    pub fn revert_to_height(&self, height: u32) -> Result<()> {
+        // Acquire the block lock, which is needed to ensure this function is not called concurrently.
+        // Note: This lock must be held for the entire scope of this function.
+        let _block_lock = self.block_lock.lock();
+        // Acquire the atomic lock, which is needed to ensure there are no concurrent calls to `atomic_finalize!`.
+        let _atomic_lock = self.atomic_lock.lock();
+
+        // Retrieve the latest height.
+        let Some(latest_height) = self.block_store().max_height() else {
+            bail!("Cannot revert to block {height}: no blocks in storage");
+        };
+        // Ensure the given height exists.
+        ensure!(height <= latest_height, "Cannot revert to block {height}: the latest block is {latest_height}");
+        // If the given height is the latest height, there is nothing to revert.
+        if height == latest_height {
+            return Ok(());
+        }
+
+        // Retrieve the programs deployed in the blocks to be removed.
+        let mut program_ids = Vec::new();
+        for block_height in height + 1..=latest_height {
+            // Retrieve the block hash.
+            let Some(block_hash) = self.block_store().get_block_hash(block_height)? else {
+                bail!("Cannot revert to block {height}: missing block hash for block {block_height}");
+            };
+            // Retrieve the block transactions.
+            let Some(transactions) = self.block_store().get_block_transactions(&block_hash)? else {
+                bail!("Cannot revert to block {height}: missing transactions for block {block_height}");
+            };
+            // Collect the program IDs of the accepted deployments.
+            program_ids.extend(transactions.deployments().filter_map(|confirmed| match confirmed {
+                ConfirmedTransaction::AcceptedDeploy(_, Transaction::Deploy(_, _, deployment, _), _) => {
+                    Some(*deployment.program_id())
+                }
+                _ => None,
+            }));
+        }
+
+        // Pause the atomic writes, so that both the removal and the revert belong to a single batch.
+        #[cfg(any(feature = "rocks", feature = "embedded"))]
+        self.block_store().pause_atomic_writes()?;
+
+        // First, revert the finalize store, and then remove the blocks.
+        // Note: The blocks are removed last, as this also reverts the block tree.
+        // Note: The reverted mapping tree is only set once the revert is committed to storage.
+        let num_blocks = latest_height - height;
+        let mut mapping_tree = None;
+        if let Err(revert_error) = self.finalize_store().revert_storage_to_height(height).and_then(|tree| {
+            mapping_tree = tree;
+            self.block_store().remove_last_n(num_blocks)
+        }) {
+            if cfg!(any(feature = "rocks", feature = "embedded")) {
+                // Clear all pending atomic operations so that unpausing the atomic writes
+                // doesn't execute any of the queued storage operations.
+                self.block_store().abort_atomic();
+                self.finalize_store().abort_atomic();
+                // Disable the atomic batch override.
+                // Note: This call is guaranteed to succeed (without error), because `DISCARD_BATCH == true`.
+                self.block_store().unpause_atomic_writes::<true>()?;
+            } else if let Some(mapping_tree) = mapping_tree {
+                // Without paused atomic writes, the finalize revert is already committed, so set its mapping tree.
+                self.finalize_store().set_mapping_tree(mapping_tree);
+            }
+            // Log the revert error.
+            error!("Failed to revert to block {height} - {revert_error}");
+            return Err(revert_error);
+        }
+
+        // Unpause the atomic writes, executing the ones queued from the revert and block removal.
+        #[cfg(any(feature = "rocks", feature = "embedded"))]
+        self.block_store().unpause_atomic_writes::<false>()?;
+        // Set the reverted mapping tree, now that the revert is committed to storage.
+        if let Some(mapping_tree) = mapping_tree {
+            self.finalize_store().set_mapping_tree(mapping_tree);
+        }
+
+        // Lastly, remove the reverted programs from the process, and the reverted restrictions updates.
+        let mut process = self.process.write();
+        for program_id in program_ids {
+            process.remove_program(&program_id)?;
+        }
+        self.restrictions.write().revert_to_height(height);
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -472,6 +705,8 @@ pub(crate) mod test_helpers {
     };
     use ledger_block::{Block, Header, Metadata, Transition};
     use ledger_store::helpers::memory::ConsensusMemory;
+    #[cfg(feature = "embedded")]
+    use ledger_store::helpers::redb::ConsensusRedb;
     #[cfg(feature = "rocks")]
     use ledger_store::helpers::rocksdb::ConsensusDB;
     use ledger_test_helpers::{large_transaction_program, small_transaction_program};
@@ -479,7 +714,7 @@ pub(crate) mod test_helpers {
 
     use indexmap::IndexMap;
     use once_cell::sync::OnceCell;
-    #[cfg(feature = "rocks")]
+    #[cfg(any(feature = "rocks", feature = "embedded"))]
     use std::path::Path;
     use synthesizer_snark::VerifyingKey;
 
@@ -501,6 +736,12 @@ pub(crate) mod test_helpers {
         VM::from(ConsensusStore::open(path.to_owned()).unwrap()).unwrap()
     }
 
+    #[cfg(feature = "embedded")]
+    pub(crate) fn sample_vm_redb(path: &Path) -> VM<CurrentNetwork, ConsensusRedb<CurrentNetwork>> {
+        // Initialize a new VM.
+        VM::from(ConsensusStore::open(path.to_owned()).unwrap()).unwrap()
+    }
+
     pub(crate) fn sample_genesis_private_key(rng: &mut TestRng) -> PrivateKey<CurrentNetwork> {
         static INSTANCE: OnceCell<PrivateKey<CurrentNetwork>> = OnceCell::new();
         *INSTANCE.get_or_init(|| {
@@ -794,7 +1035,7 @@ function compute:
         let previous_block = vm.block_store().get_block(&block_hash).unwrap().unwrap();
 
         // Construct the new block header.
-        let time_since_last_block = MainnetV0::BLOCK_TIME as i64;
+        let time_since_last_block = MainnetV0::block_time() as i64;
         let (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations) = vm.speculate(
             sample_finalize_state(1),
             time_since_last_block,
@@ -812,8 +1053,8 @@ function compute:
             previous_block.height() + 1,
             0,
             0,
-            MainnetV0::GENESIS_COINBASE_TARGET,
-            MainnetV0::GENESIS_PROOF_TARGET,
+            MainnetV0::genesis_coinbase_target(),
+            MainnetV0::genesis_proof_target(),
             previous_block.last_coinbase_target(),
             previous_block.last_coinbase_timestamp(),
             previous_block.timestamp().saturating_add(time_since_last_block),
@@ -823,6 +1064,7 @@ function compute:
             vm.block_store().current_state_root(),
             transactions.to_transactions_root().unwrap(),
             transactions.to_finalize_root(ratified_finalize_operations).unwrap(),
+            Field::zero(),
             ratifications.to_ratifications_root().unwrap(),
             Field::zero(),
             Field::zero(),
@@ -2572,4 +2814,121 @@ finalize transfer_public_to_private:
         // It should still be possible to insert the 1st block afterwards.
         vm.add_next_block(&block1).unwrap();
     }
+
+    #[cfg(feature = "embedded")]
+    #[test]
+    fn test_atomic_add_block_on_finalize_error() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a genesis private key.
+        let genesis_private_key = sample_genesis_private_key(rng);
+        let caller = Address::try_from(&genesis_private_key).unwrap();
+
+        // Initialize the genesis block.
+        let genesis = sample_genesis_block(rng);
+
+        // Initialize a VM and sample a block with a public transfer using it.
+        let vm = sample_vm();
+        vm.add_next_block(&genesis).unwrap();
+        let inputs = [Value::from_str(&caller.to_string()).unwrap(), Value::from_str("1u64").unwrap()].into_iter();
+        let transaction =
+            vm.execute(&genesis_private_key, ("credits.aleo", "transfer_public"), inputs, None, 0, None, rng).unwrap();
+        let block1 = sample_next_block(&vm, &genesis_private_key, &[transaction], rng).unwrap();
+
+        // Create a new, redb-based VM shadowing the 1st one.
+        let tempdir = tempfile::tempdir().unwrap();
+        let vm = sample_vm_redb(tempdir.path());
+        vm.add_next_block(&genesis).unwrap();
+
+        // Change the public balance of the caller, so that the finalize operations of the transfer no longer match.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from(Literal::Address(caller));
+        let balance = vm.finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap().unwrap();
+        let value = Value::from_str("1u64").unwrap();
+        vm.finalize_store().update_key_value(program_id, mapping_name, key.clone(), value).unwrap();
+
+        // Ensure the block fails to finalize, and is not left behind in storage.
+        assert!(vm.add_next_block(&block1).is_err());
+        assert_eq!(vm.block_store().max_height(), Some(0));
+        assert!(!vm.block_store().contains_block_hash(&block1.hash()).unwrap());
+        assert!(vm.block_store().get_block_hash(1).unwrap().is_none());
+
+        // Restore the public balance, and ensure the block can be added afterwards.
+        vm.finalize_store().update_key_value(program_id, mapping_name, key, balance).unwrap();
+        vm.add_next_block(&block1).unwrap();
+        assert_eq!(vm.block_store().max_height(), Some(1));
+    }
+
+    #[test]
+    fn test_genesis_from_spec() {
+        use crate::{GenesisDelegator, GenesisValidator};
+        use ledger_committee::{MIN_DELEGATOR_STAKE, MIN_VALIDATOR_STAKE};
+
+        let rng = &mut TestRng::default();
+
+        // Initialize the caller, who is also the first validator.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Sample the validators and the delegator.
+        let mut sample_address = || Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let mut validators = vec![GenesisValidator::new(caller, sample_address(), MIN_VALIDATOR_STAKE, true, 5)];
+        for _ in 0..3 {
+            validators.push(GenesisValidator::new(sample_address(), sample_address(), MIN_VALIDATOR_STAKE, false, 0));
+        }
+        let delegator = GenesisDelegator::new(sample_address(), caller, sample_address(), MIN_DELEGATOR_STAKE);
+        // Assign the remaining supply to the caller.
+        let remaining_supply = CurrentNetwork::starting_supply() - 4 * MIN_VALIDATOR_STAKE - MIN_DELEGATOR_STAKE;
+        let public_balances = indexmap::indexmap! { caller => remaining_supply };
+        // Initialize the program to deploy after genesis.
+        let program = Program::from_str(
+            r"
+program hello_genesis.aleo;
+
+function hello:
+    input r0 as u32.public;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+        )
+        .unwrap();
+
+        // Construct the genesis spec.
+        let spec = GenesisSpec::new(validators, vec![delegator], public_balances, vec![program]).unwrap();
+
+        // Construct the genesis block.
+        let vm = sample_vm();
+        let (genesis, summary) = vm.genesis_from_spec(&caller_private_key, &spec, rng).unwrap();
+        assert!(genesis.is_genesis());
+        assert_eq!(summary.block_hash(), genesis.hash());
+        assert_eq!(summary.total_bonded() + summary.total_public(), CurrentNetwork::starting_supply());
+        assert!(summary.to_string().contains("hello_genesis.aleo"));
+
+        // Check the genesis ratification matches the genesis spec.
+        match genesis.ratifications().iter().next() {
+            Some(Ratify::Genesis(committee, public_balances, bonded_balances)) => {
+                assert_eq!(**committee, spec.committee().unwrap());
+                assert_eq!(**public_balances, *spec.public_balances());
+                assert_eq!(**bonded_balances, spec.bonded_balances());
+            }
+            _ => panic!("Expected a genesis ratification"),
+        }
+
+        // Add the genesis block, and check the committee includes the delegated stake.
+        vm.add_next_block(&genesis).unwrap();
+        let committee = vm.finalize_store().committee_store().current_committee().unwrap();
+        assert_eq!(committee.get_stake(caller), MIN_VALIDATOR_STAKE + MIN_DELEGATOR_STAKE);
+        assert_eq!(committee, *summary.committee());
+
+        // Deploy the program in the block following the genesis block.
+        let deployments = vm.genesis_deployments(&caller_private_key, &spec, rng).unwrap();
+        assert_eq!(deployments.len(), 1);
+        let block = sample_next_block(&vm, &caller_private_key, &deployments, rng).unwrap();
+        assert_eq!(block.transactions().num_accepted(), 1);
+        vm.add_next_block(&block).unwrap();
+        assert!(vm.contains_program(&ProgramID::from_str("hello_genesis.aleo").unwrap()));
+
+        // Check the deployments can no longer be constructed.
+        assert!(vm.genesis_deployments(&caller_private_key, &spec, rng).is_err());
+    }
 }
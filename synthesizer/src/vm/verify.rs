@@ -39,6 +39,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     pub(crate) const MAX_PARALLEL_EXECUTE_VERIFICATIONS: usize = 1000;
 
     /// Verifies the list of transactions in the VM. On failure, returns an error.
+    #[cfg_attr(
+        feature = "spans",
+        tracing::instrument(name = "VM::check_transactions", skip_all, fields(num_transactions = transactions.len()))
+    )]
     pub fn check_transactions<R: CryptoRng + Rng>(
         &self,
         transactions: &[(&Transaction<N>, Option<Field<N>>)],
@@ -50,11 +54,17 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let deployments_for_verification = deployments.chunks(Self::MAX_PARALLEL_DEPLOY_VERIFICATIONS);
         let executions_for_verification = executions.chunks(Self::MAX_PARALLEL_EXECUTE_VERIFICATIONS);
 
+        // Retrieve the current span, so that it can be entered from the verification threads.
+        #[cfg(feature = "spans")]
+        let span = tracing::Span::current();
+
         // Verify the transactions in batches.
         for transactions in deployments_for_verification.chain(executions_for_verification) {
             // Ensure each transaction is well-formed and unique.
             let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
             cfg_iter!(transactions).zip(rngs).try_for_each(|((transaction, rejected_id), mut rng)| {
+                #[cfg(feature = "spans")]
+                let _guard = span.enter();
                 self.check_transaction(transaction, *rejected_id, &mut rng)
                     .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
             })?;
@@ -62,6 +72,41 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         Ok(())
     }
+
+    /// Returns the restriction rule that matches the given transaction at the current block height, if any.
+    /// This explains why `VM::check_transaction` rejects a restricted transaction.
+    pub fn explain_restriction(&self, transaction: &Transaction<N>) -> Option<RestrictionMatch<N>> {
+        // Retrieve the block height.
+        let block_height = self.block_store().current_block_height();
+        // Acquire the read lock on the process, to retrieve the imports of the deployed programs.
+        // Note: The process lock is acquired before the restrictions lock, in the same order as in `finalize`.
+        let process = self.process.read();
+        let get_imports = |program_id: &ProgramID<N>| Self::get_program_imports(&process, program_id);
+        // Find the matching restriction rule.
+        self.restrictions.read().explain(transaction, block_height, get_imports)
+    }
+
+    /// Returns the restriction rule that matches the given deployment at the given block height, if any.
+    fn explain_deployment_restriction(
+        &self,
+        deployment: &Deployment<N>,
+        block_height: u32,
+    ) -> Option<RestrictionMatch<N>> {
+        // Acquire the read lock on the process, to retrieve the imports of the deployed programs.
+        // Note: The process lock is acquired before the restrictions lock, in the same order as in `finalize`.
+        let process = self.process.read();
+        let get_imports = |program_id: &ProgramID<N>| Self::get_program_imports(&process, program_id);
+        // Find the matching restriction rule.
+        self.restrictions.read().get(block_height).explain_deployment(deployment, block_height, get_imports)
+    }
+
+    /// Returns the imports of the given program, or no imports if the program is not deployed.
+    fn get_program_imports(process: &Process<N>, program_id: &ProgramID<N>) -> Vec<ProgramID<N>> {
+        match process.get_program(program_id) {
+            Ok(program) => program.imports().keys().copied().collect(),
+            Err(_) => Vec::new(),
+        }
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -74,6 +119,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         rng: &mut R,
     ) -> Result<()> {
         let timer = timer!("VM::check_transaction");
+        #[cfg(feature = "spans")]
+        let _span = transaction_span(transaction).entered();
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
 
         /* Transaction */
 
@@ -163,6 +212,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 if self.contains_program(deployment.program_id()) {
                     bail!("Program ID '{}' already exists", deployment.program_id());
                 }
+                // Ensure the deployment does not import any restricted programs.
+                let block_height = self.block_store().current_block_height();
+                if let Some(restriction) = self.explain_deployment_restriction(deployment, block_height) {
+                    bail!("Invalid deployment transaction '{id}' - {restriction}");
+                }
                 // Verify the deployment if it has not been verified before.
                 if !is_partially_verified {
                     // Verify the deployment.
@@ -197,6 +251,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         }
 
         finish!(timer, "Verify the transaction");
+        #[cfg(feature = "metrics")]
+        metrics::histogram(metrics::vm::CHECK_TRANSACTION_LATENCY, start.elapsed().as_secs_f64());
         Ok(())
     }
 
@@ -309,8 +365,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let block_height = self.block_store().current_block_height();
 
         // Ensure the execution does not contain any restricted transitions.
-        if self.restrictions.contains_restricted_transitions(execution, block_height) {
-            bail!("Execution verification failed - restricted transition found");
+        if let Some(restriction) = self.restrictions.read().get(block_height).explain_execution(execution, block_height)
+        {
+            bail!("Execution verification failed - restricted transition found ({restriction})");
         }
 
         // Verify the execution proof, if it has not been partially-verified before.
@@ -554,7 +611,7 @@ mod tests {
         let deployment_transaction = vm.deploy(&caller_private_key, &program, Some(credits), 10, None, rng).unwrap();
 
         // Construct the new block header.
-        let time_since_last_block = CurrentNetwork::BLOCK_TIME as i64;
+        let time_since_last_block = CurrentNetwork::block_time() as i64;
         let (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations) = vm
             .speculate(
                 sample_finalize_state(1),
@@ -575,8 +632,8 @@ mod tests {
             1,
             0,
             0,
-            CurrentNetwork::GENESIS_COINBASE_TARGET,
-            CurrentNetwork::GENESIS_PROOF_TARGET,
+            CurrentNetwork::genesis_coinbase_target(),
+            CurrentNetwork::genesis_proof_target(),
             genesis.last_coinbase_target(),
             genesis.last_coinbase_timestamp(),
             genesis.timestamp().saturating_add(time_since_last_block),
@@ -587,6 +644,7 @@ mod tests {
             vm.block_store().current_state_root(),
             transactions.to_transactions_root().unwrap(),
             transactions.to_finalize_root(ratified_finalize_operations).unwrap(),
+            Field::zero(),
             ratifications.to_ratifications_root().unwrap(),
             Field::zero(),
             Field::zero(),
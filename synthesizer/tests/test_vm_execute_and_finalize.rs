@@ -90,7 +90,7 @@ fn run_test(test: &ProgramTest) -> serde_yaml::Mapping {
                 rng,
             )
             .unwrap();
-        let time_since_last_block = CurrentNetwork::BLOCK_TIME as i64;
+        let time_since_last_block = CurrentNetwork::block_time() as i64;
         let (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations) = vm
             .speculate(
                 construct_finalize_global_state(&vm),
@@ -137,7 +137,7 @@ fn run_test(test: &ProgramTest) -> serde_yaml::Mapping {
             }
         };
 
-        let time_since_last_block = CurrentNetwork::BLOCK_TIME as i64;
+        let time_since_last_block = CurrentNetwork::block_time() as i64;
         let (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations) = vm
             .speculate(
                 construct_finalize_global_state(&vm),
@@ -286,7 +286,7 @@ fn run_test(test: &ProgramTest) -> serde_yaml::Mapping {
             );
 
             // Speculate on the ratifications, solutions, and transaction.
-            let time_since_last_block = CurrentNetwork::BLOCK_TIME as i64;
+            let time_since_last_block = CurrentNetwork::block_time() as i64;
             let (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations) = match vm
                 .speculate(
                     construct_finalize_global_state(&vm),
@@ -431,7 +431,7 @@ fn construct_fee_records<C: ConsensusStorage<CurrentNetwork>, R: Rng + CryptoRng
             }
         }
 
-        let time_since_last_block = CurrentNetwork::BLOCK_TIME as i64;
+        let time_since_last_block = CurrentNetwork::block_time() as i64;
         let (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations) = vm
             .speculate(
                 construct_finalize_global_state(vm),
@@ -488,8 +488,8 @@ fn construct_next_block<C: ConsensusStorage<CurrentNetwork>, R: Rng + CryptoRng>
         previous_block.height() + 1,
         0,
         0,
-        CurrentNetwork::GENESIS_COINBASE_TARGET,
-        CurrentNetwork::GENESIS_PROOF_TARGET,
+        CurrentNetwork::genesis_coinbase_target(),
+        CurrentNetwork::genesis_proof_target(),
         previous_block.last_coinbase_target(),
         previous_block.last_coinbase_timestamp(),
         previous_block.timestamp().saturating_add(time_since_last_block),
@@ -499,6 +499,7 @@ fn construct_next_block<C: ConsensusStorage<CurrentNetwork>, R: Rng + CryptoRng>
         vm.block_store().current_state_root(),
         transactions.to_transactions_root().unwrap(),
         transactions.to_finalize_root(ratified_finalize_operations).unwrap(),
+        Field::zero(),
         ratifications.to_ratifications_root().unwrap(),
         Field::zero(),
         Field::zero(),
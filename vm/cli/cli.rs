@@ -13,6 +13,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "rocks")]
+use crate::cli::commands::VerifyLedger;
 use crate::cli::commands::{Build, Clean, Execute, New, Run, Update};
 
 use anstyle::{AnsiColor, Color, Style};
@@ -51,6 +53,9 @@ pub enum Command {
     Run(Run),
     #[clap(name = "update")]
     Update(Update),
+    #[cfg(feature = "rocks")]
+    #[clap(name = "verify-ledger")]
+    VerifyLedger(VerifyLedger),
 }
 
 impl Command {
@@ -63,6 +68,8 @@ impl Command {
             Self::New(command) => command.parse(),
             Self::Run(command) => command.parse(),
             Self::Update(command) => command.parse(),
+            #[cfg(feature = "rocks")]
+            Self::VerifyLedger(command) => command.parse(),
         }
     }
 }
@@ -31,6 +31,11 @@ pub use run::*;
 pub mod update;
 pub use update::*;
 
+#[cfg(feature = "rocks")]
+pub mod verify_ledger;
+#[cfg(feature = "rocks")]
+pub use verify_ledger::*;
+
 use crate::{
     console::program::{Identifier, Locator, ProgramID, Value},
     ledger::block::Transaction,
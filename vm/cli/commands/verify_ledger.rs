@@ -0,0 +1,79 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    console::network::{CanaryV0, DevnetV0, MainnetV0, Network, TestnetV0},
+    ledger::{Ledger, store::helpers::rocksdb::ConsensusDB},
+    prelude::{Block, FromBytes},
+};
+
+use aleo_std::StorageMode;
+use std::path::PathBuf;
+
+/// Verifies the integrity of a RocksDB ledger.
+#[derive(Debug, Parser)]
+pub struct VerifyLedger {
+    /// The network ID of the ledger [options: 0 = mainnet, 1 = testnet, 2 = canary, 3 = devnet].
+    #[clap(default_value = "0", long)]
+    network: u16,
+    /// The path to the ledger storage directory.
+    #[clap(long)]
+    path: PathBuf,
+    /// Succeeds if the ledger is consistent, even if the roots of some blocks could not be checked
+    /// (e.g. blocks that predate the ratified operations, or whose rollback entries were pruned).
+    #[clap(long)]
+    allow_unchecked: bool,
+}
+
+impl VerifyLedger {
+    /// Verifies the integrity of the ledger.
+    pub fn parse(self) -> Result<String> {
+        match self.network {
+            MainnetV0::ID => Self::verify::<MainnetV0>(self.path, self.allow_unchecked),
+            TestnetV0::ID => Self::verify::<TestnetV0>(self.path, self.allow_unchecked),
+            CanaryV0::ID => Self::verify::<CanaryV0>(self.path, self.allow_unchecked),
+            DevnetV0::ID => Self::verify::<DevnetV0>(self.path, self.allow_unchecked),
+            network => anyhow::bail!("Unknown network ID '{network}'"),
+        }
+    }
+
+    /// Verifies the integrity of the ledger for the given network.
+    /// Returns an error if the ledger is inconsistent, or if some blocks are unchecked and `allow_unchecked` is unset.
+    fn verify<N: Network>(path: PathBuf, allow_unchecked: bool) -> Result<String> {
+        // Ensure the ledger exists, as loading a ledger from an empty directory initializes a new one.
+        if !path.exists() {
+            anyhow::bail!("The ledger does not exist at \"{}\"", path.display());
+        }
+        // Load the genesis block.
+        let genesis_block = Block::<N>::from_bytes_le(N::genesis_bytes())?;
+        // Load the ledger, ensuring it contains the genesis block of the given network.
+        let ledger = Ledger::<N, ConsensusDB<N>>::load(genesis_block, StorageMode::Custom(path.clone()))?;
+        // Verify the integrity of the ledger.
+        let report = ledger.verify_integrity()?;
+
+        // Prepare the path string.
+        let path_string = format!("(in \"{}\")", path.display());
+
+        match (report.is_consistent(), report.is_fully_checked() || allow_unchecked) {
+            (true, true) => Ok(format!("✅ {report} {}", path_string.dimmed())),
+            (true, false) => anyhow::bail!(
+                "{report} {}\n(rerun with '--allow-unchecked' to accept the unchecked blocks)",
+                path_string.dimmed()
+            ),
+            (false, _) => anyhow::bail!("❌ {report} {}", path_string.dimmed()),
+        }
+    }
+}
@@ -25,7 +25,11 @@ fn main() -> anyhow::Result<()> {
     // Run the CLI.
     match cli.command.parse() {
         Ok(output) => println!("{output}\n"),
-        Err(error) => println!("⚠️  {error}\n"),
+        Err(error) => {
+            println!("⚠️  {error}\n");
+            // Exit with a failure status, so that scripts can detect the error.
+            std::process::exit(1);
+        }
     }
     Ok(())
 }